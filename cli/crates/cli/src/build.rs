@@ -7,6 +7,6 @@ use crate::{cli_input::LogLevelFilters, errors::CliError};
 pub fn build(parallelism: NonZeroUsize, tracing: bool) -> Result<(), CliError> {
     trace!("attempting to build server");
     crate::start::run(LogLevelFilters::default(), |message_sender| {
-        server::ProductionServer::build(message_sender, parallelism, tracing).map_ok(|_| ())
+        server::ProductionServer::build(message_sender, parallelism, false, tracing).map_ok(|_| ())
     })
 }
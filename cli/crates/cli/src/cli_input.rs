@@ -56,6 +56,9 @@ pub struct DevCommand {
     /// Do not listen for schema changes and reload
     #[arg(long)]
     pub disable_watch: bool,
+    /// Build connectors from the introspection results cached by previous runs, without network access
+    #[arg(long)]
+    pub offline: bool,
     /// Log level to print from function invocations, defaults to 'log-level'
     #[arg(long, value_name = "FUNCTION_LOG_LEVEL")]
     pub log_level_functions: Option<LogLevelFilter>,
@@ -71,6 +74,12 @@ pub struct DevCommand {
     /// A shortcut to enable fairly detailed logging
     #[arg(short, long, conflicts_with = "log_level")]
     pub verbose: bool,
+    /// Export traces and metrics to this OpenTelemetry collector endpoint
+    #[arg(long, value_name = "URL")]
+    pub otlp_endpoint: Option<String>,
+    /// The protocol used to export to the OpenTelemetry collector
+    #[arg(long, value_enum, default_value_t = OtlpProtocol::Grpc, requires = "otlp_endpoint")]
+    pub otlp_protocol: OtlpProtocol,
 }
 
 impl DevCommand {
@@ -258,6 +267,24 @@ pub struct StartCommand {
     /// IP address on which the server will listen for incomming connections. Defaults to 127.0.0.1.
     #[arg(long)]
     pub listen_address: Option<IpAddr>,
+    /// Build connectors from the introspection results cached by previous runs, without network access
+    #[arg(long)]
+    pub offline: bool,
+    /// Export traces and metrics to this OpenTelemetry collector endpoint
+    #[arg(long, value_name = "URL")]
+    pub otlp_endpoint: Option<String>,
+    /// The protocol used to export to the OpenTelemetry collector
+    #[arg(long, value_enum, default_value_t = OtlpProtocol::Grpc, requires = "otlp_endpoint")]
+    pub otlp_protocol: OtlpProtocol,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[clap(rename_all = "lowercase")]
+pub enum OtlpProtocol {
+    /// OTLP over gRPC, usually on port 4317
+    Grpc,
+    /// OTLP over HTTP with protobuf payloads, usually on port 4318
+    Http,
 }
 
 impl StartCommand {
@@ -377,6 +404,8 @@ impl ArgumentNames for DevCommand {
             (self.port != DEFAULT_PORT, "port"),
             (self.search, "search"),
             (self.disable_watch, "disable-watch"),
+            (self.offline, "offline"),
+            (self.otlp_endpoint.is_some(), "otlp-endpoint"),
         ])
     }
 }
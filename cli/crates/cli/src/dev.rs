@@ -19,6 +19,7 @@ static READY: Once = Once::new();
 pub fn dev(
     search: bool,
     watch: bool,
+    offline: bool,
     external_port: u16,
     log_level_filters: LogLevelFilters,
     tracing: bool,
@@ -28,7 +29,7 @@ pub fn dev(
     trace!("attempting to start server");
     let (message_sender, mut message_receiver) = tokio::sync::mpsc::unbounded_channel::<ServerMessage>();
 
-    let server = server::start(external_port, search, watch, tracing, message_sender);
+    let server = server::start(external_port, search, watch, offline, tracing, message_sender);
     let reporter = async move {
         let mut resolvers_reported = false;
 
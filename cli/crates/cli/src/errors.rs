@@ -52,6 +52,9 @@ pub enum CliError {
     LogsNoLinkedProject,
     #[error("error during graph introspection: {0}")]
     Introspection(String),
+    /// returned if the OpenTelemetry exporter could not be set up
+    #[error("could not set up the OpenTelemetry exporter\nCaused by: {0}")]
+    Telemetry(String),
 }
 
 #[cfg(target_family = "windows")]
@@ -18,6 +18,7 @@ mod prompts;
 mod reset;
 mod start;
 mod subgraph;
+mod telemetry;
 mod unlink;
 mod watercolor;
 
@@ -26,7 +27,7 @@ extern crate log;
 
 use crate::{
     build::build,
-    cli_input::{Args, ArgumentNames, LogsCommand, SubCommand},
+    cli_input::{Args, ArgumentNames, DevCommand, LogsCommand, StartCommand, SubCommand},
     create::create,
     deploy::deploy,
     dev::dev,
@@ -45,7 +46,8 @@ use common::{analytics::Analytics, environment::Environment};
 use errors::CliError;
 use output::report;
 use std::process;
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+use telemetry::Telemetry;
+use tracing_subscriber::{filter::LevelFilter, fmt, prelude::*, EnvFilter};
 use watercolor::ShouldColorize;
 
 fn main() {
@@ -68,7 +70,32 @@ fn main() {
 fn try_main(args: Args) -> Result<(), CliError> {
     let filter = EnvFilter::builder().parse_lossy(args.log_filter());
 
-    tracing_subscriber::registry().with(fmt::layer()).with(filter).init();
+    let telemetry = match &args.command {
+        SubCommand::Dev(DevCommand {
+            otlp_endpoint,
+            otlp_protocol,
+            ..
+        })
+        | SubCommand::Start(StartCommand {
+            otlp_endpoint,
+            otlp_protocol,
+            ..
+        }) => otlp_endpoint
+            .as_deref()
+            .map(|endpoint| Telemetry::init(endpoint, *otlp_protocol))
+            .transpose()?,
+        _ => None,
+    };
+    let telemetry_layer = telemetry.as_ref().map(|telemetry| {
+        tracing_opentelemetry::layer()
+            .with_tracer(telemetry.tracer())
+            .with_filter(LevelFilter::INFO)
+    });
+
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_filter(filter))
+        .with(telemetry_layer)
+        .init();
     trace!("subcommand: {}", args.command);
 
     // do not display header if we're in a pipe
@@ -96,6 +123,8 @@ fn try_main(args: Args) -> Result<(), CliError> {
         SubCommand::Dev(cmd) => {
             // ignoring any errors to fall back to the normal handler if there's an issue
             let _set_handler_result = ctrlc::set_handler(|| {
+                // exports the spans & metrics that are still buffered, a no-op without an OTLP endpoint
+                telemetry::shutdown();
                 report::goodbye();
                 process::exit(exitcode::OK);
             });
@@ -103,6 +132,7 @@ fn try_main(args: Args) -> Result<(), CliError> {
             dev(
                 cmd.search,
                 !cmd.disable_watch,
+                cmd.offline,
                 cmd.port,
                 cmd.log_levels(),
                 args.trace >= 2,
@@ -123,11 +153,19 @@ fn try_main(args: Args) -> Result<(), CliError> {
         }) => logs(project_branch, limit, !no_follow),
         SubCommand::Start(cmd) => {
             let _ = ctrlc::set_handler(|| {
+                // exports the spans & metrics that are still buffered, a no-op without an OTLP endpoint
+                telemetry::shutdown();
                 report::goodbye();
                 process::exit(exitcode::OK);
             });
 
-            start(cmd.listen_address(), cmd.port, cmd.log_levels(), args.trace >= 2)
+            start(
+                cmd.listen_address(),
+                cmd.port,
+                cmd.log_levels(),
+                cmd.offline,
+                args.trace >= 2,
+            )
         }
         SubCommand::Build(cmd) => {
             let _ = ctrlc::set_handler(|| {
@@ -10,7 +10,7 @@ use backend::{
 };
 use colored::Colorize;
 use common::consts::GRAFBASE_TS_CONFIG_FILE_NAME;
-use common::types::{LogLevel, UdfKind};
+use common::types::{CircuitBreakerState, LogLevel, UdfKind};
 use common::{
     consts::{GRAFBASE_DIRECTORY_NAME, GRAFBASE_SCHEMA_FILE_NAME, LOCALHOST},
     environment::Warning,
@@ -321,6 +321,23 @@ fn log_nested_events(nested_events: Vec<NestedRequestScopedMessage>, log_level_f
                     }
                 }
             }
+            NestedRequestScopedMessage::CircuitBreakerStateChanged { connector, state } => {
+                let (required_log_level, status) = match state {
+                    CircuitBreakerState::Closed => (LogLevel::Info, watercolor!("closed", @Green)),
+                    CircuitBreakerState::HalfOpen => (LogLevel::Warn, watercolor!("half open", @Yellow)),
+                    CircuitBreakerState::Open => (LogLevel::Error, watercolor!("open", @Red)),
+                };
+
+                if !log_level_filters.fetch_requests.should_display(required_log_level) {
+                    continue;
+                }
+
+                println!(
+                    "{indent}{} {} {status}",
+                    watercolor!("circuit breaker", @Yellow),
+                    connector.bold(),
+                );
+            }
         }
     }
 }
@@ -27,6 +27,7 @@ pub fn start(
     listen_address: IpAddr,
     port: u16,
     log_level_filters: LogLevelFilters,
+    offline: bool,
     tracing: bool,
 ) -> Result<(), CliError> {
     trace!("attempting to start server");
@@ -34,7 +35,7 @@ pub fn start(
         // not sure we'll keep building in the start command, so keeping the same behavior as
         // before building UDFs serially.
         let parallelism = NonZeroUsize::new(1).expect("strictly positive");
-        server::ProductionServer::build(message_sender, parallelism, tracing)
+        server::ProductionServer::build(message_sender, parallelism, offline, tracing)
             .await?
             .serve(listen_address, port)
             .await
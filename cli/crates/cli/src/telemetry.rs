@@ -0,0 +1,89 @@
+use crate::{cli_input::OtlpProtocol, errors::CliError};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::{MetricsExporterBuilder, SpanExporterBuilder, WithExportConfig};
+use opentelemetry_sdk::{
+    metrics::SdkMeterProvider, propagation::TraceContextPropagator, runtime, trace::Tracer, Resource,
+};
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+// kept around for `shutdown`, as there's no global shutdown for the meter provider like for the tracer one
+static METER_PROVIDER: OnceLock<SdkMeterProvider> = OnceLock::new();
+
+/// Exports the spans & metrics of the server to an OpenTelemetry collector for as long as it's kept
+/// around, flushing whatever is still buffered when dropped.
+pub struct Telemetry {
+    tracer: Tracer,
+    // the exporters run in the background on this runtime, separate from the server's one
+    runtime: Runtime,
+}
+
+impl Telemetry {
+    pub fn init(endpoint: &str, protocol: OtlpProtocol) -> Result<Self, CliError> {
+        let runtime = Runtime::new().map_err(|error| CliError::Telemetry(error.to_string()))?;
+        let _guard = runtime.enter();
+
+        let resource = Resource::new([KeyValue::new("service.name", "grafbase")]);
+
+        let (span_exporter, metrics_exporter): (SpanExporterBuilder, MetricsExporterBuilder) = match protocol {
+            OtlpProtocol::Grpc => (
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint)
+                    .into(),
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint)
+                    .into(),
+            ),
+            OtlpProtocol::Http => (
+                opentelemetry_otlp::new_exporter().http().with_endpoint(endpoint).into(),
+                opentelemetry_otlp::new_exporter().http().with_endpoint(endpoint).into(),
+            ),
+        };
+
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(span_exporter)
+            .with_trace_config(opentelemetry_sdk::trace::config().with_resource(resource.clone()))
+            .install_batch(runtime::Tokio)
+            .map_err(|error| CliError::Telemetry(error.to_string()))?;
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(runtime::Tokio)
+            .with_exporter(metrics_exporter)
+            .with_resource(resource)
+            .build()
+            .map_err(|error| CliError::Telemetry(error.to_string()))?;
+
+        opentelemetry::global::set_meter_provider(meter_provider.clone());
+        let _ = METER_PROVIDER.set(meter_provider);
+        // connectors forward the trace context to the downstream servers in a `traceparent` header
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+        Ok(Self { tracer, runtime })
+    }
+
+    pub fn tracer(&self) -> Tracer {
+        self.tracer.clone()
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        let _guard = self.runtime.enter();
+
+        shutdown();
+    }
+}
+
+/// Exports the spans & metrics that are still buffered, for when the process exits without dropping
+/// [`Telemetry`]. A no-op if it wasn't initialized.
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+    if let Some(meter_provider) = METER_PROVIDER.get() {
+        if let Err(error) = meter_provider.shutdown() {
+            trace!("could not flush the OpenTelemetry metrics: {error}");
+        }
+    }
+}
@@ -10,6 +10,7 @@ mod utils;
 
 mod headers;
 mod server;
+mod telemetry;
 mod transforms;
 
 const NAMESPACED_QUERY: &str = "
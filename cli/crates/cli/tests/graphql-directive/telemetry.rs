@@ -0,0 +1,114 @@
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{extract::State, http::Uri, routing::post, Router};
+use backend::project::ConfigType;
+use serde_json::Value;
+
+use crate::{server, utils::environment::Environment};
+
+/// Stands in for an OpenTelemetry collector, recording the paths the exports were sent to.
+async fn run_collector() -> (u16, Arc<Mutex<Vec<String>>>) {
+    let exports = Arc::new(Mutex::new(Vec::new()));
+
+    let app = Router::new()
+        .route("/v1/traces", post(record_export))
+        .route("/v1/metrics", post(record_export))
+        .with_state(Arc::clone(&exports));
+
+    let socket = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = socket.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        axum::Server::from_tcp(socket)
+            .unwrap()
+            .serve(app.into_make_service())
+            .await
+            .unwrap();
+    });
+
+    (port, exports)
+}
+
+async fn record_export(State(exports): State<Arc<Mutex<Vec<String>>>>, uri: Uri) {
+    exports.lock().unwrap().push(uri.path().to_string());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_otlp_export() {
+    otlp_export(Environment::grafbase_start_with_args).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_otlp_export_dev() {
+    otlp_export(Environment::grafbase_dev_with_args).await;
+}
+
+async fn otlp_export(run: fn(&mut Environment, &[&str])) {
+    let port = server::run().await;
+    let (collector_port, exports) = run_collector().await;
+
+    let mut env = Environment::init_async().await;
+    env.grafbase_init(ConfigType::GraphQL);
+    env.write_schema(format!(
+        r#"
+          extend schema
+          @graphql(
+            name: "Test",
+            namespace: false,
+            url: "http://127.0.0.1:{port}",
+            schema: "http://127.0.0.1:{port}/spec.json",
+          )
+        "#
+    ));
+    let collector_url = format!("http://127.0.0.1:{collector_port}");
+    run(
+        &mut env,
+        &["--otlp-endpoint", &collector_url, "--otlp-protocol", "http"],
+    );
+
+    let client = env.create_async_client().with_api_key();
+    client.poll_endpoint(30, 300).await;
+
+    let response = client
+        .gql::<Value>(
+            r#"
+                query {
+                    headers {
+                        name
+                        value
+                    }
+                }
+        "#,
+        )
+        .await;
+
+    // The connector request carries the W3C trace context of its span
+    let traceparent = response["data"]["headers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|header| header["name"] == "traceparent")
+        .and_then(|header| header["value"].as_str())
+        .expect("the traceparent header to be forwarded");
+
+    let parts = traceparent.split('-').collect::<Vec<_>>();
+    assert_eq!(parts.len(), 4, "{traceparent}");
+    assert_eq!(parts[0], "00");
+    assert_eq!(parts[1].len(), 32);
+    assert_eq!(parts[2].len(), 16);
+    assert_eq!(parts[3], "01");
+
+    // Spans are exported in batches every few seconds
+    for _ in 0..60 {
+        if exports.lock().unwrap().iter().any(|path| path == "/v1/traces") {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    panic!("no spans were exported to the collector");
+}
@@ -0,0 +1,128 @@
+//! Tests of specs loaded from disk & of the offline connector cache
+
+use std::net::SocketAddr;
+
+use backend::project::ConfigType;
+use serde_json::Value;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::utils::environment::Environment;
+
+use super::{doggie, mount_petstore_spec, petstore_schema, start_grafbase};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spec_from_a_local_file() {
+    let mock_server = wiremock::MockServer::start().await;
+
+    let mut env = Environment::init_async().await;
+    env.grafbase_init(ConfigType::GraphQL);
+    env.write_file("specs/petstore.json", include_str!("petstore.json"));
+    env.write_schema(local_petstore_schema(mock_server.address()));
+    env.grafbase_dev();
+
+    let client = env.create_async_client().with_api_key();
+    client.poll_endpoint(30, 300).await;
+
+    Mock::given(method("GET"))
+        .and(path("/pet/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(doggie()))
+        .mount(&mock_server)
+        .await;
+
+    insta::assert_yaml_snapshot!(
+        client
+            .gql::<Value>(
+                r#"
+                    query {
+                        petstore {
+                            pet(petId: 123) {
+                                id
+                                name
+                            }
+                        }
+                    }
+                "#,
+            )
+            .await,
+        @r###"
+    ---
+    data:
+      petstore:
+        pet:
+          id: 123
+          name: doggie
+    "###
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_offline_uses_the_cached_spec() {
+    let mock_server = wiremock::MockServer::start().await;
+    mount_petstore_spec(&mock_server).await;
+
+    let mut env = Environment::init_async().await;
+    start_grafbase(&mut env, petstore_schema(mock_server.address())).await;
+    env.kill_processes();
+
+    // Nothing serves the spec anymore, so it can only come from the cache.
+    mock_server.reset().await;
+
+    Mock::given(method("GET"))
+        .and(path("/pet/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(doggie()))
+        .mount(&mock_server)
+        .await;
+
+    env.grafbase_dev_offline();
+    let client = env.create_async_client().with_api_key();
+    client.poll_endpoint(30, 300).await;
+
+    insta::assert_yaml_snapshot!(
+        client
+            .gql::<Value>(
+                r#"
+                    query {
+                        petstore {
+                            pet(petId: 123) {
+                                id
+                                name
+                            }
+                        }
+                    }
+                "#,
+            )
+            .await,
+        @r###"
+    ---
+    data:
+      petstore:
+        pet:
+          id: 123
+          name: doggie
+    "###
+    );
+
+    assert!(!mock_server
+        .received_requests()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .any(|request| request.url.path() == "/spec.json"));
+}
+
+fn local_petstore_schema(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            namespace: true,
+            url: "http://{address}",
+            schema: "specs/petstore.json",
+          )
+        "#
+    )
+}
@@ -5,6 +5,7 @@ mod utils;
 mod headers;
 mod http_spy;
 mod introspection_headers;
+mod local_spec;
 mod remote_unions;
 mod transforms;
 
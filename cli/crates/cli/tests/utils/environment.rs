@@ -311,16 +311,15 @@ impl Environment {
     }
 
     pub fn grafbase_dev(&mut self) {
-        let command = cmd!(
-            cargo_bin("grafbase"),
-            "--trace",
-            "2",
-            "dev",
-            "--disable-watch",
-            "--port",
-            self.port.to_string()
-        )
-        .dir(&self.directory);
+        self.grafbase_dev_with_args(&[]);
+    }
+
+    pub fn grafbase_dev_with_args(&mut self, args: &[&str]) {
+        let port = self.port.to_string();
+        let mut all_args = vec!["--trace", "2", "dev", "--disable-watch", "--port", &port];
+        all_args.extend_from_slice(args);
+
+        let command = duct::cmd(cargo_bin("grafbase"), all_args).dir(&self.directory);
         #[cfg(feature = "dynamodb")]
         let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
         let command = command.start().unwrap();
@@ -329,15 +328,15 @@ impl Environment {
     }
 
     pub fn grafbase_start(&mut self) {
-        let command = cmd!(
-            cargo_bin("grafbase"),
-            "--trace",
-            "2",
-            "start",
-            "--port",
-            self.port.to_string()
-        )
-        .dir(&self.directory);
+        self.grafbase_start_with_args(&[]);
+    }
+
+    pub fn grafbase_start_with_args(&mut self, args: &[&str]) {
+        let port = self.port.to_string();
+        let mut all_args = vec!["--trace", "2", "start", "--port", &port];
+        all_args.extend_from_slice(args);
+
+        let command = duct::cmd(cargo_bin("grafbase"), all_args).dir(&self.directory);
         #[cfg(feature = "dynamodb")]
         let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
         let command = command.start().unwrap();
@@ -421,6 +420,25 @@ impl Environment {
         self.commands.push(command);
     }
 
+    pub fn grafbase_dev_offline(&mut self) {
+        let command = cmd!(
+            cargo_bin("grafbase"),
+            "--trace",
+            "2",
+            "dev",
+            "--disable-watch",
+            "--offline",
+            "--port",
+            self.port.to_string()
+        )
+        .dir(&self.directory);
+        #[cfg(feature = "dynamodb")]
+        let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
+        let command = command.start().unwrap();
+
+        self.commands.push(command);
+    }
+
     pub fn append_to_schema(&self, contents: &'static str) {
         let mut file = fs::OpenOptions::new().append(true).open(&self.schema_path).unwrap();
 
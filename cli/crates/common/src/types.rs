@@ -1,4 +1,4 @@
-pub use common_types::UdfKind;
+pub use common_types::{CircuitBreakerState, UdfKind};
 use std::net::Ipv4Addr;
 
 #[derive(Clone, Copy)]
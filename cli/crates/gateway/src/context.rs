@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, net::IpAddr, sync::Arc};
 
 use futures_util::future::BoxFuture;
 use gateway_core::serving::{AUTHORIZATION_HEADER, X_API_KEY_HEADER};
@@ -10,6 +10,7 @@ pub struct Context {
     pub(crate) x_api_key_header: Option<String>,
     pub(crate) authorization_header: Option<String>,
     pub(crate) headers: HeaderMap,
+    pub(crate) client_ip: Option<IpAddr>,
     // TODO: or use a queue?
     wait_until_sender: UnboundedSender<BoxFuture<'static, ()>>,
 }
@@ -18,6 +19,7 @@ impl Context {
     pub(crate) fn new(
         headers: HeaderMap,
         params: &HashMap<String, String>,
+        client_ip: Option<IpAddr>,
         wait_until_sender: UnboundedSender<BoxFuture<'static, ()>>,
     ) -> Arc<Self> {
         Arc::new(crate::Context {
@@ -31,6 +33,7 @@ impl Context {
                 .and_then(|value| value.to_str().ok().map(ToString::to_string))
                 .or_else(|| params.get(AUTHORIZATION_HEADER).cloned()),
             headers,
+            client_ip,
             wait_until_sender,
         })
     }
@@ -52,4 +55,8 @@ impl gateway_core::RequestContext for Context {
     fn headers(&self) -> &HeaderMap {
         &self.headers
     }
+
+    fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
 }
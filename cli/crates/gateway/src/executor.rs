@@ -3,16 +3,31 @@ use std::{collections::HashMap, sync::Arc};
 use axum::response::IntoResponse;
 use common_types::auth::ExecutionAuth;
 use dynamodb::{DynamoDBBatchersData, DynamoDBContext};
-use engine::{registry::resolvers::graphql, RequestHeaders};
+use engine::{
+    registry::resolvers::{custom::CustomResolverBatcher, graphql, http_client::HttpClient, join::JoinBatcher},
+    RequestHeaders,
+};
 use gateway_core::{RequestContext, StreamingFormat};
-use graphql_extensions::{authorization::AuthExtension, runtime_log::RuntimeLogExtension};
-use runtime_local::{Bridge, LocalPgTransportFactory, LocalSearchEngine, UdfInvokerImpl};
+use graphql_extensions::{
+    apollo_tracing::ApolloTracing, authorization::AuthExtension, runtime_log::RuntimeLogExtension,
+};
+use runtime::{kv::Kv, mongodb::MongoTransport, pg::PgTransportFactory};
+use runtime_local::{
+    Bridge, InMemoryKv, LocalMongoTransport, LocalPgTransportFactory, LocalSearchEngine, UdfInvokerImpl,
+};
 
 pub struct Executor {
     #[allow(dead_code)]
     env_vars: HashMap<String, String>,
     bridge: Bridge,
     registry: Arc<engine::Registry>,
+    // shared between requests, so database connections are pooled across them.
+    pg_transport_factory: PgTransportFactory,
+    mongo_transport: MongoTransport,
+    // shared between requests, so cached values such as OAuth2 tokens outlive a request.
+    kv: Kv,
+    // shared between requests, so connections to connector APIs are reused.
+    http_client: HttpClient,
 }
 
 impl Executor {
@@ -21,6 +36,10 @@ impl Executor {
             env_vars,
             bridge,
             registry,
+            pg_transport_factory: LocalPgTransportFactory::runtime_factory(),
+            mongo_transport: LocalMongoTransport::runtime_transport(),
+            kv: InMemoryKv::runtime_kv(),
+            http_client: HttpClient::new(),
         }
     }
 
@@ -108,17 +127,23 @@ impl Executor {
 
         Ok(engine::Schema::build(engine::Registry::clone(&self.registry))
             .data(dynamodb_batchers_data)
-            .data(graphql::QueryBatcher::new())
+            .data(graphql::QueryBatcher::with_client(self.http_client.clone()))
+            .data(JoinBatcher::new())
             .data(search_engine)
+            .data(CustomResolverBatcher::new(resolver_engine.clone()))
             .data(resolver_engine)
             .data(auth)
-            .data(LocalPgTransportFactory::runtime_factory())
+            .data(self.pg_transport_factory.clone())
+            .data(self.mongo_transport.clone())
+            .data(self.kv.clone())
+            .data(self.http_client.clone())
             .data(RequestHeaders::from(&ctx.headers_as_map()))
             .data(runtime_ctx)
             .extension(RuntimeLogExtension::new(Box::new(
                 runtime_local::LogEventReceiverImpl::new(self.bridge.clone()),
             )))
             .extension(AuthExtension::new(ctx.ray_id().to_string()))
+            .extension(ApolloTracing)
             .finish())
     }
 }
@@ -1,6 +1,6 @@
 use engine::registry::CachePartialRegistry;
-use gateway_core::{CacheConfig, CacheControl};
-use runtime_local::InMemoryCache;
+use gateway_core::{CacheConfig, CacheControl, RateLimiter};
+use runtime_local::{InMemoryCache, InMemoryKv};
 use std::{collections::HashMap, ops::Deref, sync::Arc};
 
 use self::executor::Executor;
@@ -39,14 +39,18 @@ impl Gateway {
             auth_config: registry.auth.clone(),
             bridge: bridge.clone(),
         });
+        let rate_limiter = RateLimiter::new(Arc::clone(&registry), InMemoryKv::default());
         let executor = Arc::new(Executor::new(env_vars, bridge, registry));
         Gateway {
-            inner: Arc::new(gateway_core::Gateway::new(
-                executor,
-                Arc::new(InMemoryCache::<engine::Response>::new()),
-                cache_config,
-                authorizer,
-            )),
+            inner: Arc::new(
+                gateway_core::Gateway::new(
+                    executor,
+                    Arc::new(InMemoryCache::<engine::Response>::new()),
+                    cache_config,
+                    authorizer,
+                )
+                .with_rate_limiter(rate_limiter),
+            ),
         }
     }
 
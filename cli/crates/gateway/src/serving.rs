@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr};
 
 use axum::{
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
     response::IntoResponse,
     routing::post,
     Router,
@@ -27,6 +27,7 @@ pub(super) fn router(gateway: Gateway) -> Router {
 
 async fn post_graphql(
     State(gateway): State<Gateway>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
     headers: HeaderMap,
     Query(params): Query<HashMap<String, String>>,
     body: Bytes,
@@ -37,7 +38,8 @@ async fn post_graphql(
         .and_then(|value| value.to_str().ok())
         .and_then(StreamingFormat::from_accept_header);
     let (sender, receiver) = mpsc::unbounded_channel();
-    let ctx = crate::Context::new(headers, &params, sender);
+    let client_ip = connect_info.map(|ConnectInfo(address)| address.ip());
+    let ctx = crate::Context::new(headers, &params, client_ip, sender);
     // FIXME: Pathfinder doesn't send the proper content-type, so axum complains about it.
     let request: engine::Request = match serde_json::from_slice(&body[..]) {
         Ok(req) => req,
@@ -52,6 +54,7 @@ async fn post_graphql(
 
 async fn get_graphql(
     State(gateway): State<Gateway>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
     headers: HeaderMap,
     Query(mut params): Query<HashMap<String, String>>,
 ) -> crate::Response {
@@ -60,7 +63,8 @@ async fn get_graphql(
         .and_then(|value| value.to_str().ok())
         .and_then(StreamingFormat::from_accept_header);
     let (sender, receiver) = mpsc::unbounded_channel();
-    let ctx = crate::Context::new(headers, &params, sender);
+    let client_ip = connect_info.map(|ConnectInfo(address)| address.ip());
+    let ctx = crate::Context::new(headers, &params, client_ip, sender);
 
     let Some(query) = params.remove(QUERY_REQUEST_PARAMETER) else {
         return Error::BadRequest("Missing 'query' parameter".into()).into();
@@ -54,6 +54,12 @@ pub async fn log_event_endpoint(
                 duration,
                 body,
             }),
+            InputLogEventType::CircuitBreakerStateChanged { connector, state } => {
+                OutputLogEventType::NestedEvent(crate::types::NestedRequestScopedMessage::CircuitBreakerStateChanged {
+                    connector,
+                    state,
+                })
+            }
         },
     };
     handler_state.message_sender.send(message).unwrap();
@@ -111,6 +111,10 @@ pub enum LogEventType {
         duration: std::time::Duration,
         body: Option<String>,
     },
+    CircuitBreakerStateChanged {
+        connector: String,
+        state: common::types::CircuitBreakerState,
+    },
 }
 
 #[derive(Deserialize, Debug)]
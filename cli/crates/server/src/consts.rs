@@ -12,3 +12,4 @@ pub const WRAPPER_WORKER_JS_PATH: &str = "custom-resolvers/wrapper-worker.js";
 pub const GRAFBASE_WASM_SDK_NAME: &str = "grafbase-wasm-sdk_bg.wasm";
 pub const GRAFBASE_WASM_SDK_PATH: &str = "custom-resolvers/grafbase-wasm-sdk_bg.wasm";
 pub const GRAFBASE_KV_DATA_PATH: &str = "kv/data";
+pub const CONNECTOR_CACHE_DIR: &str = "connectors";
@@ -1,12 +1,18 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
+use common::environment::Project;
 use common_types::UdfKind;
 use engine::registry::Registry;
 use itertools::Itertools;
 use parser_sdl::{GraphqlDirective, OpenApiDirective, ParseResult, PostgresDirective};
 use postgres_types::transport::TcpTransport;
+use reqwest::Url;
+use sha2::Digest;
 
-use crate::errors::ServerError;
+use crate::{consts::CONNECTOR_CACHE_DIR, errors::ServerError};
 
 // Contract between this crate and CLI
 #[derive(serde::Serialize)]
@@ -16,9 +22,17 @@ pub struct ParserResult {
 }
 
 /// Transform the input schema into a Registry
-pub async fn parse_schema(schema: &str, environment: &HashMap<String, String>) -> Result<ParserResult, ServerError> {
+///
+/// When `offline` is set, connectors are built from the introspection results cached in
+/// `.grafbase` by previous runs instead of fetching them.
+pub async fn parse_schema(
+    schema: &str,
+    environment: &HashMap<String, String>,
+    offline: bool,
+) -> Result<ParserResult, ServerError> {
     let connector_parsers = ConnectorParsers {
         http_client: reqwest::Client::new(),
+        offline,
     };
 
     let ParseResult {
@@ -43,28 +57,38 @@ pub async fn parse_schema(schema: &str, environment: &HashMap<String, String>) -
 
 struct ConnectorParsers {
     http_client: reqwest::Client,
+    offline: bool,
 }
 
 #[async_trait::async_trait]
 impl parser_sdl::ConnectorParsers for ConnectorParsers {
     async fn fetch_and_parse_openapi(&self, directive: OpenApiDirective) -> Result<Registry, Vec<String>> {
-        let mut request = self.http_client.get(&directive.schema_url);
+        let (spec, format) = match local_spec_path(&directive.schema_url) {
+            Some(path) => {
+                let spec = tokio::fs::read_to_string(&path)
+                    .await
+                    .map_err(|error| vec![format!("Could not read the spec at {}: {error}", path.display())])?;
 
-        for (name, value) in directive.introspection_headers() {
-            request = request.header(name, value);
-        }
+                let url = Url::from_file_path(&path).map(String::from).unwrap_or_default();
 
-        let response = request.send().await.map_err(|e| vec![e.to_string()])?;
+                (spec, parser_openapi::Format::guess(None, &url))
+            }
+            None => {
+                let cache = ConnectorCache::new("openapi", &[&directive.schema_url]);
 
-        let content_type = response
-            .headers()
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|header_value| header_value.to_str().ok())
-            .map(ToOwned::to_owned);
+                let (content_type, spec) = if self.offline {
+                    cache.read(&directive.name).await?
+                } else {
+                    let fetched = self.fetch_openapi_spec(&directive).await?;
+                    cache.write(&fetched).await;
+                    fetched
+                };
 
-        let spec = response.text().await.map_err(|e| vec![e.to_string()])?;
+                let format = parser_openapi::Format::guess(content_type.as_deref(), &directive.schema_url);
 
-        let format = parser_openapi::Format::guess(content_type.as_deref(), &directive.schema_url);
+                (spec, format)
+            }
+        };
 
         let mut registry = Registry::new();
 
@@ -75,29 +99,175 @@ impl parser_sdl::ConnectorParsers for ConnectorParsers {
     }
 
     async fn fetch_and_parse_graphql(&self, directive: GraphqlDirective) -> Result<Registry, Vec<String>> {
-        parser_graphql::parse_schema(
+        let prefix = directive
+            .transforms
+            .as_ref()
+            .and_then(|transforms| transforms.prefix_types.as_deref());
+
+        // URLs were accepted before the schema could be read from a file, they're introspected as usual.
+        if let Some(path) = directive.schema.as_deref().and_then(local_spec_path) {
+            let sdl = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|error| vec![format!("Could not read the schema at {}: {error}", path.display())])?;
+
+            return parser_graphql::parse_sdl(
+                &directive.name,
+                directive.namespace,
+                &directive.url,
+                directive.headers(),
+                directive.fetch_policy(),
+                &sdl,
+                prefix,
+            )
+            .map_err(|errors| errors.into_iter().map(|error| error.to_string()).collect());
+        }
+
+        let cache = ConnectorCache::new(
+            "graphql",
+            &[
+                &directive.name,
+                &directive.namespace.to_string(),
+                directive.url.as_str(),
+                prefix.unwrap_or_default(),
+            ],
+        );
+
+        if self.offline {
+            return cache.read(&directive.name).await;
+        }
+
+        let registry = parser_graphql::parse_schema(
             self.http_client.clone(),
             &directive.name,
             directive.namespace,
             &directive.url,
             directive.headers(),
+            directive.fetch_policy(),
             directive.introspection_headers(),
-            directive
-                .transforms
-                .as_ref()
-                .and_then(|transforms| transforms.prefix_types.as_deref()),
+            prefix,
         )
         .await
-        .map_err(|errors| errors.into_iter().map(|error| error.to_string()).collect::<Vec<_>>())
+        .map_err(|errors| errors.into_iter().map(|error| error.to_string()).collect::<Vec<_>>())?;
+
+        cache.write(&registry).await;
+
+        Ok(registry)
     }
 
     async fn fetch_and_parse_postgres(&self, directive: &PostgresDirective) -> Result<Registry, Vec<String>> {
+        let cache = ConnectorCache::new(
+            "postgres",
+            &[
+                directive.name(),
+                &directive.namespace().to_string(),
+                directive.connection_string(),
+            ],
+        );
+
+        if self.offline {
+            return cache.read(directive.name()).await;
+        }
+
         let transport = TcpTransport::new(directive.connection_string())
             .await
             .map_err(|error| vec![error.to_string()])?;
 
-        parser_postgres::introspect(&transport, directive.name(), directive.namespace())
+        let registry = parser_postgres::introspect(&transport, directive.name(), directive.namespace())
             .await
-            .map_err(|error| vec![error.to_string()])
+            .map_err(|error| vec![error.to_string()])?;
+
+        cache.write(&registry).await;
+
+        Ok(registry)
+    }
+}
+
+impl ConnectorParsers {
+    async fn fetch_openapi_spec(&self, directive: &OpenApiDirective) -> Result<(Option<String>, String), Vec<String>> {
+        let mut request = self.http_client.get(&directive.schema_url);
+
+        for (name, value) in directive.introspection_headers() {
+            request = request.header(name, value);
+        }
+
+        let response = request.send().await.map_err(|e| vec![e.to_string()])?;
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|header_value| header_value.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let spec = response.text().await.map_err(|e| vec![e.to_string()])?;
+
+        Ok((content_type, spec))
+    }
+}
+
+/// Anything other than an HTTP(S) URL is a path to a schema on disk, relative paths being
+/// resolved against the grafbase directory so the schema is picked up by the file watcher.
+fn local_spec_path(schema_url: &str) -> Option<PathBuf> {
+    match Url::parse(schema_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => None,
+        Ok(url) if url.scheme() == "file" => url.to_file_path().ok(),
+        _ => Some(Project::get().grafbase_directory_path.join(schema_url)),
+    }
+}
+
+/// The result of introspecting a connector, stored in `.grafbase` so `--offline` can reuse it.
+struct ConnectorCache {
+    kind: &'static str,
+    path: PathBuf,
+}
+
+impl ConnectorCache {
+    fn new(kind: &'static str, key: &[&str]) -> Self {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(kind.as_bytes());
+        for part in key {
+            // Separating the parts so that e.g. `ab`, `c` and `a`, `bc` don't collide.
+            hasher.update(part.len().to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+
+        let path = Project::get()
+            .dot_grafbase_directory_path
+            .join(CONNECTOR_CACHE_DIR)
+            .join(format!("{kind}-{:x}.json", hasher.finalize()));
+
+        Self { kind, path }
+    }
+
+    async fn read<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Vec<String>> {
+        let contents = tokio::fs::read(&self.path).await.map_err(|_| {
+            vec![format!(
+                "The {} connector `{name}` has not been cached yet, run the command without --offline once to fetch it",
+                self.kind
+            )]
+        })?;
+
+        serde_json::from_slice(&contents).map_err(|error| {
+            vec![format!(
+                "Could not read the cached {} connector `{name}`, run the command without --offline to fetch it again: {error}",
+                self.kind
+            )]
+        })
+    }
+
+    /// Failing to cache is not fatal, we'll just have to fetch again.
+    async fn write<T: serde::Serialize + Sync>(&self, value: &T) {
+        let result = async {
+            let contents = serde_json::to_vec(value)?;
+            if let Some(parent) = self.path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&self.path, contents).await?;
+            Ok::<_, std::io::Error>(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            warn!("could not cache the {} connector: {error}", self.kind);
+        }
     }
 }
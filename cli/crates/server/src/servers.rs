@@ -49,6 +49,7 @@ impl ProductionServer {
     pub async fn build(
         message_sender: UnboundedSender<ServerMessage>,
         parallelism: NonZeroUsize,
+        offline: bool,
         tracing: bool,
     ) -> Result<Self, ServerError> {
         create_project_dot_grafbase_directory()?;
@@ -57,7 +58,7 @@ impl ProductionServer {
         let ParsingResponse {
             registry,
             detected_udfs,
-        } = run_schema_parser(&environment_variables, None).await?;
+        } = run_schema_parser(&environment_variables, None, offline).await?;
 
         let (bridge_app, bridge_state) =
             bridge::build_router(message_sender.clone(), Arc::clone(&registry), tracing).await?;
@@ -110,8 +111,8 @@ impl ProductionServer {
             self.registry,
         )
         .into_router();
-        let gateway_server =
-            axum::Server::bind(&SocketAddr::new(listen_address, port)).serve(gateway_app.into_make_service());
+        let gateway_server = axum::Server::bind(&SocketAddr::new(listen_address, port))
+            .serve(gateway_app.into_make_service_with_connect_info::<SocketAddr>());
 
         let _ = self.message_sender.send(ServerMessage::Ready { listen_address, port });
         tokio::select! {
@@ -141,10 +142,12 @@ impl ProductionServer {
 /// # Panics
 ///
 /// The spawned server and miniflare thread can panic if either of the two inner spawned threads panic
+#[allow(clippy::fn_params_excessive_bools)]
 pub async fn start(
     port: u16,
     search: bool,
     watch: bool,
+    offline: bool,
     tracing: bool,
     message_sender: UnboundedSender<ServerMessage>,
 ) -> Result<(), ServerError> {
@@ -166,10 +169,10 @@ pub async fn start(
                 let relative_path = path.strip_prefix(&project.path).expect("must succeed by definition").to_owned();
                 watch_event_bus.send(Event::Reload(relative_path)).expect("cannot fail");
             }) => { result }
-            result = server_loop(port, search, message_sender, event_bus, tracing) => { result }
+            result = server_loop(port, search, message_sender, event_bus, offline, tracing) => { result }
         }
     } else {
-        server_loop(port, search, message_sender, event_bus, tracing).await
+        server_loop(port, search, message_sender, event_bus, offline, tracing).await
     }
 }
 
@@ -178,6 +181,7 @@ async fn server_loop(
     search: bool,
     message_sender: UnboundedSender<ServerMessage>,
     event_bus: broadcast::Sender<Event>,
+    offline: bool,
     tracing: bool,
 ) -> Result<(), ServerError> {
     let proxy_event_bus = event_bus.clone();
@@ -198,7 +202,7 @@ async fn server_loop(
         let receiver = event_bus.subscribe();
 
         tokio::select! {
-            result = spawn_servers(proxy_port, message_sender.clone(), event_bus.clone(), path_changed.as_deref(), tracing) => {
+            result = spawn_servers(proxy_port, message_sender.clone(), event_bus.clone(), path_changed.as_deref(), offline, tracing) => {
                 result?;
             }
             path = wait_for_event_and_match(receiver, |event| match event {
@@ -224,6 +228,7 @@ async fn spawn_servers(
     message_sender: UnboundedSender<ServerMessage>,
     event_bus: broadcast::Sender<Event>,
     path_changed: Option<&Path>,
+    offline: bool,
     tracing: bool,
 ) -> Result<(), ServerError> {
     let bridge_event_bus = event_bus.clone();
@@ -239,7 +244,7 @@ async fn spawn_servers(
     let ParsingResponse {
         registry,
         mut detected_udfs,
-    } = match run_schema_parser(&environment_variables, Some(event_bus)).await {
+    } = match run_schema_parser(&environment_variables, Some(event_bus), offline).await {
         Ok(parsing_response) => parsing_response,
         Err(error) => {
             let _: Result<_, _> = message_sender.send(ServerMessage::CompilationError(error.to_string()));
@@ -299,7 +304,8 @@ async fn spawn_servers(
         let app =
             gateway::Gateway::new(environment_variables, gateway::Bridge::new(bridge_port), registry).into_router();
         // run it with hyper on localhost:3000
-        let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap())
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>());
         WORKER_PORT.store(server.local_addr().port(), Ordering::Relaxed);
         server
     };
@@ -402,6 +408,7 @@ pub struct ParsingResponse {
 async fn run_schema_parser(
     environment_variables: &HashMap<String, String>,
     event_bus: Option<broadcast::Sender<Event>>,
+    offline: bool,
 ) -> Result<ParsingResponse, ServerError> {
     trace!("parsing schema");
     let project = Project::get();
@@ -427,7 +434,7 @@ async fn run_schema_parser(
     let crate::parser::ParserResult {
         registry,
         required_udfs,
-    } = crate::parser::parse_schema(&schema, environment_variables).await?;
+    } = crate::parser::parse_schema(&schema, environment_variables, offline).await?;
 
     let offset = REGISTRY_PARSED_EPOCH_OFFSET_MILLIS.load(Ordering::Acquire);
     let registry_mtime = SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(offset));
@@ -1,4 +1,4 @@
-use common::types::{LogLevel, UdfKind};
+use common::types::{CircuitBreakerState, LogLevel, UdfKind};
 use std::{net::IpAddr, path::PathBuf};
 
 pub const ASSETS_GZIP: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/assets.tar.gz"));
@@ -31,6 +31,10 @@ pub enum NestedRequestScopedMessage {
         duration: std::time::Duration,
         body: Option<String>,
     },
+    CircuitBreakerStateChanged {
+        connector: String,
+        state: CircuitBreakerState,
+    },
 }
 
 #[derive(Clone, Debug)]
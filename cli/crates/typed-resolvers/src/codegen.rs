@@ -139,7 +139,7 @@ fn write_resolver_type<O: fmt::Write>(schema: &AnalyzedSchema<'_>, out: &mut O)
         return Ok(());
     }
 
-    out.write_str("\nimport { ResolverFn } from '@grafbase/sdk'\n\n")?;
+    out.write_str(RESOLVER_TYPES)?;
     out.write_str("export type Resolver = {\n")?;
 
     for (object_id, field_id, field) in fields {
@@ -161,7 +161,7 @@ fn write_resolver_type<O: fmt::Write>(schema: &AnalyzedSchema<'_>, out: &mut O)
 
         writeln!(
             out,
-            "{INDENT}'{resolver_id}': ResolverFn<Schema['{parent_object_type_name}'], {arguments}, {rendered_field_type}>"
+            "{INDENT}'{resolver_id}': ResolverFn<'{parent_object_type_name}', {arguments}, {rendered_field_type}>"
         )?;
     }
 
@@ -182,3 +182,40 @@ const HEADER: &str = r#"// This is a generated file. It should not be edited man
 //  }
 
 "#;
+
+/// The arguments passed to resolvers, on top of the parent and the field arguments.
+const RESOLVER_TYPES: &str = r#"
+import { Context, Info } from '@grafbase/sdk'
+
+/** The identity the request was authorized with. */
+export type ResolverAuth =
+  | { type: 'apiKey' }
+  | { type: 'public' }
+  | { type: 'token'; subject: string | null; groups: Array<string>; claims: Record<string, any> };
+
+/** A field selected on the value returned by a resolver. */
+export type ResolverSelectionField = {
+  name: string;
+  alias: string | null;
+  arguments: Record<string, any>;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverContext = Context & {
+  auth?: ResolverAuth;
+};
+
+export type ResolverInfo<ParentType extends keyof Schema> = Info & {
+  parentType: ParentType;
+  returnType: string;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverFn<ParentType extends keyof Schema, Args, Return> = (
+  parent: Schema[ParentType],
+  args: Args,
+  context: ResolverContext,
+  info: ResolverInfo<ParentType>,
+) => Return | Promise<Return>;
+
+"#;
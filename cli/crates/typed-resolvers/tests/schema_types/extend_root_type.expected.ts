@@ -26,10 +26,41 @@ export type Schema = {
   };
 };
 
-import { ResolverFn } from '@grafbase/sdk'
+import { Context, Info } from '@grafbase/sdk'
+
+/** The identity the request was authorized with. */
+export type ResolverAuth =
+  | { type: 'apiKey' }
+  | { type: 'public' }
+  | { type: 'token'; subject: string | null; groups: Array<string>; claims: Record<string, any> };
+
+/** A field selected on the value returned by a resolver. */
+export type ResolverSelectionField = {
+  name: string;
+  alias: string | null;
+  arguments: Record<string, any>;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverContext = Context & {
+  auth?: ResolverAuth;
+};
+
+export type ResolverInfo<ParentType extends keyof Schema> = Info & {
+  parentType: ParentType;
+  returnType: string;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverFn<ParentType extends keyof Schema, Args, Return> = (
+  parent: Schema[ParentType],
+  args: Args,
+  context: ResolverContext,
+  info: ResolverInfo<ParentType>,
+) => Return | Promise<Return>;
 
 export type Resolver = {
-  'Query.ping': ResolverFn<Schema['Query'], { name: string | null,  }, string>
-  'Mutation.pong': ResolverFn<Schema['Mutation'], {  }, string>
+  'Query.ping': ResolverFn<'Query', { name: string | null,  }, string>
+  'Mutation.pong': ResolverFn<'Mutation', {  }, string>
 }
 
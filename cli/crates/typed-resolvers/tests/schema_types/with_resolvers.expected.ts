@@ -40,12 +40,43 @@ export type Schema = {
   };
 };
 
-import { ResolverFn } from '@grafbase/sdk'
+import { Context, Info } from '@grafbase/sdk'
+
+/** The identity the request was authorized with. */
+export type ResolverAuth =
+  | { type: 'apiKey' }
+  | { type: 'public' }
+  | { type: 'token'; subject: string | null; groups: Array<string>; claims: Record<string, any> };
+
+/** A field selected on the value returned by a resolver. */
+export type ResolverSelectionField = {
+  name: string;
+  alias: string | null;
+  arguments: Record<string, any>;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverContext = Context & {
+  auth?: ResolverAuth;
+};
+
+export type ResolverInfo<ParentType extends keyof Schema> = Info & {
+  parentType: ParentType;
+  returnType: string;
+  selectionSet: Array<ResolverSelectionField>;
+};
+
+export type ResolverFn<ParentType extends keyof Schema, Args, Return> = (
+  parent: Schema[ParentType],
+  args: Args,
+  context: ResolverContext,
+  info: ResolverInfo<ParentType>,
+) => Return | Promise<Return>;
 
 export type Resolver = {
-  'User.linkedInProfile': ResolverFn<Schema['User'], {  }, string>
-  'Query.user': ResolverFn<Schema['Query'], { anonymize: boolean | null,  }, Schema['User'] | null>
-  'Query.users': ResolverFn<Schema['Query'], { filter: Schema['UserFilter'] | null, take: number,  }, Array<Schema['User'] | null> | null>
-  'Query.other': ResolverFn<Schema['Query'], {  }, Schema['Other'] | null>
+  'User.linkedInProfile': ResolverFn<'User', {  }, string>
+  'Query.user': ResolverFn<'Query', { anonymize: boolean | null,  }, Schema['User'] | null>
+  'Query.users': ResolverFn<'Query', { filter: Schema['UserFilter'] | null, take: number,  }, Array<Schema['User'] | null> | null>
+  'Query.other': ResolverFn<'Query', {  }, Schema['Other'] | null>
 }
 
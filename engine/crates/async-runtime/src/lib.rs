@@ -1,4 +1,6 @@
-use core::future::Future;
+use core::{future::Future, time::Duration};
+
+use futures_util::Stream;
 
 #[cfg(target_arch = "wasm32")]
 pub fn spawn(future: impl Future<Output = ()> + Send + 'static) {
@@ -10,6 +12,23 @@ pub fn spawn(future: impl Future<Output = ()> + Send + 'static) {
     tokio::spawn(future);
 }
 
+/// Runs blocking code, such as file IO, without blocking the executor. There are no threads to
+/// run it on in wasm, so it runs in place.
+#[cfg(target_arch = "wasm32")]
+pub async fn spawn_blocking<T>(function: impl FnOnce() -> T) -> T {
+    function()
+}
+
+/// Runs blocking code, such as file IO, without blocking the executor. There are no threads to
+/// run it on in wasm, so it runs in place.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn spawn_blocking<T: Send + 'static>(function: impl FnOnce() -> T + Send + 'static) -> T {
+    match tokio::task::spawn_blocking(function).await {
+        Ok(value) => value,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 pub fn make_send_on_wasm<T>(future: impl Future<Output = T>) -> impl Future<Output = T> + Send {
     send_wrapper::SendWrapper::new(future)
@@ -22,3 +41,26 @@ where
 {
     future
 }
+
+#[cfg(target_arch = "wasm32")]
+pub fn make_stream_send_on_wasm<T>(stream: impl Stream<Item = T>) -> impl Stream<Item = T> + Send {
+    send_wrapper::SendWrapper::new(stream)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn make_stream_send_on_wasm<S>(stream: S) -> S
+where
+    S: Stream + Send,
+{
+    stream
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
@@ -143,6 +143,10 @@ impl ExecutionAuthToken {
             .unwrap_or_default()
     }
 
+    pub fn token_claims(&self) -> &BTreeMap<String, Value> {
+        &self.token_claims
+    }
+
     pub fn get_claim(&self, claim_name: &str) -> Option<String> {
         self.token_claims.get(claim_name).map(|v| v.to_string())
     }
@@ -45,6 +45,19 @@ pub enum LogLevel {
     Debug,
 }
 
+/// The state of the circuit breaker of a connector
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "snake_case")]
+pub enum CircuitBreakerState {
+    /// Requests are sent as usual
+    Closed,
+    /// Requests fail straight away, as the server has been failing
+    Open,
+    /// A single request is sent to check whether the server has recovered
+    HalfOpen,
+}
+
 #[derive(serde::Deserialize, serde::Serialize, Debug)]
 pub struct Operation<'a> {
     pub name: Option<std::borrow::Cow<'a, str>>,
@@ -100,6 +113,10 @@ pub enum LogEventType<'a> {
         message: String,
         url: String,
     },
+    CircuitBreakerStateChanged {
+        connector: String,
+        state: CircuitBreakerState,
+    },
 }
 
 impl LogEventType<'_> {
@@ -118,6 +135,11 @@ impl LogEventType<'_> {
             },
             LogEventType::BadRequest { .. } => LogLevel::Error,
             LogEventType::UdfMessage { level, .. } => *level,
+            LogEventType::CircuitBreakerStateChanged { state, .. } => match state {
+                CircuitBreakerState::Closed => LogLevel::Info,
+                CircuitBreakerState::HalfOpen => LogLevel::Warn,
+                CircuitBreakerState::Open => LogLevel::Error,
+            },
         }
     }
 }
@@ -262,6 +262,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 resolver: #crate_name::registry::resolvers::Resolver::Parent,
                 required_operation: None,
                 auth: None,
+                complexity: None,
             });
         });
 
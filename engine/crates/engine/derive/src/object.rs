@@ -353,6 +353,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Generat
                         resolver: #crate_name::registry::resolvers::Resolver::Parent,
                         required_operation: None,
                         auth: None,
+                        complexity: None,
                     });
                 });
 
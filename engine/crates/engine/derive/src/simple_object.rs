@@ -174,6 +174,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     resolver: #crate_name::registry::resolvers::Resolver::Parent,
                     required_operation: None,
                     auth: None,
+                    complexity: None,
                 });
             });
         }
@@ -19,11 +19,13 @@ use crate::{
 
 mod executable;
 mod service;
+mod tokens;
 mod utils;
 
 use engine_value::{ConstValue, Name, Number, Value};
 pub use executable::{parse_field, parse_query, parse_selection_set};
 pub use service::parse_schema;
+pub use tokens::count_tokens;
 
 #[derive(Parser)]
 #[grammar = "graphql.pest"]
@@ -0,0 +1,102 @@
+/// Counts the lexical tokens of a GraphQL document: punctuators, names, numbers and strings.
+/// Whitespace, commas and comments aren't tokens.
+///
+/// It doesn't validate the document, so it's cheap enough to reject overly large queries before
+/// parsing them.
+pub fn count_tokens(input: &str) -> usize {
+    let mut chars = input.chars().peekable();
+    let mut count = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | ',' | '\t' | '\n' | '\r' | '\u{feff}' => continue,
+            '#' => {
+                while chars.next_if(|&c| c != '\n' && c != '\r').is_some() {}
+                continue;
+            }
+            '.' => {
+                // spread
+                chars.next_if_eq(&'.');
+                chars.next_if_eq(&'.');
+            }
+            '"' => {
+                if chars.next_if_eq(&'"').is_some() {
+                    if chars.next_if_eq(&'"').is_some() {
+                        skip_block_string(&mut chars);
+                    }
+                    // otherwise an empty string
+                } else {
+                    skip_string(&mut chars);
+                }
+            }
+            c if c == '_' || c.is_ascii_alphabetic() => {
+                while chars.next_if(|&c| c == '_' || c.is_ascii_alphanumeric()).is_some() {}
+            }
+            c if c == '-' || c.is_ascii_digit() => {
+                while chars
+                    .next_if(|&c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+                    .is_some()
+                {}
+            }
+            // punctuators, anything else being invalid anyway
+            _ => {}
+        }
+
+        count += 1;
+    }
+
+    count
+}
+
+fn skip_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' | '\n' | '\r' => return,
+            _ => {}
+        }
+    }
+}
+
+fn skip_block_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    let mut quotes = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                quotes += 1;
+                if quotes == 3 {
+                    return;
+                }
+            }
+            '\\' => {
+                quotes = 0;
+                // escaped triple quotes
+                if chars.next_if_eq(&'"').is_some() {
+                    chars.next_if_eq(&'"');
+                    chars.next_if_eq(&'"');
+                }
+            }
+            _ => quotes = 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_tokens;
+
+    #[test]
+    fn counts_tokens() {
+        assert_eq!(count_tokens(""), 0);
+        assert_eq!(count_tokens("{ hello }"), 3);
+        assert_eq!(count_tokens("# comment\n{ a, b }"), 4);
+        assert_eq!(
+            count_tokens(r#"query Q($id: ID!) { user(id: $id, limit: -1.5e3) { ...F name(x: "a \" b") } }"#),
+            31
+        );
+        assert_eq!(count_tokens(r#"{ a(x: """ block "" \""" """, y: "") }"#), 11);
+    }
+}
@@ -1,9 +1,10 @@
 use engine_parser::{types::SelectionSet, Positioned};
+use engine_value::ConstValue;
 use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::{
     registry::{resolvers::ResolvedValue, NamedType},
-    ContextSelectionSet, Error, QueryEnv, QueryPath, SchemaEnv,
+    ContextSelectionSet, Error, QueryEnv, QueryPath, SchemaEnv, ServerError,
 };
 
 #[derive(Debug)]
@@ -13,6 +14,17 @@ pub struct DeferredWorkload {
     pub path: QueryPath,
     pub current_type_name: NamedType<'static>,
     pub parent_resolver_value: Option<ResolvedValue>,
+    /// Set if this workload is an item of a list with `@stream`, rather than a fragment with `@defer`
+    pub streamed_item: Option<StreamedItem>,
+}
+
+/// An item of a list with `@stream`, which is delivered after the initial response.
+#[derive(Debug)]
+pub enum StreamedItem {
+    /// An item with a selection set, resolved like a deferred fragment
+    Container,
+    /// A leaf item, which has already been resolved along with its list
+    Leaf(Result<ConstValue, ServerError>),
 }
 
 impl DeferredWorkload {
@@ -29,6 +41,21 @@ impl DeferredWorkload {
             path,
             current_type_name,
             parent_resolver_value,
+            streamed_item: None,
+        }
+    }
+
+    pub fn stream_item(
+        label: Option<String>,
+        selection_set: Positioned<SelectionSet>,
+        path: QueryPath,
+        current_type_name: NamedType<'static>,
+        item: StreamedItem,
+        parent_resolver_value: Option<ResolvedValue>,
+    ) -> Self {
+        DeferredWorkload {
+            streamed_item: Some(item),
+            ..Self::new(label, selection_set, path, current_type_name, parent_resolver_value)
         }
     }
 
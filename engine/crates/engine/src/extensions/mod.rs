@@ -12,8 +12,10 @@ use engine_value::Name;
 use graph_entities::ResponseNodeId;
 
 use crate::{
-    parser::types::ExecutableDocument, registry::MetaFieldType, Data, DataContext, Error, QueryPath, Request, Response,
-    Result, SchemaEnv, ServerError, ServerResult, ValidationResult, Value, Variables,
+    parser::types::ExecutableDocument,
+    registry::{resolvers::Resolver, MetaFieldType},
+    Data, DataContext, Error, QueryPath, Request, Response, Result, SchemaEnv, ServerError, ServerResult,
+    ValidationResult, Value, Variables,
 };
 
 /// Context for extension
@@ -111,6 +113,9 @@ pub struct ResolveInfo<'a> {
 
     /// Arguments for the resolved value, if they exist, resolved.
     pub input_values: Vec<(Positioned<Name>, Option<Value>)>,
+
+    /// The resolver of the field, `None` for the items of a list.
+    pub resolver: Option<&'a Resolver>,
 }
 
 type RequestFut<'a> = &'a mut (dyn Future<Output = Response> + Send + Unpin);
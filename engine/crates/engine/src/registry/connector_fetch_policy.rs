@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// How we send requests to a connectors downstream server
+pub struct ConnectorFetchPolicy {
+    /// How long we wait for each attempt at a request before giving up on it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>,
+    /// How many times we retry a request that failed in a way that can be retried
+    #[serde(default)]
+    pub retries: u32,
+    /// When to stop sending requests to a downstream server that keeps failing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<CircuitBreakerPolicy>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The circuit breaker of a connector opens after `failure_threshold` requests in a row have
+/// failed, failing any requests to the connector straight away. Once `reset_timeout` has passed
+/// we let a single request through to probe whether the server has recovered.
+pub struct CircuitBreakerPolicy {
+    pub failure_threshold: u32,
+    pub reset_timeout: Duration,
+}
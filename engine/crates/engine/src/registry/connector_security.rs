@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use super::ConnectorHeaderValue;
+
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+/// The security schemes of a connectors downstream server that we have credentials for,
+/// keyed by the name of the scheme in the connectors spec
+pub struct ConnectorSecurity(BTreeMap<String, SecurityScheme>);
+
+impl ConnectorSecurity {
+    pub fn new(schemes: impl IntoIterator<Item = (String, SecurityScheme)>) -> Self {
+        ConnectorSecurity(schemes.into_iter().collect())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SecurityScheme> {
+        self.0.get(name)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub enum SecurityScheme {
+    /// An API key sent as a header, query parameter or cookie
+    ApiKey {
+        name: String,
+        location: ApiKeyLocation,
+        value: ConnectorHeaderValue,
+    },
+    /// HTTP basic authentication, where the value is `username:password`
+    Basic { value: ConnectorHeaderValue },
+    /// A token sent in the `Authorization` header with the `Bearer` scheme
+    Bearer { value: ConnectorHeaderValue },
+    /// A bearer token we fetch from the token URL with the OAuth2 client credentials flow
+    OAuth2ClientCredentials {
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
@@ -1,10 +1,14 @@
 pub mod builder;
 mod cache_control;
+mod connector_fetch_policy;
 mod connector_headers;
+mod connector_security;
 pub mod enums;
 mod export_sdl;
 pub mod federation;
 pub mod field_set;
+mod operation_limits;
+mod rate_limiting;
 pub mod relations;
 pub mod resolvers;
 pub mod scalars;
@@ -41,8 +45,12 @@ pub use self::{
         CacheAccessScope, CacheControl, CacheControlError, CacheInvalidation, CacheInvalidationPolicy,
         CachePartialRegistry,
     },
+    connector_fetch_policy::{CircuitBreakerPolicy, ConnectorFetchPolicy},
     connector_headers::{ConnectorHeaderValue, ConnectorHeaders},
+    connector_security::{ApiKeyLocation, ConnectorSecurity, SecurityScheme},
     field_set::FieldSet,
+    operation_limits::{OperationLimitExceeded, OperationLimits},
+    rate_limiting::{RateLimitAlgorithm, RateLimitConfig, RateLimitKey, RateLimitQuota},
     type_names::{
         InputValueType, MetaFieldType, ModelName, NamedType, TypeCondition, TypeReference, WrappingType,
         WrappingTypeIter,
@@ -326,6 +334,9 @@ pub struct MetaField {
     pub resolver: Resolver,
     pub required_operation: Option<Operations>,
     pub auth: Option<AuthConfig>,
+    /// The weight of the field in the complexity of operations, declared with `@complexity`.
+    /// Fields count for 1 otherwise.
+    pub complexity: Option<usize>,
 }
 
 impl MetaField {
@@ -361,6 +372,7 @@ impl Hash for MetaField {
         self.edges.hash(state);
         self.relation.hash(state);
         self.resolver.hash(state);
+        self.complexity.hash(state);
     }
 }
 
@@ -378,6 +390,7 @@ impl PartialEq for MetaField {
             && self.edges.eq(&other.edges)
             && self.relation.eq(&other.relation)
             && self.resolver.eq(&other.resolver)
+            && self.complexity.eq(&other.complexity)
     }
 }
 
@@ -1312,6 +1325,10 @@ pub struct MongoDBConfiguration {
     pub data_source: String,
     pub database: String,
     pub namespace: bool,
+    /// If set, the requests are sent over the MongoDB wire protocol instead of
+    /// the Atlas Data API, and the Data API settings are empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_string: Option<String>,
 }
 
 #[derive(
@@ -1379,6 +1396,14 @@ pub struct Registry {
     pub mongodb_configurations: HashMap<String, MongoDBConfiguration>,
     #[serde(default)]
     pub http_headers: BTreeMap<String, ConnectorHeaders>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub http_security: BTreeMap<String, ConnectorSecurity>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub http_fetch_policies: BTreeMap<String, ConnectorFetchPolicy>,
+    /// The extensions of upstream errors the GraphQL connectors forward, when they don't forward
+    /// all of them.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub graphql_error_extensions: BTreeMap<String, BTreeSet<String>>,
     #[serde(default)]
     pub postgres_databases: HashMap<String, DatabaseDefinition>,
     #[serde(default)]
@@ -1391,6 +1416,10 @@ pub struct Registry {
     pub federation_entities: BTreeMap<String, FederationEntity>,
     #[serde(default)]
     pub enable_ai: bool,
+    #[serde(default, skip_serializing_if = "RateLimitConfig::is_empty")]
+    pub rate_limiting: RateLimitConfig,
+    #[serde(default, skip_serializing_if = "OperationLimits::is_empty")]
+    pub operation_limits: OperationLimits,
 }
 
 impl Default for Registry {
@@ -1408,12 +1437,17 @@ impl Default for Registry {
             auth: Default::default(),
             mongodb_configurations: Default::default(),
             http_headers: Default::default(),
+            http_security: Default::default(),
+            http_fetch_policies: Default::default(),
+            graphql_error_extensions: Default::default(),
             postgres_databases: Default::default(),
             search_config: Default::default(),
             enable_caching: false,
             enable_kv: false,
             federation_entities: Default::default(),
             enable_ai: false,
+            rate_limiting: Default::default(),
+            operation_limits: Default::default(),
         }
     }
 }
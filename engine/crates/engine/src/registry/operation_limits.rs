@@ -0,0 +1,155 @@
+use crate::{validation::ValidationResult, ErrorExtensionValues, ServerError};
+
+#[derive(Clone, Copy, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
+/// Limits on the shape of the operations clients can send, declared with `@operationLimits`.
+/// Operations exceeding any of them are rejected before being executed.
+pub struct OperationLimits {
+    /// How deeply fields can be nested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<usize>,
+    /// The complexity of the operation, each field counting for its `@complexity` weight or 1 plus
+    /// the complexity of its children multiplied by the size of the list it returns
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<usize>,
+    /// How many fields can have an alias
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<usize>,
+    /// How many fields can be selected on the root type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_fields: Option<usize>,
+    /// How many tokens the query document can have, checked before parsing it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<usize>,
+    /// The size assumed for lists when computing the complexity, if not given by a `first`, `last`
+    /// or `limit` argument
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_list_size: Option<usize>,
+}
+
+impl OperationLimits {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checked before parsing the query, so that large documents are rejected early.
+    pub fn check_tokens(&self, query: &str) -> Result<(), OperationLimitExceeded> {
+        match self.tokens {
+            Some(max) if engine_parser::count_tokens(query) > max => Err(OperationLimitExceeded::Tokens { max }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check(&self, validation: &ValidationResult) -> Result<(), OperationLimitExceeded> {
+        check(self.depth, validation.depth, |max| OperationLimitExceeded::Depth {
+            max,
+        })?;
+        check(self.complexity, validation.complexity, |max| {
+            OperationLimitExceeded::Complexity { max }
+        })?;
+        check(self.aliases, validation.aliases, |max| {
+            OperationLimitExceeded::Aliases { max }
+        })?;
+        check(self.root_fields, validation.root_fields, |max| {
+            OperationLimitExceeded::RootFields { max }
+        })
+    }
+}
+
+/// An operation exceeded one of the [`OperationLimits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum OperationLimitExceeded {
+    #[error("Query is nested too deep.")]
+    Depth { max: usize },
+    #[error("Query is too complex.")]
+    Complexity { max: usize },
+    #[error("Query contains too many aliases.")]
+    Aliases { max: usize },
+    #[error("Query contains too many root fields.")]
+    RootFields { max: usize },
+    #[error("Query contains too many tokens.")]
+    Tokens { max: usize },
+}
+
+impl OperationLimitExceeded {
+    fn limit(&self) -> (&'static str, usize) {
+        match *self {
+            Self::Depth { max } => ("depth", max),
+            Self::Complexity { max } => ("complexity", max),
+            Self::Aliases { max } => ("aliases", max),
+            Self::RootFields { max } => ("rootFields", max),
+            Self::Tokens { max } => ("tokens", max),
+        }
+    }
+}
+
+impl From<OperationLimitExceeded> for ServerError {
+    fn from(error: OperationLimitExceeded) -> Self {
+        let (limit, max) = error.limit();
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", "OPERATION_LIMIT_EXCEEDED");
+        extensions.set("limit", limit);
+        extensions.set("max", max);
+
+        ServerError {
+            extensions: Some(extensions),
+            ..ServerError::new(error.to_string(), None)
+        }
+    }
+}
+
+fn check(
+    max: Option<usize>,
+    actual: usize,
+    error: fn(usize) -> OperationLimitExceeded,
+) -> Result<(), OperationLimitExceeded> {
+    match max {
+        Some(max) if actual > max => Err(error(max)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits() {
+        let limits = OperationLimits {
+            depth: Some(2),
+            tokens: Some(5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            limits.check_tokens("{ a { b } }"),
+            Err(OperationLimitExceeded::Tokens { max: 5 })
+        );
+        assert_eq!(limits.check_tokens("{ a }"), Ok(()));
+
+        let validation = ValidationResult {
+            cache_control: Default::default(),
+            cache_invalidation_policies: Default::default(),
+            complexity: 100,
+            depth: 3,
+            aliases: 100,
+            root_fields: 100,
+        };
+        assert_eq!(limits.check(&validation), Err(OperationLimitExceeded::Depth { max: 2 }));
+        assert_eq!(limits.check(&ValidationResult { depth: 2, ..validation }), Ok(()));
+    }
+
+    #[test]
+    fn error_extensions() {
+        let error = ServerError::from(OperationLimitExceeded::RootFields { max: 3 });
+        insta::assert_json_snapshot!(error, @r###"
+        {
+          "message": "Query contains too many root fields.",
+          "extensions": {
+            "code": "OPERATION_LIMIT_EXCEEDED",
+            "limit": "rootFields",
+            "max": 3
+          }
+        }
+        "###);
+    }
+}
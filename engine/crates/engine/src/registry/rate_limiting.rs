@@ -0,0 +1,65 @@
+use std::{collections::BTreeMap, time::Duration};
+
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
+/// How many requests clients can send to the gateway, declared with `@rateLimit`.
+///
+/// Requests are counted against the quota of their operation if it has one, the global quota
+/// otherwise. Root fields with a quota of their own are counted separately on top of that.
+/// Requests count for 1, or for the complexity of their operation if `cost` is enabled.
+pub struct RateLimitConfig {
+    /// What the requests are counted by
+    #[serde(default)]
+    pub key: RateLimitKey,
+    /// Whether the IP address of clients is taken from the headers set by proxies, rather than
+    /// from the connection. Clients can set those headers themselves without a proxy in front.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trust_proxy_headers: bool,
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
+    /// The quota of all requests, if there's one for the whole schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global: Option<RateLimitQuota>,
+    /// Overrides of the global quota, by operation name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub operations: BTreeMap<String, RateLimitQuota>,
+    /// Quotas of the root fields, by root type & field name, e.g. `Mutation.createUser`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub root_fields: BTreeMap<String, RateLimitQuota>,
+    /// Whether requests count for the complexity of their operation instead of 1. Operations
+    /// costing more than the limit of a quota are rejected.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cost: bool,
+}
+
+impl RateLimitConfig {
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.operations.is_empty() && self.root_fields.is_empty()
+    }
+}
+
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// The IP address of the client. Requests without the other keys are counted by it too.
+    #[default]
+    Ip,
+    ApiKey,
+    JwtClaim(String),
+    Header(String),
+}
+
+#[derive(Clone, Copy, Default, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
+pub enum RateLimitAlgorithm {
+    /// Allows bursts of up to `limit` requests, refilling the bucket evenly over the duration
+    #[default]
+    TokenBucket,
+    /// Allows `limit` requests in any window of the duration, approximated from the counts of the
+    /// current and previous windows
+    SlidingWindow,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
+/// `limit` requests are allowed per `duration`
+pub struct RateLimitQuota {
+    pub limit: u32,
+    pub duration: Duration,
+}
@@ -1,6 +1,7 @@
 mod consts;
 mod cursor;
 mod input;
+mod lookup;
 mod normalize;
 mod operation;
 mod pagination;
@@ -12,6 +13,7 @@ use super::{ResolvedValue, ResolverContext};
 use crate::{ContextExt, ContextField, Error};
 use async_runtime::make_send_on_wasm;
 use futures_util::Future;
+pub use lookup::AtlasLookup;
 pub use operation::OperationType;
 use std::pin::Pin;
 
@@ -29,6 +31,10 @@ pub struct AtlasDataApiResolver {
     pub operation_type: OperationType,
     pub directive_name: String,
     pub collection: String,
+    /// Set for relation fields, which are fetched with a `$lookup` stage
+    /// in the query of the parent document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lookup: Option<AtlasLookup>,
 }
 
 impl AtlasDataApiResolver {
@@ -36,7 +42,17 @@ impl AtlasDataApiResolver {
         &'a self,
         ctx: &'a ContextField<'_>,
         resolver_ctx: &'a ResolverContext<'_>,
+        last_resolver_value: Option<&'a ResolvedValue>,
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedValue, Error>> + Send + 'a>> {
+        // the related documents are already part of the parent document.
+        if let OperationType::Lookup = self.operation_type {
+            let value = last_resolver_value
+                .and_then(|value| value.get_field(resolver_ctx.field.target_field_name()))
+                .unwrap_or_else(ResolvedValue::null);
+
+            return Box::pin(async move { Ok(value) });
+        }
+
         let config = ctx
             .get_mongodb_config(&self.directive_name)
             .expect("directive must exist");
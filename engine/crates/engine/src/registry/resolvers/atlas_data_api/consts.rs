@@ -21,3 +21,11 @@ pub(super) const OP_TIMESTAMP: &str = "$timestamp";
 pub(super) const OP_LONG: &str = "$numberLong";
 pub(super) const OP_DECIMAL: &str = "$numberDecimal";
 pub(super) const OP_ELEM_MATCH: &str = "$elemMatch";
+pub(super) const OP_ALL: &str = "$all";
+pub(super) const OP_REGEX: &str = "$regex";
+pub(super) const OP_OPTIONS: &str = "$options";
+pub(super) const OP_LIKE: &str = "$like";
+pub(super) const OP_ILIKE: &str = "$ilike";
+pub(super) const OP_STARTS_WITH: &str = "$startsWith";
+pub(super) const OP_ENDS_WITH: &str = "$endsWith";
+pub(super) const REGULAR_EXPRESSION: &str = "$regularExpression";
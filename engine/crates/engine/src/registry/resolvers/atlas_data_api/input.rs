@@ -26,7 +26,9 @@ pub(super) fn by(ctx: &ContextField<'_>) -> ServerResult<JsonMap> {
 pub(super) fn filter(ctx: &ContextField<'_>) -> ServerResult<JsonMap> {
     let map = ctx.input_by_name("filter")?;
     let input_type = ctx.find_argument_type("filter")?;
-    let map = normalize::flatten_keys(normalize::keys_and_values(ctx, map, input_type));
+    let map = normalize::patterns(normalize::keys_and_values(ctx, map, input_type))
+        .map_err(|error| error.into_server_error(ctx.item.pos))?;
+    let map = normalize::flatten_keys(map);
 
     let map = match pagination::before(ctx)? {
         Some(before) => {
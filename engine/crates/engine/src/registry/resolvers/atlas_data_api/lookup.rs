@@ -0,0 +1,87 @@
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+
+use super::{projection, AtlasDataApiResolver};
+use crate::{
+    registry::{resolvers::Resolver, MetaField, MetaType},
+    ContextExt, ContextField, Error, SelectionField,
+};
+
+/// Defines how the documents of a related collection are joined to the
+/// parent document.
+#[serde_with::minify_field_names(serialize = "minified", deserialize = "minified")]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct AtlasLookup {
+    /// The field in the parent document, as named in the database.
+    pub local_field: String,
+    /// The field in the related documents, as named in the database.
+    pub foreign_field: String,
+}
+
+/// Generates the `$lookup` stages for all selected relation fields. The stages
+/// embed the related documents to the parent document, using the database name
+/// of the relation field as the key.
+pub(super) fn stages<'a>(
+    ctx: &'a ContextField<'a>,
+    selection: impl Iterator<Item = SelectionField<'a>> + 'a,
+    target: &IndexMap<String, MetaField>,
+) -> Result<Vec<Value>, Error> {
+    let mut stages = Vec::new();
+
+    for field in selection.flat_map(|selection| selection.selection_set()) {
+        let field_name = field.field.name.as_str();
+
+        let meta_field = target
+            .get(field_name)
+            .ok_or_else(|| Error::new(format!("Field name {field_name} not found from the selection type.")))?;
+
+        let Resolver::MongoResolver(AtlasDataApiResolver {
+            lookup: Some(ref lookup),
+            ref collection,
+            ..
+        }) = meta_field.resolver
+        else {
+            continue;
+        };
+
+        let fields = ctx
+            .get_type(meta_field.ty.base_type_name())
+            .and_then(MetaType::fields)
+            .ok_or_else(|| Error::new(format!("Relation field {field_name} must point to a model.")))?;
+
+        let mut pipeline = stages(ctx, std::iter::once(field), fields)?;
+        let projection = projection::project(ctx, std::iter::once(field), fields)?;
+        pipeline.push(json!({ "$project": projection }));
+
+        let database_name = meta_field.target_field_name();
+
+        stages.push(json!({
+            "$lookup": {
+                "from": collection,
+                "localField": lookup.local_field,
+                "foreignField": lookup.foreign_field,
+                "as": database_name,
+                "pipeline": pipeline,
+            }
+        }));
+
+        // `$lookup` always returns an array, for single relations we take the first document.
+        if !meta_field.ty.is_list() {
+            stages.push(json!({
+                "$set": {
+                    database_name: { "$arrayElemAt": [format!("${database_name}"), 0] }
+                }
+            }));
+        }
+    }
+
+    Ok(stages)
+}
+
+/// True, if the field is a relation resolved by a `$lookup` stage.
+pub(super) fn is_lookup(field: &MetaField) -> bool {
+    matches!(
+        field.resolver,
+        Resolver::MongoResolver(AtlasDataApiResolver { lookup: Some(_), .. })
+    )
+}
@@ -1,11 +1,16 @@
 use super::{value::MongoValue, JsonMap};
 use crate::{
     registry::{
-        resolvers::atlas_data_api::consts::OP_ELEM_MATCH, type_kinds::InputType, MetaInputValue, TypeReference,
+        resolvers::atlas_data_api::consts::{
+            OP_ALL, OP_ELEM_MATCH, OP_ENDS_WITH, OP_ILIKE, OP_LIKE, OP_OPTIONS, OP_REGEX, OP_STARTS_WITH,
+            REGULAR_EXPRESSION,
+        },
+        type_kinds::InputType,
+        MetaInputValue, TypeReference,
     },
-    ContextField,
+    ContextField, Error,
 };
-use serde_json::Value;
+use serde_json::{json, Value};
 
 /// Given the input keys, converts them to the names on MongoDB.
 ///
@@ -116,6 +121,102 @@ pub(super) fn flatten_keys(input: JsonMap) -> JsonMap {
     result
 }
 
+/// MongoDB has no pattern operators other than `$regex`. Converts the `like`, `ilike`,
+/// `startsWith` and `endsWith` filters into regular expressions, escaping the user input.
+/// Null patterns don't filter anything, and are left out.
+///
+/// A field can only have one `$regex`, so if multiple pattern filters are given for the same
+/// field, they are combined with `$all`, which for a string field means all of the expressions
+/// must match.
+pub(super) fn patterns(input: JsonMap) -> Result<JsonMap, Error> {
+    let mut result = JsonMap::new();
+    let mut expressions = Vec::new();
+
+    for (key, value) in input {
+        if !PATTERN_OPERATORS.contains(&key.as_str()) {
+            result.insert(key, patterns_in_value(value)?);
+            continue;
+        }
+
+        let pattern = match value {
+            Value::Null => continue,
+            Value::String(pattern) => pattern,
+            _ => {
+                let filter = key.trim_start_matches('$');
+                return Err(Error::new(format!("The {filter} filter requires a string")));
+            }
+        };
+
+        match key.as_str() {
+            OP_LIKE => expressions.push((like_to_regex(&pattern), "s")),
+            OP_ILIKE => expressions.push((like_to_regex(&pattern), "is")),
+            OP_STARTS_WITH => expressions.push((format!("^{}", regex::escape(&pattern)), "")),
+            OP_ENDS_WITH => expressions.push((format!("{}$", regex::escape(&pattern)), "")),
+            _ => expressions.push((pattern, "")),
+        }
+    }
+
+    match expressions.len() {
+        0 => (),
+        1 => {
+            let (pattern, options) = expressions.pop().unwrap();
+            result.insert(OP_REGEX.to_string(), Value::from(pattern));
+
+            if !options.is_empty() {
+                result.insert(OP_OPTIONS.to_string(), Value::from(options));
+            }
+        }
+        _ => {
+            let expressions = expressions
+                .into_iter()
+                .map(|(pattern, options)| json!({ REGULAR_EXPRESSION: { "pattern": pattern, "options": options } }))
+                .collect();
+
+            result.insert(OP_ALL.to_string(), Value::Array(expressions));
+        }
+    }
+
+    Ok(result)
+}
+
+const PATTERN_OPERATORS: &[&str] = &[OP_LIKE, OP_ILIKE, OP_STARTS_WITH, OP_ENDS_WITH, OP_REGEX];
+
+fn patterns_in_value(value: Value) -> Result<Value, Error> {
+    match value {
+        Value::Object(object) => Ok(Value::Object(patterns(object)?)),
+        Value::Array(values) => Ok(Value::Array(
+            values.into_iter().map(patterns_in_value).collect::<Result<_, _>>()?,
+        )),
+        value => Ok(value),
+    }
+}
+
+/// Converts an SQL `LIKE` pattern into an anchored regular expression. `%` matches any
+/// sequence of characters, `_` a single character, and a backslash escapes the next character.
+fn like_to_regex(pattern: &str) -> String {
+    let mut regex = String::with_capacity(pattern.len() + 2);
+    let mut chars = pattern.chars();
+
+    regex.push('^');
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    regex.push_str(&regex::escape(&escaped.to_string()));
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    regex.push('$');
+
+    regex
+}
+
 fn normalize<F>(normalize: F, ctx: &ContextField<'_>, value: Value, input_meta: &MetaInputValue) -> Value
 where
     F: Fn(&ContextField<'_>, JsonMap, InputType<'_>) -> JsonMap,
@@ -11,6 +11,10 @@ pub enum OperationType {
     DeleteMany,
     UpdateOne,
     UpdateMany,
+    Aggregate,
+    /// Selects documents of a related collection, fetched with a `$lookup` stage
+    /// together with the parent document.
+    Lookup,
 }
 
 impl AsRef<str> for OperationType {
@@ -24,6 +28,8 @@ impl AsRef<str> for OperationType {
             Self::DeleteMany => "deleteMany",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::Aggregate => "aggregate",
+            Self::Lookup => "lookup",
         }
     }
 }
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use serde_json::Value;
 
-use super::{normalize, JsonMap};
+use super::{lookup, normalize, JsonMap};
 use crate::{
     names::MONGODB_OUTPUT_FIELD_ID,
     registry::{MetaField, MetaType},
@@ -40,6 +40,12 @@ fn recurse<'a>(
 
         let database_name = meta_field.target_field_name().to_string();
 
+        // the related documents are projected in the pipeline of the lookup stage.
+        if lookup::is_lookup(meta_field) {
+            output.insert(database_name, Value::from(1));
+            continue;
+        }
+
         match ctx.get_type(meta_field.ty.base_type_name()).and_then(MetaType::fields) {
             Some(fields) => {
                 let mut inner = JsonMap::new();
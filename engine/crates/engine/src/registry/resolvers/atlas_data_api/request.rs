@@ -3,7 +3,7 @@ mod query;
 use super::OperationType;
 use crate::{
     registry::{
-        resolvers::{ResolvedValue, ResolverContext},
+        resolvers::{http_client::HttpClient, ResolvedValue, ResolverContext},
         MongoDBConfiguration,
     },
     ContextExt, ContextField, Error,
@@ -12,7 +12,10 @@ use http::{
     header::{ACCEPT, CONTENT_TYPE, USER_AGENT},
     StatusCode,
 };
-use query::{AtlasQuery, DeleteMany, DeleteOne, FindMany, FindOne, InsertMany, InsertOne, UpdateMany, UpdateOne};
+use query::{
+    Aggregate, AtlasQuery, DeleteMany, DeleteOne, FindMany, FindOne, InsertMany, InsertOne, UpdateMany, UpdateOne,
+};
+use runtime::mongodb::MongoTransport;
 use serde::Serialize;
 use serde_json::Value;
 
@@ -38,6 +41,8 @@ pub(super) async fn execute(
         OperationType::InsertMany => InsertMany::new(ctx)?.into(),
         OperationType::UpdateOne => UpdateOne::new(ctx)?.into(),
         OperationType::UpdateMany => UpdateMany::new(ctx)?.into(),
+        OperationType::Aggregate => Aggregate::new(ctx, resolver_ctx)?.into(),
+        OperationType::Lookup => unreachable!("lookups are resolved from the parent document"),
     };
 
     // In some cases, if our input is empty, we want to short-circuit here and
@@ -57,18 +62,34 @@ pub(super) async fn execute(
         query,
     };
 
-    let url = format!("{}/action/{}", config.url, operation_type);
+    let action = request.query.action(operation_type);
+
+    let value = match config.connection_string {
+        Some(ref connection_string) => execute_wire_protocol(ctx, connection_string, action, &request).await?,
+        None => execute_data_api(ctx, config, action, &request).await?,
+    };
+
+    request.convert_result(ctx, resolver_ctx, value)
+}
+
+async fn execute_data_api(
+    ctx: &ContextField<'_>,
+    config: &MongoDBConfiguration,
+    action: OperationType,
+    request: &AtlasRequest<'_>,
+) -> Result<Value, Error> {
+    let url = format!("{}/action/{action}", config.url);
     let runtime_ctx = ctx.data::<runtime::Context>()?;
     let ray_id = runtime_ctx.ray_id();
     let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
 
-    let request_builder = reqwest::Client::new()
+    let request_builder = HttpClient::from_context(ctx)?
         .post(url)
         .header(CONTENT_TYPE, headers::APPLICATION_EJSON_CONTENT_TYPE)
         .header(ACCEPT, headers::APPLICATION_JSON_CONTENT_TYPE)
         .header(headers::API_KEY_HEADER_NAME, &config.api_key)
         .header(USER_AGENT, "Grafbase")
-        .json(&request);
+        .json(request);
 
     let value = super::super::logged_fetch::send_logged_request(ray_id, fetch_log_endpoint_url, request_builder)
         .await
@@ -80,7 +101,23 @@ pub(super) async fn execute(
         .map_err(map_err)?
         .take();
 
-    request.convert_result(ctx, resolver_ctx, value)
+    Ok(value)
+}
+
+/// Sends the same request body we'd send to the Data API over the MongoDB wire protocol.
+async fn execute_wire_protocol(
+    ctx: &ContextField<'_>,
+    connection_string: &str,
+    action: OperationType,
+    request: &AtlasRequest<'_>,
+) -> Result<Value, Error> {
+    let transport = ctx.data::<MongoTransport>()?;
+    let request = serde_json::to_value(request)?;
+
+    transport
+        .execute(connection_string, action.as_ref(), request)
+        .await
+        .map_err(|error| Error::new(error.to_string()))
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -103,6 +140,7 @@ impl<'a> AtlasRequest<'a> {
         let result = match self.query {
             AtlasQuery::FindOne(ref query) => query.convert_result(&mut value),
             AtlasQuery::FindMany(ref query) => query.convert_result(ctx, resolver_ctx, &mut value)?,
+            AtlasQuery::Aggregate(ref query) => query.convert_result(&mut value),
             _ => ResolvedValue::new(value),
         };
 
@@ -1,3 +1,4 @@
+mod aggregate;
 mod delete_many;
 mod delete_one;
 mod find_many;
@@ -7,6 +8,7 @@ mod insert_one;
 mod update_many;
 mod update_one;
 
+pub(super) use aggregate::Aggregate;
 pub(super) use delete_many::DeleteMany;
 pub(super) use delete_one::DeleteOne;
 pub(super) use find_many::FindMany;
@@ -16,7 +18,11 @@ pub(super) use insert_one::InsertOne;
 pub(super) use update_many::UpdateMany;
 pub(super) use update_one::UpdateOne;
 
-use serde_json::Value;
+use super::OperationType;
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+
+use crate::registry::resolvers::atlas_data_api::JsonMap;
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(untagged)]
@@ -29,6 +35,7 @@ pub(super) enum AtlasQuery {
     DeleteMany(DeleteMany),
     UpdateOne(UpdateOne),
     UpdateMany(UpdateMany),
+    Aggregate(Aggregate),
 }
 
 impl AtlasQuery {
@@ -40,6 +47,15 @@ impl AtlasQuery {
         }
     }
 
+    /// The Data API action to call. Queries with relations are sent as aggregations.
+    pub(super) fn action(&self, operation_type: OperationType) -> OperationType {
+        match self {
+            AtlasQuery::FindOne(query) if query.is_aggregate() => OperationType::Aggregate,
+            AtlasQuery::FindMany(query) if query.is_aggregate() => OperationType::Aggregate,
+            _ => operation_type,
+        }
+    }
+
     pub(super) fn empty_response(&self) -> Value {
         match self {
             AtlasQuery::UpdateOne(query) => query.empty_response(),
@@ -48,3 +64,70 @@ impl AtlasQuery {
         }
     }
 }
+
+/// The body of a query fetching documents. If the selection has relation fields,
+/// the query is sent as an aggregation pipeline with `$lookup` stages.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(untagged)]
+pub(super) enum FindRequest {
+    Find {
+        filter: JsonMap,
+        projection: JsonMap,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<IndexMap<String, Value>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+    },
+    Aggregate {
+        pipeline: Vec<Value>,
+    },
+}
+
+impl FindRequest {
+    pub(super) fn new(
+        filter: JsonMap,
+        projection: JsonMap,
+        sort: Option<IndexMap<String, Value>>,
+        limit: Option<usize>,
+        lookups: Vec<Value>,
+    ) -> Self {
+        if lookups.is_empty() {
+            return Self::Find {
+                filter,
+                projection,
+                sort,
+                limit,
+            };
+        }
+
+        Self::pipeline(filter, projection, sort, limit, lookups)
+    }
+
+    pub(super) fn pipeline(
+        filter: JsonMap,
+        projection: JsonMap,
+        sort: Option<IndexMap<String, Value>>,
+        limit: Option<usize>,
+        lookups: Vec<Value>,
+    ) -> Self {
+        let mut pipeline = vec![json!({ "$match": filter })];
+
+        if let Some(sort) = sort {
+            pipeline.push(json!({ "$sort": sort }));
+        }
+
+        if let Some(limit) = limit {
+            pipeline.push(json!({ "$limit": limit }));
+        }
+
+        // lookups after limiting, so we only join the documents we return.
+        pipeline.extend(lookups);
+        pipeline.push(json!({ "$project": projection }));
+
+        Self::Aggregate { pipeline }
+    }
+
+    pub(super) fn is_aggregate(&self) -> bool {
+        matches!(self, Self::Aggregate { .. })
+    }
+}
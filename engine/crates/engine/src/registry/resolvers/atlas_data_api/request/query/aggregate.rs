@@ -0,0 +1,134 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::AtlasQuery;
+use crate::{
+    registry::{
+        resolvers::{
+            atlas_data_api::{input, JsonMap},
+            ResolvedValue, ResolverContext,
+        },
+        type_kinds::{OutputType, SelectionSetTarget},
+        MetaType,
+    },
+    Context, ContextField, Error,
+};
+
+const GROUP_BY: &str = "groupBy";
+
+/// The accumulators available in the aggregate output, with their MongoDB operators.
+const ACCUMULATORS: &[(&str, &str)] = &[("sum", "$sum"), ("avg", "$avg"), ("min", "$min"), ("max", "$max")];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Aggregate {
+    pipeline: Vec<Value>,
+}
+
+impl Aggregate {
+    pub fn new(ctx: &ContextField<'_>, resolver_ctx: &ResolverContext<'_>) -> Result<Self, Error> {
+        let filter = input::filter(ctx)?;
+        let group_by = group_by(ctx, resolver_ctx)?;
+
+        let group_id = if group_by.is_empty() {
+            Value::Null
+        } else {
+            group_by
+                .iter()
+                .map(|field| (field.clone(), Value::from(format!("${field}"))))
+                .collect::<JsonMap>()
+                .into()
+        };
+
+        let mut group = JsonMap::new();
+        group.insert(String::from("_id"), group_id);
+        group.insert(String::from("count"), json!({ "$sum": 1 }));
+
+        let mut project = JsonMap::new();
+        project.insert(String::from("_id"), Value::from(0));
+        project.insert(String::from("group"), Value::from("$_id"));
+        project.insert(String::from("count"), Value::from(1));
+
+        let selection_target: SelectionSetTarget<'_> = resolver_ctx.ty.try_into().unwrap();
+
+        for (name, operator) in ACCUMULATORS {
+            if !ctx.look_ahead().field(name).exists() {
+                continue;
+            }
+
+            let output_type = selection_target
+                .field(name)
+                .and_then(|field| ctx.registry().lookup(&field.ty).ok());
+
+            let Some(fields) = output_type.as_ref().and_then(OutputType::field_map) else {
+                continue;
+            };
+
+            let mut values = JsonMap::new();
+
+            // $group doesn't allow nested or dotted output fields, so we accumulate to
+            // flat fields first and nest them in the projection.
+            for (i, field) in fields.values().enumerate() {
+                let database_name = field.target_field_name();
+                let accumulated = format!("{name}{i}");
+
+                group.insert(accumulated.clone(), json!({ *operator: format!("${database_name}") }));
+                values.insert(database_name.to_string(), Value::from(format!("${accumulated}")));
+            }
+
+            project.insert((*name).to_string(), Value::Object(values));
+        }
+
+        let pipeline = vec![
+            json!({ "$match": filter }),
+            json!({ "$group": group }),
+            json!({ "$sort": { "_id": 1 } }),
+            json!({ "$project": project }),
+        ];
+
+        Ok(Self { pipeline })
+    }
+
+    pub fn convert_result(&self, result: &mut Value) -> ResolvedValue {
+        let documents = result
+            .as_object_mut()
+            .and_then(|object| object.remove("documents"))
+            .unwrap_or(Value::Array(Vec::new()));
+
+        ResolvedValue::new(documents)
+    }
+}
+
+/// The database names of the fields to group by.
+fn group_by(ctx: &ContextField<'_>, resolver_ctx: &ResolverContext<'_>) -> Result<Vec<String>, Error> {
+    let Ok(variants) = ctx.input_by_name::<Vec<String>>(GROUP_BY) else {
+        return Ok(Vec::new());
+    };
+
+    let enum_type = resolver_ctx
+        .field
+        .args
+        .get(GROUP_BY)
+        .and_then(|argument| ctx.registry().types.get(argument.ty.named_type().as_str()));
+
+    let Some(MetaType::Enum(enum_type)) = enum_type else {
+        return Err(Error::new("groupBy argument must be an enum"));
+    };
+
+    variants
+        .into_iter()
+        .map(|variant| {
+            enum_type
+                .enum_values
+                .get(&variant)
+                .map(|value| value.value.clone().unwrap_or_else(|| variant.clone()))
+                .ok_or_else(|| Error::new(format!("unknown field {variant} in groupBy")))
+        })
+        .collect()
+}
+
+impl From<Aggregate> for AtlasQuery {
+    fn from(value: Aggregate) -> Self {
+        Self::Aggregate(value)
+    }
+}
@@ -1,11 +1,11 @@
-use super::AtlasQuery;
+use super::{AtlasQuery, FindRequest};
 use crate::{
     names::OUTPUT_EDGE_CURSOR,
     registry::{
         resolvers::{
             atlas_data_api::{
                 cursor::AtlasCursor,
-                input,
+                input, lookup,
                 pagination::{self, PaginationContext},
                 projection, JsonMap,
             },
@@ -15,10 +15,8 @@ use crate::{
     },
     Context, ContextField, Error,
 };
-use indexmap::IndexMap;
 use runtime::search::GraphqlCursor;
 use serde::Serialize;
-use serde_json::Value;
 
 #[derive(Debug, Clone)]
 struct Metadata {
@@ -28,11 +26,8 @@ struct Metadata {
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FindMany {
-    filter: JsonMap,
-    projection: JsonMap,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    sort: Option<IndexMap<String, Value>>,
-    limit: Option<usize>,
+    #[serde(flatten)]
+    request: FindRequest,
     #[serde(skip)]
     metadata: Metadata,
 }
@@ -62,7 +57,8 @@ impl FindMany {
         let selection_field_types = selection_type.as_ref().and_then(OutputType::field_map).unwrap();
 
         let selection = ctx.look_ahead().field("edges").field("node").selection_fields();
-        let projection = projection::project(ctx, selection.into_iter(), selection_field_types)?;
+        let projection = projection::project(ctx, selection.iter().copied(), selection_field_types)?;
+        let lookups = lookup::stages(ctx, selection.into_iter(), selection_field_types)?;
         let filter = input::filter(ctx)?;
 
         let order_by = input::order_by(ctx);
@@ -73,14 +69,15 @@ impl FindMany {
         let metadata = Metadata { order_by };
 
         Ok(Self {
-            filter,
-            projection,
-            sort,
-            limit,
+            request: FindRequest::new(filter, projection, sort, limit, lookups),
             metadata,
         })
     }
 
+    pub fn is_aggregate(&self) -> bool {
+        self.request.is_aggregate()
+    }
+
     pub fn convert_result(
         &self,
         ctx: &ContextField<'_>,
@@ -3,7 +3,7 @@ use serde::Serialize;
 use crate::{
     registry::{
         resolvers::{
-            atlas_data_api::{input, projection, JsonMap},
+            atlas_data_api::{input, lookup, projection},
             ResolvedValue, ResolverContext,
         },
         type_kinds::SelectionSetTarget,
@@ -11,13 +11,13 @@ use crate::{
     ContextField, Error,
 };
 
-use super::AtlasQuery;
+use super::{AtlasQuery, FindRequest};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FindOne {
-    filter: JsonMap,
-    projection: JsonMap,
+    #[serde(flatten)]
+    request: FindRequest,
 }
 
 impl FindOne {
@@ -26,19 +26,39 @@ impl FindOne {
         let available_fields = selection_set.field_map().unwrap();
         let selection = ctx.look_ahead().selection_fields();
 
-        let projection = projection::project(ctx, selection.into_iter(), available_fields)?;
+        let projection = projection::project(ctx, selection.iter().copied(), available_fields)?;
+        let lookups = lookup::stages(ctx, selection.into_iter(), available_fields)?;
         let filter = input::by(ctx)?;
 
-        Ok(Self { filter, projection })
+        let request = if lookups.is_empty() {
+            FindRequest::Find {
+                filter,
+                projection,
+                sort: None,
+                limit: None,
+            }
+        } else {
+            FindRequest::pipeline(filter, projection, None, Some(1), lookups)
+        };
+
+        Ok(Self { request })
+    }
+
+    pub fn is_aggregate(&self) -> bool {
+        self.request.is_aggregate()
     }
 
     pub fn convert_result(&self, result: &mut serde_json::Value) -> ResolvedValue {
-        let value = result
-            .as_object_mut()
-            .and_then(|object| object.remove("document"))
-            .unwrap_or(serde_json::Value::Null);
+        let value = match self.request {
+            // aggregations always return a list of documents.
+            FindRequest::Aggregate { .. } => result
+                .as_object_mut()
+                .and_then(|object| object.remove("documents"))
+                .and_then(|mut documents| documents.as_array_mut().and_then(|documents| documents.pop())),
+            FindRequest::Find { .. } => result.as_object_mut().and_then(|object| object.remove("document")),
+        };
 
-        ResolvedValue::new(value)
+        ResolvedValue::new(value.unwrap_or(serde_json::Value::Null))
     }
 }
 
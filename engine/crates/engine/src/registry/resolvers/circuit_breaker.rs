@@ -0,0 +1,177 @@
+//! Circuit breakers for connectors, so we stop waiting on downstream servers that keep failing.
+
+use std::sync::{Arc, Mutex};
+
+use common_types::CircuitBreakerState;
+use web_time::Instant;
+
+use crate::{registry::CircuitBreakerPolicy, Error, ErrorExtensions};
+
+/// The circuit breaker of a single connector. The state is shared by every clone, and by every
+/// breaker the [`HttpClient`](super::http_client::HttpClient) hands out for the connector.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    connector: String,
+    policy: CircuitBreakerPolicy,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum State {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { probe_started: Instant },
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Closed { failures: 0 }
+    }
+}
+
+impl State {
+    fn public(self) -> CircuitBreakerState {
+        match self {
+            State::Closed { .. } => CircuitBreakerState::Closed,
+            State::Open { .. } => CircuitBreakerState::Open,
+            State::HalfOpen { .. } => CircuitBreakerState::HalfOpen,
+        }
+    }
+}
+
+impl CircuitBreaker {
+    pub(super) fn new(connector: String, policy: CircuitBreakerPolicy, state: Arc<Mutex<State>>) -> Self {
+        CircuitBreaker {
+            connector,
+            policy,
+            state,
+        }
+    }
+
+    pub fn connector(&self) -> &str {
+        &self.connector
+    }
+
+    /// Checks whether we may send a request to the connector, returning the new state if that
+    /// moved the breaker to half open.
+    ///
+    /// Once the reset timeout has passed an open breaker lets a single probe through. If that
+    /// probe never reports back, we let another one through after the reset timeout again.
+    pub fn acquire(&self) -> Result<Option<CircuitBreakerState>, CircuitOpen> {
+        self.acquire_at(Instant::now())
+    }
+
+    /// Records the outcome of a request, returning the new state if it changed.
+    pub fn record(&self, success: bool) -> Option<CircuitBreakerState> {
+        self.record_at(success, Instant::now())
+    }
+
+    fn acquire_at(&self, now: Instant) -> Result<Option<CircuitBreakerState>, CircuitOpen> {
+        let mut state = self.state.lock().expect("poisoned circuit breaker");
+
+        match *state {
+            State::Closed { .. } => Ok(None),
+            State::Open { since } | State::HalfOpen { probe_started: since }
+                if now.duration_since(since) >= self.policy.reset_timeout =>
+            {
+                let changed = !matches!(*state, State::HalfOpen { .. });
+                *state = State::HalfOpen { probe_started: now };
+                Ok(changed.then_some(CircuitBreakerState::HalfOpen))
+            }
+            State::Open { .. } | State::HalfOpen { .. } => Err(CircuitOpen {
+                connector: self.connector.clone(),
+            }),
+        }
+    }
+
+    fn record_at(&self, success: bool, now: Instant) -> Option<CircuitBreakerState> {
+        let mut state = self.state.lock().expect("poisoned circuit breaker");
+        let previous = *state;
+
+        *state = match (previous, success) {
+            (_, true) => State::Closed { failures: 0 },
+            (State::Closed { failures }, false) if failures + 1 < self.policy.failure_threshold => {
+                State::Closed { failures: failures + 1 }
+            }
+            // A request sent before the breaker opened shouldn't extend how long it stays open.
+            (State::Open { since }, false) => State::Open { since },
+            (State::Closed { .. } | State::HalfOpen { .. }, false) => State::Open { since: now },
+        };
+
+        (previous.public() != state.public()).then(|| state.public())
+    }
+}
+
+/// We didn't send a request as the circuit breaker of its connector is open.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("the {connector} connector is unavailable as its requests have been failing, it will be retried shortly")]
+pub struct CircuitOpen {
+    pub connector: String,
+}
+
+impl ErrorExtensions for CircuitOpen {
+    fn extend(&self) -> Error {
+        Error::new(self.to_string()).extend_with(|_, extensions| {
+            extensions.set("code", "CIRCUIT_OPEN");
+            extensions.set("connector", self.connector.clone());
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(
+            "petstore".into(),
+            CircuitBreakerPolicy {
+                failure_threshold: 2,
+                reset_timeout: Duration::from_secs(10),
+            },
+            Arc::default(),
+        )
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = breaker();
+        let now = Instant::now();
+
+        assert_eq!(breaker.record_at(false, now), None);
+        assert_eq!(breaker.record_at(true, now), None);
+        assert_eq!(breaker.record_at(false, now), None);
+        assert_eq!(breaker.record_at(false, now), Some(CircuitBreakerState::Open));
+
+        assert_eq!(
+            breaker.acquire_at(now + Duration::from_secs(1)),
+            Err(CircuitOpen {
+                connector: "petstore".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let breaker = breaker();
+        let now = Instant::now();
+
+        breaker.record_at(false, now);
+        breaker.record_at(false, now);
+
+        let later = now + Duration::from_secs(10);
+        assert_eq!(breaker.acquire_at(later), Ok(Some(CircuitBreakerState::HalfOpen)));
+        // Only the one probe gets through until it reports back.
+        assert!(breaker.acquire_at(later).is_err());
+
+        assert_eq!(breaker.record_at(false, later), Some(CircuitBreakerState::Open));
+        assert!(breaker.acquire_at(later + Duration::from_secs(1)).is_err());
+
+        let even_later = later + Duration::from_secs(10);
+        assert_eq!(breaker.acquire_at(even_later), Ok(Some(CircuitBreakerState::HalfOpen)));
+        assert_eq!(breaker.record_at(true, even_later), Some(CircuitBreakerState::Closed));
+        assert_eq!(breaker.acquire_at(even_later), Ok(None));
+    }
+}
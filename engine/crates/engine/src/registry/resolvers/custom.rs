@@ -1,6 +1,10 @@
-use std::hash::Hash;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
 
-use common_types::UdfKind;
+use common_types::{auth::ExecutionAuth, UdfKind};
+use dataloader::{DataLoader, Loader, NoCache};
 use dynamodb::attribute_to_value;
 use dynomite::AttributeValue;
 use runtime::udf::{
@@ -9,11 +13,15 @@ use runtime::udf::{
 };
 
 use super::ResolvedValue;
-use crate::{ContextExt, ContextField, Error, ErrorExtensionValues};
+use crate::{ContextExt, ContextField, Error, ErrorExtensionValues, QueryPathSegment, SelectionField};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
 pub struct CustomResolver {
     pub resolver_name: String,
+    /// Whether the resolver is invoked once for all the parents of the field at a given depth,
+    /// rather than once per parent. See [`CustomResolverBatcher`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub batched: bool,
 }
 
 impl CustomResolver {
@@ -51,6 +59,60 @@ impl CustomResolver {
             .map(|(name, value)| value.into_json().map(|value| (name.to_string(), value)))
             .collect::<serde_json::Result<_>>()?;
         let ray_id = runtime_ctx.ray_id();
+        let context = UdfRequestContext {
+            request: UdfRequestContextRequest {
+                headers: serde_json::to_value(&runtime_ctx.headers_as_map()).expect("must be valid"),
+            },
+            auth: ctx.data_opt::<ExecutionAuth>().map(Into::into),
+        };
+        let look_ahead = ctx.look_ahead();
+        let selected_fields = selection_set(
+            look_ahead
+                .iter_selection_fields()
+                .flat_map(|field| field.selection_set()),
+        )?;
+        let mut info = serde_json::json!({
+            "fieldName": ctx.item.name.node.as_str(),
+            "parentType": ctx.parent_type.name(),
+            "returnType": ctx.field.ty.as_str(),
+            "selectionSet": selected_fields,
+            "variableValues": &ctx.query_env.variables,
+        });
+
+        if self.batched {
+            let batcher = ctx.data::<CustomResolverBatcher>()?;
+            let invocation = BatchedInvocation {
+                batch: Batch {
+                    resolver_name: self.resolver_name.clone(),
+                    field_path: ctx
+                        .path
+                        .iter()
+                        .filter_map(|segment| match segment {
+                            QueryPathSegment::Field(name) => Some(name.to_string()),
+                            QueryPathSegment::Index(_) => None,
+                        })
+                        .collect(),
+                    ray_id: ray_id.to_string(),
+                    context: serde_json::to_string(&context)?,
+                    info: serde_json::to_string(&info)?,
+                },
+                item: serde_json::to_string(&serde_json::json!({
+                    "parent": parent.data_resolved(),
+                    "args": arguments,
+                }))?,
+            };
+
+            return batcher
+                .loader
+                .load_one(invocation)
+                .await?
+                .ok_or_else(|| Error::new("Batched resolver result is missing"))?
+                .map(ResolvedValue::new);
+        }
+
+        // The path differs for every parent, so only unbatched invocations get it.
+        info["path"] = serde_json::to_value(ctx.response_path())?;
+
         let future = custom_resolvers_engine.invoke(
             ray_id,
             UdfRequest {
@@ -59,16 +121,8 @@ impl CustomResolver {
                 payload: CustomResolverRequestPayload {
                     arguments,
                     parent: Some(parent.data_resolved().clone()),
-                    context: UdfRequestContext {
-                        request: UdfRequestContextRequest {
-                            headers: serde_json::to_value(&runtime_ctx.headers_as_map()).expect("must be valid"),
-                        },
-                    },
-                    info: Some(serde_json::json!({
-                        "fieldName": ctx.item.name.node.as_str(),
-                        "path": ctx.response_path(),
-                        "variableValues": &ctx.query_env.variables,
-                    })),
+                    context,
+                    info: Some(info),
                 },
                 udf_kind: UdfKind::Resolver,
             },
@@ -76,23 +130,194 @@ impl CustomResolver {
 
         match future.await? {
             CustomResolverResponse::Success(value) => Ok(ResolvedValue::new(value)),
-            CustomResolverResponse::GraphQLError { message, extensions } => {
-                let mut error = Error::new(message);
-                error.extensions = extensions.map(|extensions| {
-                    ErrorExtensionValues(
-                        extensions
-                            .into_iter()
-                            .filter_map(|(key, value)| Some((key, crate::Value::from_json(value).ok()?)))
-                            .collect(),
-                    )
-                });
-                Err(error)
-            }
+            CustomResolverResponse::GraphQLError { message, extensions } => Err(graphql_error(message, extensions)),
             CustomResolverResponse::Error(_err) => Err(CustomResolverError::InvocationError.into()),
         }
     }
 }
 
+fn graphql_error(message: String, extensions: Option<BTreeMap<String, serde_json::Value>>) -> Error {
+    let mut error = Error::new(message);
+    error.extensions = extensions.map(|extensions| {
+        ErrorExtensionValues(
+            extensions
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, crate::Value::from_json(value).ok()?)))
+                .collect(),
+        )
+    });
+    error
+}
+
+/// The fields selected on the value returned by a resolver, recursively.
+fn selection_set<'a>(fields: impl Iterator<Item = SelectionField<'a>>) -> Result<serde_json::Value, Error> {
+    fields
+        .map(|field| {
+            let arguments = field
+                .arguments()?
+                .into_iter()
+                .map(|(name, value)| Ok((name.to_string(), value.into_json()?)))
+                .collect::<Result<serde_json::Map<_, _>, Error>>()?;
+
+            Ok(serde_json::json!({
+                "name": field.name(),
+                "alias": field.alias(),
+                "arguments": arguments,
+                "selectionSet": selection_set(field.selection_set())?,
+            }))
+        })
+        .collect::<Result<Vec<_>, Error>>()
+        .map(serde_json::Value::Array)
+}
+
+/// Groups the invocations of batched custom resolvers, so that a resolver is invoked once with all
+/// the parents of a field at a given depth instead of once per parent.
+///
+/// The resolver receives an array of `{ parent, args }` objects as its first argument, and must
+/// return an array with the result of each of them, in the same order. Each result is either
+/// `{ data }` or `{ error: { message, extensions } }`, so that an error only affects its own item.
+pub struct CustomResolverBatcher {
+    loader: DataLoader<CustomResolverLoader, NoCache>,
+}
+
+impl CustomResolverBatcher {
+    #[must_use]
+    pub fn new(engine: CustomResolversEngine) -> Self {
+        Self {
+            loader: DataLoader::new(CustomResolverLoader { engine }, async_runtime::spawn),
+        }
+    }
+}
+
+/// The invocations sharing a batch are sent to the resolver together.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Batch {
+    resolver_name: String,
+    /// The path of the field without the list indices, which is shared by all the parents at a
+    /// given depth.
+    field_path: Vec<String>,
+    ray_id: String,
+    // The values below are serialized to JSON, as `serde_json::Value` can't be hashed.
+    context: String,
+    info: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct BatchedInvocation {
+    batch: Batch,
+    /// The parent and arguments of this invocation.
+    item: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BatchedItemResponse {
+    Data(serde_json::Value),
+    Error {
+        message: String,
+        #[serde(default)]
+        extensions: Option<BTreeMap<String, serde_json::Value>>,
+    },
+}
+
+struct CustomResolverLoader {
+    engine: CustomResolversEngine,
+}
+
+#[async_trait::async_trait]
+impl Loader<BatchedInvocation> for CustomResolverLoader {
+    type Value = Result<serde_json::Value, Error>;
+    type Error = Error;
+
+    async fn load(
+        &self,
+        invocations: &[BatchedInvocation],
+    ) -> Result<HashMap<BatchedInvocation, Self::Value>, Self::Error> {
+        let mut batches = BTreeMap::<_, Vec<_>>::new();
+        for invocation in invocations {
+            batches.entry(&invocation.batch).or_default().push(invocation);
+        }
+
+        // Batches of different fields are independent of each other.
+        let results = futures_util::future::try_join_all(
+            batches
+                .into_iter()
+                .map(|(batch, invocations)| self.load_batch(batch, invocations)),
+        )
+        .await?;
+
+        Ok(results.into_iter().flatten().collect())
+    }
+}
+
+impl CustomResolverLoader {
+    async fn load_batch(
+        &self,
+        batch: &Batch,
+        invocations: Vec<&BatchedInvocation>,
+    ) -> Result<Vec<(BatchedInvocation, Result<serde_json::Value, Error>)>, Error> {
+        let items = invocations
+            .iter()
+            .map(|invocation| serde_json::from_str(&invocation.item))
+            .collect::<serde_json::Result<Vec<serde_json::Value>>>()?;
+
+        let response = self
+            .engine
+            .invoke(
+                &batch.ray_id,
+                UdfRequest {
+                    name: &batch.resolver_name,
+                    request_id: &batch.ray_id,
+                    payload: CustomResolverRequestPayload {
+                        arguments: HashMap::new(),
+                        parent: Some(serde_json::Value::Array(items)),
+                        context: serde_json::from_str(&batch.context)?,
+                        info: Some(serde_json::from_str(&batch.info)?),
+                    },
+                    udf_kind: UdfKind::Resolver,
+                },
+            )
+            .await;
+
+        // Errors affecting the whole invocation are reported on every item of the batch.
+        let whole_batch_error = match response {
+            Ok(CustomResolverResponse::Success(serde_json::Value::Array(values)))
+                if values.len() == invocations.len() =>
+            {
+                return Ok(invocations
+                    .into_iter()
+                    .zip(values)
+                    .map(|(invocation, value)| {
+                        let result = match serde_json::from_value(value) {
+                            Ok(BatchedItemResponse::Data(value)) => Ok(value),
+                            Ok(BatchedItemResponse::Error { message, extensions }) => {
+                                Err(graphql_error(message, extensions))
+                            }
+                            Err(_) => Err(Error::new(format!(
+                                "The batched resolver `{}` must return either `data` or `error` for each item",
+                                batch.resolver_name
+                            ))),
+                        };
+                        (invocation.clone(), result)
+                    })
+                    .collect());
+            }
+            Ok(CustomResolverResponse::Success(_)) => Error::new(format!(
+                "The batched resolver `{}` must return an array with one result per item",
+                batch.resolver_name
+            )),
+            Ok(CustomResolverResponse::GraphQLError { message, extensions }) => graphql_error(message, extensions),
+            Ok(CustomResolverResponse::Error(_)) => CustomResolverError::InvocationError.into(),
+            Err(error) => error.into(),
+        };
+
+        Ok(invocations
+            .into_iter()
+            .map(|invocation| (invocation.clone(), Err(whole_batch_error.clone())))
+            .collect())
+    }
+}
+
 /// Magic function to convert the dynamodb format to the format we want to have on the
 /// resolver.
 fn dynamodb_to_json(model_data: serde_json::Value) -> serde_json::Value {
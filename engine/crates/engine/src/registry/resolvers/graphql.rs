@@ -5,7 +5,11 @@
 //!
 //! Note that the resolver supports passing headers to the upstream server (e.g. for
 //! authentication), but these are fetched from the
-//! [`Registry.http_headers`](crate::registry::Registry) field.
+//! [`Registry.http_headers`](crate::registry::Registry) field. The same goes for the timeout and
+//! retries of requests, in the `Registry.http_fetch_policies` field.
+//!
+//! Errors sent back by the upstream server are forwarded to the client, see
+//! [`Resolver::upstream_error`].
 //!
 //! Defining the resolver within the schema is done through the `@graphql` directive, e.g.:
 //!
@@ -28,24 +32,29 @@
 
 mod response;
 pub mod serializer;
+mod subscription;
 
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     pin::Pin,
 };
 
-use async_runtime::make_send_on_wasm;
+use async_runtime::{make_send_on_wasm, make_stream_send_on_wasm};
 use dataloader::{DataLoader, Loader, NoCache};
 use engine_parser::{
     parse_query,
     types::{
         ExecutableDocument, Field, FragmentDefinition, OperationType, Selection, SelectionSet, VariableDefinition,
     },
+    Pos,
 };
 use engine_value::{ConstValue, Name, Variables};
-use futures_util::Future;
-use http::{header::USER_AGENT, StatusCode};
+use futures_util::{Future, Stream, StreamExt};
+use http::{
+    header::{ACCEPT, CONTENT_TYPE, USER_AGENT},
+    StatusCode,
+};
 use inflector::Inflector;
 use url::Url;
 
@@ -53,22 +62,35 @@ use self::serializer::Serializer;
 use super::ResolvedValue;
 use crate::{
     registry::{
-        resolvers::{graphql::response::UpstreamResponse, logged_fetch::send_logged_request},
+        resolvers::{
+            circuit_breaker::CircuitOpen,
+            graphql::response::UpstreamResponse,
+            http_client::{Connector, HttpClient},
+            logged_fetch::{send_connector_request, FetchError, ResponseBody},
+        },
         type_kinds::SelectionSetTarget,
-        MetaField, Registry,
+        ConnectorFetchPolicy, MetaField, Registry,
     },
-    ServerError,
+    ErrorExtensions, QueryPath, QueryPathSegment, ServerError,
 };
 
 pub struct QueryBatcher {
     loader: DataLoader<QueryLoader, NoCache>,
+    client: HttpClient,
 }
 
 impl QueryBatcher {
     #[must_use]
     pub fn new() -> Self {
+        Self::with_client(HttpClient::new())
+    }
+
+    /// A batcher sending its queries with the given client, so it shares connections with it.
+    #[must_use]
+    pub fn with_client(client: HttpClient) -> Self {
         Self {
-            loader: DataLoader::new(QueryLoader, async_runtime::spawn),
+            loader: DataLoader::new(QueryLoader { client: client.clone() }, async_runtime::spawn),
+            client,
         }
     }
 }
@@ -153,7 +175,9 @@ impl Resolver {
     }
 }
 
-struct QueryLoader;
+struct QueryLoader {
+    client: HttpClient,
+}
 
 #[async_trait::async_trait]
 impl Loader<QueryData> for QueryLoader {
@@ -161,13 +185,13 @@ impl Loader<QueryData> for QueryLoader {
     type Error = Error;
 
     async fn load(&self, queries: &[QueryData]) -> Result<HashMap<QueryData, Self::Value>, Self::Error> {
-        load(queries).await
+        load(&self.client, queries).await
     }
 }
 
 type LoadResult = Result<HashMap<QueryData, (UpstreamResponse, StatusCode)>, Error>;
 
-fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send>> {
+fn load(client: &HttpClient, queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send>> {
     #[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
     struct ResolverDetails {
         name: String,
@@ -175,8 +199,12 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
         headers: Vec<(String, String)>,
         ray_id: String,
         fetch_log_endpoint_url: Option<String>,
+        fetch_policy: ConnectorFetchPolicy,
+        idempotent: bool,
     }
 
+    let client = client.clone();
+
     let mut resolver_queries: BTreeMap<_, Vec<Query>> = BTreeMap::default();
 
     for data in queries.iter().cloned() {
@@ -186,6 +214,8 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
             headers: data.headers,
             ray_id: data.ray_id,
             fetch_log_endpoint_url: data.fetch_log_endpoint_url,
+            fetch_policy: data.fetch_policy,
+            idempotent: data.idempotent,
         };
 
         resolver_queries.entry(id).or_default().push(data.query.clone());
@@ -201,7 +231,7 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
                 group_queries(queries.clone())
             };
 
-            let mut request_builder = reqwest::Client::new()
+            let mut request_builder = client
                 .post(resolver.url.clone())
                 .header(USER_AGENT, "Grafbase") // Some APIs (such a GitHub's) require a User-Agent.
                 .json(&query);
@@ -210,13 +240,18 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
                 request_builder = request_builder.header(name, value);
             }
 
-            let response = send_logged_request(
+            let circuit_breaker = client.circuit_breaker(Connector::GraphQL(&resolver.name), &resolver.fetch_policy);
+
+            let response = send_connector_request(
                 &resolver.ray_id,
                 resolver.fetch_log_endpoint_url.as_deref(),
+                &resolver.fetch_policy,
+                circuit_breaker.as_ref(),
+                resolver.idempotent,
+                ResponseBody::Buffered,
                 request_builder,
             )
-            .await
-            .map_err(|e| Error::RequestError(e.to_string()))?;
+            .await?;
 
             let http_status = response.status();
 
@@ -225,7 +260,22 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
                 response.text().await.map_err(|e| Error::RequestError(e.to_string())),
             )?;
 
-            for query in queries {
+            let grouped = queries.len() > 1;
+
+            for (index, query) in queries.into_iter().enumerate() {
+                let mut upstream_response = upstream_response.clone();
+
+                // The upstream server answered all the grouped queries at once, so each query only
+                // keeps the errors of its own root fields, and the first one the errors of none.
+                if grouped {
+                    let response_keys = query.root_response_keys();
+
+                    upstream_response.errors.retain(|error| match error.path.first() {
+                        Some(QueryPathSegment::Field(key)) => response_keys.contains(key.as_str()),
+                        _ => index == 0,
+                    });
+                }
+
                 let key = QueryData {
                     query,
                     headers: resolver.headers.clone(),
@@ -233,9 +283,11 @@ fn load(queries: &[QueryData]) -> Pin<Box<dyn Future<Output = LoadResult> + Send
                     resolver_name: resolver.name.clone(),
                     ray_id: resolver.ray_id.clone(),
                     fetch_log_endpoint_url: resolver.fetch_log_endpoint_url.clone(),
+                    fetch_policy: resolver.fetch_policy.clone(),
+                    idempotent: resolver.idempotent,
                 };
 
-                results.insert(key, (upstream_response.clone(), http_status));
+                results.insert(key, (upstream_response, http_status));
             }
         }
 
@@ -322,6 +374,12 @@ struct QueryData {
 
     /// Used internally in dev mode.
     fetch_log_endpoint_url: Option<String>,
+
+    /// The timeout & retries of requests to the remote endpoint.
+    fetch_policy: ConnectorFetchPolicy,
+
+    /// Whether the request can safely be retried, which is the case for queries but not mutations.
+    idempotent: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize)]
@@ -330,11 +388,53 @@ struct Query {
     variables: BTreeMap<Name, ConstValue>,
 }
 
+impl Query {
+    /// The response keys of the root fields the query selects.
+    fn root_response_keys(&self) -> HashSet<String> {
+        let document = parse_query(&self.query).expect("valid serialized query");
+        let mut response_keys = HashSet::new();
+
+        for (_, operation) in document.operations.iter() {
+            collect_response_keys(&document, &operation.node.selection_set.node, &mut response_keys);
+        }
+
+        response_keys
+    }
+}
+
+fn collect_response_keys(document: &ExecutableDocument, selection_set: &SelectionSet, keys: &mut HashSet<String>) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                keys.insert(field.node.response_key().node.to_string());
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = document.fragments.get(&spread.node.fragment_name.node) {
+                    collect_response_keys(document, &fragment.node.selection_set.node, keys);
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_response_keys(document, &fragment.node.selection_set.node, keys);
+            }
+        }
+    }
+}
+
 pub enum Target {
     SelectionSet(Box<dyn Iterator<Item = Selection> + Send + Sync>),
     Field(Field, MetaField),
 }
 
+impl Target {
+    /// The field the upstream data is wrapped in, which we unwrap to get the resolved value.
+    fn wrapping_field(&self) -> Option<String> {
+        match self {
+            Target::SelectionSet(_) => None,
+            Target::Field(field, _) => Some(field.name.node.to_string()),
+        }
+    }
+}
+
 impl Resolver {
     /// Resolve the given list of [`Selection`]s at the upstream server, returning the final
     /// result.
@@ -356,44 +456,29 @@ impl Resolver {
         variables: Variables,
         variable_definitions: HashMap<&'a Name, &'a VariableDefinition>,
         registry: &'a Registry,
-        batcher: Option<&'a QueryBatcher>,
+        batcher: &'a QueryBatcher,
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedValue, Error>> + Send + 'a>> {
-        let mut query = String::new();
-
-        let prefix = self.type_prefix.as_ref().cloned().or(
-            // If we don't have a type_prefix we fall back to the namespace.
-            // This is mostly for backwards compatability reasons.
-            // Every new connector from 2023-10-17 should gave type_prefix set correctly
-            self.namespace.as_ref().map(inflector::Inflector::to_pascal_case),
-        );
-
-        let wrapping_field = match &target {
-            Target::SelectionSet(_) => None,
-            Target::Field(field, _) => Some(field.name.node.to_string()),
-        };
+        let prefix = self.prefix();
+        let wrapping_field = target.wrapping_field();
 
         Box::pin(make_send_on_wasm(async move {
-            let mut serializer = Serializer::new(
+            if operation == OperationType::Subscription {
+                return Err(Error::UnsupportedOperation("subscription"));
+            }
+
+            let query = serialize(
+                operation,
                 prefix.as_deref(),
                 fragment_definitions,
+                target,
+                current_type,
+                variables,
                 variable_definitions,
-                &mut query,
                 registry,
-            );
-
-            match operation {
-                OperationType::Query => serializer.query(target, current_type)?,
-                OperationType::Mutation => serializer.mutation(target, current_type)?,
-                OperationType::Subscription => return Err(Error::UnsupportedOperation("subscription")),
-            };
-
-            let variables = variables
-                .into_iter()
-                .filter(|(name, _)| serializer.variable_references().any(|reference| reference == name))
-                .collect();
+            )?;
 
             let query_data = QueryData {
-                query: Query { query, variables },
+                query,
                 headers: headers
                     .iter()
                     .copied()
@@ -403,19 +488,20 @@ impl Resolver {
                 url: self.url.to_string(),
                 ray_id: ray_id.to_owned(),
                 fetch_log_endpoint_url: fetch_log_endpoint_url.map(str::to_owned),
+                fetch_policy: self.fetch_policy(registry),
+                idempotent: operation == OperationType::Query,
             };
 
-            let value = match (batcher, operation) {
-                (_, OperationType::Subscription) => return Err(Error::UnsupportedOperation("subscription")),
-                (Some(batcher), OperationType::Query) => batcher.loader.load_one(query_data).await?,
-                _ => load(&[query_data]).await?.into_values().next(),
+            let value = match operation {
+                OperationType::Query => batcher.loader.load_one(query_data).await?,
+                _ => load(&batcher.client, &[query_data]).await?.into_values().next(),
             };
 
             let Some(value) = value else {
                 return Err(Error::MalformedUpstreamResponse);
             };
 
-            let (UpstreamResponse { mut data, errors }, http_status) = value;
+            let (UpstreamResponse { data, errors }, http_status) = value;
 
             if !http_status.is_success() {
                 // If we haven't had a fatal error we should still report the http error
@@ -427,25 +513,219 @@ impl Resolver {
 
             errors.into_iter().for_each(error_handler);
 
-            if let Some(prefix) = prefix {
-                prefix_result_typename(&mut data, &prefix);
+            Ok(resolved_value(data, prefix.as_deref(), wrapping_field.as_deref()))
+        }))
+    }
+
+    /// Subscribe to the given [`Target`] at the upstream server, returning a stream with the
+    /// result of each event the server sends back.
+    ///
+    /// The subscription is sent over the GraphQL over SSE protocol, so the upstream server needs
+    /// to support it.
+    ///
+    /// # Errors
+    ///
+    /// See [`Error`] for more details.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn subscribe<'a>(
+        &'a self,
+        client: &'a HttpClient,
+        ray_id: &'a str,
+        fetch_log_endpoint_url: Option<&'a str>,
+        headers: &'a [(&'a str, &'a str)],
+        fragment_definitions: HashMap<&'a Name, &'a FragmentDefinition>,
+        target: Target,
+        current_type: Option<SelectionSetTarget<'a>>,
+        mut error_handler: impl FnMut(ServerError) + Send + 'a,
+        variables: Variables,
+        variable_definitions: HashMap<&'a Name, &'a VariableDefinition>,
+        registry: &'a Registry,
+    ) -> Pin<Box<dyn Stream<Item = Result<ResolvedValue, Error>> + Send + 'a>> {
+        let prefix = self.prefix();
+        let wrapping_field = target.wrapping_field();
+
+        Box::pin(make_stream_send_on_wasm(async_stream::try_stream! {
+            let query = serialize(
+                OperationType::Subscription,
+                prefix.as_deref(),
+                fragment_definitions,
+                target,
+                current_type,
+                variables,
+                variable_definitions,
+                registry,
+            )?;
+
+            let mut request_builder = client
+                .post(self.url.clone())
+                .header(USER_AGENT, "Grafbase")
+                .header(ACCEPT, "text/event-stream")
+                .json(&query);
+
+            for (name, value) in headers {
+                request_builder = request_builder.header(*name, *value);
             }
 
-            let mut resolved_value = ResolvedValue::new(match wrapping_field {
-                Some(field) => data
-                    .as_object_mut()
-                    .and_then(|m| m.remove(&field))
-                    .unwrap_or(serde_json::Value::Null),
-                None => data,
-            });
+            let fetch_policy = self.fetch_policy(registry);
+            let circuit_breaker = client.circuit_breaker(Connector::GraphQL(&self.name()), &fetch_policy);
+
+            // Subscribing has no side effects, so it's safe to retry.
+            let response = send_connector_request(
+                ray_id,
+                fetch_log_endpoint_url,
+                &fetch_policy,
+                circuit_breaker.as_ref(),
+                true,
+                ResponseBody::Streamed,
+                request_builder,
+            )
+            .await?;
+
+            let http_status = response.status();
 
-            if resolved_value.data_resolved().is_null() {
-                resolved_value.early_return_null = true;
+            let is_event_stream = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/event-stream"));
+
+            // Servers answer with a single result when they can't start the subscription, e.g.
+            // because it failed validation.
+            if !is_event_stream {
+                let UpstreamResponse { data, errors } = UpstreamResponse::from_response_text(
+                    http_status,
+                    response.text().await.map_err(|e| Error::RequestError(e.to_string())),
+                )?;
+
+                errors.into_iter().for_each(&mut error_handler);
+                yield resolved_value(data, prefix.as_deref(), wrapping_field.as_deref());
+                return;
             }
 
-            Ok(resolved_value)
+            let events = subscription::server_sent_events(response.bytes_stream());
+            futures_util::pin_mut!(events);
+
+            while let Some(event) = events.next().await {
+                let event = event.map_err(|e| Error::RequestError(e.to_string()))?;
+
+                match event.event.as_deref() {
+                    Some("next") => {
+                        let UpstreamResponse { data, errors } =
+                            UpstreamResponse::from_response_text(http_status, Ok::<_, Error>(event.data))?;
+
+                        errors.into_iter().for_each(&mut error_handler);
+                        yield resolved_value(data, prefix.as_deref(), wrapping_field.as_deref());
+                    }
+                    Some("complete") => break,
+                    _ => {}
+                }
+            }
         }))
     }
+
+    /// Rewrites an error sent back by the upstream server so it fits in our response.
+    ///
+    /// The path of the error is relative to the operation we sent upstream, whose root is either
+    /// the selection set of the namespace field, or the field itself. Its locations point to that
+    /// operation as well, which the client has never seen, so they're replaced by the location of
+    /// the field. Its extensions are kept, unless the connector has an allow-list for them.
+    #[must_use]
+    pub fn upstream_error(
+        &self,
+        mut error: ServerError,
+        field_path: &QueryPath,
+        field_pos: Pos,
+        registry: &Registry,
+    ) -> ServerError {
+        let mut path = field_path.iter().cloned().collect::<Vec<_>>();
+        if !error.path.is_empty() {
+            if self.namespace.is_none() {
+                path.pop();
+            }
+            path.append(&mut error.path);
+        }
+        error.path = path;
+
+        error.locations = vec![field_pos];
+
+        let allowed_extensions = registry
+            .graphql_error_extensions
+            .get(&format!("GraphQLConnector{}", self.name()));
+
+        if let (Some(extensions), Some(allowed_extensions)) = (&mut error.extensions, allowed_extensions) {
+            extensions.0.retain(|name, _| allowed_extensions.contains(name));
+        }
+
+        error
+    }
+
+    fn prefix(&self) -> Option<String> {
+        self.type_prefix.as_ref().cloned().or(
+            // If we don't have a type_prefix we fall back to the namespace.
+            // This is mostly for backwards compatability reasons.
+            // Every new connector from 2023-10-17 should gave type_prefix set correctly
+            self.namespace.as_ref().map(inflector::Inflector::to_pascal_case),
+        )
+    }
+
+    fn fetch_policy(&self, registry: &Registry) -> ConnectorFetchPolicy {
+        registry
+            .http_fetch_policies
+            .get(&format!("GraphQLConnector{}", self.name()))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Serialize the operation we send to the upstream server, along with the variables it uses.
+#[allow(clippy::too_many_arguments)]
+fn serialize<'a>(
+    operation: OperationType,
+    prefix: Option<&'a str>,
+    fragment_definitions: HashMap<&'a Name, &'a FragmentDefinition>,
+    target: Target,
+    current_type: Option<SelectionSetTarget<'a>>,
+    variables: Variables,
+    variable_definitions: HashMap<&'a Name, &'a VariableDefinition>,
+    registry: &'a Registry,
+) -> Result<Query, Error> {
+    let mut query = String::new();
+
+    let mut serializer = Serializer::new(prefix, fragment_definitions, variable_definitions, &mut query, registry);
+
+    match operation {
+        OperationType::Query => serializer.query(target, current_type)?,
+        OperationType::Mutation => serializer.mutation(target, current_type)?,
+        OperationType::Subscription => serializer.subscription(target, current_type)?,
+    };
+
+    let variables = variables
+        .into_iter()
+        .filter(|(name, _)| serializer.variable_references().any(|reference| reference == name))
+        .collect();
+
+    Ok(Query { query, variables })
+}
+
+/// The value of the resolved field, from the data the upstream server sent back.
+fn resolved_value(mut data: serde_json::Value, prefix: Option<&str>, wrapping_field: Option<&str>) -> ResolvedValue {
+    if let Some(prefix) = prefix {
+        prefix_result_typename(&mut data, prefix);
+    }
+
+    let mut resolved_value = ResolvedValue::new(match wrapping_field {
+        Some(field) => data
+            .as_object_mut()
+            .and_then(|m| m.remove(field))
+            .unwrap_or(serde_json::Value::Null),
+        None => data,
+    });
+
+    if resolved_value.data_resolved().is_null() {
+        resolved_value.early_return_null = true;
+    }
+
+    resolved_value
 }
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq)]
@@ -470,6 +750,27 @@ pub enum Error {
 
     #[error("received an unexpected status from the downstream server: {0}")]
     HttpErrorResponse(u16),
+
+    #[error(transparent)]
+    CircuitOpen(#[from] CircuitOpen),
+}
+
+impl From<FetchError> for Error {
+    fn from(error: FetchError) -> Self {
+        match error {
+            FetchError::CircuitOpen(error) => Error::CircuitOpen(error),
+            error => Error::RequestError(error.to_string()),
+        }
+    }
+}
+
+impl ErrorExtensions for Error {
+    fn extend(&self) -> crate::Error {
+        match self {
+            Error::CircuitOpen(error) => error.extend(),
+            error => crate::Error::new(error.to_string()),
+        }
+    }
 }
 
 /// Before the resolver returns the JSON to the caller, it needs to iterate the JSON, find any
@@ -573,7 +874,7 @@ mod tests {
         let result = resolve_registry(
             Resolver::stub("Test", "myApi", server.uri()),
             registry.clone(),
-            None,
+            &QueryBatcher::new(),
             query,
         )
         .await;
@@ -625,14 +926,14 @@ mod tests {
         let foo = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry.clone(),
-            Some(&batcher),
+            &batcher,
             "query { foo }",
         );
 
         let bar = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry,
-            Some(&batcher),
+            &batcher,
             "query { bar }",
         );
 
@@ -672,14 +973,14 @@ mod tests {
         let foo = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry.clone(),
-            Some(&batcher),
+            &batcher,
             "query Hello { foo }",
         );
 
         let bar = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry,
-            Some(&batcher),
+            &batcher,
             "query World { bar }",
         );
 
@@ -689,6 +990,51 @@ mod tests {
         server.verify().await;
     }
 
+    #[tokio::test]
+    async fn batched_queries_only_get_their_own_errors() {
+        let server = MockServer::start().await;
+        let batcher = QueryBatcher::new();
+
+        let registry = RegistryBuilder::default()
+            .build_object("Query")
+            .insert_field("foo", "String")
+            .insert_field("bar", "String")
+            .finalize_object()
+            .finalize();
+
+        let response = json!({
+            "data": { "foo": null, "bar": "bar" },
+            "errors": [{ "message": "foo failed", "path": ["foo"] }]
+        });
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let foo = resolve_registry(
+            Resolver::stub("Test", "", server.uri()),
+            registry.clone(),
+            &batcher,
+            "query { foo }",
+        );
+
+        let bar = resolve_registry(
+            Resolver::stub("Test", "", server.uri()),
+            registry,
+            &batcher,
+            "query { bar }",
+        );
+
+        let (foo, bar) = join!(foo, bar);
+
+        assert_eq!(foo.unwrap()["errors"][0]["message"], "foo failed");
+        assert_eq!(bar.unwrap().get("errors"), None);
+
+        server.verify().await;
+    }
+
     #[tokio::test]
     async fn mutations_are_never_batched() {
         let server = MockServer::start().await;
@@ -723,14 +1069,14 @@ mod tests {
         let foo = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry.clone(),
-            Some(&batcher),
+            &batcher,
             "mutation { foo }",
         );
 
         let bar = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry,
-            Some(&batcher),
+            &batcher,
             "mutation { bar }",
         );
 
@@ -782,14 +1128,14 @@ mod tests {
         let foo = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry.clone(),
-            Some(&batcher),
+            &batcher,
             "query Foo($foo: ID) { foo(id: $foo) }",
         );
 
         let bar = resolve_registry(
             Resolver::stub("Test", "", server.uri()),
             registry,
-            Some(&batcher),
+            &batcher,
             "query Foo($bar: ID) { bar(id: $bar) }",
         );
 
@@ -804,7 +1150,7 @@ mod tests {
     async fn resolve_registry(
         resolver: Resolver,
         registry: Registry,
-        batcher: Option<&QueryBatcher>,
+        batcher: &QueryBatcher,
         query: impl AsRef<str>,
     ) -> Result<Value, Error> {
         let mut errors = vec![];
@@ -912,4 +1258,49 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn upstream_errors() {
+        let error = || {
+            serde_json::from_value::<ServerError>(json!({
+                "message": "Too many requests",
+                "locations": [{ "line": 2, "column": 5 }],
+                "path": ["repository", "issues", 0],
+                "extensions": { "code": "RATE_LIMITED", "trace": "secret" }
+            }))
+            .unwrap()
+        };
+
+        let field_path = QueryPath::empty().child("github");
+        let field_pos = Pos { line: 3, column: 9 };
+        let mut registry = Registry::new();
+
+        let namespaced = Resolver::stub("github", "github", "https://api.github.com/graphql");
+        assert_eq!(
+            serde_json::to_value(namespaced.upstream_error(error(), &field_path, field_pos, &registry)).unwrap(),
+            json!({
+                "message": "Too many requests",
+                "locations": [{ "line": 3, "column": 9 }],
+                "path": ["github", "repository", "issues", 0],
+                "extensions": { "code": "RATE_LIMITED", "trace": "secret" }
+            })
+        );
+
+        registry.graphql_error_extensions.insert(
+            "GraphQLConnectorgithub".to_string(),
+            ["code".to_string()].into_iter().collect(),
+        );
+
+        let field_path = QueryPath::empty().child("repository");
+        let unnamespaced = Resolver::stub("github", "", "https://api.github.com/graphql");
+        assert_eq!(
+            serde_json::to_value(unnamespaced.upstream_error(error(), &field_path, field_pos, &registry)).unwrap(),
+            json!({
+                "message": "Too many requests",
+                "locations": [{ "line": 3, "column": 9 }],
+                "path": ["repository", "issues", 0],
+                "extensions": { "code": "RATE_LIMITED" }
+            })
+        );
+    }
 }
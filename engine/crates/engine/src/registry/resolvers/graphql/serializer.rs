@@ -75,8 +75,8 @@ impl<'a, 'b> Serializer<'a, 'b> {
 
     /// Get an iterator over variable references the serializer has serialized.
     ///
-    /// This list will be empty, until [`Serializer::query()`] or [`Serializer::mutation()`] is
-    /// called.
+    /// This list will be empty, until [`Serializer::query()`], [`Serializer::mutation()`] or
+    /// [`Serializer::subscription()`] is called.
     pub fn variable_references(&self) -> impl Iterator<Item = &Name> {
         self.variable_references.iter()
     }
@@ -127,6 +127,28 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
         self.prepend_declaration("mutation")
     }
 
+    /// Serialize subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the buffer fails.
+    pub fn subscription(&mut self, target: Target, current_type: Option<SelectionSetTarget<'a>>) -> Result<(), Error> {
+        match target {
+            Target::SelectionSet(selections) => {
+                self.serialize_selections(selections, current_type)?;
+            }
+            Target::Field(field, schema_field) => {
+                self.open_object()?;
+                self.serialize_field(&field, Some(&schema_field))?;
+                self.close_object()?;
+            }
+        }
+
+        self.serialize_fragment_definitions(current_type.is_some())?;
+
+        self.prepend_declaration("subscription")
+    }
+
     fn serialize_selection(
         &mut self,
         selection: Selection,
@@ -626,6 +648,18 @@ mod tests {
         insta::assert_snapshot!(serialize(input));
     }
 
+    #[test]
+    fn subscription() {
+        let input = r"
+        subscription {
+          repositoryUpdated {
+            id
+          }
+        }";
+
+        insta::assert_snapshot!(serialize(input));
+    }
+
     fn serialize(input: &str) -> String {
         let mut buf = String::new();
         let (selections, fragment_definitions) = input_to_selections(input);
@@ -658,6 +692,15 @@ mod tests {
                     Some(mutation_ty),
                 )
                 .unwrap();
+        } else if input.trim_start().starts_with("subscription") {
+            let subscription_ty = registry.lookup_by_str("Subscription").unwrap().try_into().unwrap();
+
+            serializer
+                .subscription(
+                    Target::SelectionSet(Box::new(selections.into_iter())),
+                    Some(subscription_ty),
+                )
+                .unwrap();
         } else {
             panic!("invalid input data");
         }
@@ -726,6 +769,11 @@ mod tests {
         query_fields.insert("bar".into(), MetaField::new("bar", "String"));
         query_fields.insert("qux".into(), MetaField::new("qux", "Qux"));
 
+        registry.insert_type(ObjectType::new(
+            "Subscription",
+            [MetaField::new("repositoryUpdated", "GithubRepository")],
+        ));
+
         registry
     }
 }
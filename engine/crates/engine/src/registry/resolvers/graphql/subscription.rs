@@ -0,0 +1,120 @@
+//! Subscriptions to an upstream server, over the [GraphQL over SSE][protocol] protocol.
+//!
+//! We use its "distinct connections" mode: each subscription is a request of its own, which the
+//! upstream server answers with a stream of `next` events holding the results of the
+//! subscription, ended by a `complete` event.
+//!
+//! [protocol]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+
+use futures_util::{Stream, StreamExt};
+
+/// A single event sent by the upstream server.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct ServerSentEvent {
+    pub event: Option<String>,
+    pub data: String,
+}
+
+/// Splits the body of a `text/event-stream` response into the events it holds.
+///
+/// See <https://html.spec.whatwg.org/multipage/server-sent-events.html#parsing-an-event-stream>.
+pub(super) fn server_sent_events<B, E>(
+    body: impl Stream<Item = Result<B, E>>,
+) -> impl Stream<Item = Result<ServerSentEvent, E>>
+where
+    B: AsRef<[u8]>,
+{
+    async_stream::try_stream! {
+        futures_util::pin_mut!(body);
+
+        let mut buffer = Vec::new();
+        let mut event = ServerSentEvent::default();
+        let mut has_data = false;
+
+        while let Some(chunk) = body.next().await {
+            buffer.extend_from_slice(chunk?.as_ref());
+
+            while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                let line = buffer.drain(..=end).collect::<Vec<_>>();
+                let line = String::from_utf8_lossy(&line);
+                let line = line.trim_end_matches(['\n', '\r']);
+
+                if line.is_empty() {
+                    // A blank line dispatches the event, unless it has no data.
+                    if has_data {
+                        yield std::mem::take(&mut event);
+                    }
+                    event = ServerSentEvent::default();
+                    has_data = false;
+                    continue;
+                }
+
+                // Lines starting with a colon are comments, which servers send to keep the
+                // connection alive.
+                if line.starts_with(':') {
+                    continue;
+                }
+
+                let (field, value) = line.split_once(':').unwrap_or((line, ""));
+                let value = value.strip_prefix(' ').unwrap_or(value);
+
+                match field {
+                    "event" => event.event = Some(value.to_string()),
+                    "data" => {
+                        if has_data {
+                            event.data.push('\n');
+                        }
+                        event.data.push_str(value);
+                        has_data = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::*;
+
+    async fn parse(chunks: &[&'static str]) -> Vec<ServerSentEvent> {
+        let body = stream::iter(chunks.iter().map(|chunk| Ok::<_, ()>(chunk.as_bytes())));
+
+        server_sent_events(body)
+            .map(|event| event.expect("no errors"))
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_parse_events() {
+        let events = parse(&[
+            ": keep-alive\n\n",
+            "event: next\ndata: {\"data\":",
+            "{\"a\":1}}\n\nevent: next\r\ndata: {\"data\":\r\ndata: {\"a\":2}}\r\n\r\n",
+            "event: complete\ndata:\n\n",
+        ])
+        .await;
+
+        assert_eq!(
+            events,
+            vec![
+                ServerSentEvent {
+                    event: Some("next".into()),
+                    data: r#"{"data":{"a":1}}"#.into(),
+                },
+                ServerSentEvent {
+                    event: Some("next".into()),
+                    data: "{\"data\":\n{\"a\":2}}".into(),
+                },
+                ServerSentEvent {
+                    event: Some("complete".into()),
+                    data: String::new(),
+                },
+            ]
+        );
+    }
+}
@@ -1,14 +1,29 @@
 use std::{collections::BTreeMap, pin::Pin};
 
 use async_runtime::make_send_on_wasm;
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine as _};
 use futures_util::Future;
 use reqwest::Url;
 
-use self::parameters::ParamApply;
-use super::{ResolvedValue, ResolverContext};
-use crate::{registry::variables::VariableResolveDefinition, Context, ContextExt, ContextField, Error, RequestHeaders};
+use self::{
+    parameters::{cookie_value, simple_style_value, ParamApply},
+    security::Credentials,
+};
+use super::{
+    http_client::{Connector, HttpClient},
+    ResolvedValue, ResolverContext,
+};
+use crate::{
+    registry::variables::VariableResolveDefinition, Context, ContextExt, ContextField, Error, ErrorExtensions,
+    RequestHeaders,
+};
 
+mod multipart;
+mod pagination;
 mod parameters;
+mod security;
+
+pub use pagination::{HttpPagination, PaginationStyle};
 
 #[serde_with::minify_field_names(serialize = "minified", deserialize = "minified")]
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
@@ -18,8 +33,19 @@ pub struct HttpResolver {
     pub api_name: String,
     pub path_parameters: Vec<PathParameter>,
     pub query_parameters: Vec<QueryParameter>,
+    #[serde(default)]
+    pub header_parameters: Vec<HeaderParameter>,
+    #[serde(default)]
+    pub cookie_parameters: Vec<CookieParameter>,
+    #[serde(default)]
+    pub security: Vec<SecurityRequirement>,
     pub request_body: Option<RequestBody>,
     pub expected_status: ExpectedStatusCode,
+    #[serde(default)]
+    pub response_content_type: ResponseContentType,
+    /// Set when a list operation is exposed as a connection
+    #[serde(default)]
+    pub pagination: Option<HttpPagination>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
@@ -35,6 +61,26 @@ pub struct QueryParameter {
     pub encoding_style: QueryParameterEncodingStyle,
 }
 
+/// A parameter sent as a header, serialized with the OpenAPI `simple` style.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct HeaderParameter {
+    pub name: String,
+    pub variable_resolve_definition: VariableResolveDefinition,
+}
+
+/// A parameter sent in the `Cookie` header, serialized with the OpenAPI `simple` style.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct CookieParameter {
+    pub name: String,
+    pub variable_resolve_definition: VariableResolveDefinition,
+}
+
+/// The security schemes that must all be satisfied to call an operation, keyed by the
+/// name of the scheme with the OAuth2 scopes the operation needs. An operation can be
+/// called if any one of its requirements is satisfied.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct SecurityRequirement(pub BTreeMap<String, Vec<String>>);
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
 pub struct RequestBody {
     pub variable_resolve_definition: VariableResolveDefinition,
@@ -52,6 +98,19 @@ pub enum QueryParameterEncodingStyle {
 pub enum RequestBodyContentType {
     Json,
     FormEncoded(BTreeMap<String, QueryParameterEncodingStyle>),
+    /// Sent as `multipart/form-data`, with any `Upload`s in the body sent as files.
+    Multipart,
+}
+
+/// How we decode the body of a successful response.
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub enum ResponseContentType {
+    #[default]
+    Json,
+    /// Returned as a `String`
+    Text,
+    /// Returned as base64 in a `Bytes`
+    Binary,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
@@ -80,49 +139,53 @@ impl HttpResolver {
             .unwrap_or_default();
 
         Box::pin(make_send_on_wasm(async move {
-            let runtime_ctx = ctx.data::<runtime::Context>()?;
-            let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
-            let ray_id = &runtime_ctx.ray_id();
-            let url = self.build_url(ctx, last_resolver_value.as_ref())?;
-            let mut request_builder = reqwest::Client::new().request(self.method.parse()?, Url::parse(&url)?);
-
-            for (name, value) in headers {
-                request_builder = request_builder.header(name, value);
-            }
+            let credentials = match ctx.registry().http_security.get(&self.api_name) {
+                Some(security) => security::resolve_credentials(ctx, security, &self.security).await?,
+                None => Credentials::default(),
+            };
 
-            if let Some(request_body) = &self.request_body {
-                let variable = request_body
-                    .variable_resolve_definition
-                    .resolve::<serde_json::Value>(ctx, last_resolver_value)?;
-
-                match &request_body.content_type {
-                    RequestBodyContentType::Json => {
-                        request_builder = request_builder.json(&variable);
-                    }
-                    RequestBodyContentType::FormEncoded(encoding_styles) => {
-                        request_builder =
-                            request_builder.header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded");
-                        request_builder =
-                            request_builder.body(String::new().apply_body_parameters(encoding_styles, variable)?);
-                    }
-                }
+            let mut url = Url::parse(&self.build_url(ctx, last_resolver_value.as_ref())?)?;
+            if !credentials.query.is_empty() {
+                url.query_pairs_mut().extend_pairs(&credentials.query);
             }
 
-            let response = super::logged_fetch::send_logged_request(ray_id, fetch_log_endpoint_url, request_builder)
-                .await
-                .map_err(|e| Error::new(e.to_string()))?;
+            let credentials_query = credentials.query.clone();
+            let headers = self.build_headers(ctx, last_resolver_value.as_ref(), headers, credentials)?;
+
+            let body = self
+                .request_body
+                .as_ref()
+                .map(|request_body| {
+                    request_body
+                        .variable_resolve_definition
+                        .resolve::<serde_json::Value>(ctx, last_resolver_value)
+                })
+                .transpose()?;
+
+            let request = Request {
+                resolver: self,
+                ctx,
+                headers,
+                body,
+                credentials_query,
+            };
 
-            if !self.expected_status.contains(response.status()) {
-                return Err(Error::new(format!(
-                    "Received an unexpected status from the downstream server: {}",
-                    response.status(),
-                )));
+            if let Some(pagination) = &self.pagination {
+                let connection = pagination.resolve(&request, url).await?;
+                return Ok(ResolvedValue::new(connection));
             }
 
-            let data = response
-                .json::<serde_json::Value>()
-                .await
-                .map_err(|e| Error::new(e.to_string()))?;
+            let response = request.send(url).await?;
+
+            let data = match self.response_content_type {
+                ResponseContentType::Json => response.json::<serde_json::Value>().await,
+                ResponseContentType::Text => response.text().await.map(serde_json::Value::String),
+                ResponseContentType::Binary => response
+                    .bytes()
+                    .await
+                    .map(|bytes| serde_json::Value::String(STANDARD_NO_PAD.encode(bytes))),
+            }
+            .map_err(|e| Error::new(e.to_string()))?;
 
             let is_null = data.is_null();
             let mut resolved_value = ResolvedValue::new(data);
@@ -156,6 +219,139 @@ impl HttpResolver {
 
         url.apply_query_parameters(&self.query_parameters, &query_variables)
     }
+
+    /// Merges the credentials, header and cookie parameters of the operation with the
+    /// headers defined for the connector. Credentials replace connector headers of the
+    /// same name and header parameters replace both, while cookies are appended to any
+    /// connector level `Cookie` header.
+    fn build_headers(
+        &self,
+        ctx: &ContextField<'_>,
+        last_resolver_value: Option<&serde_json::Value>,
+        connector_headers: Vec<(&str, &str)>,
+        credentials: Credentials,
+    ) -> Result<Vec<(String, String)>, Error> {
+        let mut headers: Vec<(String, String)> = connector_headers
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        for (name, value) in credentials.headers {
+            headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+            headers.push((name, value));
+        }
+
+        for param in &self.header_parameters {
+            let variable: serde_json::Value = param.variable_resolve_definition.resolve(ctx, last_resolver_value)?;
+
+            let Some(value) = simple_style_value(&variable)? else {
+                continue;
+            };
+
+            headers.retain(|(name, _)| !name.eq_ignore_ascii_case(&param.name));
+            headers.push((param.name.clone(), value));
+        }
+
+        let mut cookies = credentials.cookies;
+
+        for param in &self.cookie_parameters {
+            let variable: serde_json::Value = param.variable_resolve_definition.resolve(ctx, last_resolver_value)?;
+
+            if let Some(value) = cookie_value(&variable)? {
+                cookies.push(format!("{}={value}", param.name));
+            }
+        }
+
+        if !cookies.is_empty() {
+            let cookies = cookies.join("; ");
+            let header_name = reqwest::header::COOKIE.as_str();
+
+            match headers
+                .iter_mut()
+                .find(|(name, _)| name.eq_ignore_ascii_case(header_name))
+            {
+                Some((_, value)) => {
+                    value.push_str("; ");
+                    value.push_str(&cookies);
+                }
+                None => headers.push((header_name.to_string(), cookies)),
+            }
+        }
+
+        Ok(headers)
+    }
+}
+
+/// Everything needed to send the request for an operation, possibly more than once
+/// when we follow the pages of a paginated operation.
+struct Request<'a, 'ctx> {
+    resolver: &'a HttpResolver,
+    ctx: &'a ContextField<'ctx>,
+    headers: Vec<(String, String)>,
+    body: Option<serde_json::Value>,
+    /// Credentials sent in the query string, which we also need to add to any
+    /// URLs the downstream server gives us.
+    credentials_query: Vec<(String, String)>,
+}
+
+impl Request<'_, '_> {
+    async fn send(&self, url: Url) -> Result<reqwest::Response, Error> {
+        let runtime_ctx = self.ctx.data::<runtime::Context>()?;
+        let method: reqwest::Method = self.resolver.method.parse()?;
+        let client = HttpClient::from_context(self.ctx)?;
+        let mut request_builder = client.request(method.clone(), url);
+
+        for (name, value) in &self.headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        if let Some((request_body, variable)) = self.resolver.request_body.as_ref().zip(self.body.clone()) {
+            match &request_body.content_type {
+                RequestBodyContentType::Json => {
+                    request_builder = request_builder.json(&variable);
+                }
+                RequestBodyContentType::FormEncoded(encoding_styles) => {
+                    request_builder =
+                        request_builder.header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+                    request_builder =
+                        request_builder.body(String::new().apply_body_parameters(encoding_styles, variable)?);
+                }
+                RequestBodyContentType::Multipart => {
+                    request_builder = request_builder.multipart(multipart::build_form(self.ctx, variable).await?);
+                }
+            }
+        }
+
+        let fetch_policy = self
+            .ctx
+            .registry()
+            .http_fetch_policies
+            .get(&self.resolver.api_name)
+            .cloned()
+            .unwrap_or_default();
+        let circuit_breaker = client.circuit_breaker(Connector::OpenApi(&self.resolver.api_name), &fetch_policy);
+
+        let response = super::logged_fetch::send_connector_request(
+            &runtime_ctx.ray_id(),
+            runtime_ctx.log.fetch_log_endpoint_url.as_deref(),
+            &fetch_policy,
+            circuit_breaker.as_ref(),
+            method.is_idempotent(),
+            super::logged_fetch::ResponseBody::Buffered,
+            request_builder,
+        )
+        .await
+        .map_err(|error| error.extend())?;
+
+        if !self.resolver.expected_status.contains(response.status()) {
+            return Err(Error::new(format!(
+                "Received an unexpected status from the downstream server: {}",
+                response.status(),
+            )));
+        }
+
+        Ok(response)
+    }
 }
 
 impl ExpectedStatusCode {
@@ -0,0 +1,112 @@
+//! Builds `multipart/form-data` request bodies.
+
+use std::{fs::File, io};
+
+use reqwest::multipart::{Form, Part};
+
+use crate::{types::UPLOAD_VARIABLE_PREFIX, ContextField, Error};
+
+/// Builds a multipart form from the fields of a request body.
+///
+/// `Upload`s are sent as file parts, lists are sent as one part per item and
+/// any objects are sent as JSON.
+pub(super) async fn build_form(ctx: &ContextField<'_>, variable: serde_json::Value) -> Result<Form, Error> {
+    let object = match variable {
+        serde_json::Value::Object(object) => object,
+        serde_json::Value::Null => return Ok(Form::new()),
+        _ => return Err(Error::new("Internal error: multipart request bodies must be objects")),
+    };
+
+    let mut form = Form::new();
+
+    for (name, value) in object {
+        match value {
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    if let Some(part) = part(ctx, item).await? {
+                        form = form.part(name.clone(), part);
+                    }
+                }
+            }
+            value => {
+                if let Some(part) = part(ctx, value).await? {
+                    form = form.part(name, part);
+                }
+            }
+        }
+    }
+
+    Ok(form)
+}
+
+async fn part(ctx: &ContextField<'_>, value: serde_json::Value) -> Result<Option<Part>, Error> {
+    Ok(Some(match value {
+        serde_json::Value::Null => return Ok(None),
+        serde_json::Value::String(value) => match value.strip_prefix(UPLOAD_VARIABLE_PREFIX) {
+            Some(index) => upload_part(ctx, index).await?,
+            None => Part::text(value),
+        },
+        serde_json::Value::Bool(value) => Part::text(value.to_string()),
+        serde_json::Value::Number(value) => Part::text(value.to_string()),
+        value @ (serde_json::Value::Array(_) | serde_json::Value::Object(_)) => {
+            Part::text(serde_json::to_string(&value)?).mime_str("application/json")?
+        }
+    }))
+}
+
+async fn upload_part(ctx: &ContextField<'_>, index: &str) -> Result<Part, Error> {
+    let upload = index
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| ctx.query_env.uploads.get(index))
+        .ok_or_else(|| Error::new("Could not find the file for an Upload"))?;
+
+    let file = upload.content.try_clone()?;
+    let content = async_runtime::spawn_blocking(move || read_from_start(&file)).await?;
+
+    let mut part = Part::bytes(content).file_name(upload.filename.clone());
+    if let Some(content_type) = &upload.content_type {
+        part = part.mime_str(content_type)?;
+    }
+
+    Ok(part)
+}
+
+/// Reads the whole file without moving its cursor, which is shared by all the handles of an
+/// upload as the same upload may be sent more than once, concurrently.
+fn read_from_start(file: &File) -> io::Result<Vec<u8>> {
+    let length =
+        usize::try_from(file.metadata()?.len()).map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+    let mut content = vec![0; length];
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileExt;
+
+        file.read_exact_at(&mut content, 0)?;
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::FileExt;
+
+        let mut read = 0;
+        while read < content.len() {
+            match file.seek_read(&mut content[read..], read as u64)? {
+                0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+                count => read += count,
+            }
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let mut file = file;
+        file.seek(SeekFrom::Start(0))?;
+        file.read_exact(&mut content)?;
+    }
+
+    Ok(content)
+}
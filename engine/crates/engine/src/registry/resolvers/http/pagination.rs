@@ -0,0 +1,374 @@
+//! Exposes list operations as Relay connections, translating `first` & `after` into
+//! requests for one or more pages of the downstream API.
+
+use std::collections::HashSet;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use reqwest::Url;
+use serde_json::{json, Value};
+
+use super::Request;
+use crate::Error;
+
+/// The most pages we fetch to resolve a single connection. Clients can ask for more from the end
+/// cursor.
+const MAX_PAGES: usize = 100;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct HttpPagination {
+    pub style: PaginationStyle,
+    /// The query parameter that sets the page size, if the operation has one
+    pub limit_parameter: Option<String>,
+    /// The largest page size the downstream API accepts
+    pub max_page_size: Option<usize>,
+    /// The field holding the items when the response is an object rather than a list
+    pub items_field: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub enum PaginationStyle {
+    /// The index of the first item of a page is sent in `offset_parameter`
+    OffsetLimit { offset_parameter: String },
+    /// Pages are numbered from 1 & the number is sent in `page_parameter`
+    PageNumber { page_parameter: String },
+    /// The response body contains a cursor for the next page at `next_cursor_path`,
+    /// which is sent in `cursor_parameter`
+    Cursor {
+        cursor_parameter: String,
+        next_cursor_path: Vec<String>,
+    },
+    /// The URL of the next page is in an RFC 5988 `Link` header with `rel="next"`
+    LinkHeader,
+}
+
+/// A page of the downstream API.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum Page {
+    Offset(usize),
+    Number {
+        number: usize,
+        size: Option<usize>,
+    },
+    Cursor(Option<String>),
+    /// The URL the downstream server gave us, or `None` for the first page
+    Link(Option<String>),
+}
+
+/// The cursor of an edge: the page the item was on and its index within that page.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct Position {
+    page: Page,
+    index: usize,
+}
+
+impl HttpPagination {
+    pub(super) async fn resolve(&self, request: &Request<'_, '_>, url: Url) -> Result<Value, Error> {
+        let first = request.ctx.input_by_name::<Option<usize>>("first")?;
+        let after = request.ctx.input_by_name::<Option<String>>("after")?;
+
+        let (mut page, mut skip) = match &after {
+            Some(cursor) => Position::decode(cursor)?.resume()?,
+            None => {
+                let page = match &self.style {
+                    PaginationStyle::OffsetLimit { .. } => Page::Offset(0),
+                    PaginationStyle::PageNumber { .. } => Page::Number {
+                        number: 1,
+                        size: self.page_size(first.map(|first| first + 1)),
+                    },
+                    PaginationStyle::Cursor { .. } => Page::Cursor(None),
+                    PaginationStyle::LinkHeader => Page::Link(None),
+                };
+                (page, 0)
+            }
+        };
+
+        // We fetch one item more than asked for so we know if there's a next page.
+        let wanted = first.map(|first| first + 1);
+        let mut edges = Vec::new();
+        let mut has_next_page = false;
+        let mut fetched_pages = HashSet::from([page.clone()]);
+
+        loop {
+            let limit = wanted.and_then(|wanted| self.page_size(Some((wanted - edges.len()).saturating_add(skip))));
+            let (items, next_page) = self.fetch_page(request, &url, &page, limit).await?;
+
+            edges.extend(items.into_iter().enumerate().skip(skip).map(|(index, node)| {
+                let cursor = Position {
+                    page: page.clone(),
+                    index,
+                }
+                .encode();
+                json!({ "node": node, "cursor": cursor })
+            }));
+            skip = 0;
+
+            match (wanted, next_page) {
+                (Some(wanted), _) if edges.len() >= wanted => {
+                    edges.truncate(wanted - 1);
+                    has_next_page = true;
+                    break;
+                }
+                // Without `first` we return a single page of whatever size the API defaults to.
+                (None, next_page) => {
+                    has_next_page = next_page.is_some();
+                    break;
+                }
+                (Some(_), Some(next_page)) => {
+                    // A server giving us a cursor or link we already followed would have us loop forever.
+                    if !fetched_pages.insert(next_page.clone()) {
+                        break;
+                    }
+
+                    if fetched_pages.len() > MAX_PAGES {
+                        has_next_page = true;
+                        break;
+                    }
+
+                    page = next_page;
+                }
+                (Some(_), None) => break,
+            }
+        }
+
+        let start_cursor = edges.first().map(|edge| edge["cursor"].clone());
+        let end_cursor = edges.last().map(|edge| edge["cursor"].clone());
+
+        Ok(json!({
+            "edges": edges,
+            "pageInfo": {
+                "hasNextPage": has_next_page,
+                "hasPreviousPage": after.is_some(),
+                "startCursor": start_cursor,
+                "endCursor": end_cursor,
+            }
+        }))
+    }
+
+    fn page_size(&self, wanted: Option<usize>) -> Option<usize> {
+        self.limit_parameter.as_ref()?;
+
+        match (wanted, self.max_page_size) {
+            (Some(wanted), Some(max)) => Some(wanted.min(max)),
+            (Some(size), None) | (None, Some(size)) => Some(size),
+            (None, None) => None,
+        }
+    }
+
+    /// Fetches a page, returning its items & the page that follows it, if any.
+    async fn fetch_page(
+        &self,
+        request: &Request<'_, '_>,
+        url: &Url,
+        page: &Page,
+        limit: Option<usize>,
+    ) -> Result<(Vec<Value>, Option<Page>), Error> {
+        let mut url = url.clone();
+
+        // The page size has to stay the same from one numbered page to the next, so we keep
+        // the one we started with.
+        let limit = match page {
+            Page::Number { size, .. } => *size,
+            _ => limit,
+        };
+
+        match (&self.style, page) {
+            (PaginationStyle::OffsetLimit { offset_parameter }, Page::Offset(offset)) => {
+                url.query_pairs_mut().append_pair(offset_parameter, &offset.to_string());
+                self.append_limit(&mut url, limit);
+            }
+            (PaginationStyle::PageNumber { page_parameter }, Page::Number { number, .. }) => {
+                url.query_pairs_mut().append_pair(page_parameter, &number.to_string());
+                self.append_limit(&mut url, limit);
+            }
+            (PaginationStyle::Cursor { cursor_parameter, .. }, Page::Cursor(cursor)) => {
+                if let Some(cursor) = cursor {
+                    url.query_pairs_mut().append_pair(cursor_parameter, cursor);
+                }
+                self.append_limit(&mut url, limit);
+            }
+            (PaginationStyle::LinkHeader, Page::Link(None)) => {
+                self.append_limit(&mut url, limit);
+            }
+            (PaginationStyle::LinkHeader, Page::Link(Some(link))) => {
+                let link = Url::parse(link).map_err(|_| invalid_cursor())?;
+
+                // We send our credentials along, so we only follow links back to the same API.
+                if link.origin() != url.origin() {
+                    return Err(Error::new(
+                        "The downstream server returned a pagination link to a different host",
+                    ));
+                }
+
+                url = with_query_pairs(link, &request.credentials_query);
+            }
+            _ => return Err(invalid_cursor()),
+        }
+
+        let response = request.send(url).await?;
+        let next_link = next_link(response.headers());
+        let body = response.json::<Value>().await.map_err(|e| Error::new(e.to_string()))?;
+
+        let items = match &self.items_field {
+            Some(field) => body.get(field),
+            None => Some(&body),
+        };
+
+        let items = match items {
+            Some(Value::Array(items)) => items.clone(),
+            None | Some(Value::Null) => Vec::new(),
+            Some(_) => return Err(Error::new("Expected the downstream server to return a list")),
+        };
+
+        // Offsets & page numbers can go on forever, so a page shorter than we asked for is the last one.
+        let is_short_page = limit.map(|limit| items.len() < limit).unwrap_or_default();
+
+        let next_page = match (&self.style, page) {
+            _ if items.is_empty() => None,
+            (PaginationStyle::OffsetLimit { .. }, Page::Offset(offset)) if !is_short_page => {
+                offset.checked_add(items.len()).map(Page::Offset)
+            }
+            (PaginationStyle::PageNumber { .. }, Page::Number { number, size }) if !is_short_page => {
+                number.checked_add(1).map(|number| Page::Number { number, size: *size })
+            }
+            (PaginationStyle::Cursor { next_cursor_path, .. }, _) => {
+                next_cursor(&body, next_cursor_path).map(|cursor| Page::Cursor(Some(cursor)))
+            }
+            (PaginationStyle::LinkHeader, _) => next_link.map(|link| Page::Link(Some(link))),
+            _ => None,
+        };
+
+        Ok((items, next_page))
+    }
+
+    fn append_limit(&self, url: &mut Url, limit: Option<usize>) {
+        if let Some((limit_parameter, limit)) = self.limit_parameter.as_ref().zip(limit) {
+            url.query_pairs_mut().append_pair(limit_parameter, &limit.to_string());
+        }
+    }
+}
+
+impl Position {
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).expect("positions to serialize"))
+    }
+
+    fn decode(cursor: &str) -> Result<Self, Error> {
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid_cursor())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid_cursor())
+    }
+
+    /// The page to fetch to resume after this position, and the number of its items to skip.
+    /// Positions are decoded from the client's cursors, so they could be anything.
+    fn resume(self) -> Result<(Page, usize), Error> {
+        let next_index = self.index.checked_add(1).ok_or_else(invalid_cursor)?;
+
+        match self.page {
+            // Offsets can start anywhere, so there's no need to fetch the items before the cursor.
+            Page::Offset(offset) => {
+                let offset = offset.checked_add(next_index).ok_or_else(invalid_cursor)?;
+                Ok((Page::Offset(offset), 0))
+            }
+            page => Ok((page, next_index)),
+        }
+    }
+}
+
+fn invalid_cursor() -> Error {
+    Error::new("Invalid cursor")
+}
+
+fn next_cursor(body: &Value, path: &[String]) -> Option<String> {
+    let value = path.iter().try_fold(body, |value, key| value.get(key))?;
+
+    match value {
+        Value::String(cursor) if !cursor.is_empty() => Some(cursor.clone()),
+        Value::Number(cursor) => Some(cursor.to_string()),
+        _ => None,
+    }
+}
+
+/// Finds the `rel="next"` URL in the `Link` headers of a response.
+fn next_link(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get_all(reqwest::header::LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let (url, params) = link.trim().strip_prefix('<')?.split_once('>')?;
+
+            let is_next = params.split(';').any(|param| {
+                param
+                    .trim()
+                    .strip_prefix("rel=")
+                    .map(|rel| rel.trim_matches('"').split_whitespace().any(|rel| rel == "next"))
+                    .unwrap_or_default()
+            });
+
+            is_next.then(|| url.to_string())
+        })
+}
+
+/// Sets the given query pairs on a URL, replacing any with the same name.
+fn with_query_pairs(mut url: Url, pairs: &[(String, String)]) -> Url {
+    if pairs.is_empty() {
+        return url;
+    }
+
+    let existing = url
+        .query_pairs()
+        .filter(|(name, _)| !pairs.iter().any(|(pair_name, _)| pair_name == name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+
+    url.query_pairs_mut().clear().extend_pairs(existing).extend_pairs(pairs);
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_link() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::LINK,
+            r#"<https://api.example.com/pets?page=1>; rel="prev", <https://api.example.com/pets?page=3>; rel="next""#
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            next_link(&headers).as_deref(),
+            Some("https://api.example.com/pets?page=3")
+        );
+    }
+
+    #[test]
+    fn test_position_round_trip() {
+        let cursor = Position {
+            page: Page::Cursor(Some("abc".into())),
+            index: 3,
+        }
+        .encode();
+
+        let position = Position::decode(&cursor).unwrap();
+
+        assert_eq!(position.index, 3);
+        assert!(matches!(position.page, Page::Cursor(Some(cursor)) if cursor == "abc"));
+        assert!(Position::decode("not a cursor").is_err());
+    }
+
+    #[test]
+    fn test_resume_position() {
+        let resume = |page, index| Position { page, index }.resume();
+
+        assert_eq!(resume(Page::Offset(10), 2).unwrap(), (Page::Offset(13), 0));
+        assert_eq!(resume(Page::Cursor(None), 2).unwrap(), (Page::Cursor(None), 3));
+        assert!(resume(Page::Offset(usize::MAX - 1), 1).is_err());
+        assert!(resume(Page::Cursor(None), usize::MAX).is_err());
+    }
+}
@@ -147,6 +147,39 @@ where
     Ok(())
 }
 
+/// Serializes a header or cookie parameter with the OpenAPI `simple` style: lists are
+/// comma separated, and objects become comma separated keys and values. Returns `None`
+/// for null values, which are not sent at all.
+pub fn simple_style_value(value: &serde_json::Value) -> Result<Option<String>, Error> {
+    use serde_json::Value;
+
+    let value = match value {
+        Value::Null => return Ok(None),
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => json_scalar_to_header_string(value)?.into_owned(),
+        Value::Array(values) => values
+            .iter()
+            .map(json_scalar_to_header_string)
+            .collect::<Result<Vec<_>, _>>()?
+            .join(","),
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| Ok(vec![Cow::Borrowed(key.as_str()), json_scalar_to_header_string(value)?]))
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(","),
+    };
+
+    Ok(Some(value))
+}
+
+/// Serializes a cookie parameter like [`simple_style_value`], percent-encoding it so the value
+/// can't inject cookies of its own.
+pub fn cookie_value(value: &serde_json::Value) -> Result<Option<String>, Error> {
+    Ok(simple_style_value(value)?.map(|value| urlencoding::encode(&value).into_owned()))
+}
+
 fn json_scalar_to_path_string(value: &serde_json::Value) -> Result<Cow<'_, str>, Error> {
     use serde_json::Value;
     match value {
@@ -171,6 +204,18 @@ fn json_scalar_to_query_string(value: &serde_json::Value) -> Result<Cow<'_, str>
     }
 }
 
+fn json_scalar_to_header_string(value: &serde_json::Value) -> Result<Cow<'_, str>, Error> {
+    use serde_json::Value;
+    match value {
+        Value::Bool(b) => Ok(Cow::Owned(b.to_string())),
+        Value::Number(number) => Ok(Cow::Owned(number.to_string())),
+        Value::String(string) => Ok(Cow::Borrowed(string)),
+        Value::Null => Err(Error::new("HTTP header parameters cannot have nested nulls")),
+        Value::Array(_) => Err(Error::new("HTTP header parameters cannot have nested arrays")),
+        Value::Object(_) => Err(Error::new("HTTP header parameters cannot have nested objects")),
+    }
+}
+
 struct DeepObjectIter<'a> {
     stack: Vec<DeepObjectStackEntry<'a>>,
     parameter_name: &'a str,
@@ -388,4 +433,19 @@ mod tests {
             @"https://example.com/users?id[a_list][1][other]=string&id[a_list][0][two]=2&id[a_list][0][one]=1"
         );
     }
+
+    #[test]
+    fn test_simple_style_values() {
+        let test = |value| simple_style_value(&value).unwrap();
+
+        assert_eq!(test(json!(null)), None);
+        assert_eq!(test(json!(1)).as_deref(), Some("1"));
+        assert_eq!(test(json!("2023-10-16")).as_deref(), Some("2023-10-16"));
+        assert_eq!(test(json!([1, 2, 3])).as_deref(), Some("1,2,3"));
+        assert_eq!(
+            test(json!({"role": "admin", "firstName": "Alex"})).as_deref(),
+            Some("role,admin,firstName,Alex")
+        );
+        assert!(simple_style_value(&json!([[1]])).is_err());
+    }
 }
@@ -0,0 +1,224 @@
+//! Resolves the credentials for the security requirements of an operation.
+
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use super::SecurityRequirement;
+use crate::{
+    registry::{
+        resolvers::http_client::HttpClient, ApiKeyLocation, ConnectorHeaderValue, ConnectorSecurity, SecurityScheme,
+    },
+    Context, ContextExt, ContextField, Error, RequestHeaders,
+};
+
+const TOKEN_CACHE_KEY_PREFIX: &str = "openapi-oauth2-token";
+
+/// The lifetime of a cached token when the authorization server doesn't tell us when it expires.
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(300);
+
+/// We stop using a cached token this long before it expires, so a token doesn't expire in flight.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Some key-value stores don't support expiration times shorter than this.
+const MIN_TOKEN_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub(super) struct Credentials {
+    pub headers: Vec<(String, String)>,
+    pub query: Vec<(String, String)>,
+    pub cookies: Vec<String>,
+}
+
+/// Picks the first of the requirements we have credentials for and resolves them.
+///
+/// If we can't satisfy any of the requirements we don't send any credentials and
+/// let the downstream server decide what to do with the request.
+pub(super) async fn resolve_credentials(
+    ctx: &ContextField<'_>,
+    security: &ConnectorSecurity,
+    requirements: &[SecurityRequirement],
+) -> Result<Credentials, Error> {
+    let request_headers = ctx.data::<RequestHeaders>().ok();
+    let mut credentials = Credentials::default();
+
+    let Some(requirement) = requirements
+        .iter()
+        .find(|requirement| is_satisfied(requirement, security, request_headers))
+    else {
+        return Ok(credentials);
+    };
+
+    for (scheme_name, scopes) in &requirement.0 {
+        let Some(scheme) = security.get(scheme_name) else {
+            continue;
+        };
+
+        match scheme {
+            SecurityScheme::ApiKey { name, location, value } => {
+                let value = credential_value(value, request_headers).unwrap_or_default().to_string();
+
+                match location {
+                    ApiKeyLocation::Header => credentials.headers.push((name.clone(), value)),
+                    ApiKeyLocation::Query => credentials.query.push((name.clone(), value)),
+                    ApiKeyLocation::Cookie => credentials.cookies.push(format!("{name}={value}")),
+                }
+            }
+            SecurityScheme::Basic { value } => {
+                let value = credential_value(value, request_headers).unwrap_or_default();
+
+                credentials.headers.push((
+                    reqwest::header::AUTHORIZATION.to_string(),
+                    format!("Basic {}", STANDARD.encode(value)),
+                ));
+            }
+            SecurityScheme::Bearer { value } => {
+                let value = credential_value(value, request_headers).unwrap_or_default();
+
+                credentials
+                    .headers
+                    .push((reqwest::header::AUTHORIZATION.to_string(), format!("Bearer {value}")));
+            }
+            SecurityScheme::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret,
+            } => {
+                let token = client_credentials_token(ctx, token_url, client_id, client_secret, scopes).await?;
+
+                credentials
+                    .headers
+                    .push((reqwest::header::AUTHORIZATION.to_string(), format!("Bearer {token}")));
+            }
+        }
+    }
+
+    Ok(credentials)
+}
+
+fn is_satisfied(
+    requirement: &SecurityRequirement,
+    security: &ConnectorSecurity,
+    request_headers: Option<&RequestHeaders>,
+) -> bool {
+    requirement.0.keys().all(|scheme_name| match security.get(scheme_name) {
+        Some(
+            SecurityScheme::ApiKey { value, .. } | SecurityScheme::Basic { value } | SecurityScheme::Bearer { value },
+        ) => credential_value(value, request_headers).is_some(),
+        Some(SecurityScheme::OAuth2ClientCredentials { .. }) => true,
+        None => false,
+    })
+}
+
+fn credential_value<'a>(
+    value: &'a ConnectorHeaderValue,
+    request_headers: Option<&'a RequestHeaders>,
+) -> Option<&'a str> {
+    match value {
+        ConnectorHeaderValue::Static(value) => Some(value),
+        ConnectorHeaderValue::Forward(header_name) => request_headers?.find(header_name),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Fetches an access token with the OAuth2 client credentials flow, caching it in the
+/// runtime key-value store until shortly before it expires.
+async fn client_credentials_token(
+    ctx: &ContextField<'_>,
+    token_url: &str,
+    client_id: &str,
+    client_secret: &str,
+    scopes: &[String],
+) -> Result<String, Error> {
+    let kv = ctx.data::<runtime::kv::Kv>().ok();
+    let cache_key = format!("{TOKEN_CACHE_KEY_PREFIX}:{token_url}:{client_id}:{}", scopes.join(" "));
+
+    if let Some(kv) = kv {
+        match kv.get_json::<String>(&cache_key).await {
+            Ok(Some(token)) => return Ok(token),
+            Ok(None) => {}
+            Err(error) => log::warn!(ctx.trace_id(), "Could not read the OAuth2 token cache: {error}"),
+        }
+    }
+
+    let runtime_ctx = ctx.data::<runtime::Context>()?;
+
+    let mut form = vec![
+        ("grant_type", "client_credentials".to_string()),
+        ("client_id", client_id.to_string()),
+        ("client_secret", client_secret.to_string()),
+    ];
+
+    if !scopes.is_empty() {
+        form.push(("scope", scopes.join(" ")));
+    }
+
+    let request_builder = HttpClient::from_context(ctx)?
+        .post(token_url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .form(&form);
+
+    let response = super::super::logged_fetch::send_logged_request(
+        &runtime_ctx.ray_id(),
+        runtime_ctx.log.fetch_log_endpoint_url.as_deref(),
+        request_builder,
+    )
+    .await
+    .map_err(|e| Error::new(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(Error::new(format!(
+            "Could not fetch an OAuth2 access token, the authorization server returned: {}",
+            response.status(),
+        )));
+    }
+
+    let TokenResponse {
+        access_token,
+        expires_in,
+    } = response
+        .json::<TokenResponse>()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+
+    if let Some((kv, ttl)) = kv.zip(token_cache_ttl(expires_in)) {
+        if let Err(error) = kv.put_json(&cache_key, &access_token, Some(ttl)).await {
+            log::warn!(ctx.trace_id(), "Could not cache the OAuth2 token: {error}");
+        }
+    }
+
+    Ok(access_token)
+}
+
+/// How long a token can be cached for, if at all: tokens expiring too soon to be kept for the
+/// minimum TTL of the key-value store are fetched again on every request rather than being used
+/// after they expired.
+fn token_cache_ttl(expires_in: Option<u64>) -> Option<Duration> {
+    let ttl = expires_in
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_TOKEN_TTL)
+        .saturating_sub(TOKEN_EXPIRY_MARGIN);
+
+    (ttl >= MIN_TOKEN_TTL).then_some(ttl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_cache_ttl_expires_before_the_token() {
+        assert_eq!(token_cache_ttl(None), Some(Duration::from_secs(270)));
+        assert_eq!(token_cache_ttl(Some(3600)), Some(Duration::from_secs(3570)));
+        assert_eq!(token_cache_ttl(Some(90)), Some(Duration::from_secs(60)));
+        assert_eq!(token_cache_ttl(Some(89)), None);
+        assert_eq!(token_cache_ttl(Some(10)), None);
+        assert_eq!(token_cache_ttl(Some(0)), None);
+    }
+}
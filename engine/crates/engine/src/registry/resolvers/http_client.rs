@@ -0,0 +1,84 @@
+//! The client we send requests to downstream servers with.
+
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+use super::circuit_breaker::{self, CircuitBreaker};
+use crate::{registry::ConnectorFetchPolicy, ContextExt, ContextField, Error};
+
+/// A client the runtime shares between requests, so connections to downstream servers
+/// are pooled and reused. Cloning it is cheap, and the clones share the same pool.
+///
+/// It also keeps track of the health of each connector, with their circuit breakers.
+#[derive(Clone, Default)]
+pub struct HttpClient {
+    client: reqwest::Client,
+    circuit_breakers: Arc<Mutex<HashMap<String, Arc<Mutex<circuit_breaker::State>>>>>,
+}
+
+impl HttpClient {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The client of the runtime. It must be shared, as the circuit breakers of a client
+    /// only see the requests sent with it.
+    pub(crate) fn from_context(ctx: &ContextField<'_>) -> Result<HttpClient, Error> {
+        ctx.data::<HttpClient>().cloned()
+    }
+
+    /// The circuit breaker of a connector, if its fetch policy has one.
+    pub(crate) fn circuit_breaker(
+        &self,
+        connector: Connector<'_>,
+        policy: &ConnectorFetchPolicy,
+    ) -> Option<CircuitBreaker> {
+        let breaker_policy = policy.circuit_breaker.clone()?;
+
+        let state = self
+            .circuit_breakers
+            .lock()
+            .expect("poisoned circuit breakers")
+            .entry(connector.registry_key())
+            .or_default()
+            .clone();
+
+        Some(CircuitBreaker::new(connector.name().to_string(), breaker_policy, state))
+    }
+}
+
+/// A connector, so its circuit breaker is keyed like the registry keys its fetch policies.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Connector<'a> {
+    /// A GraphQL connector, by its name.
+    GraphQL(&'a str),
+    /// An OpenAPI connector, by its unique namespace.
+    OpenApi(&'a str),
+}
+
+impl<'a> Connector<'a> {
+    fn registry_key(self) -> String {
+        match self {
+            Connector::GraphQL(name) => format!("GraphQLConnector{name}"),
+            Connector::OpenApi(namespace) => namespace.to_string(),
+        }
+    }
+
+    fn name(self) -> &'a str {
+        match self {
+            Connector::GraphQL(name) | Connector::OpenApi(name) => name,
+        }
+    }
+}
+
+impl Deref for HttpClient {
+    type Target = reqwest::Client;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
@@ -1,35 +1,84 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
-use engine_parser::{types::Field, Positioned};
+use async_lock::OnceCell;
+use dataloader::{DataLoader, Loader, NoCache};
+use engine_parser::{
+    types::{Field, OperationType, Selection, SelectionSet},
+    Positioned,
+};
 use engine_value::{argument_set::ArgumentSet, ConstValue, Name, Value};
 
-use crate::{Context, ContextField, Error};
+use crate::{
+    registry::{type_kinds::SelectionSetTarget, MetaField},
+    Context, ContextField, Error, QueryPathSegment,
+};
 
 use super::{ResolvedValue, ResolverContext};
 
+/// The alias under which the key of a batched join is requested from the joined field, as the
+/// client doesn't necessarily select it. It's removed from the items once they're matched.
+const BATCH_KEY_ALIAS: &str = "_joinBatchKey";
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
 #[serde_with::minify_field_names(serialize = "minified", deserialize = "minified")]
 pub struct JoinResolver {
+    /// The fields of the namespace objects leading to the joined field from the Query type, e.g.
+    /// `github` when joining with `github { user(login: $login) }`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub namespaces: Vec<String>,
     pub field_name: String,
     pub arguments: ArgumentSet,
+    /// Set if the joined field accepts a list, so the joins of all the parents at a given depth
+    /// can be resolved with a single call. See [`JoinBatcher`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch: Option<JoinBatch>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct JoinBatch {
+    /// The field of the returned items holding the argument value of their parent.
+    ///
+    /// The items are matched with their parent by position if not set, like the federation
+    /// `_entities` field does.
+    #[serde(default)]
+    pub key: Option<String>,
 }
 
 // ArgumentSet can't be hashed so we've got a manual impl here that goes via JSON.
 // Would be nice to get rid of the Hash requirement from these types
 impl core::hash::Hash for JoinResolver {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.namespaces.hash(state);
         self.field_name.hash(state);
         serde_json::to_string(&self.arguments).unwrap_or_default().hash(state);
+        self.batch.hash(state);
     }
 }
 
 impl JoinResolver {
     pub fn new(field_name: String, arguments: Vec<(Name, Value)>) -> Self {
         JoinResolver {
+            namespaces: vec![],
             field_name,
             arguments: ArgumentSet::new(arguments),
+            batch: None,
         }
     }
+
+    /// The argument of a batched join receiving the values of all the parents.
+    ///
+    /// This is the argument referring to the fields of the parent, validation makes sure there is
+    /// only one.
+    pub fn batched_argument(&self) -> Option<Name> {
+        self.arguments
+            .clone()
+            .into_iter()
+            .find(|(_, value)| value.variables_used().next().is_some())
+            .map(|(name, _)| name)
+    }
 }
 
 impl JoinResolver {
@@ -38,41 +87,185 @@ impl JoinResolver {
         ctx: &ContextField<'_>,
         last_resolver_value: Option<ResolvedValue>,
     ) -> Result<ResolvedValue, Error> {
-        let root_type = ctx
-            .schema_env()
-            .registry
-            .root_type(engine_parser::types::OperationType::Query);
-
         let last_resolver_value = last_resolver_value.unwrap_or_default();
-        let meta_field = root_type.field(&self.field_name).ok_or_else(|| {
-            Error::new(format!(
-                "Internal error: could not find joined field {}",
-                &self.field_name
-            ))
-        })?;
-
-        let fake_query_field = self.field_for_join(ctx.item, last_resolver_value)?;
-        let join_context = ctx.to_join_context(&fake_query_field, meta_field, root_type);
+
+        if let Some(batch) = &self.batch {
+            return self.resolve_batched(ctx, batch, last_resolver_value).await;
+        }
+
+        let arguments = self.resolve_arguments(last_resolver_value)?;
+
+        self.resolve_joined_field(ctx, arguments).await
+    }
+}
+
+impl JoinResolver {
+    async fn resolve_batched(
+        &self,
+        ctx: &ContextField<'_>,
+        batch: &JoinBatch,
+        last_resolver_value: ResolvedValue,
+    ) -> Result<ResolvedValue, Error> {
+        let batched_argument = self
+            .batched_argument()
+            .ok_or_else(|| Error::new("Internal error: a batched join must refer to its parent"))?;
+
+        let mut arguments = self.resolve_arguments(last_resolver_value)?;
+        let Some(position) = arguments.iter().position(|(name, _)| name == batched_argument.as_str()) else {
+            return Ok(ResolvedValue::null());
+        };
+        let (_, parent_value) = arguments.remove(position);
+        let parent_value = parent_value
+            .into_const()
+            .ok_or_else(|| Error::new("Internal error converting intermediate values"))?
+            .into_json()?;
+
+        let join = PendingJoin {
+            batch: PendingJoinBatch {
+                field_path: ctx
+                    .path
+                    .iter()
+                    .filter_map(|segment| match segment {
+                        QueryPathSegment::Field(name) => Some(name.to_string()),
+                        QueryPathSegment::Index(_) => None,
+                    })
+                    .collect(),
+                resolver: serde_json::to_string(self)?,
+            },
+            parent_value: serde_json::to_string(&parent_value)?,
+        };
+
+        let batcher = ctx.data::<JoinBatcher>()?;
+        let shared = batcher
+            .loader
+            .load_one(join.clone())
+            .await?
+            .ok_or_else(|| Error::new("Internal error: batched join is missing"))?;
+
+        // The first join of the batch to get here resolves the joined field for all of them.
+        let parent_values = &shared.parent_values;
+        let items = shared
+            .items
+            .get_or_init(|| async move {
+                let values = parent_values
+                    .iter()
+                    .map(|value| Ok(ConstValue::from_json(serde_json::from_str(value)?)?.into_value()))
+                    .collect::<Result<Vec<_>, Error>>()?;
+                arguments.push((batched_argument.to_string(), Value::List(values)));
+
+                match self.resolve_joined_field(ctx, arguments).await?.take() {
+                    serde_json::Value::Array(items) => Ok(items),
+                    serde_json::Value::Null => Ok(vec![]),
+                    _ => Err(Error::new(format!(
+                        "The batched join with {} must return a list",
+                        self.field_name
+                    ))),
+                }
+            })
+            .await
+            .clone()?;
+
+        let item = match &batch.key {
+            Some(key) => items
+                .into_iter()
+                // Resolvers returning whole objects, like custom ones, ignore the alias.
+                .find(|item| item.get(BATCH_KEY_ALIAS).or_else(|| item.get(key)) == Some(&parent_value))
+                .map(|mut item| {
+                    if let Some(item) = item.as_object_mut() {
+                        item.remove(BATCH_KEY_ALIAS);
+                    }
+                    item
+                }),
+            None => parent_values
+                .iter()
+                .position(|value| *value == join.parent_value)
+                .and_then(|position| items.into_iter().nth(position)),
+        };
+
+        Ok(item.map(ResolvedValue::new).unwrap_or_else(ResolvedValue::null))
+    }
+
+    /// Resolves the joined field with the given arguments, descending through its namespaces the
+    /// way the fields of a query would be.
+    async fn resolve_joined_field(
+        &self,
+        ctx: &ContextField<'_>,
+        arguments: Vec<(String, Value)>,
+    ) -> Result<ResolvedValue, Error> {
+        let registry = &ctx.schema_env().registry;
+        let fake_query_field = self.field_with_arguments(ctx.item, arguments);
+
+        let mut field = &fake_query_field;
+        let mut parent_type = registry.root_type(OperationType::Query);
+        let mut last_resolver_value = None;
+
+        for namespace in &self.namespaces {
+            let meta_field = joined_field(parent_type, namespace)?;
+
+            // Namespaces used as containers have no value but their fields have resolvers.
+            if !meta_field.resolver.is_parent() {
+                let join_context = ctx.to_join_context(field, meta_field, parent_type);
+                let resolver_context = ResolverContext::new(&join_context);
+                let value = meta_field
+                    .resolver
+                    .resolve(&join_context, &resolver_context, last_resolver_value)
+                    .await?;
+
+                if value.data_resolved().is_null() {
+                    return Ok(value);
+                }
+                last_resolver_value = Some(value);
+            }
+
+            parent_type = registry.lookup_expecting::<SelectionSetTarget<'_>>(&meta_field.ty)?;
+            field = match field
+                .node
+                .selection_set
+                .node
+                .items
+                .first()
+                .map(|selection| &selection.node)
+            {
+                Some(Selection::Field(child)) => child,
+                _ => return Err(Error::new("Internal error: could not find joined field")),
+            };
+        }
+
+        let meta_field = joined_field(parent_type, &self.field_name)?;
+        let join_context = ctx.to_join_context(field, meta_field, parent_type);
         let resolver_context = ResolverContext::new(&join_context);
 
         meta_field
             .resolver
-            .resolve(&join_context, &resolver_context, None)
+            .resolve(&join_context, &resolver_context, last_resolver_value)
             .await
     }
-}
 
-impl JoinResolver {
-    fn field_for_join(
+    fn field_with_arguments(
         &self,
         actual_field: &Positioned<Field>,
-        last_resolver_value: ResolvedValue,
-    ) -> Result<Positioned<Field>, Error> {
-        let arguments = self.resolve_arguments(last_resolver_value)?;
-
+        arguments: Vec<(String, Value)>,
+    ) -> Positioned<Field> {
         let Positioned { pos, node: field } = actual_field;
 
-        Ok(Positioned::new(
+        let mut selection_set = field.selection_set.clone();
+        if let Some(key) = self.batch.as_ref().and_then(|batch| batch.key.as_deref()) {
+            selection_set.node.items.push(Positioned::new(
+                Selection::Field(Positioned::new(
+                    Field {
+                        alias: Some(Positioned::new(Name::new(BATCH_KEY_ALIAS), *pos)),
+                        name: Positioned::new(Name::new(key), *pos),
+                        arguments: vec![],
+                        directives: vec![],
+                        selection_set: Positioned::new(SelectionSet::default(), *pos),
+                    },
+                    *pos,
+                )),
+                *pos,
+            ));
+        }
+
+        let joined_field = Positioned::new(
             Field {
                 alias: None,
                 name: Positioned::new(Name::new(&self.field_name), *pos),
@@ -81,10 +274,29 @@ impl JoinResolver {
                     .map(|(name, value)| (Positioned::new(Name::new(name), *pos), Positioned::new(value, *pos)))
                     .collect(),
                 directives: field.directives.clone(),
-                selection_set: field.selection_set.clone(),
+                selection_set,
             },
             *pos,
-        ))
+        );
+
+        // Each namespace selects only the next field of the path.
+        self.namespaces.iter().rev().fold(joined_field, |child, namespace| {
+            Positioned::new(
+                Field {
+                    alias: None,
+                    name: Positioned::new(Name::new(namespace), *pos),
+                    arguments: vec![],
+                    directives: vec![],
+                    selection_set: Positioned::new(
+                        SelectionSet {
+                            items: vec![Positioned::new(Selection::Field(child), *pos)],
+                        },
+                        *pos,
+                    ),
+                },
+                *pos,
+            )
+        })
     }
 
     fn resolve_arguments(&self, last_resolver_value: ResolvedValue) -> Result<Vec<(String, Value)>, Error> {
@@ -123,3 +335,80 @@ impl JoinResolver {
         Ok(arguments.into_iter().collect())
     }
 }
+
+fn joined_field<'a>(parent_type: SelectionSetTarget<'a>, name: &str) -> Result<&'a MetaField, Error> {
+    parent_type
+        .field(name)
+        .ok_or_else(|| Error::new(format!("Internal error: could not find joined field {name}")))
+}
+
+/// Groups the batched joins of a request, so that the joins of a field at a given depth are
+/// resolved with a single call to the joined field.
+pub struct JoinBatcher {
+    loader: DataLoader<JoinLoader, NoCache>,
+}
+
+impl JoinBatcher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            loader: DataLoader::new(JoinLoader, async_runtime::spawn),
+        }
+    }
+}
+
+impl Default for JoinBatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PendingJoinBatch {
+    /// The path of the field without the list indices, which is shared by all the parents at a
+    /// given depth.
+    field_path: Vec<String>,
+    /// The serialized `JoinResolver`, as it can't be compared otherwise.
+    resolver: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PendingJoin {
+    batch: PendingJoinBatch,
+    /// The serialized value of the batched argument for this parent.
+    parent_value: String,
+}
+
+/// The joins of a batch, which share the result of the joined field.
+struct SharedJoins {
+    parent_values: Vec<String>,
+    items: OnceCell<Result<Vec<serde_json::Value>, Error>>,
+}
+
+struct JoinLoader;
+
+#[async_trait::async_trait]
+impl Loader<PendingJoin> for JoinLoader {
+    type Value = Arc<SharedJoins>;
+    type Error = Error;
+
+    async fn load(&self, joins: &[PendingJoin]) -> Result<HashMap<PendingJoin, Self::Value>, Self::Error> {
+        let mut batches = HashMap::<_, Vec<_>>::new();
+        for join in joins {
+            batches.entry(&join.batch).or_default().push(join);
+        }
+
+        let mut result = HashMap::with_capacity(joins.len());
+        for joins in batches.into_values() {
+            let shared = Arc::new(SharedJoins {
+                parent_values: joins.iter().map(|join| join.parent_value.clone()).collect(),
+                items: OnceCell::new(),
+            });
+            for join in joins {
+                result.insert(join.clone(), Arc::clone(&shared));
+            }
+        }
+
+        Ok(result)
+    }
+}
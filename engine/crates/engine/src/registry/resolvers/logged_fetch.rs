@@ -1,15 +1,299 @@
+use std::time::Duration;
+
+use common_types::CircuitBreakerState;
+use futures_util::future::{select, Either};
+use reqwest::StatusCode;
 use runtime::log::LogEvent;
+use tracing::{info_span, Instrument};
+
+use super::circuit_breaker::{CircuitBreaker, CircuitOpen};
+use crate::{registry::ConnectorFetchPolicy, Error, ErrorExtensions};
+
+/// The delay before the first retry, doubled for each retry after that.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// We won't wait any longer than this for a server that asks us to retry with `Retry-After`.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    #[error("the request timed out after {0:?}")]
+    Timeout(Duration),
+    #[error(transparent)]
+    CircuitOpen(#[from] CircuitOpen),
+}
+
+impl ErrorExtensions for FetchError {
+    fn extend(&self) -> Error {
+        match self {
+            FetchError::CircuitOpen(error) => error.extend(),
+            error => Error::new(error.to_string()),
+        }
+    }
+}
+
+/// Whether [`send_connector_request`] reads the response body before returning it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResponseBody {
+    /// The body is read within the timeout of the fetch policy, so a server sending it slowly
+    /// can't keep us waiting.
+    Buffered,
+    /// The body is left for the caller to stream, e.g. the events of a subscription. Only
+    /// receiving the response headers is subject to the timeout.
+    Streamed,
+}
 
 pub async fn send_logged_request(
     request_id: &str,
     fetch_log_endpoint_url: Option<&str>,
     request_builder: reqwest::RequestBuilder,
 ) -> Result<reqwest::Response, reqwest::Error> {
-    let start_time = web_time::Instant::now();
+    let (client, request) = request_builder.build_split();
 
+    execute_logged_request(request_id, fetch_log_endpoint_url, &client, request?).await
+}
+
+/// Sends a request to a connectors downstream server, applying the timeout & retries of its
+/// fetch policy. Every attempt is logged separately. The timeout covers reading the body too,
+/// unless it's [`ResponseBody::Streamed`].
+///
+/// Requests are only retried after server errors & failures to get a response at all if they're
+/// `idempotent`, but are always retried when the server tells us it's rate limiting us.
+///
+/// If the connector has a `circuit_breaker` the request fails straight away while it's open,
+/// and the outcome of the request, retries included, counts towards opening it.
+pub async fn send_connector_request(
+    request_id: &str,
+    fetch_log_endpoint_url: Option<&str>,
+    policy: &ConnectorFetchPolicy,
+    circuit_breaker: Option<&CircuitBreaker>,
+    idempotent: bool,
+    body: ResponseBody,
+    request_builder: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, FetchError> {
     let (client, request) = request_builder.build_split();
     let request = request?;
 
+    let Some(circuit_breaker) = circuit_breaker else {
+        return send_with_retries(
+            request_id,
+            fetch_log_endpoint_url,
+            policy,
+            idempotent,
+            body,
+            &client,
+            request,
+        )
+        .await;
+    };
+
+    if let Some(state) = circuit_breaker.acquire()? {
+        log_circuit_breaker_change(request_id, fetch_log_endpoint_url, &client, circuit_breaker, state).await;
+    }
+
+    let result = send_with_retries(
+        request_id,
+        fetch_log_endpoint_url,
+        policy,
+        idempotent,
+        body,
+        &client,
+        request,
+    )
+    .await;
+
+    let success = match &result {
+        Ok(response) => !response.status().is_server_error(),
+        Err(_) => false,
+    };
+
+    if let Some(state) = circuit_breaker.record(success) {
+        log_circuit_breaker_change(request_id, fetch_log_endpoint_url, &client, circuit_breaker, state).await;
+    }
+
+    result
+}
+
+async fn log_circuit_breaker_change(
+    request_id: &str,
+    fetch_log_endpoint_url: Option<&str>,
+    client: &reqwest::Client,
+    circuit_breaker: &CircuitBreaker,
+    state: CircuitBreakerState,
+) {
+    let connector = circuit_breaker.connector();
+    log::warn!(
+        request_id,
+        "The circuit breaker of the {connector} connector is now {state}"
+    );
+
+    let Some(fetch_log_endpoint_url) = fetch_log_endpoint_url else {
+        return;
+    };
+
+    let result = client
+        .post(format!("{fetch_log_endpoint_url}/log-event"))
+        .json(&LogEvent {
+            request_id,
+            r#type: common_types::LogEventType::CircuitBreakerStateChanged {
+                connector: connector.to_string(),
+                state,
+            },
+        })
+        .send()
+        .await;
+
+    if let Err(error) = result {
+        log::warn!(request_id, "Could not log the circuit breaker change: {error}");
+    }
+}
+
+async fn send_with_retries(
+    request_id: &str,
+    fetch_log_endpoint_url: Option<&str>,
+    policy: &ConnectorFetchPolicy,
+    idempotent: bool,
+    body: ResponseBody,
+    client: &reqwest::Client,
+    mut request: reqwest::Request,
+) -> Result<reqwest::Response, FetchError> {
+    let mut attempt = 0;
+
+    loop {
+        // Requests with streaming bodies can't be cloned, so we can only send those once.
+        let retry_request = if attempt < policy.retries {
+            request.try_clone()
+        } else {
+            None
+        };
+
+        let url = request.url().to_string();
+        let result = with_timeout(policy.timeout, async {
+            let response = execute_logged_request(request_id, fetch_log_endpoint_url, client, request).await?;
+
+            match body {
+                ResponseBody::Buffered => buffer_body(response).await,
+                ResponseBody::Streamed => Ok(response),
+            }
+        })
+        .await;
+
+        let Some(retry_request) = retry_request else {
+            return result;
+        };
+
+        let Some(delay) = retry_delay(&result, attempt, idempotent) else {
+            return result;
+        };
+
+        let reason = match &result {
+            Ok(response) => format!("the server returned {}", response.status()),
+            Err(error) => error.to_string(),
+        };
+        log::warn!(request_id, "Retrying the request to {url} in {delay:?} as {reason}");
+
+        async_runtime::sleep(delay).await;
+        request = retry_request;
+        attempt += 1;
+    }
+}
+
+async fn with_timeout(
+    timeout: Option<Duration>,
+    request: impl std::future::Future<Output = Result<reqwest::Response, reqwest::Error>>,
+) -> Result<reqwest::Response, FetchError> {
+    let Some(timeout) = timeout else {
+        return Ok(request.await?);
+    };
+
+    match select(Box::pin(request), Box::pin(async_runtime::sleep(timeout))).await {
+        Either::Left((result, _)) => Ok(result?),
+        Either::Right(_) => Err(FetchError::Timeout(timeout)),
+    }
+}
+
+/// Reads the whole body of the response, returning an equivalent response holding it.
+async fn buffer_body(mut response: reqwest::Response) -> Result<reqwest::Response, reqwest::Error> {
+    let mut builder = http::Response::builder().status(response.status());
+    builder
+        .headers_mut()
+        .expect("builder to be valid")
+        .extend(response.headers_mut().drain());
+
+    let bytes = response.bytes().await?;
+
+    Ok(builder.body(bytes).expect("response to be valid").into())
+}
+
+/// How long to wait before retrying a request, or `None` if it shouldn't be retried.
+fn retry_delay(result: &Result<reqwest::Response, FetchError>, attempt: u32, idempotent: bool) -> Option<Duration> {
+    let backoff = INITIAL_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF);
+
+    match result {
+        Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => retry_after(response, backoff),
+        Ok(response) if response.status().is_server_error() && idempotent => retry_after(response, backoff),
+        Ok(_) => None,
+        Err(_) if idempotent => Some(backoff),
+        Err(_) => None,
+    }
+}
+
+/// Respects the delay in a `Retry-After` header, unless it's longer than we're willing to wait.
+/// Only delays in seconds are supported, we back off as usual if the header has a date.
+fn retry_after(response: &reqwest::Response, backoff: Duration) -> Option<Duration> {
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+
+    match retry_after {
+        Some(delay) if delay > MAX_RETRY_AFTER => None,
+        Some(delay) => Some(delay),
+        None => Some(backoff),
+    }
+}
+
+/// Sends a single request to a downstream server within a `connector_fetch` span, propagating
+/// the trace context to it through the `traceparent` header.
+async fn execute_logged_request(
+    request_id: &str,
+    fetch_log_endpoint_url: Option<&str>,
+    client: &reqwest::Client,
+    mut request: reqwest::Request,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let span = info_span!(
+        "connector_fetch",
+        http.method = %request.method(),
+        http.url = %request.url(),
+        http.status_code = tracing::field::Empty,
+    );
+    trace_context::inject(&span, request.headers_mut());
+
+    let result = send_and_log_request(request_id, fetch_log_endpoint_url, client, request)
+        .instrument(span.clone())
+        .await;
+
+    if let Ok(response) = &result {
+        span.record("http.status_code", response.status().as_u16());
+    }
+
+    result
+}
+
+async fn send_and_log_request(
+    request_id: &str,
+    fetch_log_endpoint_url: Option<&str>,
+    client: &reqwest::Client,
+    request: reqwest::Request,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let start_time = web_time::Instant::now();
+
     let url = request.url().to_string();
     let method = request.method().to_string();
 
@@ -41,7 +325,7 @@ pub async fn send_logged_request(
         };
 
         let duration = start_time.elapsed();
-        reqwest::Client::new()
+        client
             .post(format!("{fetch_log_endpoint_url}/log-event"))
             .json(&LogEvent {
                 request_id,
@@ -62,3 +346,75 @@ pub async fn send_logged_request(
         Ok(response)
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+mod trace_context {
+    use opentelemetry::propagation::Injector;
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    /// Writes the context of the span into the headers with the globally configured propagator,
+    /// which is a no-op unless an exporter has been set up.
+    pub(super) fn inject(span: &tracing::Span, headers: &mut HeaderMap) {
+        let context = span.context();
+
+        opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&context, &mut HeaderInjector(headers));
+        });
+    }
+
+    struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+    impl Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(key.as_bytes()), HeaderValue::from_str(&value)) {
+                self.0.insert(name, value);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod trace_context {
+    pub(super) fn inject(_span: &tracing::Span, _headers: &mut reqwest::header::HeaderMap) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, retry_after: Option<&str>) -> reqwest::Response {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            builder = builder.header(reqwest::header::RETRY_AFTER, retry_after);
+        }
+        builder.body(Vec::new()).unwrap().into()
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let result = |status, retry_after| Ok(response(status, retry_after));
+
+        assert_eq!(retry_delay(&result(200, None), 0, true), None);
+        assert_eq!(retry_delay(&result(404, None), 0, true), None);
+        assert_eq!(
+            retry_delay(&result(503, None), 2, true),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(retry_delay(&result(503, None), 0, false), None);
+        assert_eq!(
+            retry_delay(&result(429, Some("2")), 0, false),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(retry_delay(&result(429, Some("3600")), 0, true), None);
+        assert_eq!(retry_delay(&result(503, None), 10, true), Some(MAX_BACKOFF));
+        assert_eq!(
+            retry_delay(&Err(FetchError::Timeout(Duration::from_secs(1))), 0, true),
+            Some(INITIAL_BACKOFF)
+        );
+        assert_eq!(
+            retry_delay(&Err(FetchError::Timeout(Duration::from_secs(1))), 0, false),
+            None
+        );
+    }
+}
@@ -9,11 +9,14 @@
 //!
 //! A Resolver always know how to apply the associated transformers.
 
+use std::{collections::HashMap, pin::Pin};
+
 use dynamo_mutation::DynamoMutationResolver;
 use dynamo_querying::DynamoResolver;
 use dynamodb::PaginatedCursor;
-use engine_parser::types::SelectionSet;
+use engine_parser::types::{FragmentDefinition, OperationType, SelectionSet, VariableDefinition};
 use engine_value::{ConstValue, Name};
+use futures_util::{Stream, StreamExt};
 use graph_entities::ConstraintID;
 use query::QueryResolver;
 use runtime::search::GraphqlCursor;
@@ -23,20 +26,26 @@ use self::{
     custom::CustomResolver,
     federation::resolve_federation_entities,
     graphql::{QueryBatcher, Target},
+    http_client::HttpClient,
     join::JoinResolver,
     transformer::Transformer,
 };
 pub use self::{introspection::IntrospectionResolver, resolved_value::ResolvedValue};
-use super::{type_kinds::OutputType, Constraint, MetaField};
-use crate::{Context, ContextExt, ContextField, Error, RequestHeaders};
+use super::{
+    type_kinds::{OutputType, SelectionSetTarget},
+    Constraint, MetaField,
+};
+use crate::{Context, ContextExt, ContextField, Error, ErrorExtensions, RequestHeaders};
 
 pub mod atlas_data_api;
+mod circuit_breaker;
 pub mod custom;
 pub mod dynamo_mutation;
 pub mod dynamo_querying;
 mod federation;
 pub mod graphql;
 pub mod http;
+pub mod http_client;
 mod introspection;
 pub mod join;
 mod logged_fetch;
@@ -182,80 +191,42 @@ impl Resolver {
                     .instrument(info_span!("http_resolver", api_name = resolver.api_name))
                     .await
             }
+            Resolver::Graphql(_) if ctx.query_env.operation.node.ty == OperationType::Subscription => {
+                // The upstream server already sent us the value of the field, with an event of the
+                // subscription. See `Resolver::subscribe`.
+                last_resolver_value.ok_or_else(|| Error::new("No data to propagate!"))
+            }
             Resolver::Graphql(resolver) => {
-                let runtime_ctx = ctx.data::<runtime::Context>()?;
-                let ray_id = runtime_ctx.ray_id();
-                let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
-
-                let registry = ctx.registry();
-                let request_headers = ctx.data::<RequestHeaders>().ok();
-                let headers = registry
-                    .http_headers
-                    .get(&format!("GraphQLConnector{}", resolver.name()))
-                    .zip(request_headers)
-                    .map(|(connector_headers, request_headers)| connector_headers.build_header_vec(request_headers))
-                    .unwrap_or_default();
-
-                let fragment_definitions = ctx
-                    .query_env
-                    .fragments
-                    .iter()
-                    .map(|(k, v)| (k, v.as_ref().node))
-                    .collect();
-
-                let variable_definitions = ctx
-                    .query_env
-                    .operation
-                    .node
-                    .variable_definitions
-                    .iter()
-                    .map(|variable_definition| (&variable_definition.node.name.node, &variable_definition.node))
-                    .collect();
-
-                let current_object = resolver_ctx.ty.try_into().ok();
-
-                let target = match resolver.namespace {
-                    Some(_) => Target::SelectionSet(Box::new(
-                        ctx.item
-                            .node
-                            .selection_set
-                            .node
-                            .items
-                            .clone()
-                            .into_iter()
-                            .map(|v| v.node),
-                    )),
-                    None => Target::Field(ctx.item.clone().into_inner(), resolver_ctx.field.clone()),
-                };
-
+                let request = GraphqlRequest::new(ctx, resolver_ctx, resolver)?;
                 let operation = ctx.query_env.operation.node.ty;
-                let error_handler = |error| ctx.add_error(error);
+                let error_handler =
+                    |error| ctx.add_error(resolver.upstream_error(error, &ctx.path, ctx.item.pos, ctx.registry()));
                 let variables = ctx.query_env.variables.clone();
 
-                let batcher = &ctx.data::<QueryBatcher>()?;
+                let batcher = ctx.data::<QueryBatcher>()?;
 
                 resolver
                     .resolve(
                         // Be a lot easier to just pass the context in here...
                         operation,
-                        &ray_id,
-                        fetch_log_endpoint_url,
-                        &headers,
-                        fragment_definitions,
-                        target,
-                        current_object,
+                        request.ray_id,
+                        request.fetch_log_endpoint_url,
+                        &request.headers,
+                        request.fragment_definitions,
+                        request.target,
+                        request.current_object,
                         error_handler,
                         variables,
-                        variable_definitions,
-                        registry,
-                        Some(batcher),
+                        request.variable_definitions,
+                        ctx.registry(),
+                        batcher,
                     )
                     .instrument(info_span!("graphql_resolver", name = resolver.name().as_ref()))
                     .await
-                    .map_err(Into::into)
+                    .map_err(|error| error.extend())
             }
             Resolver::MongoResolver(resolver) => resolver
-                .resolve(ctx, resolver_ctx)
+                .resolve(ctx, resolver_ctx, last_resolver_value.as_ref())
                 .instrument(info_span!(
                     "mongodb_resolver",
                     operation_type = resolver.operation_type.as_ref(),
@@ -290,6 +261,46 @@ impl Resolver {
         }
     }
 
+    /// Subscribe to a root field of a subscription, returning a stream with the resolved value of
+    /// the field for each event. Only the fields of GraphQL connectors can be subscribed to.
+    pub(crate) fn subscribe<'a>(
+        &'a self,
+        ctx: &'a ContextField<'a>,
+    ) -> Pin<Box<dyn Stream<Item = Result<ResolvedValue, Error>> + Send + 'a>> {
+        Box::pin(async_stream::try_stream! {
+            let resolver = match self {
+                Resolver::Graphql(resolver) => resolver,
+                _ => Err(Error::new(format!(
+                    "Subscriptions are not supported on the {} field.",
+                    ctx.item.node.name.node
+                )))?,
+            };
+
+            let resolver_ctx = ResolverContext::new(ctx);
+            let request = GraphqlRequest::new(ctx, &resolver_ctx, resolver)?;
+            let client = HttpClient::from_context(ctx)?;
+
+            let events = resolver.subscribe(
+                &client,
+                request.ray_id,
+                request.fetch_log_endpoint_url,
+                &request.headers,
+                request.fragment_definitions,
+                request.target,
+                request.current_object,
+                |error| ctx.add_error(resolver.upstream_error(error, &ctx.path, ctx.item.pos, ctx.registry())),
+                ctx.query_env.variables.clone(),
+                request.variable_definitions,
+                ctx.registry(),
+            );
+            futures_util::pin_mut!(events);
+
+            while let Some(event) = events.next().await {
+                yield event.map_err(|error| error.extend())?;
+            }
+        })
+    }
+
     pub fn and_then(mut self, resolver: impl Into<Resolver>) -> Self {
         let resolver = resolver.into();
         match &mut self {
@@ -319,6 +330,99 @@ impl Resolver {
     pub fn is_join(&self) -> bool {
         matches!(self, Self::Join(_))
     }
+
+    /// The kind of data source this resolver fetches from, `None` if it only works with the value
+    /// of its parent.
+    pub fn kind(&self) -> Option<&'static str> {
+        match self {
+            Resolver::Parent | Resolver::Transformer(_) => None,
+            Resolver::DynamoResolver(_) | Resolver::DynamoMutationResolver(_) => Some("dynamo"),
+            Resolver::Query(_) => Some("search"),
+            Resolver::CustomResolver(_) => Some("custom"),
+            Resolver::Composition(resolvers) => resolvers.iter().find_map(Resolver::kind),
+            Resolver::Http(_) => Some("http"),
+            Resolver::Graphql(_) => Some("graphql"),
+            Resolver::MongoResolver(_) => Some("mongodb"),
+            Resolver::PostgresResolver(_) => Some("postgres"),
+            Resolver::FederationEntitiesResolver => Some("federation"),
+            Resolver::Introspection(_) => Some("introspection"),
+            Resolver::Join(_) => Some("join"),
+        }
+    }
+}
+
+/// What the resolver of a GraphQL connector needs from the context of the field it resolves.
+struct GraphqlRequest<'a> {
+    ray_id: &'a str,
+    fetch_log_endpoint_url: Option<&'a str>,
+    headers: Vec<(&'a str, &'a str)>,
+    fragment_definitions: HashMap<&'a Name, &'a FragmentDefinition>,
+    variable_definitions: HashMap<&'a Name, &'a VariableDefinition>,
+    target: Target,
+    current_object: Option<SelectionSetTarget<'a>>,
+}
+
+impl<'a> GraphqlRequest<'a> {
+    fn new(
+        ctx: &'a ContextField<'a>,
+        resolver_ctx: &ResolverContext<'a>,
+        resolver: &graphql::Resolver,
+    ) -> Result<Self, Error> {
+        let runtime_ctx = ctx.data::<runtime::Context>()?;
+        let ray_id = runtime_ctx.ray_id();
+        let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
+
+        let registry = ctx.registry();
+        let request_headers = ctx.data::<RequestHeaders>().ok();
+        let headers = registry
+            .http_headers
+            .get(&format!("GraphQLConnector{}", resolver.name()))
+            .zip(request_headers)
+            .map(|(connector_headers, request_headers)| connector_headers.build_header_vec(request_headers))
+            .unwrap_or_default();
+
+        let fragment_definitions = ctx
+            .query_env
+            .fragments
+            .iter()
+            .map(|(k, v)| (k, v.as_ref().node))
+            .collect();
+
+        let variable_definitions = ctx
+            .query_env
+            .operation
+            .node
+            .variable_definitions
+            .iter()
+            .map(|variable_definition| (&variable_definition.node.name.node, &variable_definition.node))
+            .collect();
+
+        let current_object = resolver_ctx.ty.try_into().ok();
+
+        let target = match resolver.namespace {
+            Some(_) => Target::SelectionSet(Box::new(
+                ctx.item
+                    .node
+                    .selection_set
+                    .node
+                    .items
+                    .clone()
+                    .into_iter()
+                    .map(|v| v.node),
+            )),
+            None => Target::Field(ctx.item.clone().into_inner(), resolver_ctx.field.clone()),
+        };
+
+        Ok(GraphqlRequest {
+            ray_id,
+            fetch_log_endpoint_url,
+            headers,
+            fragment_definitions,
+            variable_definitions,
+            target,
+            current_object,
+        })
+    }
 }
 
 #[non_exhaustive]
@@ -6,6 +6,8 @@ pub(super) use simple::ByFilterIterator;
 
 use grafbase_sql_ast::ast::ConditionTree;
 
+use crate::Error;
+
 #[derive(Clone)]
 pub enum FilterIterator<'a> {
     By(ByFilterIterator<'a>),
@@ -13,11 +15,11 @@ pub enum FilterIterator<'a> {
 }
 
 impl<'a> Iterator for FilterIterator<'a> {
-    type Item = ConditionTree<'a>;
+    type Item = Result<ConditionTree<'a>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
-            FilterIterator::By(iterator) => iterator.next().map(ConditionTree::from),
+            FilterIterator::By(iterator) => iterator.next().map(ConditionTree::from).map(Ok),
             FilterIterator::Complex(iterator) => iterator.next(),
         }
     }
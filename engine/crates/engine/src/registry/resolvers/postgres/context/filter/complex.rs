@@ -4,7 +4,10 @@ use grafbase_sql_ast::ast::{Column, Comparable, ConditionTree, Expression, Row,
 use postgres_types::database_definition::TableColumnWalker;
 use serde_json::{Map, Value};
 
-use crate::registry::{resolvers::postgres::context::PostgresContext, type_kinds::InputType};
+use crate::{
+    registry::{resolvers::postgres::context::PostgresContext, type_kinds::InputType},
+    Error,
+};
 
 #[derive(Clone)]
 pub struct ComplexFilterIterator<'a> {
@@ -28,11 +31,17 @@ impl<'a> ComplexFilterIterator<'a> {
 }
 
 impl<'a> Iterator for ComplexFilterIterator<'a> {
-    type Item = ConditionTree<'a>;
+    type Item = Result<ConditionTree<'a>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let (field, value) = self.filter.pop_front()?;
 
+        self.condition(field, value).transpose()
+    }
+}
+
+impl<'a> ComplexFilterIterator<'a> {
+    fn condition(&self, field: String, value: Value) -> Result<Option<ConditionTree<'a>>, Error> {
         let table = self
             .context
             .database_definition
@@ -76,7 +85,7 @@ impl<'a> Iterator for ComplexFilterIterator<'a> {
             let nested = Self::new(self.context, input_type, object);
 
             for condition in nested {
-                conditions.push(Expression::from(condition))
+                conditions.push(Expression::from(condition?))
             }
 
             let table = relation.referenced_table();
@@ -85,7 +94,7 @@ impl<'a> Iterator for ComplexFilterIterator<'a> {
             select.value(1);
             select.so_that(ConditionTree::And(conditions));
 
-            return Some(ConditionTree::exists(select));
+            return Ok(Some(ConditionTree::exists(select)));
         }
 
         let operations = match value {
@@ -100,7 +109,7 @@ impl<'a> Iterator for ComplexFilterIterator<'a> {
                     let nested = Self::new(self.context, self.input_type, operation);
 
                     for operation in nested {
-                        operations.push(Expression::from(operation));
+                        operations.push(Expression::from(operation?));
                     }
                 }
 
@@ -111,9 +120,9 @@ impl<'a> Iterator for ComplexFilterIterator<'a> {
                     _ => unreachable!(),
                 };
 
-                return Some(tree);
+                return Ok(Some(tree));
             }
-            _ => return None,
+            _ => return Ok(None),
         };
 
         let column = self
@@ -122,15 +131,19 @@ impl<'a> Iterator for ComplexFilterIterator<'a> {
             .find_column_for_client_field(&field, table.id())
             .expect("column for input field not found");
 
-        Some(generate_conditions(operations, column))
+        generate_conditions(operations, column).map(Some)
     }
 }
 
-fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker<'_>) -> ConditionTree<'_> {
+fn generate_conditions(
+    operations: Map<String, Value>,
+    column: TableColumnWalker<'_>,
+) -> Result<ConditionTree<'_>, Error> {
     let mut compares = Vec::with_capacity(operations.len());
 
     for (key, value) in operations {
         let table_column = (column.table().database_name(), column.database_name());
+        let is_range = column.database_type().is_range() && !column.is_array();
 
         let compare = match key.as_str() {
             "eq" => match value {
@@ -147,6 +160,16 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
             "lte" => table_column.less_than_or_equals(value),
             "in" => table_column.in_selection(Row::from(value)),
             "nin" => table_column.not_in_selection(Row::from(value)),
+            "contains" if is_range => table_column.compare_raw("@>", value),
+            "containedBy" => table_column.compare_raw("<@", value),
+            "overlaps" if is_range => table_column.compare_raw("&&", value),
+            // parsed as a web search, as the tsquery syntax would reject plain text such as `fat rats`.
+            "matches" => table_column.compare_raw("@@ websearch_to_tsquery", Row::from((value,))),
+            "like" => table_column.compare_raw("LIKE", value),
+            "ilike" => table_column.compare_raw("ILIKE", value),
+            "startsWith" => table_column.compare_raw("LIKE", format!("{}%", escape_like(&key, &value)?)),
+            "endsWith" => table_column.compare_raw("LIKE", format!("%{}", escape_like(&key, &value)?)),
+            "regex" => table_column.compare_raw("~", value),
             "contains" => table_column.array_contains(value),
             "contained" => table_column.array_contained(value),
             "overlaps" => table_column.array_overlaps(value),
@@ -156,7 +179,7 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
                     _ => unreachable!("non-object not filter"),
                 };
 
-                let expression = Expression::from(ConditionTree::not(generate_conditions(operations, column)));
+                let expression = Expression::from(ConditionTree::not(generate_conditions(operations, column)?));
                 compares.push(expression);
 
                 continue;
@@ -167,5 +190,24 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
         compares.push(Expression::from(compare));
     }
 
-    ConditionTree::And(compares)
+    Ok(ConditionTree::And(compares))
+}
+
+/// Escapes the wildcards of a `LIKE` pattern, so the value is matched literally.
+fn escape_like(operation: &str, value: &Value) -> Result<String, Error> {
+    let value = value
+        .as_str()
+        .ok_or_else(|| Error::new(format!("The {operation} filter requires a string")))?;
+
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    Ok(escaped)
 }
@@ -1,4 +1,7 @@
-use crate::{Error, SelectionField};
+use crate::{
+    registry::scalars::{DynamicParse, VectorScalar},
+    Error, SelectionField,
+};
 use engine_value::{Name, Value};
 use grafbase_sql_ast::ast::{Aliasable, Column, Comparable, ConditionTree, Expression, Order, OrderDefinition};
 use indexmap::IndexMap;
@@ -9,21 +12,45 @@ use postgres_types::{
 use runtime::search::GraphqlCursor;
 use serde::Deserialize;
 
+/// A column the innermost query is ordered by.
+#[derive(Debug, Clone)]
+struct OrderColumn {
+    table: String,
+    column: String,
+    /// Orders by the distance of a vector column to the given vector, using the pgvector distance operator.
+    distance: Option<(&'static str, serde_json::Value)>,
+}
+
+impl OrderColumn {
+    fn expression(&self) -> Expression<'static> {
+        let column = Column::from((self.table.clone(), self.column.clone()));
+
+        match self.distance {
+            Some((operator, ref vector)) => column.compare_raw(operator, vector.clone()).into(),
+            None => column.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CollectionOrdering {
-    inner: Vec<((String, String), Option<Order>)>,
+    inner: Vec<(OrderColumn, Option<Order>)>,
     outer: Vec<(String, Option<Order>)>,
 }
 
 impl CollectionOrdering {
     pub fn raw_order(&self) -> impl ExactSizeIterator<Item = (&str, Option<Order>)> + '_ {
-        self.inner.iter().map(|((_, column), order)| (column.as_str(), *order))
+        self.inner
+            .iter()
+            .map(|(column, order)| (column.column.as_str(), *order))
     }
 
     pub fn inner(&self) -> impl ExactSizeIterator<Item = OrderDefinition<'static>> + '_ {
-        self.inner
-            .iter()
-            .map(|((table, column), order)| (Column::from((table.clone(), column.clone())).into(), *order))
+        self.inner.iter().map(|(column, order)| (column.expression(), *order))
+    }
+
+    fn has_distance(&self) -> bool {
+        self.inner.iter().any(|(column, _)| column.distance.is_some())
     }
 
     pub fn outer(&self) -> impl ExactSizeIterator<Item = OrderDefinition<'static>> + '_ {
@@ -40,7 +67,7 @@ pub struct CollectionArgs {
     first: Option<u64>,
     last: Option<u64>,
     order_by: CollectionOrdering,
-    extra_columns: Vec<Column<'static>>,
+    extra_columns: Vec<Expression<'static>>,
     before: Option<SQLCursor>,
     after: Option<SQLCursor>,
 }
@@ -137,7 +164,14 @@ impl CollectionArgs {
             let Some((field, value)) = value.as_object().and_then(IndexMap::first) else {
                 continue;
             };
-            let Some(direction) = value.as_str() else { continue };
+
+            let (direction, distance) = match value {
+                Value::Object(nearest) => ("ASC", Some(vector_distance(nearest)?)),
+                value => match value.as_str() {
+                    Some(direction) => (direction, None),
+                    None => continue,
+                },
+            };
 
             // For `last` to work, we must reverse the order of the inner query.
             let inner_direction = match direction {
@@ -158,21 +192,27 @@ impl CollectionArgs {
                 .find_column_for_client_field(&field, table.id())
                 .expect("ordering with non-existing column");
 
-            let sql_column = Column::from((table.database_name().to_string(), column.database_name().to_string()));
+            let order_column = OrderColumn {
+                table: table.database_name().to_string(),
+                column: column.database_name().to_string(),
+                distance,
+            };
 
             // We must name our order columns for them to be visible in the order by statement of the
             // outer queries.
             let alias = format!("{}_{}", table.database_name(), column.database_name());
-            extra_columns.push(sql_column.clone().alias(alias.clone()));
-
-            order_by.inner.push((
-                (table.database_name().to_string(), column.database_name().to_string()),
-                Some(inner_direction),
-            ));
+            extra_columns.push(order_column.expression().alias(alias.clone()));
 
+            order_by.inner.push((order_column, Some(inner_direction)));
             order_by.outer.push((alias, Some(outer_direction)));
         }
 
+        if order_by.has_distance() && (before.is_some() || after.is_some()) {
+            return Err(Error::new(
+                "before and after parameters can't be used when ordering by vector distance",
+            ));
+        }
+
         Ok(Self {
             first,
             last,
@@ -241,11 +281,31 @@ impl CollectionArgs {
 
     /// A set of extra columns needing to select in the collecting query. Needed to handle the ordering of the outer
     /// layers.
-    pub(crate) fn extra_columns(&self) -> impl ExactSizeIterator<Item = Column<'static>> + '_ {
+    pub(crate) fn extra_columns(&self) -> impl ExactSizeIterator<Item = Expression<'static>> + '_ {
         self.extra_columns.clone().into_iter()
     }
 }
 
+/// Parses a vector ordering input, e.g. `{ nearestTo: [1.0, 2.0], distance: COSINE }`, into the pgvector
+/// distance operator and the vector in its text format.
+fn vector_distance(input: &IndexMap<Name, Value>) -> Result<(&'static str, serde_json::Value), Error> {
+    let operator = match input.get("distance").and_then(Value::as_str) {
+        None | Some("L2") => "<->",
+        Some("COSINE") => "<=>",
+        Some("INNER_PRODUCT") => "<#>",
+        Some(other) => return Err(Error::new(format!("unknown vector distance: {other}"))),
+    };
+
+    let vector = input
+        .get("nearestTo")
+        .and_then(|value| value.clone().into_const())
+        .map(VectorScalar::parse)
+        .and_then(Result::ok)
+        .ok_or_else(|| Error::new("nearestTo must be a list of numbers"))?;
+
+    Ok((operator, vector))
+}
+
 fn generate_filter(fields: &[(&str, &serde_json::Value, OrderDirection)]) -> Option<Expression<'static>> {
     let mut filters: Vec<Expression<'static>> = Vec::new();
     let max_id = fields.len() - 1;
@@ -2,6 +2,7 @@ use std::future::Future;
 
 use common_types::LogEventType;
 use runtime::log::LogEvent;
+use tracing::{info_span, Instrument};
 
 use crate::{registry::resolvers::postgres::context::PostgresContext, Error};
 
@@ -11,6 +12,8 @@ pub(super) async fn query<F>(ctx: &PostgresContext<'_>, sql: &str, operation: F)
 where
     F: Future<Output = postgres_types::Result<Vec<RowData>>>,
 {
+    let operation = operation.instrument(info_span!("sql_query", db.statement = sql));
+
     let Some(log_endpoint_url) = ctx.fetch_log_endpoint_url()? else {
         return operation.await.map_err(|error| Error::new(error.to_string()));
     };
@@ -47,6 +50,8 @@ pub(super) async fn execute<F>(ctx: &PostgresContext<'_>, sql: &str, operation:
 where
     F: Future<Output = postgres_types::Result<i64>>,
 {
+    let operation = operation.instrument(info_span!("sql_query", db.statement = sql));
+
     let Some(log_endpoint_url) = ctx.fetch_log_endpoint_url()? else {
         return operation.await.map_err(|error| Error::new(error.to_string()));
     };
@@ -4,7 +4,9 @@ use grafbase_sql_ast::ast::{json_build_object, Aliasable, Column, ConditionTree,
 pub fn build<'a>(ctx: &'a PostgresContext<'a>, filter: FilterIterator<'a>) -> Result<Delete<'a>, crate::Error> {
     let sql_table = Table::from((ctx.table().schema(), ctx.table().database_name())).alias(ctx.table().database_name());
     let mut query = Delete::from_table(sql_table);
-    query.so_that(filter.fold(ConditionTree::NoCondition, ConditionTree::and));
+    query.so_that(filter.try_fold(ConditionTree::NoCondition, |tree, condition| {
+        condition.map(|condition| tree.and(condition))
+    })?);
 
     if let Some(selection) = ctx.returning_selection() {
         let mut returning = Vec::new();
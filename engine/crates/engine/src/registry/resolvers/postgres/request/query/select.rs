@@ -20,7 +20,7 @@ pub fn build<'a>(builder: SelectBuilder<'a>) -> Result<Select<'a>, crate::Error>
 
     if let Some(filters) = builder.filter() {
         for filter in filters {
-            inner_nested.and_where(filter);
+            inner_nested.and_where(filter?);
         }
     }
 
@@ -104,7 +104,7 @@ pub fn build<'a>(builder: SelectBuilder<'a>) -> Result<Select<'a>, crate::Error>
     match builder.collection_args() {
         Some(args) => {
             for column in args.extra_columns() {
-                json_select.column(column);
+                json_select.value(column);
             }
 
             // SQL doesn't guarantee ordering if it's not defined in the query.
@@ -6,7 +6,9 @@ use crate::registry::resolvers::postgres::context::{FilterIterator, PostgresCont
 
 pub fn build<'a>(ctx: &'a PostgresContext<'a>, filter: FilterIterator<'a>) -> Result<Query<'a>, crate::Error> {
     let mut update = Update::table(ctx.table().database_name());
-    update.so_that(filter.fold(ConditionTree::NoCondition, ConditionTree::and));
+    update.so_that(filter.try_fold(ConditionTree::NoCondition, |tree, condition| {
+        condition.map(|condition| tree.and(condition))
+    })?);
 
     for item in ctx.update_input()? {
         match item {
@@ -0,0 +1,166 @@
+use engine_value::ConstValue;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{Error, InputValueError, InputValueResult};
+
+pub struct GeometryScalar;
+
+impl<'a> SDLDefinitionScalar<'a> for GeometryScalar {
+    fn name() -> Option<&'a str> {
+        Some("Geometry")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some(
+            "A spatial object. Given either as a GeoJSON geometry object, or as a string in WKT, EWKT or hex-encoded EWKB format. Returned as GeoJSON.",
+        )
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://datatracker.ietf.org/doc/html/rfc7946")
+    }
+}
+
+impl DynamicParse for GeometryScalar {
+    fn parse(value: ConstValue) -> InputValueResult<Value> {
+        match value {
+            ConstValue::String(geometry) => Ok(Value::String(geometry)),
+            value @ ConstValue::Object(_) => {
+                let geometry = Value::deserialize(value)
+                    .map_err(|error| InputValueError::ty_custom("Geometry", error.to_string()))?;
+
+                geojson_to_wkt(&geometry)
+                    .map(Value::String)
+                    .map_err(|error| InputValueError::ty_custom("Geometry", format!("Invalid GeoJSON: {error}")))
+            }
+            _ => Err(InputValueError::ty_custom("Geometry", "Cannot parse into a Geometry")),
+        }
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        matches!(value, ConstValue::String(_) | ConstValue::Object(_))
+    }
+
+    fn to_value(value: Value) -> Result<ConstValue, Error> {
+        match value {
+            // PostGIS casts geometries to GeoJSON when rendering JSON, other types come as strings.
+            Value::Object(_) | Value::String(_) => {
+                ConstValue::from_json(value).map_err(|error| Error::new(error.to_string()))
+            }
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into a Geometry",
+            )),
+        }
+    }
+}
+
+/// Converts a GeoJSON geometry object into its Well-Known Text representation.
+fn geojson_to_wkt(geometry: &Value) -> Result<String, &'static str> {
+    let kind = geometry.get("type").and_then(Value::as_str).ok_or("missing type")?;
+
+    if kind == "GeometryCollection" {
+        let geometries = geometry
+            .get("geometries")
+            .and_then(Value::as_array)
+            .ok_or("missing geometries")?
+            .iter()
+            .map(geojson_to_wkt)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(format!("GEOMETRYCOLLECTION({})", geometries.join(",")));
+    }
+
+    let coordinates = geometry.get("coordinates").ok_or("missing coordinates")?;
+
+    let wkt = match kind {
+        "Point" => format!("POINT({})", position(coordinates)?),
+        "MultiPoint" => format!(
+            "MULTIPOINT({})",
+            nested(coordinates, |point| Ok(format!("({})", position(point)?)))?
+        ),
+        "LineString" => format!("LINESTRING({})", positions(coordinates)?),
+        "MultiLineString" => format!("MULTILINESTRING({})", nested(coordinates, ring)?),
+        "Polygon" => format!("POLYGON({})", nested(coordinates, ring)?),
+        "MultiPolygon" => format!(
+            "MULTIPOLYGON({})",
+            nested(coordinates, |polygon| Ok(format!("({})", nested(polygon, ring)?)))?
+        ),
+        _ => return Err("unsupported geometry type"),
+    };
+
+    Ok(wkt)
+}
+
+fn nested(value: &Value, f: impl Fn(&Value) -> Result<String, &'static str>) -> Result<String, &'static str> {
+    let items = value.as_array().ok_or("coordinates must be arrays")?;
+    Ok(items.iter().map(f).collect::<Result<Vec<_>, _>>()?.join(","))
+}
+
+fn ring(value: &Value) -> Result<String, &'static str> {
+    Ok(format!("({})", positions(value)?))
+}
+
+fn positions(value: &Value) -> Result<String, &'static str> {
+    nested(value, position)
+}
+
+fn position(value: &Value) -> Result<String, &'static str> {
+    let position = value.as_array().ok_or("a position must be an array of numbers")?;
+
+    if !(2..=4).contains(&position.len()) || !position.iter().all(Value::is_number) {
+        return Err("a position must be an array of two to four numbers");
+    }
+
+    Ok(position.iter().join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::geojson_to_wkt;
+
+    #[test]
+    fn point_to_wkt() {
+        let point = json!({ "type": "Point", "coordinates": [30, 10.5] });
+        assert_eq!(Ok(String::from("POINT(30 10.5)")), geojson_to_wkt(&point));
+    }
+
+    #[test]
+    fn polygon_to_wkt() {
+        let polygon = json!({
+            "type": "Polygon",
+            "coordinates": [[[30, 10], [40, 40], [20, 40], [30, 10]]]
+        });
+
+        assert_eq!(
+            Ok(String::from("POLYGON((30 10,40 40,20 40,30 10))")),
+            geojson_to_wkt(&polygon)
+        );
+    }
+
+    #[test]
+    fn collection_to_wkt() {
+        let collection = json!({
+            "type": "GeometryCollection",
+            "geometries": [
+                { "type": "Point", "coordinates": [4, 6] },
+                { "type": "MultiPoint", "coordinates": [[1, 2], [3, 4]] }
+            ]
+        });
+
+        assert_eq!(
+            Ok(String::from("GEOMETRYCOLLECTION(POINT(4 6),MULTIPOINT((1 2),(3 4)))")),
+            geojson_to_wkt(&collection)
+        );
+    }
+
+    #[test]
+    fn invalid_position() {
+        let point = json!({ "type": "Point", "coordinates": [30] });
+        assert!(geojson_to_wkt(&point).is_err());
+    }
+}
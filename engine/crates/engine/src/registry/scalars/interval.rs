@@ -0,0 +1,40 @@
+use engine_value::ConstValue;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{Error, InputValueError, InputValueResult};
+
+pub struct IntervalScalar;
+
+impl<'a> SDLDefinitionScalar<'a> for IntervalScalar {
+    fn name() -> Option<&'a str> {
+        Some("Interval")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some(
+            "A time span, such as `P1DT2H` (ISO 8601) or `1 day 02:00:00`. The value is returned in the format of the database server.",
+        )
+    }
+}
+
+impl DynamicParse for IntervalScalar {
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        match value {
+            ConstValue::String(interval) if !interval.trim().is_empty() => Ok(serde_json::Value::String(interval)),
+            _ => Err(InputValueError::ty_custom("Interval", "Cannot parse into an Interval")),
+        }
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        matches!(value, ConstValue::String(interval) if !interval.trim().is_empty())
+    }
+
+    fn to_value(value: serde_json::Value) -> Result<ConstValue, Error> {
+        match value {
+            serde_json::Value::String(interval) => Ok(ConstValue::String(interval)),
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into an Interval",
+            )),
+        }
+    }
+}
@@ -46,9 +46,24 @@ mod naive_datetime;
 pub use naive_datetime::NaiveDateTimeScalar;
 mod uuid;
 pub use self::uuid::UuidScalar;
+mod range;
+pub use self::range::{MultiRangeScalar, RangeScalar};
+mod interval;
+pub use self::interval::IntervalScalar;
+mod tsvector;
+pub use self::tsvector::TsVectorScalar;
+mod geometry;
+pub use self::geometry::GeometryScalar;
+mod vector;
+pub use self::vector::VectorScalar;
+mod upload;
+pub use self::upload::UploadScalar;
 mod federation;
 pub use self::federation::*;
 
+/// The scalars holding strings, which support the pattern filters of the database connectors.
+pub const STRING_SCALARS: &[&str] = &["PhoneNumber", "String", "URL"];
+
 /// ` SDLDefinitionScalar` trait is to be implemented for every custom scalar we add into `engine`
 ///
 /// The purpose of this trait is to give a definition of a Scalar based on a SDL.
@@ -283,5 +298,12 @@ pub type PossibleScalar = merge_scalar!(
     TimeScalar,
     NaiveDateTimeScalar,
     UuidScalar,
+    RangeScalar,
+    MultiRangeScalar,
+    IntervalScalar,
+    TsVectorScalar,
+    GeometryScalar,
+    VectorScalar,
+    UploadScalar,
     FederationAnyScalar
 );
@@ -0,0 +1,156 @@
+use engine_value::ConstValue;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{Error, InputValueError, InputValueResult};
+
+pub struct RangeScalar;
+
+impl RangeScalar {
+    /// A range literal is either `empty`, or two bounds separated with a comma, enclosed in
+    /// brackets (inclusive) or parentheses (exclusive). A missing bound is unbounded.
+    fn is_valid_literal(literal: &str) -> bool {
+        let literal = literal.trim();
+
+        if literal.eq_ignore_ascii_case("empty") {
+            return true;
+        }
+
+        literal.len() >= 3 && literal.starts_with(['[', '(']) && literal.ends_with([']', ')']) && literal.contains(',')
+    }
+}
+
+impl<'a> SDLDefinitionScalar<'a> for RangeScalar {
+    fn name() -> Option<&'a str> {
+        Some("Range")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some(
+            "A range of values, such as `[2023-01-01,2023-02-01)` or `(1,10]`. Square brackets define an inclusive and parentheses an exclusive bound. A missing bound is unbounded.",
+        )
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://www.postgresql.org/docs/current/rangetypes.html#RANGETYPES-IO")
+    }
+}
+
+impl DynamicParse for RangeScalar {
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        match value {
+            ConstValue::String(range) if Self::is_valid_literal(&range) => Ok(serde_json::Value::String(range)),
+            ConstValue::String(_) => Err(InputValueError::ty_custom("Range", "Invalid range literal")),
+            _ => Err(InputValueError::ty_custom("Range", "Cannot parse into a Range")),
+        }
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        match value {
+            ConstValue::String(range) => Self::is_valid_literal(range),
+            _ => false,
+        }
+    }
+
+    fn to_value(value: serde_json::Value) -> Result<ConstValue, Error> {
+        match value {
+            serde_json::Value::String(range) => Ok(ConstValue::String(range)),
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into a Range",
+            )),
+        }
+    }
+}
+
+pub struct MultiRangeScalar;
+
+impl MultiRangeScalar {
+    fn is_valid_literal(literal: &str) -> bool {
+        let literal = literal.trim();
+
+        let Some(ranges) = literal.strip_prefix('{').and_then(|literal| literal.strip_suffix('}')) else {
+            return false;
+        };
+
+        ranges.trim().is_empty() || RangeScalar::is_valid_literal(ranges)
+    }
+}
+
+impl<'a> SDLDefinitionScalar<'a> for MultiRangeScalar {
+    fn name() -> Option<&'a str> {
+        Some("MultiRange")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some("An ordered list of non-overlapping ranges, such as `{[1,3), [5,7)}`.")
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://www.postgresql.org/docs/current/rangetypes.html#RANGETYPES-IO")
+    }
+}
+
+impl DynamicParse for MultiRangeScalar {
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        match value {
+            ConstValue::String(ranges) if Self::is_valid_literal(&ranges) => Ok(serde_json::Value::String(ranges)),
+            ConstValue::String(_) => Err(InputValueError::ty_custom("MultiRange", "Invalid multirange literal")),
+            _ => Err(InputValueError::ty_custom(
+                "MultiRange",
+                "Cannot parse into a MultiRange",
+            )),
+        }
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        match value {
+            ConstValue::String(ranges) => Self::is_valid_literal(ranges),
+            _ => false,
+        }
+    }
+
+    fn to_value(value: serde_json::Value) -> Result<ConstValue, Error> {
+        match value {
+            serde_json::Value::String(ranges) => Ok(ConstValue::String(ranges)),
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into a MultiRange",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_value::ConstValue;
+
+    use crate::registry::scalars::{DynamicParse, MultiRangeScalar, RangeScalar};
+
+    #[test]
+    fn check_valid_ranges() {
+        for range in ["[1,10)", "(,5]", "[2023-01-01 10:00:00+00,)", "empty"] {
+            assert!(
+                RangeScalar::parse(ConstValue::String(range.to_string())).is_ok(),
+                "{range}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_invalid_ranges() {
+        for range in ["1,10", "[1)", "{[1,10)}"] {
+            assert!(
+                RangeScalar::parse(ConstValue::String(range.to_string())).is_err(),
+                "{range}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_valid_multiranges() {
+        for ranges in ["{}", "{[1,3), [5,7)}", "{(,5]}"] {
+            assert!(
+                MultiRangeScalar::parse(ConstValue::String(ranges.to_string())).is_ok(),
+                "{ranges}"
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use engine_value::ConstValue;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{Error, InputValueError, InputValueResult};
+
+pub struct TsVectorScalar;
+
+impl<'a> SDLDefinitionScalar<'a> for TsVectorScalar {
+    fn name() -> Option<&'a str> {
+        Some("TsVector")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some("A document optimized for full-text search, as a list of lexemes, such as `'fat':2 'rat':3`.")
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://www.postgresql.org/docs/current/datatype-textsearch.html")
+    }
+}
+
+impl DynamicParse for TsVectorScalar {
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        match value {
+            ConstValue::String(lexemes) => Ok(serde_json::Value::String(lexemes)),
+            _ => Err(InputValueError::ty_custom("TsVector", "Cannot parse into a TsVector")),
+        }
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        matches!(value, ConstValue::String(_))
+    }
+
+    fn to_value(value: serde_json::Value) -> Result<ConstValue, Error> {
+        match value {
+            serde_json::Value::String(lexemes) => Ok(ConstValue::String(lexemes)),
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into a TsVector",
+            )),
+        }
+    }
+}
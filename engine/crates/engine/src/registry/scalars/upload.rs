@@ -0,0 +1,46 @@
+use engine_value::ConstValue;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{types::UPLOAD_VARIABLE_PREFIX, Error, InputValueError, InputValueResult};
+
+pub struct UploadScalar;
+
+impl<'a> SDLDefinitionScalar<'a> for UploadScalar {
+    fn name() -> Option<&'a str> {
+        Some("Upload")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some("A file sent alongside the request as defined by the GraphQL multipart request spec. Only usable in mutations.")
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://github.com/jaydenseric/graphql-multipart-request-spec")
+    }
+}
+
+impl DynamicParse for UploadScalar {
+    fn is_valid(value: &ConstValue) -> bool {
+        matches!(value, ConstValue::String(upload) if is_upload(upload))
+    }
+
+    fn to_value(_value: serde_json::Value) -> Result<ConstValue, Error> {
+        Err(Error::new("Data violation: An Upload can't be used as an output"))
+    }
+
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        match value {
+            ConstValue::String(upload) if is_upload(&upload) => Ok(serde_json::Value::String(upload)),
+            _ => Err(InputValueError::ty_custom(
+                "Upload",
+                "Expected a file from a multipart request",
+            )),
+        }
+    }
+}
+
+fn is_upload(value: &str) -> bool {
+    value
+        .strip_prefix(UPLOAD_VARIABLE_PREFIX)
+        .is_some_and(|index| index.parse::<usize>().is_ok())
+}
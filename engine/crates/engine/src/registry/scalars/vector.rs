@@ -0,0 +1,100 @@
+use engine_value::ConstValue;
+use itertools::Itertools;
+
+use super::{DynamicParse, SDLDefinitionScalar};
+use crate::{Error, InputValueError, InputValueResult};
+
+pub struct VectorScalar;
+
+impl VectorScalar {
+    /// Renders a list of numbers in the pgvector text format, e.g. `[1,2.5,3]`.
+    pub fn to_literal(value: &ConstValue) -> Option<String> {
+        let ConstValue::List(dimensions) = value else {
+            return None;
+        };
+
+        let dimensions = dimensions
+            .iter()
+            .map(|dimension| match dimension {
+                ConstValue::Number(number) => Some(number.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(format!("[{}]", dimensions.iter().join(",")))
+    }
+}
+
+impl<'a> SDLDefinitionScalar<'a> for VectorScalar {
+    fn name() -> Option<&'a str> {
+        Some("Vector")
+    }
+
+    fn description() -> Option<&'a str> {
+        Some("A vector of floating point numbers, such as an embedding, given as a list of numbers.")
+    }
+
+    fn specified_by() -> Option<&'a str> {
+        Some("https://github.com/pgvector/pgvector")
+    }
+}
+
+impl DynamicParse for VectorScalar {
+    fn parse(value: ConstValue) -> InputValueResult<serde_json::Value> {
+        Self::to_literal(&value)
+            .map(serde_json::Value::String)
+            .ok_or_else(|| InputValueError::ty_custom("Vector", "Cannot parse into a Vector"))
+    }
+
+    fn is_valid(value: &ConstValue) -> bool {
+        Self::to_literal(value).is_some()
+    }
+
+    fn to_value(value: serde_json::Value) -> Result<ConstValue, Error> {
+        let value = match value {
+            // pgvector renders the text format, which happens to be a valid JSON array.
+            serde_json::Value::String(literal) => serde_json::from_str(&literal).map_err(|error| {
+                Error::new(format!(
+                    "Data violation: Cannot coerce the initial value into a Vector: {error}"
+                ))
+            })?,
+            value => value,
+        };
+
+        match value {
+            serde_json::Value::Array(_) => ConstValue::from_json(value).map_err(|error| Error::new(error.to_string())),
+            _ => Err(Error::new(
+                "Data violation: Cannot coerce the initial value into a Vector",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_value::ConstValue;
+    use serde_json::json;
+
+    use crate::registry::scalars::{DynamicParse, VectorScalar};
+
+    #[test]
+    fn check_vector_to_literal() {
+        let value = ConstValue::from_json(json!([1, 2.5, -3])).unwrap();
+
+        assert_eq!(Ok(json!("[1,2.5,-3]")), VectorScalar::parse(value).map_err(|_| ()));
+    }
+
+    #[test]
+    fn check_vector_with_non_numbers() {
+        let value = ConstValue::from_json(json!([1, "2"])).unwrap();
+
+        assert!(VectorScalar::parse(value).is_err());
+    }
+
+    #[test]
+    fn check_vector_from_database() {
+        let value = VectorScalar::to_value(json!("[1,2.5,-3]")).unwrap();
+
+        assert_eq!(ConstValue::from_json(json!([1, 2.5, -3])).unwrap(), value);
+    }
+}
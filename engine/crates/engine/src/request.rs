@@ -6,7 +6,7 @@ use std::{
 
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{Data, ParseRequestError, UploadValue, Value, Variables};
+use crate::{types::UPLOAD_VARIABLE_PREFIX, Data, ParseRequestError, UploadValue, Value, Variables};
 
 /// GraphQL request.
 ///
@@ -117,7 +117,7 @@ impl Request {
             None => return,
         };
         self.uploads.push(upload);
-        *variable = Value::String(format!("#__graphql_file__:{}", self.uploads.len() - 1));
+        *variable = Value::String(format!("{UPLOAD_VARIABLE_PREFIX}{}", self.uploads.len() - 1));
     }
 }
 
@@ -125,6 +125,26 @@ pub async fn resolve_deferred_container<'a>(
     resolve_container_inner(ctx, true, None, parent_resolver_value).await
 }
 
+/// Resolve the root container of a subscription for an event of one of its fields, the resolved
+/// value of the field being the one the event holds.
+pub(crate) async fn resolve_subscription_event(
+    ctx: &ContextField<'_>,
+    value: ResolvedValue,
+) -> ServerResult<ResponseNodeId> {
+    let node = resolve_field(ctx, Some(value)).await?;
+
+    let mut container = ResponseContainer::new_container();
+    container.insert(
+        ResponseNodeRelation::NotARelation {
+            field: ctx.item.node.name.node.to_string().into(),
+            response_key: ctx.item.node.alias.as_ref().map(|alias| alias.node.to_string().into()),
+        },
+        node,
+    );
+
+    Ok(ctx.response().await.insert_node(container))
+}
+
 /// Resolve an container by executing each of the fields concurrently.
 pub async fn resolve_container_native<'a, T: ContainerType + ?Sized>(
     ctx: &ContextSelectionSetLegacy<'a>,
@@ -390,6 +410,7 @@ impl<'a> FieldExecutionSet<'a> {
                     required_operation: meta_field.and_then(|f| f.required_operation),
                     auth: meta_field.and_then(|f| f.auth.as_ref()),
                     input_values: args_values,
+                    resolver: meta_field.map(|f| &f.resolver),
                 };
 
                 if field.node.directives.is_empty() {
@@ -595,6 +616,7 @@ impl<'a> Fields<'a> {
                                     required_operation: meta_field.and_then(|f| f.required_operation),
                                     auth: meta_field.and_then(|f| f.auth.as_ref()),
                                     input_values: args_values,
+                                    resolver: meta_field.map(|f| &f.resolver),
                                 };
 
                                 let resolve_fut = async {
@@ -1,10 +1,14 @@
 use std::future::Future;
 
-use engine_value::Name;
+use engine_parser::types::Directive;
+use engine_value::{Name, Variables};
 use futures_util::future::BoxFuture;
 use graph_entities::{CompactValue, QueryResponseNode, ResponseList, ResponseNodeId, ResponsePrimitive};
+use serde::Deserialize;
 
 use crate::{
+    deferred::{DeferredWorkload, StreamedItem},
+    directive::DirectiveDeserializer,
     extensions::ResolveInfo,
     parser::types::Field,
     registry::{
@@ -16,6 +20,9 @@ use crate::{
 };
 
 /// Resolve a list by executing each of the items concurrently.
+///
+/// If the field has `@stream` on it, only the first `initialCount` items are resolved and the
+/// others are sent as deferred workloads.
 pub async fn resolve_list<'a>(
     ctx: ContextList<'a>,
     field: &'a Positioned<Field>,
@@ -28,6 +35,7 @@ pub async fn resolve_list<'a>(
         field: &Positioned<Field>,
         ty: &MetaType,
         value: ResolvedValue,
+        stream: Option<StreamDirective>,
     ) -> Result<ResponseNodeId, ServerError> {
         // First we need to make sure our parent resolve data actually has a list
         // (or return null early if we're on a nullable list)
@@ -53,6 +61,13 @@ pub async fn resolve_list<'a>(
             }
         };
 
+        let mut items = items;
+        if let Some(stream) = stream.filter(|directive| directive.should_stream(&ctx)) {
+            let streamed_items = items.split_off(stream.initial_count.min(items.len()));
+            let first_index = items.len();
+            stream_items(&ctx, stream.label, first_index, streamed_items)?;
+        }
+
         let futures = items.into_iter().enumerate().map(|(idx, item)| -> BoxFuture<'_, _> {
             if item.data_resolved().is_null() {
                 // If the current item is null we should just stop executing here and return null
@@ -68,7 +83,7 @@ pub async fn resolve_list<'a>(
                     Box::pin(async move { resolve_container(&selection_ctx, None, item).await })
                 }
                 ContextWithIndex::List(list_context) => {
-                    let resolve_future = inner(list_context.clone(), field, ty, item);
+                    let resolve_future = inner(list_context.clone(), field, ty, item, None);
 
                     if ctx.query_env().extensions.is_empty() {
                         Box::pin(resolve_future)
@@ -123,7 +138,100 @@ pub async fn resolve_list<'a>(
         Ok(ctx.response().await.insert_node(ResponseList::with_children(children)))
     }
 
-    inner(ctx, field, inner_ty, value).await
+    let stream = StreamDirective::parse(&field.node.directives, &ctx.query_env().variables)?;
+
+    inner(ctx, field, inner_ty, value, stream).await
+}
+
+/// Sends the items of a list with `@stream` after its initial ones as deferred workloads, so
+/// they're delivered in order after the initial response.
+fn stream_items(
+    ctx: &ContextList<'_>,
+    label: Option<String>,
+    first_index: usize,
+    items: Vec<ResolvedValue>,
+) -> Result<(), ServerError> {
+    let Some(sender) = ctx.deferred_workloads() else {
+        return Ok(());
+    };
+
+    for (idx, item) in items.into_iter().enumerate() {
+        let workload = match ctx.with_index(first_index + idx) {
+            ContextWithIndex::SelectionSet(selection_ctx) => {
+                // Null items have nothing to resolve, like in `inner`.
+                let streamed_item = match item.data_resolved() {
+                    serde_json::Value::Null => StreamedItem::Leaf(Ok(Value::Null)),
+                    _ => StreamedItem::Container,
+                };
+                DeferredWorkload::stream_item(
+                    label.clone(),
+                    selection_ctx.item.clone(),
+                    selection_ctx.path.clone(),
+                    selection_ctx.ty.name().to_string().into(),
+                    streamed_item,
+                    Some(item),
+                )
+            }
+            ContextWithIndex::Field(field_ctx) => {
+                let leaf = match item.data_resolved() {
+                    serde_json::Value::Null => Ok(Value::Null),
+                    _ => resolve_leaf_value(&field_ctx, item),
+                };
+                DeferredWorkload::stream_item(
+                    label.clone(),
+                    Positioned::new(Default::default(), field_ctx.item.pos),
+                    field_ctx.path.clone(),
+                    field_ctx.field_base_type().name().to_string().into(),
+                    StreamedItem::Leaf(leaf),
+                    None,
+                )
+            }
+            ContextWithIndex::List(_) => unreachable!("lists of lists are never streamed"),
+        };
+
+        sender
+            .send(workload)
+            .map_err(|error| ctx.set_error_path(error.into_server_error(ctx.pos())))?;
+    }
+
+    Ok(())
+}
+
+#[derive(serde::Deserialize, PartialEq, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct StreamDirective {
+    label: Option<String>,
+    #[serde(default)]
+    initial_count: usize,
+    #[serde(rename = "if", default = "default_true")]
+    should_stream: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+const STREAM: &str = "stream";
+
+impl StreamDirective {
+    fn parse(directives: &[Positioned<Directive>], variables: &Variables) -> Result<Option<Self>, ServerError> {
+        directives
+            .iter()
+            .find(|directive| directive.node.name.node == STREAM)
+            .map(|directive| {
+                StreamDirective::deserialize(DirectiveDeserializer::new(&directive.node, variables))
+                    .map_err(|error| error.into_server_error(STREAM, directive.pos))
+            })
+            .transpose()
+    }
+
+    /// Lists are only streamed for requests supporting incremental delivery, and only if their
+    /// items aren't lists themselves.
+    fn should_stream(&self, ctx: &ContextList<'_>) -> bool {
+        self.should_stream
+            && ctx.deferred_workloads().is_some()
+            && !matches!(ctx.with_index(0), ContextWithIndex::List(_))
+    }
 }
 
 fn apply_extensions<'a>(
@@ -163,6 +271,7 @@ fn apply_extensions<'a>(
             required_operation: meta_field.and_then(|f| f.required_operation),
             auth: meta_field.and_then(|f| f.auth.as_ref()),
             input_values: args_values,
+            resolver: None,
         };
 
         let resolve_fut = async move { Ok(Some(resolve_fut.await?)) };
@@ -176,6 +285,12 @@ fn apply_extensions<'a>(
 }
 
 async fn resolve_leaf_field(ctx: ContextField<'_>, item: ResolvedValue) -> Result<ResponseNodeId, ServerError> {
+    let item = resolve_leaf_value(&ctx, item)?;
+
+    Ok(ctx.response().await.insert_node(ResponsePrimitive::new(item.into())))
+}
+
+fn resolve_leaf_value(ctx: &ContextField<'_>, item: ResolvedValue) -> Result<Value, ServerError> {
     let mut result = Value::try_from(item.take()).map_err(|err| Error::new(format!("{err:?}")));
 
     // Yes it's ugly...
@@ -183,9 +298,7 @@ async fn resolve_leaf_field(ctx: ContextField<'_>, item: ResolvedValue) -> Resul
         result = result.and_then(|value| resolve_scalar(value, &scalar.name));
     }
 
-    let item = result.map_err(|error| ctx.set_error_path(error.into_server_error(ctx.item.pos)))?;
-
-    Ok(ctx.response().await.insert_node(ResponsePrimitive::new(item.into())))
+    result.map_err(|error| ctx.set_error_path(error.into_server_error(ctx.item.pos)))
 }
 
 fn resolve_scalar(value: Value, base_type_name: &str) -> Result<Value, Error> {
@@ -240,3 +353,72 @@ fn json_kind_str(value: &serde_json::Value) -> &'static str {
         serde_json::Value::Object(_) => "object",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use engine_parser::{parse_query, types::Selection};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_stream_parsing() {
+        assert_eq!(
+            parse_directive("@stream").unwrap(),
+            StreamDirective {
+                label: None,
+                initial_count: 0,
+                should_stream: true
+            }
+        );
+
+        assert_eq!(
+            parse_directive("@stream(if: false, initialCount: 2)").unwrap(),
+            StreamDirective {
+                label: None,
+                initial_count: 2,
+                should_stream: false
+            }
+        );
+
+        assert_eq!(
+            parse_directive("@stream(label: $one, initialCount: $two)").unwrap(),
+            StreamDirective {
+                label: Some("hello".into()),
+                initial_count: 3,
+                should_stream: true
+            }
+        );
+    }
+
+    #[test]
+    fn negative_initial_count_error() {
+        insta::assert_display_snapshot!(parse_directive("@stream(initialCount: -1)").unwrap_err(), @"Error interpreting @stream: invalid value: integer `-1`, expected usize for the argument `initialCount`");
+    }
+
+    fn parse_directive(directive_string: &str) -> Result<StreamDirective, ServerError> {
+        let selection = parse_query(format!("{{ names {directive_string} }}"))
+            .unwrap()
+            .operations
+            .iter()
+            .next()
+            .unwrap()
+            .1
+            .selection_set
+            .items[0]
+            .clone();
+
+        let Selection::Field(field) = selection.node else {
+            unreachable!()
+        };
+
+        StreamDirective::parse(
+            &field.node.directives,
+            &Variables::from_json(json!({
+                "one": "hello",
+                "two": 3
+            })),
+        )
+        .map(Option::unwrap)
+    }
+}
@@ -55,7 +55,8 @@ impl serde::Serialize for StreamingPayload {
 /// An incremental response payload as described in the [stream & defer RFC][1].
 ///
 /// This is very similar to the main Response payload, but with additional fields for
-/// `label`, `path` & `has_next`.
+/// `label`, `path` & `has_next`.  The payloads of `@stream` hold an item of the list in `items`
+/// rather than `data`.
 ///
 /// [1]: https://github.com/graphql/graphql-wg/blob/main/rfcs/DeferStream.md#payload-format
 #[derive(Debug, Default)]
@@ -65,6 +66,8 @@ pub struct IncrementalPayload {
     pub path: QueryPath,
     pub has_next: bool,
     pub errors: Vec<ServerError>,
+    /// Whether `data` is an item of a list with `@stream`, in which case `path` points to the item.
+    pub streamed_item: bool,
 }
 
 impl Response {
@@ -100,7 +103,11 @@ impl serde::Serialize for GraphqlIncrementalPayload<'_> {
         // 1. It's for the structure nested inside.
         // 2. It calls `self.0.data.as_graphql_data()`
         let mut map = serializer.serialize_map(Some(5))?;
-        map.serialize_entry("data", &self.0.data.as_graphql_data())?;
+        if self.0.streamed_item {
+            map.serialize_entry("items", &[self.0.data.as_graphql_data()])?;
+        } else {
+            map.serialize_entry("data", &self.0.data.as_graphql_data())?;
+        }
         map.serialize_entry("path", &self.0.path.iter().collect::<Vec<_>>())?;
         map.serialize_entry("hasNext", &self.0.has_next)?;
         if let Some(label) = &self.0.label {
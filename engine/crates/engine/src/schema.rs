@@ -3,7 +3,7 @@ use std::{any::Any, ops::Deref, sync::Arc};
 use dynamodb::CurrentDateTime;
 
 use futures_util::stream::{self, Stream, StreamExt};
-use graph_entities::CompactValue;
+use graph_entities::{CompactValue, ResponsePrimitive};
 use indexmap::map::IndexMap;
 
 use crate::{
@@ -23,7 +23,8 @@ use crate::{
     types::QueryRoot,
     validation::{check_rules, ValidationMode},
     BatchRequest, BatchResponse, CacheControl, ContextExt, ContextSelectionSet, LegacyInputType, LegacyOutputType,
-    ObjectType, QueryEnv, QueryEnvBuilder, QueryPath, Request, Response, ServerError, SubscriptionType, Variables, ID,
+    Name, ObjectType, QueryEnv, QueryEnvBuilder, QueryPath, Request, Response, ServerError, SubscriptionType, Value,
+    Variables, ID,
 };
 
 /// Schema builder
@@ -31,8 +32,6 @@ pub struct SchemaBuilder {
     validation_mode: ValidationMode,
     registry: Registry,
     data: Data,
-    complexity: Option<usize>,
-    depth: Option<usize>,
     extensions: Vec<Box<dyn ExtensionFactory>>,
 }
 
@@ -65,14 +64,35 @@ impl SchemaBuilder {
     /// Set the maximum complexity a query can have. By default, there is no limit.
     #[must_use]
     pub fn limit_complexity(mut self, complexity: usize) -> Self {
-        self.complexity = Some(complexity);
+        self.registry.operation_limits.complexity = Some(complexity);
         self
     }
 
     /// Set the maximum depth a query can have. By default, there is no limit.
     #[must_use]
     pub fn limit_depth(mut self, depth: usize) -> Self {
-        self.depth = Some(depth);
+        self.registry.operation_limits.depth = Some(depth);
+        self
+    }
+
+    /// Set the maximum number of aliases a query can have. By default, there is no limit.
+    #[must_use]
+    pub fn limit_aliases(mut self, aliases: usize) -> Self {
+        self.registry.operation_limits.aliases = Some(aliases);
+        self
+    }
+
+    /// Set the maximum number of root fields a query can select. By default, there is no limit.
+    #[must_use]
+    pub fn limit_root_fields(mut self, root_fields: usize) -> Self {
+        self.registry.operation_limits.root_fields = Some(root_fields);
+        self
+    }
+
+    /// Set the maximum number of tokens a query can have. By default, there is no limit.
+    #[must_use]
+    pub fn limit_tokens(mut self, tokens: usize) -> Self {
+        self.registry.operation_limits.tokens = Some(tokens);
         self
     }
 
@@ -155,8 +175,6 @@ impl SchemaBuilder {
 
         Schema(Arc::new(SchemaInner {
             validation_mode: self.validation_mode,
-            complexity: self.complexity,
-            depth: self.depth,
             extensions: self.extensions,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
@@ -187,8 +205,6 @@ impl Deref for SchemaEnv {
 #[doc(hidden)]
 pub struct SchemaInner {
     pub(crate) validation_mode: ValidationMode,
-    pub(crate) complexity: Option<usize>,
-    pub(crate) depth: Option<usize>,
     pub(crate) extensions: Vec<Box<dyn ExtensionFactory>>,
     pub(crate) env: SchemaEnv,
 }
@@ -229,8 +245,6 @@ impl Schema {
             validation_mode: ValidationMode::Strict,
             registry,
             data: Default::default(),
-            complexity: None,
-            depth: None,
             extensions: Default::default(),
         }
     }
@@ -361,6 +375,27 @@ impl Schema {
             visible: None,
         });
 
+        registry.add_directive(MetaDirective {
+            name: "stream".to_string(),
+            description: Some("Causes the items of a list field after the first `initialCount` ones to be omitted in the initial response and delivered as subsequent responses afterward.".to_string()),
+            locations: vec![__DirectiveLocation::FIELD],
+            args: [
+                MetaInputValue::new("if", "Boolean!")
+                    .with_description("When true the list may be streamed")
+                    .with_default(engine_value::ConstValue::Boolean(true)),
+                MetaInputValue::new("label", "String")
+                    .with_description("This label should be used by GraphQL clients to identify the data from patch responses and associate it with the correct list."),
+                MetaInputValue::new("initialCount", "Int")
+                    .with_description("The number of items to return in the initial response")
+                    .with_default(engine_value::ConstValue::Number(0.into())),
+            ]
+                .into_iter()
+                .map(|directive| (directive.name.clone(), directive))
+                .collect(),
+            is_repeatable: false,
+            visible: None,
+        });
+
         // register scalars
         <bool as LegacyInputType>::create_type_info(registry);
         <i32 as LegacyInputType>::create_type_info(registry);
@@ -412,12 +447,17 @@ impl Schema {
         mut extensions: Extensions,
         request: Request,
         session_data: Arc<Data>,
-    ) -> Result<(QueryEnvBuilder, CacheControl), Vec<ServerError>> {
+    ) -> Result<(QueryEnvBuilder, CacheControl, usize), Vec<ServerError>> {
         let mut request = request;
         let query_data = Arc::new(std::mem::take(&mut request.data));
         extensions.attach_query_data(query_data.clone());
 
         let request = extensions.prepare_request(request).await?;
+        let limits = &self.env.registry.operation_limits;
+        limits
+            .check_tokens(&request.query)
+            .map_err(|error| vec![error.into()])?;
+
         let mut document = {
             let query = &request.query;
             let fut_parse = async { parse_query(&query).map_err(Into::<ServerError>::into) };
@@ -442,17 +482,7 @@ impl Schema {
         };
 
         // check limit
-        if let Some(limit_complexity) = self.complexity {
-            if validation_result.complexity > limit_complexity {
-                return Err(vec![ServerError::new("Query is too complex.", None)]);
-            }
-        }
-
-        if let Some(limit_depth) = self.depth {
-            if validation_result.depth > limit_depth {
-                return Err(vec![ServerError::new("Query is nested too deep.", None)]);
-            }
-        }
+        limits.check(&validation_result).map_err(|error| vec![error.into()])?;
 
         let operation = if let Some(operation_name) = &request.operation_name {
             match document.operations {
@@ -505,7 +535,28 @@ impl Schema {
             response: Default::default(),
             deferred_workloads: None,
         };
-        Ok((QueryEnvBuilder::new(env), validation_result.cache_control))
+        Ok((
+            QueryEnvBuilder::new(env),
+            validation_result.cache_control,
+            validation_result.complexity,
+        ))
+    }
+
+    /// Adds the cost of the operation to the extensions of the response when clients are limited by
+    /// it, with the complexity limit or the rate limits.
+    fn with_cost(&self, response: Response, complexity: usize) -> Response {
+        let registry = self.registry();
+        let max = registry.operation_limits.complexity;
+        if max.is_none() && !registry.rate_limiting.cost {
+            return response;
+        }
+
+        let mut cost = IndexMap::new();
+        cost.insert(Name::new("requested"), Value::from(complexity));
+        if let Some(max) = max {
+            cost.insert(Name::new("maximum"), Value::from(max));
+        }
+        response.extension("cost", Value::Object(cost))
     }
 
     async fn execute_once(&self, env: QueryEnv) -> Response {
@@ -560,9 +611,12 @@ impl Schema {
             let extensions = extensions.clone();
             async move {
                 match self.prepare_request(extensions, request, Default::default()).await {
-                    Ok((env_builder, cache_control)) => {
+                    Ok((env_builder, cache_control, complexity)) => {
                         let env = env_builder.build();
-                        let fut = async { self.execute_once(env.clone()).await.cache_control(cache_control) };
+                        let fut = async {
+                            let response = self.execute_once(env.clone()).await.cache_control(cache_control);
+                            self.with_cost(response, complexity)
+                        };
                         futures_util::pin_mut!(fut);
                         env.extensions
                             .execute(env.operation_name.as_deref(), &env.operation, &mut fut)
@@ -607,7 +661,7 @@ impl Schema {
         futures_util::stream::StreamExt::boxed({
             let extensions = extensions.clone();
             async_stream::stream! {
-                let (env_builder, cache_control) = match schema.prepare_request(extensions, request, session_data).await {
+                let (env_builder, cache_control, complexity) = match schema.prepare_request(extensions, request, session_data).await {
                     Ok(res) => res,
                     Err(errors) => {
                         yield Response::from_errors_with_type(errors, OperationType::Subscription).into_streaming_payload(false);
@@ -623,6 +677,7 @@ impl Schema {
                         .execute_once(env.clone())
                         .await
                         .cache_control(cache_control);
+                    let initial_response = schema.with_cost(initial_response, complexity);
 
                     let mut next_workload = receiver.receive();
 
@@ -648,10 +703,7 @@ impl Schema {
                 );
 
                 let mut streams = Vec::new();
-                if let Err(err) = collect_subscription_streams(&ctx, &crate::EmptySubscription, &mut streams) {
-                    // This hasNext: false is probably not correct, but we dont' support subscriptios atm so whatever
-                    yield Response::from_errors_with_type(vec![err], OperationType::Subscription).into_streaming_payload(false);
-                }
+                collect_subscription_streams(&ctx, &mut streams);
 
                 let mut stream = stream::select_all(streams);
                 while let Some(resp) = stream.next().await {
@@ -679,11 +731,23 @@ async fn process_deferred_workload(
     schema: &Schema,
     env: &QueryEnv,
 ) -> IncrementalPayload {
-    let context = workload.to_context(&schema.env, env);
-    let result = resolver_utils::resolve_deferred_container(&context, workload.parent_resolver_value.clone()).await;
+    let result = match &workload.streamed_item {
+        Some(deferred::StreamedItem::Leaf(item)) => match item.clone() {
+            Ok(item) => Ok(env
+                .response
+                .lock()
+                .await
+                .insert_node(ResponsePrimitive::new(item.into()))),
+            Err(error) => Err(error),
+        },
+        _ => {
+            let context = workload.to_context(&schema.env, env);
+            resolver_utils::resolve_deferred_container(&context, workload.parent_resolver_value.clone()).await
+        }
+    };
 
-    let mut data = std::mem::take(&mut *context.response().await);
-    let mut errors = std::mem::take(&mut *context.query_env.errors.lock().expect("to be able to lock this mutex"));
+    let mut data = std::mem::take(&mut *env.response.lock().await);
+    let mut errors = std::mem::take(&mut *env.errors.lock().expect("to be able to lock this mutex"));
 
     let root_node = match result {
         Ok(root_node) => root_node,
@@ -701,6 +765,7 @@ async fn process_deferred_workload(
         path: workload.path,
         has_next: false, // We hardcode this to false here, the function calling us should override
         errors,
+        streamed_item: workload.streamed_item.is_some(),
     }
 }
 
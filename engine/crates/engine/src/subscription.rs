@@ -1,13 +1,13 @@
 use std::{borrow::Cow, pin::Pin};
 
-use engine_parser::types::OperationType;
 use futures_util::stream::{Stream, StreamExt};
 
 use crate::{
     parser::types::{Selection, TypeCondition},
     registry,
     registry::Registry,
-    ContextField, ContextSelectionSet, Response, ServerError, ServerResult,
+    resolver_utils::resolve_subscription_event,
+    ContextExt, ContextField, ContextSelectionSet, Response,
 };
 
 /// A GraphQL subscription object
@@ -38,68 +38,75 @@ pub trait SubscriptionType: Send + Sync {
 
 type BoxFieldStream<'a> = Pin<Box<dyn Stream<Item = Response> + 'a + Send>>;
 
-pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + 'static>(
-    ctx: &ContextSelectionSet<'a>,
-    root: &'a T,
-    streams: &mut Vec<BoxFieldStream<'a>>,
-) -> ServerResult<()> {
+/// Collects a stream of responses for each root field of a subscription, with the events the
+/// resolvers of the fields subscribe to, e.g. the ones of an upstream server for the fields of a
+/// GraphQL connector.
+pub(crate) fn collect_subscription_streams<'a>(ctx: &ContextSelectionSet<'a>, streams: &mut Vec<BoxFieldStream<'a>>) {
     for selection in &ctx.item.node.items {
         match &selection.node {
             Selection::Field(field) => streams.push(Box::pin({
                 let ctx = ctx.clone();
                 async_stream::stream! {
                     let ctx = ctx.with_field(field);
-                    let field_name = ctx.item.node.response_key().node.clone();
-                    let stream = root.create_field_stream(&ctx);
-                    if let Some(mut stream) = stream {
-                        while let Some(resp) = stream.next().await {
-                            yield resp;
-                        }
-                    } else {
-                        let err = ServerError::new(format!(r#"Cannot query field "{}" on type "{}"."#, field_name, T::type_name()), Some(ctx.item.pos))
-                            .with_path(vec![field_name.as_str().into()]);
-                        yield Response::from_errors_with_type(vec![err], OperationType::Subscription);
+                    let events = ctx.field.resolver.subscribe(&ctx);
+                    futures_util::pin_mut!(events);
+
+                    while let Some(event) = events.next().await {
+                        let result = match event {
+                            Ok(value) => resolve_subscription_event(&ctx, value).await,
+                            Err(error) => Err(ctx.set_error_path(error.into_server_error(field.pos))),
+                        };
+
+                        let operation_name = ctx.query_env.operation_name.as_deref();
+                        let operation = &ctx.query_env.operation;
+
+                        // Each event gets a response of its own, so we take what resolving it
+                        // added to the query. Validation only allows a single root field, so no
+                        // other stream shares them.
+                        let data = std::mem::take(&mut *ctx.response().await);
+                        let mut response = match result {
+                            Ok(root) => {
+                                let mut data = data;
+                                data.set_root_unchecked(root);
+                                Response::new(data, operation_name, operation)
+                            }
+                            Err(error) => Response::from_errors(vec![error], operation_name, operation),
+                        };
+
+                        response
+                            .errors
+                            .extend(std::mem::take(&mut *ctx.query_env.errors.lock().unwrap()));
+
+                        yield response;
                     }
                 }
             })),
             Selection::FragmentSpread(fragment_spread) => {
-                if let Some(fragment) = ctx
-                    .query_env
-                    .fragments
-                    .get(&fragment_spread.node.fragment_name.node)
-                {
+                if let Some(fragment) = ctx.query_env.fragments.get(&fragment_spread.node.fragment_name.node) {
                     collect_subscription_streams(
                         &ctx.with_selection_set(&fragment.node.selection_set, ctx.ty),
-                        root,
                         streams,
-                    )?;
+                    );
                 }
             }
             Selection::InlineFragment(inline_fragment) => {
-                if let Some(TypeCondition { on: name }) = inline_fragment
-                    .node
-                    .type_condition
-                    .as_ref()
-                    .map(|v| &v.node)
+                if let Some(TypeCondition { on: name }) = inline_fragment.node.type_condition.as_ref().map(|v| &v.node)
                 {
-                    if name.node.as_str() == T::type_name() {
+                    if name.node.as_str() == ctx.ty.name() {
                         collect_subscription_streams(
                             &ctx.with_selection_set(&inline_fragment.node.selection_set, ctx.ty),
-                            root,
                             streams,
-                        )?;
+                        );
                     }
                 } else {
                     collect_subscription_streams(
                         &ctx.with_selection_set(&inline_fragment.node.selection_set, ctx.ty),
-                        root,
                         streams,
-                    )?;
+                    );
                 }
             }
         }
     }
-    Ok(())
 }
 
 impl<T: SubscriptionType> SubscriptionType for &T {
@@ -18,4 +18,5 @@ pub use id::ID;
 pub use maybe_undefined::MaybeUndefined;
 pub use merged_object::{MergedObject, MergedObjectTail};
 pub(crate) use query_root::QueryRoot;
+pub(crate) use upload::UPLOAD_VARIABLE_PREFIX;
 pub use upload::{Upload, UploadValue};
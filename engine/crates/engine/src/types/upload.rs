@@ -2,6 +2,10 @@ use std::{borrow::Cow, fs::File, io::Read};
 
 use crate::{registry, ContextField, InputValueError, InputValueResult, LegacyInputType, Value};
 
+/// Variables holding an upload are replaced with this prefix followed by the index of
+/// the upload in [`crate::Request::uploads`].
+pub(crate) const UPLOAD_VARIABLE_PREFIX: &str = "#__graphql_file__:";
+
 /// A file upload value.
 pub struct UploadValue {
     /// The name of the file.
@@ -109,10 +113,9 @@ impl LegacyInputType for Upload {
     }
 
     fn parse(value: Option<Value>) -> InputValueResult<Self> {
-        const PREFIX: &str = "#__graphql_file__:";
         let value = value.unwrap_or_default();
         if let Value::String(s) = &value {
-            if let Some(filename) = s.strip_prefix(PREFIX) {
+            if let Some(filename) = s.strip_prefix(UPLOAD_VARIABLE_PREFIX) {
                 return Ok(Upload(filename.parse::<usize>().unwrap()));
             }
         }
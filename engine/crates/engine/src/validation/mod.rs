@@ -32,6 +32,12 @@ pub struct ValidationResult {
 
     /// Query depth
     pub depth: usize,
+
+    /// Number of aliased fields
+    pub aliases: usize,
+
+    /// Number of fields selected on the root type
+    pub root_fields: usize,
 }
 
 /// Validation mode
@@ -44,6 +50,14 @@ pub enum ValidationMode {
     Fast,
 }
 
+/// The complexity of the operations of the document, as checked against the complexity limit.
+pub fn compute_complexity(registry: &Registry, doc: &ExecutableDocument, variables: Option<&Variables>) -> usize {
+    let mut ctx = VisitorContext::new(registry, doc, variables);
+    let mut complexity = 0;
+    visit(&mut visitors::ComplexityCalculate::new(&mut complexity), &mut ctx, doc);
+    complexity
+}
+
 pub fn check_rules(
     registry: &Registry,
     doc: &ExecutableDocument,
@@ -55,6 +69,8 @@ pub fn check_rules(
     let mut cache_invalidation_policies = Default::default();
     let mut complexity = 0;
     let mut depth = 0;
+    let mut aliases = 0;
+    let mut root_fields = 0;
 
     match mode {
         ValidationMode::Strict => {
@@ -75,10 +91,12 @@ pub fn check_rules(
                 .with(rules::VariablesAreInputTypes)
                 .with(rules::VariableInAllowedPosition::default())
                 .with(rules::ScalarLeafs)
+                .with(rules::SingleRootField)
                 .with(rules::PossibleFragmentSpreads::default())
                 .with(rules::ProvidedNonNullArguments)
                 .with(rules::KnownDirectives::default())
                 .with(rules::DirectivesUnique)
+                .with(rules::StreamOnListFields)
                 .with(rules::OverlappingFieldsCanBeMerged)
                 .with(rules::UploadFile)
                 .with(visitors::CacheControlCalculate {
@@ -86,20 +104,25 @@ pub fn check_rules(
                     invalidation_policies: &mut cache_invalidation_policies,
                 })
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::AliasesCalculate::new(&mut aliases))
+                .with(visitors::RootFieldsCalculate::new(&mut root_fields));
 
             visit(&mut visitor, &mut ctx, doc);
         }
         ValidationMode::Fast => {
             let mut visitor = VisitorNil
                 .with(rules::NoFragmentCycles::default())
+                .with(rules::SingleRootField)
                 .with(rules::UploadFile)
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                     invalidation_policies: &mut cache_invalidation_policies,
                 })
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::AliasesCalculate::new(&mut aliases))
+                .with(visitors::RootFieldsCalculate::new(&mut root_fields));
 
             visit(&mut visitor, &mut ctx, doc);
         }
@@ -114,5 +137,7 @@ pub fn check_rules(
         cache_invalidation_policies,
         complexity,
         depth,
+        aliases,
+        root_fields,
     })
 }
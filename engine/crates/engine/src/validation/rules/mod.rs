@@ -15,6 +15,8 @@ mod overlapping_fields_can_be_merged;
 mod possible_fragment_spreads;
 mod provided_non_null_arguments;
 mod scalar_leafs;
+mod single_root_field;
+mod stream_on_list_fields;
 mod unique_argument_names;
 mod unique_variable_names;
 mod upload_file;
@@ -38,6 +40,8 @@ pub use overlapping_fields_can_be_merged::OverlappingFieldsCanBeMerged;
 pub use possible_fragment_spreads::PossibleFragmentSpreads;
 pub use provided_non_null_arguments::ProvidedNonNullArguments;
 pub use scalar_leafs::ScalarLeafs;
+pub use single_root_field::SingleRootField;
+pub use stream_on_list_fields::StreamOnListFields;
 pub use unique_argument_names::UniqueArgumentNames;
 pub use unique_variable_names::UniqueVariableNames;
 pub use upload_file::UploadFile;
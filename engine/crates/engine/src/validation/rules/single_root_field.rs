@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use crate::{
+    parser::types::{OperationDefinition, OperationType, Selection, SelectionSet},
+    validation::visitor::{Visitor, VisitorContext},
+    Name, Positioned,
+};
+
+/// Subscriptions must select a single root field, as each root field becomes a stream of
+/// events of its own.
+#[derive(Default)]
+pub struct SingleRootField;
+
+impl<'a> Visitor<'a> for SingleRootField {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation_definition: &'a Positioned<OperationDefinition>,
+    ) {
+        if operation_definition.node.ty != OperationType::Subscription {
+            return;
+        }
+
+        let mut response_keys = HashSet::new();
+        let mut visited_fragments = HashSet::new();
+        collect_response_keys(
+            ctx,
+            &operation_definition.node.selection_set.node,
+            &mut response_keys,
+            &mut visited_fragments,
+        );
+
+        if response_keys.len() > 1 {
+            let message = match name {
+                Some(name) => format!("Subscription \"{name}\" must select only one top level field."),
+                None => "Anonymous Subscription must select only one top level field.".to_string(),
+            };
+            ctx.report_error(vec![operation_definition.pos], message);
+        }
+    }
+}
+
+fn collect_response_keys<'a>(
+    ctx: &VisitorContext<'a>,
+    selection_set: &'a SelectionSet,
+    response_keys: &mut HashSet<&'a str>,
+    visited_fragments: &mut HashSet<&'a str>,
+) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                response_keys.insert(field.node.response_key().node.as_str());
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let fragment_name = fragment_spread.node.fragment_name.node.as_str();
+                if !visited_fragments.insert(fragment_name) {
+                    continue;
+                }
+                if let Some(fragment) = ctx.fragment(fragment_name) {
+                    collect_response_keys(ctx, &fragment.node.selection_set.node, response_keys, visited_fragments);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                collect_response_keys(
+                    ctx,
+                    &inline_fragment.node.selection_set.node,
+                    response_keys,
+                    visited_fragments,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn factory() -> SingleRootField {
+        SingleRootField
+    }
+
+    #[test]
+    fn single_root_field() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          subscription Events {
+            events { id }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn same_root_field_in_fragments() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          subscription Events {
+            events { id }
+            ... on Subscription { events { name } }
+            ...EventsFragment
+          }
+          fragment EventsFragment on Subscription {
+            events { id }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn multiple_root_fields() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          subscription Events {
+            events { id }
+            otherEvents { id }
+          }
+        "#,
+            r#"Subscription "Events" must select only one top level field."#
+        );
+    }
+
+    #[test]
+    fn multiple_root_fields_in_a_fragment() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          subscription {
+            events { id }
+            ...OtherEvents
+          }
+          fragment OtherEvents on Subscription {
+            otherEvents { id }
+          }
+        "#,
+            "Anonymous Subscription must select only one top level field."
+        );
+    }
+
+    #[test]
+    fn aliased_root_fields() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          subscription Events {
+            events { id }
+            moreEvents: events { id }
+          }
+        "#,
+            r#"Subscription "Events" must select only one top level field."#
+        );
+    }
+}
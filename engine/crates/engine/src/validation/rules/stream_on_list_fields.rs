@@ -0,0 +1,94 @@
+use engine_value::Value;
+
+use crate::{
+    parser::types::Field,
+    validation::visitor::{Visitor, VisitorContext},
+    Positioned,
+};
+
+#[derive(Default)]
+pub struct StreamOnListFields;
+
+impl<'a> Visitor<'a> for StreamOnListFields {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let Some(directive) = field
+            .node
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == "stream")
+        else {
+            return;
+        };
+
+        if let Some(schema_field) = ctx.parent_type().and_then(|ty| ty.field_by_name(&field.node.name.node)) {
+            if !schema_field.ty.is_list() {
+                ctx.report_error(
+                    vec![directive.pos],
+                    format!(
+                        "The @stream directive can only be used on list fields, but \"{}\" is of type \"{}\"",
+                        field.node.name,
+                        schema_field.ty.as_str()
+                    ),
+                );
+            }
+        }
+
+        if let Some(initial_count) = directive.node.get_argument("initialCount") {
+            if let Value::Number(number) = &initial_count.node {
+                if !number.as_i64().is_some_and(|count| count >= 0) {
+                    ctx.report_error(
+                        vec![initial_count.pos],
+                        "The initialCount argument of @stream must be a non-negative integer",
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn factory() -> StreamOnListFields {
+        StreamOnListFields
+    }
+
+    #[test]
+    fn stream_on_list_field() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            human { pets @stream(initialCount: 1) { name } }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn stream_on_non_list_field() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            human @stream { name }
+          }
+        "#,
+            r#"The @stream directive can only be used on list fields, but "human" is of type "Human""#
+        );
+    }
+
+    #[test]
+    fn stream_with_negative_initial_count() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            human { pets @stream(initialCount: -1) { name } }
+          }
+        "#,
+            "The initialCount argument of @stream must be a non-negative integer"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use engine_parser::types::Field;
+
+use crate::{
+    validation::visitor::{VisitMode, Visitor, VisitorContext},
+    Positioned,
+};
+
+pub struct AliasesCalculate<'a> {
+    aliases: &'a mut usize,
+}
+
+impl<'a> AliasesCalculate<'a> {
+    pub fn new(aliases: &'a mut usize) -> Self {
+        Self { aliases }
+    }
+}
+
+impl<'ctx, 'a> Visitor<'ctx> for AliasesCalculate<'a> {
+    fn mode(&self) -> VisitMode {
+        // fragments are counted every time they're used
+        VisitMode::Inline
+    }
+
+    fn enter_field(&mut self, _ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
+        if field.node.alias.is_some() {
+            *self.aliases += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        parser::parse_query,
+        registry::Registry,
+        validation::{visit, VisitorContext},
+    };
+
+    fn check_aliases(query: &str, expect_aliases: usize) {
+        let registry = Registry::default();
+        let doc = parse_query(query).unwrap();
+        let mut ctx = VisitorContext::new(&registry, &doc, None);
+        let mut aliases = 0;
+        let mut aliases_calculate = AliasesCalculate::new(&mut aliases);
+        visit(&mut aliases_calculate, &mut ctx, &doc);
+        assert_eq!(aliases, expect_aliases);
+    }
+
+    #[test]
+    fn aliases() {
+        check_aliases("{ value obj { a } }", 0);
+        check_aliases("{ first: value second: value obj { a: b } }", 3);
+        check_aliases(
+            r#"
+        fragment A on MyObj {
+            x: a y: a
+        }
+
+        query {
+            first: obj { ... A }
+            second: obj { ... A }
+        }"#,
+            6,
+        );
+    }
+}
@@ -1,16 +1,26 @@
 use engine_parser::types::{ExecutableDocument, OperationDefinition, VariableDefinition};
-use engine_value::Name;
+use engine_value::{ConstValue, Name};
 
 use crate::{
     parser::types::Field,
-    registry::{ComplexityType, MetaType, MetaTypeName},
+    registry::{ComplexityType, MetaField, MetaType, MetaTypeName},
     validation::visitor::{VisitMode, Visitor, VisitorContext},
     Positioned,
 };
 
+/// Arguments setting how many items a list field returns, as generated for the collections of the
+/// connectors.
+const LIST_SIZE_ARGUMENTS: [&str; 3] = ["first", "last", "limit"];
+
+/// Each field counts for its `@complexity` weight or 1, plus the complexity of its children
+/// multiplied by the size of the list it returns. Sizes are taken from the `first`, `last` or
+/// `limit` arguments, falling back to the `defaultListSize` of the operation limits.
 pub struct ComplexityCalculate<'ctx, 'a> {
     pub complexity: &'a mut usize,
     pub complexity_stack: Vec<usize>,
+    /// Whether the fields being visited take a list size argument. Their connection's `edges` were
+    /// already accounted for by the argument and aren't multiplied again.
+    pub sized_stack: Vec<bool>,
     pub variable_definition: Option<&'ctx [Positioned<VariableDefinition>]>,
 }
 
@@ -19,9 +29,41 @@ impl<'ctx, 'a> ComplexityCalculate<'ctx, 'a> {
         Self {
             complexity,
             complexity_stack: Default::default(),
+            sized_stack: Default::default(),
             variable_definition: None,
         }
     }
+
+    /// How many times the complexity of the children of the field counts.
+    fn list_size(
+        &self,
+        ctx: &VisitorContext<'ctx>,
+        meta_field: &MetaField,
+        field: &Field,
+        in_sized_field: bool,
+    ) -> usize {
+        let variable_definitions = self.variable_definition.unwrap_or_default();
+        let size = LIST_SIZE_ARGUMENTS.iter().find_map(|name| {
+            let argument = meta_field.args.get(*name)?;
+            ctx.param_value::<Option<usize>>(variable_definitions, field, name, None)
+                .ok()
+                .flatten()
+                .or_else(|| match &argument.default_value {
+                    Some(ConstValue::Number(number)) => number.as_u64().and_then(|size| usize::try_from(size).ok()),
+                    _ => None,
+                })
+        });
+
+        if let Some(size) = size {
+            return size;
+        }
+
+        if takes_list_size(meta_field) || (meta_field.ty.is_list() && !in_sized_field) {
+            ctx.registry.operation_limits.default_list_size.unwrap_or(1)
+        } else {
+            1
+        }
+    }
 }
 
 impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
@@ -46,44 +88,66 @@ impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
         self.variable_definition = Some(&operation_definition.node.variable_definitions);
     }
 
-    fn enter_field(&mut self, _ctx: &mut VisitorContext<'_>, _field: &Positioned<Field>) {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
         self.complexity_stack.push(0);
+        self.sized_stack
+            .push(find_meta_field(ctx, field).is_some_and(takes_list_size));
     }
 
     fn exit_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
         let children_complex = self.complexity_stack.pop().unwrap();
-
-        if let Some(MetaType::Object(object)) = ctx.parent_type() {
-            if let Some(meta_field) = object
-                .fields
-                .get(MetaTypeName::concrete_typename(field.node.name.node.as_str()))
-            {
-                if let Some(compute_complexity) = &meta_field.compute_complexity {
-                    match compute_complexity {
-                        ComplexityType::Const(n) => {
-                            *self.complexity_stack.last_mut().unwrap() += n;
-                        }
-                        ComplexityType::Fn(f) => {
-                            if meta_field.ty.is_list() {
-                                match f(ctx, self.variable_definition.unwrap(), &field.node, children_complex) {
-                                    Ok(n) => {
-                                        *self.complexity_stack.last_mut().unwrap() += n;
-                                    }
-                                    Err(err) => ctx.report_error(vec![field.pos], err.to_string()),
+        self.sized_stack.pop();
+        let in_sized_field = self.sized_stack.last().copied().unwrap_or_default();
+
+        if let Some(meta_field) = find_meta_field(ctx, field) {
+            if let Some(compute_complexity) = &meta_field.compute_complexity {
+                match compute_complexity {
+                    ComplexityType::Const(n) => {
+                        *self.complexity_stack.last_mut().unwrap() += n;
+                    }
+                    ComplexityType::Fn(f) => {
+                        if meta_field.ty.is_list() {
+                            match f(ctx, self.variable_definition.unwrap(), &field.node, children_complex) {
+                                Ok(n) => {
+                                    *self.complexity_stack.last_mut().unwrap() += n;
                                 }
+                                Err(err) => ctx.report_error(vec![field.pos], err.to_string()),
                             }
                         }
                     }
-
-                    return;
                 }
+
+                return;
             }
+
+            let weight = meta_field.complexity.unwrap_or(1);
+            let size = self.list_size(ctx, meta_field, &field.node, in_sized_field);
+            let complexity = self.complexity_stack.last_mut().unwrap();
+            *complexity = complexity
+                .saturating_add(weight)
+                .saturating_add(size.saturating_mul(children_complex));
+            return;
         }
 
         *self.complexity_stack.last_mut().unwrap() += 1 + children_complex;
     }
 }
 
+fn find_meta_field<'ctx>(ctx: &VisitorContext<'ctx>, field: &Positioned<Field>) -> Option<&'ctx MetaField> {
+    match ctx.parent_type() {
+        Some(MetaType::Object(object)) => object
+            .fields
+            .get(MetaTypeName::concrete_typename(field.node.name.node.as_str())),
+        _ => None,
+    }
+}
+
+fn takes_list_size(meta_field: &MetaField) -> bool {
+    LIST_SIZE_ARGUMENTS
+        .iter()
+        .any(|name| meta_field.args.contains_key(*name))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::diverging_sub_expression)]
@@ -91,8 +155,9 @@ mod tests {
     use super::*;
     use crate::{
         parser::parse_query,
+        registry::Registry,
         validation::{visit, VisitorContext},
-        EmptyMutation, EmptySubscription, Object, Schema,
+        EmptyMutation, EmptySubscription, Object, Schema, Variables,
     };
 
     struct Query;
@@ -100,6 +165,16 @@ mod tests {
     #[derive(Copy, Clone)]
     struct MyObj;
 
+    struct MyConnection;
+
+    #[Object(internal)]
+    #[allow(unreachable_code)]
+    impl MyConnection {
+        async fn edges(&self) -> Vec<MyObj> {
+            todo!()
+        }
+    }
+
     #[Object(internal)]
     #[allow(unreachable_code)]
     impl MyObj {
@@ -137,6 +212,20 @@ mod tests {
         async fn d(&self) -> MyObj {
             todo!()
         }
+
+        #[allow(unused_variables)]
+        async fn items(&self, first: Option<usize>, last: Option<usize>) -> Vec<MyObj> {
+            todo!()
+        }
+
+        #[allow(unused_variables)]
+        async fn connection(&self, #[graphql(default = 3)] first: usize) -> MyConnection {
+            todo!()
+        }
+
+        async fn list(&self) -> Vec<MyObj> {
+            todo!()
+        }
     }
 
     fn check_complex(query: &str, expect_complex: usize) {
@@ -263,4 +352,45 @@ mod tests {
             20,
         );
     }
+
+    #[test]
+    fn declared_field_weight() {
+        let mut registry = Schema::create_registry_static::<Query, EmptyMutation, EmptySubscription>();
+        registry.query_root_mut().field_by_name_mut("obj").unwrap().complexity = Some(5);
+
+        let doc = parse_query("{ value obj { a b } }").unwrap();
+        let mut ctx = VisitorContext::new(&registry, &doc, None);
+        let mut complex = 0;
+        visit(&mut ComplexityCalculate::new(&mut complex), &mut ctx, &doc);
+        // value #1, obj #5, a b #2
+        assert_eq!(complex, 8);
+    }
+
+    #[test]
+    fn list_sizes() {
+        let mut registry = Schema::create_registry_static::<Query, EmptyMutation, EmptySubscription>();
+        let variables = Variables::from_json(serde_json::json!({ "size": 5 }));
+        let complexity = |registry: &Registry, query: &str| {
+            let doc = parse_query(query).unwrap();
+            let mut ctx = VisitorContext::new(registry, &doc, Some(&variables));
+            let mut complex = 0;
+            visit(&mut ComplexityCalculate::new(&mut complex), &mut ctx, &doc);
+            complex
+        };
+
+        // items #1, a b #2 for each of the 10 items
+        assert_eq!(complexity(&registry, "{ items(first: 10) { a b } }"), 21);
+        assert_eq!(complexity(&registry, "{ items(first: 10) { c { a } } }"), 21);
+        assert_eq!(
+            complexity(&registry, "query ($size: Int) { items(last: $size) { a } }"),
+            6
+        );
+        // the default value of the argument, edges #1 not multiplied again
+        assert_eq!(complexity(&registry, "{ connection { edges { a b } } }"), 10);
+        assert_eq!(complexity(&registry, "{ items { a } list { a } }"), 4);
+
+        registry.operation_limits.default_list_size = Some(4);
+        assert_eq!(complexity(&registry, "{ items { a } list { a } }"), 10);
+        assert_eq!(complexity(&registry, "{ items(first: 2) { a } }"), 3);
+    }
 }
@@ -1,7 +1,11 @@
+mod aliases;
 mod cache_control;
 mod complexity;
 mod depth;
+mod root_fields;
 
+pub use aliases::AliasesCalculate;
 pub use cache_control::CacheControlCalculate;
 pub use complexity::ComplexityCalculate;
 pub use depth::DepthCalculate;
+pub use root_fields::RootFieldsCalculate;
@@ -0,0 +1,95 @@
+use engine_parser::types::{Field, OperationDefinition};
+use engine_value::Name;
+
+use crate::{
+    validation::visitor::{VisitMode, Visitor, VisitorContext},
+    Positioned,
+};
+
+/// The largest number of fields selected on the root type by an operation of the document.
+pub struct RootFieldsCalculate<'a> {
+    max_root_fields: &'a mut usize,
+    root_fields: usize,
+    current_depth: usize,
+}
+
+impl<'a> RootFieldsCalculate<'a> {
+    pub fn new(max_root_fields: &'a mut usize) -> Self {
+        Self {
+            max_root_fields,
+            root_fields: 0,
+            current_depth: 0,
+        }
+    }
+}
+
+impl<'ctx, 'a> Visitor<'ctx> for RootFieldsCalculate<'a> {
+    fn mode(&self) -> VisitMode {
+        VisitMode::Inline
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _name: Option<&'ctx Name>,
+        _operation_definition: &'ctx Positioned<OperationDefinition>,
+    ) {
+        self.root_fields = 0;
+    }
+
+    fn exit_operation_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _name: Option<&'ctx Name>,
+        _operation_definition: &'ctx Positioned<OperationDefinition>,
+    ) {
+        *self.max_root_fields = (*self.max_root_fields).max(self.root_fields);
+    }
+
+    fn enter_field(&mut self, _ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
+        if self.current_depth == 0 {
+            self.root_fields += 1;
+        }
+        self.current_depth += 1;
+    }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
+        self.current_depth -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        parser::parse_query,
+        registry::Registry,
+        validation::{visit, VisitorContext},
+    };
+
+    fn check_root_fields(query: &str, expect_root_fields: usize) {
+        let registry = Registry::default();
+        let doc = parse_query(query).unwrap();
+        let mut ctx = VisitorContext::new(&registry, &doc, None);
+        let mut root_fields = 0;
+        let mut root_fields_calculate = RootFieldsCalculate::new(&mut root_fields);
+        visit(&mut root_fields_calculate, &mut ctx, &doc);
+        assert_eq!(root_fields, expect_root_fields);
+    }
+
+    #[test]
+    fn root_fields() {
+        check_root_fields("{ value obj { a b c } }", 2);
+        check_root_fields(
+            r#"
+        fragment Root on Query {
+            value
+            ... on Query { other: value }
+        }
+
+        query A { obj { a } ... Root }
+        query B { value }"#,
+            3,
+        );
+    }
+}
@@ -190,6 +190,7 @@ async fn call_authorizer(
                 request: runtime::udf::UdfRequestContextRequest {
                     headers: serde_json::to_value(ctx.headers_as_map()).expect("must be valid"),
                 },
+                auth: None,
             },
         },
     };
@@ -18,6 +18,7 @@ pub use build_key::{build_cache_key, BuildKeyError};
 use engine::registry::CachePartialRegistry;
 
 use super::RequestContext;
+use crate::metrics::metrics;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum CacheReadStatus {
@@ -28,22 +29,26 @@ pub enum CacheReadStatus {
 }
 
 impl CacheReadStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            CacheReadStatus::Hit => "HIT",
+            CacheReadStatus::Miss { .. } => "MISS",
+            CacheReadStatus::Stale { revalidated } => {
+                if *revalidated {
+                    "UPDATING"
+                } else {
+                    "STALE"
+                }
+            }
+            CacheReadStatus::Bypass => "BYPASS",
+        }
+    }
+
     fn into_headers(self) -> http::HeaderMap {
         let mut headers = http::HeaderMap::new();
         headers.insert(
             http::HeaderName::from_static(X_GRAFBASE_CACHE),
-            http::HeaderValue::from_static(match self {
-                CacheReadStatus::Hit => "HIT",
-                CacheReadStatus::Miss { .. } => "MISS",
-                CacheReadStatus::Stale { revalidated } => {
-                    if revalidated {
-                        "UPDATING"
-                    } else {
-                        "STALE"
-                    }
-                }
-                CacheReadStatus::Bypass => "BYPASS",
-            }),
+            http::HeaderValue::from_static(self.as_str()),
         );
         if let CacheReadStatus::Miss { max_age } = self {
             headers.typed_insert(headers::CacheControl::new().with_public().with_max_age(max_age));
@@ -127,23 +132,30 @@ where
     Error: std::fmt::Display,
     Response: super::Response<Error = Error>,
 {
-    let (response, headers) = match response {
+    let (response, cache_read) = match response {
         Ok(execution_response) => match execution_response {
-            ExecutionResponse::Cached(cached) => (cached, CacheReadStatus::Hit.into_headers()),
+            ExecutionResponse::Cached(cached) => (cached, Some(CacheReadStatus::Hit)),
             ExecutionResponse::Stale {
                 response,
                 cache_revalidation: revalidated,
-            } => (response, CacheReadStatus::Stale { revalidated }.into_headers()),
-            ExecutionResponse::Origin { response, cache_read } => (
-                response,
-                cache_read.map(CacheReadStatus::into_headers).unwrap_or_default(),
-            ),
+            } => (response, Some(CacheReadStatus::Stale { revalidated })),
+            ExecutionResponse::Origin { response, cache_read } => (response, cache_read),
         },
         Err(e) => {
             log::error!(ctx.ray_id(), "Execution error: {}", e);
+            metrics().record_error("execution");
             return Ok(Response::error(StatusCode::INTERNAL_SERVER_ERROR, "Execution error"));
         }
     };
+
+    if response.is_err() {
+        metrics().record_error("graphql");
+    }
+    if let Some(cache_read) = &cache_read {
+        metrics().record_cache_read(cache_read);
+    }
+
+    let headers = cache_read.map(CacheReadStatus::into_headers).unwrap_or_default();
     Response::engine(response).map(|resp| resp.with_additional_headers(headers))
 }
 
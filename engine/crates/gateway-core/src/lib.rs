@@ -10,6 +10,8 @@ mod admin;
 mod auth;
 mod cache;
 mod executor;
+mod metrics;
+mod rate_limiting;
 mod response;
 pub mod serving;
 mod streaming;
@@ -17,6 +19,7 @@ mod streaming;
 pub use auth::{authorize_request, AdminAuthError, AuthError, Authorizer};
 pub use cache::{CacheConfig, CacheControl};
 pub use executor::Executor;
+pub use rate_limiting::{RateLimit, RateLimited, RateLimiter};
 pub use response::Response;
 pub use streaming::{encode_stream_response, format::StreamingFormat};
 
@@ -37,6 +40,7 @@ pub struct Gateway<Executor: self::Executor, Cache> {
     cache: Arc<Cache>,
     cache_config: CacheConfig,
     authorizer: Box<dyn Authorizer<Context = Executor::Context>>,
+    rate_limiter: Option<Box<dyn RateLimit<Executor::Context>>>,
 }
 
 impl<Executor, Cache> Gateway<Executor, Cache>
@@ -58,6 +62,15 @@ where
             cache,
             cache_config,
             authorizer,
+            rate_limiter: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_rate_limiter(self, rate_limiter: impl RateLimit<Executor::Context> + 'static) -> Self {
+        Self {
+            rate_limiter: Some(Box::new(rate_limiter)),
+            ..self
         }
     }
 
@@ -89,6 +102,31 @@ where
         ctx: &Arc<Executor::Context>,
         request: engine::Request,
         streaming_format: Option<StreamingFormat>,
+    ) -> Result<Executor::Response, Executor::Error> {
+        let operation_name = request.operation_name.clone();
+        let streaming = streaming_format.is_some();
+        let span = info_span!(
+            "operation",
+            graphql.operation.name = operation_name.as_deref(),
+            graphql.streaming = streaming,
+        );
+
+        let start_time = web_time::Instant::now();
+        let response = self
+            .execute_operation(ctx, request, streaming_format)
+            .instrument(span)
+            .await;
+
+        metrics::metrics().record_request(start_time.elapsed(), streaming);
+
+        response
+    }
+
+    async fn execute_operation(
+        &self,
+        ctx: &Arc<Executor::Context>,
+        request: engine::Request,
+        streaming_format: Option<StreamingFormat>,
     ) -> Result<Executor::Response, Executor::Error> {
         let Ok(auth) = self
             .authorizer
@@ -103,6 +141,33 @@ where
             )));
         };
 
+        if let Some(rate_limiter) = &self.rate_limiter {
+            match rate_limiter
+                .check(ctx.as_ref(), &request, &auth)
+                .instrument(info_span!("rate_limit"))
+                .await
+            {
+                Ok(()) => {}
+                Err(RateLimited::Exceeded { retry_after }) => {
+                    // Retry-After is in whole seconds
+                    let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                    let mut headers = http::HeaderMap::new();
+                    headers.insert(http::header::RETRY_AFTER, http::HeaderValue::from(seconds));
+
+                    return Ok(
+                        Executor::Response::error(http::StatusCode::TOO_MANY_REQUESTS, "Too many requests")
+                            .with_additional_headers(headers),
+                    );
+                }
+                Err(RateLimited::TooExpensive { cost, limit }) => {
+                    return Ok(Executor::Response::error(
+                        http::StatusCode::BAD_REQUEST,
+                        &format!("The operation costs {cost}, more than the rate limit of {limit} allows"),
+                    ));
+                }
+            }
+        }
+
         if let Some(streaming_format) = streaming_format {
             Arc::clone(&self.executor)
                 .execute_stream(Arc::clone(ctx), auth, request, streaming_format)
@@ -0,0 +1,63 @@
+//! The OpenTelemetry instruments of the gateway. They're created from the global meter provider,
+//! so recording is a no-op unless the host has set up an exporter.
+
+use std::{sync::OnceLock, time::Duration};
+
+use opentelemetry::{
+    metrics::{Counter, Histogram},
+    KeyValue,
+};
+
+use crate::cache::CacheReadStatus;
+
+pub(crate) struct GatewayMetrics {
+    request_latency: Histogram<f64>,
+    errors: Counter<u64>,
+    cache_reads: Counter<u64>,
+}
+
+pub(crate) fn metrics() -> &'static GatewayMetrics {
+    static METRICS: OnceLock<GatewayMetrics> = OnceLock::new();
+
+    METRICS.get_or_init(|| {
+        let meter = opentelemetry::global::meter("grafbase-gateway");
+
+        GatewayMetrics {
+            request_latency: meter
+                .f64_histogram("graphql.request.duration")
+                .with_description("The time it took to execute a GraphQL request")
+                .with_unit(opentelemetry::metrics::Unit::new("ms"))
+                .init(),
+            errors: meter
+                .u64_counter("graphql.request.errors")
+                .with_description("The number of GraphQL requests that failed or returned errors")
+                .init(),
+            cache_reads: meter
+                .u64_counter("graphql.cache.reads")
+                .with_description("The number of cache lookups, by status")
+                .init(),
+        }
+    })
+}
+
+impl GatewayMetrics {
+    /// The operation name is left out, as clients choose it freely and every distinct value would
+    /// be a series of its own. It's on the operation span instead.
+    pub(crate) fn record_request(&self, duration: Duration, streaming: bool) {
+        self.request_latency.record(
+            duration.as_secs_f64() * 1000.0,
+            &[KeyValue::new("graphql.streaming", streaming)],
+        );
+    }
+
+    /// `kind` is either `execution` for requests that failed altogether or `graphql` for
+    /// responses with errors in them.
+    pub(crate) fn record_error(&self, kind: &'static str) {
+        self.errors.add(1, &[KeyValue::new("kind", kind)]);
+    }
+
+    /// The cache hit ratio is the share of reads with the `HIT` status.
+    pub(crate) fn record_cache_read(&self, status: &CacheReadStatus) {
+        self.cache_reads.add(1, &[KeyValue::new("status", status.as_str())]);
+    }
+}
@@ -0,0 +1,772 @@
+//! Rate limiting of the requests sent to the gateway, as declared with `@rateLimit`.
+//!
+//! Counters are kept in a [`KvStore`] so that they're shared by every instance of the gateway.
+//! Reads and writes aren't atomic, so concurrent requests may slightly exceed a limit.
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use common_types::auth::ExecutionAuth;
+use engine::{
+    parser::types::{DocumentOperations, ExecutableDocument, OperationType, Selection, SelectionSet},
+    registry::{RateLimitAlgorithm, RateLimitConfig, RateLimitKey, RateLimitQuota},
+    validation::compute_complexity,
+    Registry,
+};
+use runtime::kv::{KvError, KvGet, KvPut, KvResult, KvStore};
+use sha2::{Digest, Sha256};
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::{serving::X_API_KEY_HEADER, RequestContext};
+
+const KV_KEY_PREFIX: &str = "rate_limit";
+
+pub struct RateLimiter<Store> {
+    registry: Arc<Registry>,
+    kv: Store,
+}
+
+/// The request exceeded one of the quotas.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RateLimited {
+    Exceeded {
+        /// How long the client should wait before sending another request
+        retry_after: Duration,
+    },
+    /// The operation costs more than the limit of a cost-based quota, so it's never allowed.
+    TooExpensive { cost: u32, limit: u32 },
+}
+
+/// What the gateway needs from a [`RateLimiter`], without being generic over its store.
+#[async_trait::async_trait]
+pub trait RateLimit<Context>: Send + Sync {
+    async fn check(&self, ctx: &Context, request: &engine::Request, auth: &ExecutionAuth) -> Result<(), RateLimited>;
+}
+
+#[async_trait::async_trait]
+impl<Context, Store> RateLimit<Context> for RateLimiter<Store>
+where
+    Context: RequestContext,
+    Store: KvStore + Send + Sync,
+    Store::Get: Send,
+    Store::Put: Send,
+{
+    async fn check(&self, ctx: &Context, request: &engine::Request, auth: &ExecutionAuth) -> Result<(), RateLimited> {
+        RateLimiter::check(self, ctx, request, auth).await
+    }
+}
+
+impl<Store> RateLimiter<Store>
+where
+    Store: KvStore + Send + Sync,
+    Store::Get: Send,
+    Store::Put: Send,
+{
+    pub fn new(registry: Arc<Registry>, kv: Store) -> Self {
+        Self { registry, kv }
+    }
+
+    fn config(&self) -> &RateLimitConfig {
+        &self.registry.rate_limiting
+    }
+
+    /// Counts the request against its quotas, failing if any of them is exceeded. The request is
+    /// only counted if none of them is.
+    pub async fn check(
+        &self,
+        ctx: &impl RequestContext,
+        request: &engine::Request,
+        auth: &ExecutionAuth,
+    ) -> Result<(), RateLimited> {
+        if self.config().is_empty() {
+            return Ok(());
+        }
+
+        let client = self.client_key(ctx, auth);
+        let document = engine::parser::parse_query(&request.query).ok();
+        let cost = self.cost(request, document.as_ref());
+        let now = now_ms();
+
+        let quotas = self.quotas(request, document.as_ref());
+        if self.config().cost {
+            if let Some((_, quota)) = quotas.iter().find(|(_, quota)| cost > quota.limit) {
+                return Err(RateLimited::TooExpensive {
+                    cost,
+                    limit: quota.limit,
+                });
+            }
+        }
+
+        let mut counters = Vec::new();
+        for (scope, quota) in quotas {
+            let key = format!("{KV_KEY_PREFIX}:{scope}:{client}");
+            match self.count(&key, quota, cost, now).await {
+                Ok(Ok(counter)) => counters.push((scope, key, quota, counter)),
+                Ok(Err(retry_after)) => return Err(RateLimited::Exceeded { retry_after }),
+                // better to let requests through than to fail all of them while the Kv is down
+                Err(error) => log::warn!(ctx.ray_id(), "Could not check the rate limit of {scope}: {error}"),
+            }
+        }
+
+        for (scope, key, quota, counter) in counters {
+            if let Err(error) = self.save(&key, quota, counter).await {
+                log::warn!(ctx.ray_id(), "Could not update the rate limit of {scope}: {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A hash of whatever identifies the client, so that API keys & claims aren't stored as is.
+    /// Requests without the key they're counted by are counted by their IP address, and those
+    /// without an IP address all together.
+    fn client_key(&self, ctx: &impl RequestContext, auth: &ExecutionAuth) -> String {
+        let header = |name: &str| ctx.headers().get(name).and_then(|value| value.to_str().ok());
+
+        let key = match &self.config().key {
+            RateLimitKey::Ip => None,
+            RateLimitKey::ApiKey => header(X_API_KEY_HEADER).map(|value| format!("api_key:{value}")),
+            RateLimitKey::JwtClaim(claim) => match auth {
+                ExecutionAuth::Token(token) => token
+                    .get_claim(claim)
+                    .filter(|value| !value.is_empty())
+                    .map(|value| format!("jwt_claim:{claim}:{value}")),
+                _ => None,
+            },
+            RateLimitKey::Header(name) => header(name)
+                .filter(|value| !value.is_empty())
+                .map(|value| format!("header:{name}:{value}")),
+        }
+        .unwrap_or_else(|| format!("ip:{}", self.client_ip(ctx).unwrap_or_default()));
+
+        format!("{:x}", Sha256::digest(key.as_bytes()))
+    }
+
+    fn client_ip(&self, ctx: &impl RequestContext) -> Option<String> {
+        let header = |name: &str| ctx.headers().get(name).and_then(|value| value.to_str().ok());
+
+        let forwarded = || {
+            header("cf-connecting-ip")
+                // the last address is the one the proxy received the request from, the others
+                // were set by the client
+                .or_else(|| header("x-forwarded-for").and_then(|value| value.rsplit(',').next()))
+                .or_else(|| header("x-real-ip"))
+                .map(|ip| ip.trim().to_string())
+                .filter(|ip| !ip.is_empty())
+        };
+
+        self.config()
+            .trust_proxy_headers
+            .then(forwarded)
+            .flatten()
+            .or_else(|| ctx.client_ip().map(|ip| ip.to_string()))
+    }
+
+    /// How much the request counts for: the complexity of its operation if the quotas are
+    /// cost-based, 1 otherwise.
+    fn cost(&self, request: &engine::Request, document: Option<&ExecutableDocument>) -> u32 {
+        match document {
+            Some(document) if self.config().cost => {
+                let complexity = compute_complexity(&self.registry, document, Some(&request.variables));
+                u32::try_from(complexity).unwrap_or(u32::MAX).max(1)
+            }
+            _ => 1,
+        }
+    }
+
+    /// The quotas the request is counted against: the one of its operation, or the global one,
+    /// and those of the root fields it selects.
+    fn quotas(
+        &self,
+        request: &engine::Request,
+        document: Option<&ExecutableDocument>,
+    ) -> Vec<(String, RateLimitQuota)> {
+        let operation = document.and_then(|document| find_operation(document, request.operation_name.as_deref()));
+
+        let operation_name = request
+            .operation_name
+            .as_deref()
+            .or_else(|| operation.and_then(|(name, _)| name));
+
+        let mut quotas = Vec::new();
+        match operation_name.and_then(|name| Some((name, self.config().operations.get(name)?))) {
+            Some((name, quota)) => quotas.push((format!("operation:{name}"), *quota)),
+            None => quotas.extend(self.config().global.map(|quota| ("global".to_string(), quota))),
+        }
+
+        if self.config().root_fields.is_empty() {
+            return quotas;
+        }
+
+        if let (Some(document), Some((_, definition))) = (document, operation) {
+            let type_name = match definition.ty {
+                OperationType::Query => "Query",
+                OperationType::Mutation => "Mutation",
+                OperationType::Subscription => return quotas,
+            };
+
+            let mut fields = Vec::new();
+            collect_root_fields(
+                document,
+                &definition.selection_set.node,
+                &mut HashSet::new(),
+                &mut fields,
+            );
+            fields.sort_unstable();
+            fields.dedup();
+
+            quotas.extend(fields.into_iter().filter_map(|field| {
+                let field = format!("{type_name}.{field}");
+                let quota = self.config().root_fields.get(&field)?;
+                Some((format!("field:{field}"), *quota))
+            }));
+        }
+
+        quotas
+    }
+
+    /// The counter of the quota once the request is counted against it, if it isn't exceeded.
+    async fn count(
+        &self,
+        key: &str,
+        quota: RateLimitQuota,
+        cost: u32,
+        now: u64,
+    ) -> KvResult<Result<Counter, Duration>> {
+        Ok(match self.config().algorithm {
+            RateLimitAlgorithm::TokenBucket => {
+                take_tokens(self.get_json(key).await?, quota, cost, now).map(Counter::TokenBucket)
+            }
+            RateLimitAlgorithm::SlidingWindow => {
+                take_window_slots(self.get_json(key).await?, quota, cost, now).map(Counter::SlidingWindow)
+            }
+        })
+    }
+
+    async fn save(&self, key: &str, quota: RateLimitQuota, counter: Counter) -> KvResult<()> {
+        match counter {
+            Counter::TokenBucket(bucket) => self.put_json(key, &bucket, quota.duration).await,
+            // the counts stay relevant for the next window too
+            Counter::SlidingWindow(window) => self.put_json(key, &window, quota.duration * 2).await,
+        }
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, key: &str) -> KvResult<Option<T>> {
+        self.kv
+            .get(key)
+            .json()
+            .await
+            .map_err(|error| KvError::Kv(format!("{error:?}")))
+    }
+
+    async fn put_json<T: serde::Serialize>(&self, key: &str, value: &T, expiration_ttl: Duration) -> KvResult<()> {
+        self.kv
+            .put(key, value)
+            .map_err(|error| KvError::Kv(format!("{error:?}")))?
+            .expiration_ttl(expiration_ttl)
+            .execute()
+            .await
+            .map_err(|error| KvError::Kv(format!("{error:?}")))
+    }
+}
+
+enum Counter {
+    TokenBucket(TokenBucket),
+    SlidingWindow(SlidingWindow),
+}
+
+fn take_tokens(
+    bucket: Option<TokenBucket>,
+    quota: RateLimitQuota,
+    cost: u32,
+    now: u64,
+) -> Result<TokenBucket, Duration> {
+    let limit = f64::from(quota.limit);
+    let cost = f64::from(cost);
+    let refill_per_ms = limit / duration_ms(quota).max(1) as f64;
+
+    let tokens = match bucket {
+        Some(bucket) => {
+            let elapsed = now.saturating_sub(bucket.updated_at) as f64;
+            (bucket.tokens + elapsed * refill_per_ms).min(limit)
+        }
+        None => limit,
+    };
+
+    if tokens < cost {
+        let wait = ((cost - tokens) / refill_per_ms).ceil() as u64;
+        return Err(Duration::from_millis(wait));
+    }
+
+    Ok(TokenBucket {
+        tokens: tokens - cost,
+        updated_at: now,
+    })
+}
+
+fn take_window_slots(
+    window: Option<SlidingWindow>,
+    quota: RateLimitQuota,
+    cost: u32,
+    now: u64,
+) -> Result<SlidingWindow, Duration> {
+    let window_ms = duration_ms(quota).max(1);
+    let index = now / window_ms;
+    let offset = now % window_ms;
+
+    let window = match window {
+        Some(window) if window.index == index => window,
+        Some(window) if window.index + 1 == index => SlidingWindow {
+            index,
+            current: 0,
+            previous: window.current,
+        },
+        _ => SlidingWindow {
+            index,
+            current: 0,
+            previous: 0,
+        },
+    };
+
+    // requests of the previous window are assumed to have been evenly spread over it
+    let elapsed = offset as f64 / window_ms as f64;
+    let estimate = f64::from(window.previous) * (1.0 - elapsed) + f64::from(window.current);
+
+    if estimate + f64::from(cost) > f64::from(quota.limit) {
+        let available = f64::from(quota.limit) - f64::from(cost) - f64::from(window.current);
+        let wait = if available < 0.0 || window.previous == 0 {
+            window_ms - offset
+        } else {
+            // when enough of the previous window has slid out of the current one
+            let until = (1.0 - available / f64::from(window.previous)) * window_ms as f64;
+            (until.ceil() as u64).saturating_sub(offset).max(1)
+        };
+        return Err(Duration::from_millis(wait));
+    }
+
+    Ok(SlidingWindow {
+        current: window.current.saturating_add(cost),
+        ..window
+    })
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
+struct TokenBucket {
+    tokens: f64,
+    /// Milliseconds since the Unix epoch
+    updated_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SlidingWindow {
+    /// Number of windows since the Unix epoch
+    index: u64,
+    current: u32,
+    previous: u32,
+}
+
+fn find_operation<'a>(
+    document: &'a ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Option<(Option<&'a str>, &'a engine::parser::types::OperationDefinition)> {
+    match (&document.operations, operation_name) {
+        (DocumentOperations::Single(operation), _) => Some((None, &operation.node)),
+        (DocumentOperations::Multiple(operations), Some(name)) => operations
+            .get_key_value(name)
+            .map(|(name, operation)| (Some(name.as_str()), &operation.node)),
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => operations
+            .iter()
+            .next()
+            .map(|(name, operation)| (Some(name.as_str()), &operation.node)),
+        (DocumentOperations::Multiple(_), None) => None,
+    }
+}
+
+fn collect_root_fields<'a>(
+    document: &'a ExecutableDocument,
+    selection_set: &'a SelectionSet,
+    visited_fragments: &mut HashSet<&'a str>,
+    fields: &mut Vec<&'a str>,
+) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => fields.push(field.node.name.node.as_str()),
+            Selection::InlineFragment(fragment) => {
+                collect_root_fields(document, &fragment.node.selection_set.node, visited_fragments, fields);
+            }
+            Selection::FragmentSpread(spread) => {
+                let name = spread.node.fragment_name.node.as_str();
+                if !visited_fragments.insert(name) {
+                    continue;
+                }
+                if let Some(fragment) = document.fragments.get(name) {
+                    collect_root_fields(document, &fragment.node.selection_set.node, visited_fragments, fields);
+                }
+            }
+        }
+    }
+}
+
+fn duration_ms(quota: RateLimitQuota) -> u64 {
+    u64::try_from(quota.duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        net::IpAddr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use common_types::auth::ExecutionAuth;
+    use engine::{
+        registry::{MetaField, MetaInputValue, ObjectType, RateLimitConfig, RateLimitKey, RateLimitQuota},
+        Registry,
+    };
+    use runtime::kv::{KvError, KvGet, KvPut, KvResult, KvStore};
+
+    use super::{take_tokens, take_window_slots, RateLimited, RateLimiter};
+
+    struct Context {
+        headers: http::HeaderMap,
+        client_ip: Option<IpAddr>,
+    }
+
+    impl Context {
+        fn new(client_ip: Option<&str>) -> Self {
+            Context {
+                headers: http::HeaderMap::new(),
+                client_ip: client_ip.map(|ip| ip.parse().unwrap()),
+            }
+        }
+
+        fn with_header(mut self, name: &'static str, value: &'static str) -> Self {
+            self.headers.insert(name, http::HeaderValue::from_static(value));
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::RequestContext for Context {
+        fn ray_id(&self) -> &str {
+            "ray-id"
+        }
+
+        async fn wait_until(&self, _fut: futures_util::future::BoxFuture<'static, ()>) {}
+
+        fn headers(&self) -> &http::HeaderMap {
+            &self.headers
+        }
+
+        fn client_ip(&self) -> Option<IpAddr> {
+            self.client_ip
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct TestKv(Arc<Mutex<HashMap<String, Vec<u8>>>>);
+
+    impl KvStore for TestKv {
+        type Error = KvError;
+        type Get = TestKvGet;
+        type Put = TestKvPut;
+
+        fn get(&self, name: &str) -> Self::Get {
+            TestKvGet(self.0.lock().unwrap().get(name).cloned())
+        }
+
+        fn put<T: serde::Serialize>(&self, name: &str, value: T) -> KvResult<Self::Put> {
+            Ok(TestKvPut {
+                kv: self.clone(),
+                name: name.to_string(),
+                bytes: serde_json::to_vec(&value).unwrap(),
+            })
+        }
+    }
+
+    struct TestKvGet(Option<Vec<u8>>);
+
+    #[async_trait::async_trait]
+    impl KvGet for TestKvGet {
+        type Error = KvError;
+
+        fn cache_ttl(self, _cache_ttl: Duration) -> Self {
+            self
+        }
+
+        async fn json<T: serde::de::DeserializeOwned>(self) -> KvResult<Option<T>> {
+            Ok(self.0.map(|bytes| serde_json::from_slice(&bytes).unwrap()))
+        }
+    }
+
+    struct TestKvPut {
+        kv: TestKv,
+        name: String,
+        bytes: Vec<u8>,
+    }
+
+    #[async_trait::async_trait]
+    impl KvPut for TestKvPut {
+        type Error = KvError;
+
+        fn expiration_ttl(self, _expiration_ttl: Duration) -> Self {
+            self
+        }
+
+        async fn execute(self) -> KvResult<()> {
+            self.kv.0.lock().unwrap().insert(self.name, self.bytes);
+            Ok(())
+        }
+    }
+
+    fn quota(limit: u32, seconds: u64) -> RateLimitQuota {
+        RateLimitQuota {
+            limit,
+            duration: Duration::from_secs(seconds),
+        }
+    }
+
+    fn limiter(config: RateLimitConfig) -> RateLimiter<TestKv> {
+        let mut registry = Registry {
+            rate_limiting: config,
+            ..Default::default()
+        };
+        let mut users = MetaField::new("users", "[User]");
+        users
+            .args
+            .insert("first".to_string(), MetaInputValue::new("first", "Int"));
+        registry.insert_type(ObjectType::new("Query", [users]));
+        registry.insert_type(ObjectType::new("User", [MetaField::new("name", "String")]));
+
+        RateLimiter::new(Arc::new(registry), TestKv::default())
+    }
+
+    fn quotas(limiter: &RateLimiter<TestKv>, query: &str) -> Vec<(String, RateLimitQuota)> {
+        let document = engine::parser::parse_query(query).ok();
+        limiter.quotas(&request(query), document.as_ref())
+    }
+
+    fn request(query: &str) -> engine::Request {
+        engine::Request::new(query)
+    }
+
+    fn public() -> ExecutionAuth {
+        ExecutionAuth::Public {
+            global_ops: Default::default(),
+        }
+    }
+
+    #[test]
+    fn global_and_operation_quotas() {
+        let limiter = limiter(RateLimitConfig {
+            global: Some(quota(10, 60)),
+            operations: BTreeMap::from([("Search".to_string(), quota(2, 60))]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            quotas(&limiter, "query Search { hello }"),
+            vec![("operation:Search".to_string(), quota(2, 60))]
+        );
+        assert_eq!(
+            quotas(&limiter, "query Other { hello }"),
+            vec![("global".to_string(), quota(10, 60))]
+        );
+    }
+
+    #[test]
+    fn root_field_quotas() {
+        let limiter = limiter(RateLimitConfig {
+            root_fields: BTreeMap::from([
+                ("Mutation.createUser".to_string(), quota(1, 60)),
+                ("Query.users".to_string(), quota(5, 60)),
+            ]),
+            ..Default::default()
+        });
+
+        let query = r"
+            mutation { ...Create createUser { id } }
+            fragment Create on Mutation { createUser { id } other }
+        ";
+        assert_eq!(
+            quotas(&limiter, query),
+            vec![("field:Mutation.createUser".to_string(), quota(1, 60))]
+        );
+        assert_eq!(quotas(&limiter, "query { hello }"), vec![]);
+    }
+
+    #[test]
+    fn cost_based_quotas() {
+        let cost = |limiter: &RateLimiter, query: &str| {
+            let document = engine::parser::parse_query(query).ok();
+            limiter.cost(&request(query), document.as_ref())
+        };
+
+        let by_request = limiter(RateLimitConfig::default());
+        assert_eq!(cost(&by_request, "{ users(first: 10) { name } }"), 1);
+
+        let limiter = limiter(RateLimitConfig {
+            cost: true,
+            ..Default::default()
+        });
+        assert_eq!(cost(&limiter, "{ users(first: 10) { name } }"), 11);
+        assert_eq!(cost(&limiter, "{ users { name } }"), 2);
+        assert_eq!(cost(&limiter, "not a query"), 1);
+
+        let quota = quota(5, 10);
+        let bucket = take_tokens(None, quota, 3, 0).unwrap();
+        assert!(take_tokens(Some(bucket), quota, 3, 0).is_err());
+
+        let window = take_window_slots(None, quota, 3, 0).unwrap();
+        let window = take_window_slots(Some(window), quota, 2, 0).unwrap();
+        assert_eq!(
+            take_window_slots(Some(window), quota, 1, 0),
+            Err(Duration::from_millis(10_000))
+        );
+    }
+
+    #[test]
+    fn client_keys() {
+        let ctx = Context::new(Some("10.0.0.3"))
+            .with_header("x-forwarded-for", "10.0.0.1, 10.0.0.2")
+            .with_header("x-tenant", "acme");
+
+        let by_ip = limiter(RateLimitConfig::default());
+        let by_proxy_ip = limiter(RateLimitConfig {
+            trust_proxy_headers: true,
+            ..Default::default()
+        });
+        let by_header = limiter(RateLimitConfig {
+            key: RateLimitKey::Header("x-tenant".to_string()),
+            ..Default::default()
+        });
+        let by_api_key = limiter(RateLimitConfig {
+            key: RateLimitKey::ApiKey,
+            ..Default::default()
+        });
+
+        // the same on every instance of the gateway
+        assert_eq!(
+            by_ip.client_key(&ctx, &public()),
+            "8de2f0fc0518a7779dde07762284913d7a3f27a8b58b432e5dc7502e870a2015"
+        );
+        // only the address the proxy received the request from is trusted
+        assert_eq!(
+            by_proxy_ip.client_key(&ctx, &public()),
+            by_ip.client_key(&Context::new(Some("10.0.0.2")), &public())
+        );
+        assert_ne!(by_header.client_key(&ctx, &public()), by_ip.client_key(&ctx, &public()));
+        // without an API key, the request is counted by its IP address
+        assert_eq!(
+            by_api_key.client_key(&ctx, &public()),
+            by_ip.client_key(&ctx, &public())
+        );
+    }
+
+    #[test]
+    fn token_bucket() {
+        let quota = quota(2, 10);
+
+        let bucket = take_tokens(None, quota, 1, 0).unwrap();
+        let bucket = take_tokens(Some(bucket), quota, 1, 0).unwrap();
+        assert!(matches!(
+            take_tokens(Some(bucket), quota, 1, 1000),
+            Err(wait) if wait.as_millis().abs_diff(4000) <= 1
+        ));
+        // one token every 5 seconds
+        let bucket = take_tokens(Some(bucket), quota, 1, 5001).unwrap();
+        assert!(take_tokens(Some(bucket), quota, 1, 5001).is_err());
+    }
+
+    #[test]
+    fn sliding_window() {
+        let quota = quota(2, 10);
+
+        let window = take_window_slots(None, quota, 1, 0).unwrap();
+        let window = take_window_slots(Some(window), quota, 1, 1000).unwrap();
+        assert_eq!(
+            take_window_slots(Some(window), quota, 1, 2000),
+            Err(Duration::from_millis(8000))
+        );
+        // half of the previous window still counts, so a single request is allowed
+        let window = take_window_slots(Some(window), quota, 1, 15000).unwrap();
+        assert_eq!(
+            take_window_slots(Some(window), quota, 1, 15000),
+            Err(Duration::from_millis(5000))
+        );
+        assert!(take_window_slots(Some(window), quota, 1, 30000).is_ok());
+    }
+
+    #[tokio::test]
+    async fn unidentified_clients_are_limited_together() {
+        let limiter = limiter(RateLimitConfig {
+            global: Some(quota(1, 60)),
+            ..Default::default()
+        });
+        let request = request("{ hello }");
+
+        let anonymous = Context::new(None);
+        assert_eq!(limiter.check(&anonymous, &request, &public()).await, Ok(()));
+        assert!(matches!(
+            limiter.check(&anonymous, &request, &public()).await,
+            Err(RateLimited::Exceeded { retry_after }) if retry_after <= Duration::from_secs(60)
+        ));
+
+        let client = Context::new(Some("10.0.0.1"));
+        assert_eq!(limiter.check(&client, &request, &public()).await, Ok(()));
+        assert!(limiter.check(&client, &request, &public()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn rejected_requests_are_not_counted() {
+        let limiter = limiter(RateLimitConfig {
+            global: Some(quota(2, 60)),
+            root_fields: BTreeMap::from([("Query.users".to_string(), quota(1, 60))]),
+            ..Default::default()
+        });
+        let client = Context::new(Some("10.0.0.1"));
+        let users = request("{ users { name } }");
+        let hello = request("{ hello }");
+
+        assert_eq!(limiter.check(&client, &users, &public()).await, Ok(()));
+        // rejected by the quota of the field, which doesn't count against the global one
+        assert!(limiter.check(&client, &users, &public()).await.is_err());
+        assert_eq!(limiter.check(&client, &hello, &public()).await, Ok(()));
+        assert!(limiter.check(&client, &hello, &public()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn operations_costlier_than_the_quota_are_rejected() {
+        let limiter = limiter(RateLimitConfig {
+            global: Some(quota(5, 60)),
+            cost: true,
+            ..Default::default()
+        });
+        let client = Context::new(Some("10.0.0.1"));
+
+        assert_eq!(
+            limiter
+                .check(&client, &request("{ users(first: 10) { name } }"), &public())
+                .await,
+            Err(RateLimited::TooExpensive { cost: 11, limit: 5 })
+        );
+        // which doesn't count against the quota
+        for _ in 0..2 {
+            assert_eq!(
+                limiter.check(&client, &request("{ users { name } }"), &public()).await,
+                Ok(())
+            );
+        }
+        assert!(matches!(
+            limiter.check(&client, &request("{ users { name } }"), &public()).await,
+            Err(RateLimited::Exceeded { .. })
+        ));
+    }
+}
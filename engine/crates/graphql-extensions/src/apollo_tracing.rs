@@ -0,0 +1,176 @@
+//! Field level tracing in the format of Apollo tracing, which is returned in the `tracing`
+//! extension of the response for requests with the [`TRACING_HEADER`].
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use engine::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute, NextResolve, ResolveInfo},
+    graph_entities::ResponseNodeId,
+    parser::types::OperationDefinition,
+    QueryPathSegment, RequestHeaders, Response, ServerResult,
+};
+use engine_value::ConstValue;
+use serde_json::json;
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Requests with this header get the timings of their resolvers back.
+pub const TRACING_HEADER: &str = "x-grafbase-tracing";
+
+const TRACING_EXTENSION: &str = "tracing";
+
+/// Apollo tracing extension
+///
+/// Records when each field of the operation started resolving, how long it took and the kind of
+/// resolver it used. It's a no-op for requests without the [`TRACING_HEADER`].
+pub struct ApolloTracing;
+
+impl ExtensionFactory for ApolloTracing {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ApolloTracingExtension {
+            trace: Mutex::new(None),
+        })
+    }
+}
+
+struct ApolloTracingExtension {
+    // only set while executing an operation that asked for tracing
+    trace: Mutex<Option<Trace>>,
+}
+
+struct Trace {
+    start_time: SystemTime,
+    start: Instant,
+    resolvers: Vec<ResolverTrace>,
+}
+
+struct ResolverTrace {
+    path: Vec<QueryPathSegment>,
+    parent_type: String,
+    field_name: String,
+    return_type: String,
+    resolver_kind: Option<&'static str>,
+    start_offset: Duration,
+    duration: Duration,
+}
+
+impl ApolloTracingExtension {
+    fn elapsed(&self) -> Option<Duration> {
+        let trace = self.trace.lock().unwrap();
+        trace.as_ref().map(|trace| trace.start.elapsed())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for ApolloTracingExtension {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        operation: &OperationDefinition,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let enabled = ctx
+            .data_opt::<RequestHeaders>()
+            .and_then(|headers| headers.find(TRACING_HEADER))
+            .is_some();
+
+        if !enabled {
+            return next.run(ctx, operation_name, operation).await;
+        }
+
+        *self.trace.lock().unwrap() = Some(Trace {
+            start_time: SystemTime::now(),
+            start: Instant::now(),
+            resolvers: Vec::new(),
+        });
+
+        let response = next.run(ctx, operation_name, operation).await;
+
+        let trace = self.trace.lock().unwrap().take();
+        match trace {
+            Some(trace) => response.extension(TRACING_EXTENSION, trace.into_value()),
+            None => response,
+        }
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<ResponseNodeId>> {
+        let Some(start_offset) = self.elapsed() else {
+            return next.run(ctx, info).await;
+        };
+
+        let path = info.path.iter().cloned().collect();
+        let parent_type = info.parent_type.to_string();
+        let field_name = info.name.to_string();
+        let return_type = info.return_type.to_string();
+        let resolver_kind = info.resolver.and_then(|resolver| resolver.kind());
+
+        let result = next.run(ctx, info).await;
+
+        if let Some(trace) = self.trace.lock().unwrap().as_mut() {
+            trace.resolvers.push(ResolverTrace {
+                path,
+                parent_type,
+                field_name,
+                return_type,
+                resolver_kind,
+                start_offset,
+                duration: trace.start.elapsed().saturating_sub(start_offset),
+            });
+        }
+
+        result
+    }
+}
+
+impl Trace {
+    fn into_value(mut self) -> ConstValue {
+        let duration = self.start.elapsed();
+        let end_time = self.start_time + duration;
+
+        self.resolvers.sort_by_key(|resolver| resolver.start_offset);
+        let resolvers = self
+            .resolvers
+            .into_iter()
+            .map(|resolver| {
+                json!({
+                    "path": resolver.path,
+                    "parentType": resolver.parent_type,
+                    "fieldName": resolver.field_name,
+                    "returnType": resolver.return_type,
+                    "resolverKind": resolver.resolver_kind,
+                    "startOffset": nanos(resolver.start_offset),
+                    "duration": nanos(resolver.duration),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        ConstValue::from_json(json!({
+            "version": 1,
+            "startTime": rfc3339(self.start_time),
+            "endTime": rfc3339(end_time),
+            "duration": nanos(duration),
+            "execution": {
+                "resolvers": resolvers,
+            },
+        }))
+        .unwrap_or_default()
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn rfc3339(time: SystemTime) -> Option<String> {
+    let nanos = time.duration_since(UNIX_EPOCH).ok()?.as_nanos();
+    let time = time::OffsetDateTime::from_unix_timestamp_nanos(i128::try_from(nanos).ok()?).ok()?;
+
+    time.format(&time::format_description::well_known::Rfc3339).ok()
+}
@@ -1,2 +1,3 @@
+pub mod apollo_tracing;
 pub mod authorization;
 pub mod runtime_log;
@@ -1,7 +1,14 @@
 use super::{dynamo::enable_local_dynamo, Inner};
 use crate::Engine;
-use engine::{registry::resolvers::graphql::QueryBatcher, Schema};
+use common_types::auth::ExecutionAuth;
+use engine::{
+    registry::resolvers::{
+        custom::CustomResolverBatcher, graphql::QueryBatcher, http_client::HttpClient, join::JoinBatcher,
+    },
+    Schema,
+};
 use futures::future::BoxFuture;
+use graphql_extensions::apollo_tracing::ApolloTracing;
 use parser_sdl::{ConnectorParsers, GraphqlDirective, OpenApiDirective, ParseResult, PostgresDirective, Registry};
 use postgres_types::transport::TcpTransport;
 use runtime::udf::{CustomResolverRequestPayload, CustomResolversEngine, UdfInvoker};
@@ -13,6 +20,7 @@ pub struct EngineBuilder {
     openapi_specs: HashMap<String, String>,
     environment_variables: HashMap<String, String>,
     custom_resolvers: Option<CustomResolversEngine>,
+    auth: Option<ExecutionAuth>,
     local_dynamo: bool,
 }
 
@@ -43,6 +51,7 @@ impl EngineBuilder {
             openapi_specs: HashMap::new(),
             environment_variables: HashMap::new(),
             custom_resolvers: None,
+            auth: None,
             local_dynamo: false,
         }
     }
@@ -72,6 +81,14 @@ impl EngineBuilder {
         }
     }
 
+    /// Executes the requests as if they had been authorized with the given identity.
+    pub fn with_auth(self, auth: ExecutionAuth) -> Self {
+        Self {
+            auth: Some(auth),
+            ..self
+        }
+    }
+
     pub async fn build(self) -> Engine {
         let ParseResult { registry, .. } = parser_sdl::parse(&self.schema, &self.environment_variables, true, &self)
             .await
@@ -79,8 +96,12 @@ impl EngineBuilder {
 
         let registry = serde_json::from_value(serde_json::to_value(registry).unwrap()).unwrap();
 
+        let http_client = HttpClient::new();
+
         let mut schema_builder = Schema::build(registry)
-            .data(QueryBatcher::new())
+            .data(QueryBatcher::with_client(http_client.clone()))
+            .data(JoinBatcher::new())
+            .data(http_client)
             .data(runtime::Context::new(
                 &Arc::new(RequestContext {
                     ray_id: String::new(),
@@ -91,14 +112,23 @@ impl EngineBuilder {
                     request_log_event_id: None,
                 },
             ))
-            .data(runtime_local::LocalPgTransportFactory::runtime_factory());
+            .data(runtime_local::LocalPgTransportFactory::runtime_factory())
+            .data(runtime_local::LocalMongoTransport::runtime_transport())
+            .data(runtime_local::InMemoryKv::runtime_kv())
+            .extension(ApolloTracing);
 
         if self.local_dynamo {
             schema_builder = enable_local_dynamo(schema_builder).await;
         }
 
         if let Some(custom_resolvers) = self.custom_resolvers {
-            schema_builder = schema_builder.data(custom_resolvers);
+            schema_builder = schema_builder
+                .data(CustomResolverBatcher::new(custom_resolvers.clone()))
+                .data(custom_resolvers);
+        }
+
+        if let Some(auth) = self.auth {
+            schema_builder = schema_builder.data(auth);
         }
 
         let schema = schema_builder.finish();
@@ -133,17 +163,41 @@ impl ConnectorParsers for EngineBuilder {
     }
 
     async fn fetch_and_parse_graphql(&self, directive: GraphqlDirective) -> Result<Registry, Vec<String>> {
+        let prefix = directive
+            .transforms
+            .as_ref()
+            .and_then(|transforms| transforms.prefix_types.as_deref());
+
+        // URLs aren't read, the schema is introspected from the url of the directive instead.
+        let schema_path = directive
+            .schema
+            .as_deref()
+            .filter(|schema| !matches!(url::Url::parse(schema), Ok(url) if matches!(url.scheme(), "http" | "https")));
+
+        if let Some(schema) = schema_path {
+            let sdl = std::fs::read_to_string(schema).map_err(|error| vec![error.to_string()])?;
+
+            return parser_graphql::parse_sdl(
+                &directive.name,
+                directive.namespace,
+                &directive.url,
+                directive.headers(),
+                directive.fetch_policy(),
+                &sdl,
+                prefix,
+            )
+            .map_err(|errors| errors.into_iter().map(|error| error.to_string()).collect::<Vec<_>>());
+        }
+
         parser_graphql::parse_schema(
             reqwest::Client::new(),
             &directive.name,
             directive.namespace,
             &directive.url,
             directive.headers(),
+            directive.fetch_policy(),
             directive.introspection_headers(),
-            directive
-                .transforms
-                .as_ref()
-                .and_then(|transforms| transforms.prefix_types.as_deref()),
+            prefix,
         )
         .await
         .map_err(|errors| errors.into_iter().map(|error| error.to_string()).collect::<Vec<_>>())
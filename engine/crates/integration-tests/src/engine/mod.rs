@@ -1,9 +1,9 @@
 mod builder;
 mod dynamo;
 
-use std::{collections::HashMap, future::IntoFuture, sync::Arc};
+use std::{collections::HashMap, future::IntoFuture, io::Write, sync::Arc};
 
-use engine::{Request, RequestHeaders, Response, Schema, StreamingPayload, Variables};
+use engine::{Request, RequestHeaders, Response, Schema, StreamingPayload, UploadValue, Variables};
 use futures::{future::BoxFuture, Stream, StreamExt};
 
 pub use self::builder::EngineBuilder;
@@ -27,6 +27,7 @@ impl Engine {
         ExecutionRequest {
             graphql: operation.into(),
             headers: HashMap::new(),
+            uploads: Vec::new(),
             schema: &self.inner.schema,
         }
     }
@@ -44,6 +45,7 @@ impl Engine {
 pub struct ExecutionRequest<'a> {
     graphql: GraphQlRequest,
     headers: HashMap<String, String>,
+    uploads: Vec<(String, UploadValue)>,
     schema: &'a Schema,
 }
 
@@ -60,6 +62,30 @@ impl ExecutionRequest<'_> {
         ));
         self
     }
+
+    /// Uploads a file into the variable at `variable_path` (e.g. `variables.file`), as a
+    /// multipart request would
+    pub fn upload(
+        mut self,
+        variable_path: impl Into<String>,
+        filename: impl Into<String>,
+        content_type: Option<&str>,
+        content: impl AsRef<[u8]>,
+    ) -> Self {
+        let mut file = tempfile::tempfile().expect("to be able to create a temporary file");
+        file.write_all(content.as_ref())
+            .expect("to be able to write a temporary file");
+
+        self.uploads.push((
+            variable_path.into(),
+            UploadValue {
+                filename: filename.into(),
+                content_type: content_type.map(ToOwned::to_owned),
+                content: file,
+            },
+        ));
+        self
+    }
 }
 
 impl<'a> IntoFuture for ExecutionRequest<'a> {
@@ -76,6 +102,9 @@ impl<'a> IntoFuture for ExecutionRequest<'a> {
             if let Some(variables) = self.graphql.variables {
                 request = request.variables(variables);
             }
+            for (variable_path, upload) in self.uploads {
+                request.set_upload(&variable_path, upload);
+            }
             self.schema.execute(request).await
         })
     }
@@ -11,7 +11,7 @@ pub mod udfs;
 pub use crate::engine::{Engine, EngineBuilder};
 pub use helpers::{GetPath, ResponseExt};
 pub use mocks::graphql::MockGraphQlServer;
-pub use mongodb::{with_mongodb, with_namespaced_mongodb};
+pub use mongodb::{with_mongodb, with_mongodb_wire_protocol, with_namespaced_mongodb};
 pub use types::{Error, ResponseData};
 
 use names::{Generator, Name};
@@ -1,12 +1,22 @@
 //! A mock GraphQL server for testing the GraphQL connector
 
-use std::{net::TcpListener, time::Duration};
+use std::{convert::Infallible, net::TcpListener, time::Duration};
 
 use async_graphql::{
-    EmptyMutation, EmptySubscription, InputObject, Interface, Object, Schema, SimpleObject, Union, ID,
+    EmptyMutation, EmptySubscription, InputObject, Interface, Object, Schema, SimpleObject, Subscription, Union, ID,
 };
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
-use axum::{http::HeaderMap, routing::post, Router};
+use axum::{
+    extract::State,
+    http::{header::ACCEPT, HeaderMap},
+    response::{
+        sse::{Event, Sse},
+        IntoResponse, Response,
+    },
+    routing::post,
+    Router,
+};
+use futures::{stream, Stream, StreamExt};
 
 pub struct MockGraphQlServer {
     shutdown: Option<tokio::sync::oneshot::Sender<()>>,
@@ -23,7 +33,18 @@ impl Drop for MockGraphQlServer {
 
 impl MockGraphQlServer {
     pub async fn new() -> MockGraphQlServer {
-        let app = Router::new().route("/", post(graphql_handler));
+        Self::start(false).await
+    }
+
+    /// Starts a server that also exposes a `Subscription` type, served over GraphQL over SSE
+    pub async fn with_subscriptions() -> MockGraphQlServer {
+        Self::start(true).await
+    }
+
+    async fn start(subscriptions: bool) -> MockGraphQlServer {
+        let app = Router::new()
+            .route("/", post(graphql_handler))
+            .with_state(subscriptions);
 
         let socket = TcpListener::bind("127.0.0.1:0").unwrap();
         let port = socket.local_addr().unwrap().port();
@@ -55,7 +76,12 @@ impl MockGraphQlServer {
     }
 }
 
-async fn graphql_handler(headers: HeaderMap, req: GraphQLRequest) -> GraphQLResponse {
+async fn graphql_handler(State(subscriptions): State<bool>, headers: HeaderMap, req: GraphQLRequest) -> Response {
+    let accepts_event_stream = headers
+        .get(ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/event-stream"));
+
     let headers = headers
         .into_iter()
         .map(|(name, value)| {
@@ -65,9 +91,25 @@ async fn graphql_handler(headers: HeaderMap, req: GraphQLRequest) -> GraphQLResp
             )
         })
         .collect();
-    let schema = Schema::build(Query { headers }, EmptyMutation, EmptySubscription).finish();
+    let query = Query { headers };
+
+    if !subscriptions {
+        let schema = Schema::build(query, EmptyMutation, EmptySubscription).finish();
+        return GraphQLResponse::from(schema.execute(req.into_inner()).await).into_response();
+    }
 
-    schema.execute(req.into_inner()).await.into()
+    let schema = Schema::build(query, EmptyMutation, Subscription).finish();
+    if !accepts_event_stream {
+        return GraphQLResponse::from(schema.execute(req.into_inner()).await).into_response();
+    }
+
+    let events = schema
+        .execute_stream(req.into_inner())
+        .map(|response| Event::default().event("next").json_data(response).unwrap())
+        .chain(stream::once(async { Event::default().event("complete").data("") }))
+        .map(Ok::<_, Infallible>);
+
+    Sse::new(events).into_response()
 }
 
 struct Query {
@@ -135,6 +177,21 @@ impl Query {
         None
     }
 
+    // Out of order and skipping unknown users, for batched joins
+    async fn users_by_emails(&self, emails: Vec<String>) -> Vec<User> {
+        emails
+            .into_iter()
+            .rev()
+            .filter_map(|email| {
+                let name = email.strip_suffix("@example.com")?;
+                Some(User {
+                    name: name.to_uppercase(),
+                    email,
+                })
+            })
+            .collect()
+    }
+
     async fn headers(&self) -> Vec<Header> {
         self.headers
             .clone()
@@ -144,6 +201,29 @@ impl Query {
     }
 }
 
+struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    async fn pull_requests(&self) -> impl Stream<Item = PullRequest> {
+        stream::iter([
+            PullRequest {
+                title: "Creating the thing".into(),
+                checks: vec!["Success!".into()],
+                author: UserOrBot::User(User {
+                    name: "Jim".into(),
+                    email: "jim@example.com".into(),
+                }),
+            },
+            PullRequest {
+                title: "Some bot PR".into(),
+                checks: vec!["Success!".into()],
+                author: UserOrBot::Bot(Bot { id: "123".into() }),
+            },
+        ])
+    }
+}
+
 #[derive(SimpleObject)]
 struct Header {
     name: String,
@@ -0,0 +1,152 @@
+use graphql_extensions::apollo_tracing::TRACING_HEADER;
+use integration_tests::{runtime, udfs::RustUdfs, EngineBuilder, ResponseExt};
+use runtime::udf::{CustomResolverRequestPayload, CustomResolverResponse};
+use serde_json::{json, Value};
+
+const SCHEMA: &str = r#"
+    type Query {
+        list: [Item]! @resolver(name: "list")
+    }
+
+    type Item {
+        id: Int!
+        double: Int! @resolver(name: "double")
+    }
+"#;
+
+fn engine() -> EngineBuilder {
+    EngineBuilder::new(SCHEMA).with_custom_resolvers(
+        RustUdfs::new()
+            .resolver("list", CustomResolverResponse::Success(json!([{"id": 1}, {"id": 2}])))
+            .resolver("double", |payload: CustomResolverRequestPayload| {
+                let id = payload.parent.unwrap()["id"].as_i64().unwrap();
+                Ok(CustomResolverResponse::Success(json!(id * 2)))
+            }),
+    )
+}
+
+#[test]
+fn resolvers_are_traced_with_the_header() {
+    runtime().block_on(async {
+        let engine = engine().build().await;
+
+        let mut response = engine
+            .execute("query { list { id double } }")
+            .header(TRACING_HEADER, "1")
+            .await
+            .into_value();
+
+        let tracing = response["extensions"]["tracing"].take();
+        assert_eq!(tracing["version"], 1);
+        assert!(tracing["startTime"].is_string(), "{tracing}");
+        assert!(tracing["endTime"].is_string(), "{tracing}");
+        assert!(tracing["duration"].is_u64(), "{tracing}");
+
+        let resolvers = without_timings(&tracing["execution"]["resolvers"]);
+
+        insta::assert_json_snapshot!(resolvers, @r###"
+        [
+          {
+            "fieldName": "list",
+            "parentType": "Query",
+            "path": [
+              "list"
+            ],
+            "resolverKind": "custom",
+            "returnType": "[Item]!"
+          },
+          {
+            "fieldName": "double",
+            "parentType": "Item",
+            "path": [
+              "list",
+              0,
+              "double"
+            ],
+            "resolverKind": "custom",
+            "returnType": "Int!"
+          },
+          {
+            "fieldName": "id",
+            "parentType": "Item",
+            "path": [
+              "list",
+              0,
+              "id"
+            ],
+            "resolverKind": null,
+            "returnType": "Int!"
+          },
+          {
+            "fieldName": "double",
+            "parentType": "Item",
+            "path": [
+              "list",
+              1,
+              "double"
+            ],
+            "resolverKind": "custom",
+            "returnType": "Int!"
+          },
+          {
+            "fieldName": "id",
+            "parentType": "Item",
+            "path": [
+              "list",
+              1,
+              "id"
+            ],
+            "resolverKind": null,
+            "returnType": "Int!"
+          }
+        ]
+        "###);
+    });
+}
+
+#[test]
+fn resolvers_are_not_traced_without_the_header() {
+    runtime().block_on(async {
+        let engine = engine().build().await;
+
+        insta::assert_json_snapshot!(
+            engine.execute("query { list { id } }").await.into_value(),
+            @r###"
+        {
+          "data": {
+            "list": [
+              {
+                "id": 1
+              },
+              {
+                "id": 2
+              }
+            ]
+          }
+        }
+        "###
+        );
+    });
+}
+
+/// Drops the timings, which differ on every run, after checking they're consistent. The resolvers
+/// are sorted by path as the order they start in isn't deterministic either.
+fn without_timings(resolvers: &Value) -> Vec<Value> {
+    let mut resolvers = resolvers
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|resolver| {
+            let mut resolver = resolver.as_object().unwrap().clone();
+            assert!(resolver.remove("startOffset").unwrap().is_u64());
+            assert!(resolver.remove("duration").unwrap().is_u64());
+            Value::Object(resolver)
+        })
+        .collect::<Vec<_>>();
+
+    resolvers.sort_by_key(|resolver| {
+        let path = resolver["path"].as_array().unwrap();
+        (path.len(), Value::Array(path.clone()).to_string())
+    });
+    resolvers
+}
@@ -1,3 +1,12 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common_types::auth::{ExecutionAuth, Operations};
 use integration_tests::{runtime, udfs::RustUdfs, EngineBuilder, ResponseExt};
 use runtime::udf::{CustomResolverRequestPayload, CustomResolverResponse};
 use serde_json::json;
@@ -106,6 +115,7 @@ fn custom_resolver_context() {
               {
                 "item": {
                   "fieldName": "item",
+                  "parentType": "Object",
                   "path": {
                     "key": "item",
                     "prev": {
@@ -119,12 +129,15 @@ fn custom_resolver_context() {
                     },
                     "typename": "JSON!"
                   },
+                  "returnType": "JSON!",
+                  "selectionSet": [],
                   "variableValues": {}
                 }
               },
               {
                 "item": {
                   "fieldName": "item",
+                  "parentType": "Object",
                   "path": {
                     "key": "item",
                     "prev": {
@@ -138,6 +151,8 @@ fn custom_resolver_context() {
                     },
                     "typename": "JSON!"
                   },
+                  "returnType": "JSON!",
+                  "selectionSet": [],
                   "variableValues": {}
                 }
               }
@@ -148,3 +163,171 @@ fn custom_resolver_context() {
         );
     });
 }
+
+#[test]
+fn batched_custom_resolver() {
+    runtime().block_on(async {
+        let schema = r#"
+            type Query {
+                list: [Object]! @resolver(name: "list")
+            }
+
+            type Object {
+                item: Int @resolver(name: "item", batch: true)
+            }
+        "#;
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "list",
+                        CustomResolverResponse::Success(json!([{"id": 1}, {"id": 2}, {"id": 3}])),
+                    )
+                    .resolver("item", {
+                        let invocations = Arc::clone(&invocations);
+                        move |payload: CustomResolverRequestPayload| {
+                            invocations.fetch_add(1, Ordering::SeqCst);
+                            let results = payload
+                                .parent
+                                .unwrap()
+                                .as_array()
+                                .unwrap()
+                                .iter()
+                                .map(|item| match item["parent"]["id"].as_i64().unwrap() {
+                                    2 => json!({ "error": { "message": "no item for 2" } }),
+                                    id => json!({ "data": id * 10 }),
+                                })
+                                .collect();
+                            Ok(CustomResolverResponse::Success(serde_json::Value::Array(results)))
+                        }
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine.execute("query { list { item } }").await.into_value(),
+            @r###"
+        {
+          "data": {
+            "list": [
+              {
+                "item": 10
+              },
+              {
+                "item": null
+              },
+              {
+                "item": 30
+              }
+            ]
+          },
+          "errors": [
+            {
+              "locations": [
+                {
+                  "column": 16,
+                  "line": 1
+                }
+              ],
+              "message": "no item for 2",
+              "path": [
+                "list",
+                1,
+                "item"
+              ]
+            }
+          ]
+        }
+        "###
+        );
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn custom_resolver_auth_and_selection_set() {
+    runtime().block_on(async {
+        let schema = r#"
+            type Query {
+                user: User @resolver(name: "user")
+            }
+
+            type User {
+                name: String
+                friends(first: Int): [User]
+            }
+        "#;
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new().resolver("user", |payload: CustomResolverRequestPayload| {
+                    let info = payload.info.unwrap();
+                    Ok(CustomResolverResponse::Success(json!({
+                        "name": serde_json::to_string(&json!({
+                            "auth": payload.context.auth,
+                            "parentType": info["parentType"],
+                            "returnType": info["returnType"],
+                            "selectionSet": info["selectionSet"],
+                        }))
+                        .unwrap(),
+                    })))
+                }),
+            )
+            .with_auth(ExecutionAuth::new_from_token(
+                Operations::all(),
+                BTreeSet::from(["admin".to_string()]),
+                Some(("user-1".to_string(), Operations::all())),
+                BTreeMap::from([("plan".to_string(), json!("pro"))]),
+            ))
+            .build()
+            .await;
+
+        let response = engine
+            .execute("query { user { name buddies: friends(first: 2) { name } } }")
+            .await
+            .into_value();
+        let payload: serde_json::Value =
+            serde_json::from_str(response["data"]["user"]["name"].as_str().unwrap()).unwrap();
+
+        insta::assert_json_snapshot!(payload, @r###"
+        {
+          "auth": {
+            "claims": {
+              "plan": "pro"
+            },
+            "groups": [
+              "admin"
+            ],
+            "subject": "user-1",
+            "type": "token"
+          },
+          "parentType": "Query",
+          "returnType": "User",
+          "selectionSet": [
+            {
+              "alias": null,
+              "arguments": {},
+              "name": "name",
+              "selectionSet": []
+            },
+            {
+              "alias": "buddies",
+              "arguments": {
+                "first": 2
+              },
+              "name": "friends",
+              "selectionSet": [
+                {
+                  "alias": null,
+                  "arguments": {},
+                  "name": "name",
+                  "selectionSet": []
+                }
+              ]
+            }
+          ]
+        }
+        "###);
+    });
+}
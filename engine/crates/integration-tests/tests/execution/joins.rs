@@ -1,5 +1,10 @@
 //! Tests of the join directive
 
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
 use integration_tests::{runtime, udfs::RustUdfs, EngineBuilder, MockGraphQlServer, ResponseExt};
 use runtime::udf::{CustomResolverRequestPayload, CustomResolverResponse};
 use serde_json::{json, Value};
@@ -113,3 +118,295 @@ fn join_on_connector_type() {
         );
     });
 }
+
+#[test]
+fn join_on_connector_namespace() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::new().await;
+        let port = graphql_mock.port();
+
+        let schema = format!(
+            r#"
+            extend schema
+                @graphql(
+                    name: "gothub",
+                    namespace: true,
+                    url: "http://127.0.0.1:{port}",
+                )
+
+            extend type Query {{
+                ticket: Ticket! @resolver(name: "ticket")
+            }}
+
+            type Ticket {{
+                issueId: ID!
+                issue: GothubPullRequestOrIssue @join(select: "gothub {{ pullRequestOrIssue(id: $issueId) }}")
+            }}
+            "#
+        );
+
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new().resolver("ticket", CustomResolverResponse::Success(json!({"issueId": "3"}))),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(r#"
+                query {
+                    ticket {
+                        issue {
+                            __typename
+                            title
+                        }
+                    }
+                }
+                "#)
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "ticket": {
+            "issue": {
+              "__typename": "GothubIssue",
+              "title": "Everythings fucked"
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn batched_join_by_key() {
+    runtime().block_on(async {
+        let schema = r#"
+            extend type Query {
+                usersByIds(ids: [ID!]!): [User!]! @resolver(name: "usersByIds")
+                posts: [Post!]! @resolver(name: "posts")
+            }
+
+            type User {
+                id: ID!
+                name: String!
+            }
+
+            type Post {
+                title: String!
+                authorId: ID!
+                author: User @join(select: "usersByIds(ids: $authorId)", batch: { key: "id" })
+            }
+        "#;
+
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "posts",
+                        CustomResolverResponse::Success(json!([
+                            {"title": "Hello", "authorId": "1"},
+                            {"title": "Bonjour", "authorId": "2"},
+                            {"title": "Hallo", "authorId": "1"},
+                            {"title": "Hola", "authorId": "3"},
+                        ])),
+                    )
+                    .resolver("usersByIds", {
+                        let invocations = Arc::clone(&invocations);
+                        move |input: CustomResolverRequestPayload| {
+                            invocations.fetch_add(1, Ordering::SeqCst);
+                            // Out of order and without user 3, to make sure users are matched by id.
+                            let mut ids = input.arguments["ids"].as_array().unwrap().clone();
+                            ids.reverse();
+                            let users = ids
+                                .iter()
+                                .filter(|id| id.as_str() != Some("3"))
+                                .map(|id| json!({"id": id, "name": format!("User {}", id.as_str().unwrap())}))
+                                .collect::<Vec<_>>();
+                            Ok(CustomResolverResponse::Success(users.into()))
+                        }
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute("{ posts { title author { name } } }")
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "posts": [
+            {
+              "author": {
+                "name": "User 1"
+              },
+              "title": "Hello"
+            },
+            {
+              "author": {
+                "name": "User 2"
+              },
+              "title": "Bonjour"
+            },
+            {
+              "author": {
+                "name": "User 1"
+              },
+              "title": "Hallo"
+            },
+            {
+              "author": null,
+              "title": "Hola"
+            }
+          ]
+        }
+        "###
+        );
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn batched_join_by_position() {
+    runtime().block_on(async {
+        let schema = r#"
+            extend type Query {
+                greetings(names: [String!]!): [String!]! @resolver(name: "greetings")
+                users: [User!]! @resolver(name: "users")
+            }
+
+            type User {
+                name: String!
+                greeting: String! @join(select: "greetings(names: $name)", batch: {})
+            }
+        "#;
+
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "users",
+                        CustomResolverResponse::Success(json!([{"name": "Bob"}, {"name": "Alice"}])),
+                    )
+                    .resolver("greetings", {
+                        let invocations = Arc::clone(&invocations);
+                        move |input: CustomResolverRequestPayload| {
+                            invocations.fetch_add(1, Ordering::SeqCst);
+                            let greetings = input.arguments["names"]
+                                .as_array()
+                                .unwrap()
+                                .iter()
+                                .map(|name| format!("Hello {}", name.as_str().unwrap()))
+                                .collect::<Vec<_>>();
+                            Ok(CustomResolverResponse::Success(greetings.into()))
+                        }
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute("{ users { greeting } }")
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "users": [
+            {
+              "greeting": "Hello Bob"
+            },
+            {
+              "greeting": "Hello Alice"
+            }
+          ]
+        }
+        "###
+        );
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn batched_join_by_unselected_key_on_connector() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::new().await;
+        let port = graphql_mock.port();
+
+        let schema = format!(
+            r#"
+            extend schema
+                @graphql(
+                    name: "gothub",
+                    namespace: false,
+                    url: "http://127.0.0.1:{port}",
+                )
+
+            extend type Query {{
+                commits: [Commit!]! @resolver(name: "commits")
+            }}
+
+            type Commit {{
+                message: String!
+                authorEmail: String!
+                author: User @join(select: "usersByEmails(emails: $authorEmail)", batch: {{ key: "email" }})
+            }}
+            "#
+        );
+
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(RustUdfs::new().resolver(
+                "commits",
+                CustomResolverResponse::Success(json!([
+                    {"message": "Initial commit", "authorEmail": "jim@example.com"},
+                    {"message": "Fix everything", "authorEmail": "pessimist@example.com"},
+                    {"message": "Break everything", "authorEmail": "someone@example.org"},
+                    {"message": "Revert", "authorEmail": "jim@example.com"},
+                ])),
+            ))
+            .build()
+            .await;
+
+        // The email is needed to match the users with their commits but isn't selected.
+        insta::assert_json_snapshot!(
+            engine
+                .execute("{ commits { message author { name } } }")
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "commits": [
+            {
+              "author": {
+                "name": "JIM"
+              },
+              "message": "Initial commit"
+            },
+            {
+              "author": {
+                "name": "PESSIMIST"
+              },
+              "message": "Fix everything"
+            },
+            {
+              "author": null,
+              "message": "Break everything"
+            },
+            {
+              "author": {
+                "name": "JIM"
+              },
+              "message": "Revert"
+            }
+          ]
+        }
+        "###
+        );
+    });
+}
@@ -0,0 +1,132 @@
+use std::io::Write;
+
+use integration_tests::{runtime, EngineBuilder, ResponseExt};
+use serde_json::json;
+use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn upstream_errors_are_forwarded() {
+    runtime().block_on(async {
+        let mock_server = MockServer::start().await;
+        mock_rate_limited(&mock_server).await;
+
+        let sdl_file = sdl_file();
+        let engine = EngineBuilder::new(schema(mock_server.address(), &sdl_file, None))
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine.execute("query { gothub { serverVersion } }").await.into_value(),
+            @r###"
+        {
+          "data": {
+            "gothub": {
+              "serverVersion": null
+            }
+          },
+          "errors": [
+            {
+              "extensions": {
+                "code": "RATE_LIMITED",
+                "internalTrace": "upstream-1234"
+              },
+              "locations": [
+                {
+                  "column": 9,
+                  "line": 1
+                }
+              ],
+              "message": "Too many requests",
+              "path": [
+                "gothub",
+                "serverVersion"
+              ]
+            }
+          ]
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn upstream_error_extensions_can_be_restricted() {
+    runtime().block_on(async {
+        let mock_server = MockServer::start().await;
+        mock_rate_limited(&mock_server).await;
+
+        let sdl_file = sdl_file();
+        let engine = EngineBuilder::new(schema(mock_server.address(), &sdl_file, Some(r#"["code"]"#)))
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine.execute("query { gothub { serverVersion } }").await.into_value()["errors"],
+            @r###"
+        [
+          {
+            "extensions": {
+              "code": "RATE_LIMITED"
+            },
+            "locations": [
+              {
+                "column": 9,
+                "line": 1
+              }
+            ],
+            "message": "Too many requests",
+            "path": [
+              "gothub",
+              "serverVersion"
+            ]
+          }
+        ]
+        "###
+        );
+    });
+}
+
+async fn mock_rate_limited(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "serverVersion": null },
+            "errors": [{
+                "message": "Too many requests",
+                "locations": [{ "line": 1, "column": 9 }],
+                "path": ["serverVersion"],
+                "extensions": { "code": "RATE_LIMITED", "internalTrace": "upstream-1234" }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+fn sdl_file() -> tempfile::NamedTempFile {
+    let mut sdl_file = tempfile::NamedTempFile::new().unwrap();
+    sdl_file.write_all(b"type Query { serverVersion: String }").unwrap();
+    sdl_file
+}
+
+fn schema(
+    address: &std::net::SocketAddr,
+    sdl_file: &tempfile::NamedTempFile,
+    error_extensions: Option<&str>,
+) -> String {
+    let error_extensions = error_extensions
+        .map(|error_extensions| format!("errorExtensions: {error_extensions},"))
+        .unwrap_or_default();
+
+    format!(
+        r#"
+          extend schema
+          @graphql(
+            name: "gothub",
+            namespace: true,
+            url: "http://{address}",
+            schema: "{path}",
+            {error_extensions}
+          )
+        "#,
+        path = sdl_file.path().display()
+    )
+}
@@ -1,4 +1,7 @@
 mod basic;
 mod defer;
+mod errors;
 mod headers;
+mod sdl_schema;
+mod subscriptions;
 mod transforms;
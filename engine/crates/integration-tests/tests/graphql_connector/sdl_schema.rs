@@ -0,0 +1,87 @@
+use std::io::Write;
+
+use integration_tests::{runtime, EngineBuilder, MockGraphQlServer, ResponseExt};
+
+const UPSTREAM_SDL: &str = r#"
+    type Query {
+        serverVersion: String!
+        pullRequestOrIssue(id: ID!): PullRequestOrIssue
+    }
+
+    interface PullRequestOrIssue {
+        title: String!
+    }
+
+    type PullRequest implements PullRequestOrIssue {
+        title: String!
+        checks: [String!]!
+    }
+
+    type Issue implements PullRequestOrIssue {
+        title: String!
+    }
+"#;
+
+#[test]
+fn graphql_schema_from_sdl_file() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::new().await;
+
+        let mut sdl_file = tempfile::NamedTempFile::new().unwrap();
+        sdl_file.write_all(UPSTREAM_SDL.as_bytes()).unwrap();
+
+        let schema = format!(
+            r#"
+              extend schema
+              @graphql(
+                name: "gothub",
+                namespace: true,
+                url: "http://127.0.0.1:{port}",
+                schema: "{path}",
+              )
+            "#,
+            port = graphql_mock.port(),
+            path = sdl_file.path().display()
+        );
+
+        let engine = EngineBuilder::new(schema).build().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        gothub {
+                            serverVersion
+                            pullRequestOrIssue(id: "1") {
+                                __typename
+                                title
+                                ... on GothubPullRequest {
+                                    checks
+                                }
+                            }
+                        }
+                    }
+                    "#
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "gothub": {
+              "pullRequestOrIssue": {
+                "__typename": "GothubPullRequest",
+                "checks": [
+                  "Success!"
+                ],
+                "title": "Creating the thing"
+              },
+              "serverVersion": "1"
+            }
+          }
+        }
+        "###
+        );
+    });
+}
@@ -0,0 +1,132 @@
+use integration_tests::{runtime, EngineBuilder, MockGraphQlServer, ResponseExt};
+
+#[test]
+fn test_subscription_with_namespace() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::with_subscriptions().await;
+
+        let engine = EngineBuilder::new(schema(graphql_mock.port(), true)).build().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute_stream(
+                    r#"
+                    subscription {
+                        gothub {
+                            pullRequests {
+                                title
+                                author {
+                                    __typename
+                                    ... on GothubUser {
+                                        email
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "#,
+                )
+                .into_iter()
+                .await
+                .map(ResponseExt::into_value)
+                .collect::<Vec<_>>(),
+            @r###"
+        [
+          {
+            "data": {
+              "gothub": {
+                "pullRequests": {
+                  "author": {
+                    "__typename": "GothubUser",
+                    "email": "jim@example.com"
+                  },
+                  "title": "Creating the thing"
+                }
+              }
+            },
+            "hasNext": false
+          },
+          {
+            "data": {
+              "gothub": {
+                "pullRequests": {
+                  "author": {
+                    "__typename": "GothubBot"
+                  },
+                  "title": "Some bot PR"
+                }
+              }
+            },
+            "hasNext": false
+          }
+        ]
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_subscription_without_namespace() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::with_subscriptions().await;
+
+        let engine = EngineBuilder::new(schema(graphql_mock.port(), false)).build().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute_stream(
+                    r#"
+                    subscription {
+                        pullRequests {
+                            title
+                            checks
+                        }
+                    }
+                    "#,
+                )
+                .into_iter()
+                .await
+                .map(ResponseExt::into_value)
+                .collect::<Vec<_>>(),
+            @r###"
+        [
+          {
+            "data": {
+              "pullRequests": {
+                "checks": [
+                  "Success!"
+                ],
+                "title": "Creating the thing"
+              }
+            },
+            "hasNext": false
+          },
+          {
+            "data": {
+              "pullRequests": {
+                "checks": [
+                  "Success!"
+                ],
+                "title": "Some bot PR"
+              }
+            },
+            "hasNext": false
+          }
+        ]
+        "###
+        );
+    });
+}
+
+fn schema(port: u16, namespace: bool) -> String {
+    format!(
+        r#"
+          extend schema
+          @graphql(
+            name: "gothub",
+            namespace: {namespace},
+            url: "http://127.0.0.1:{port}",
+          )
+        "#
+    )
+}
@@ -1,5 +1,6 @@
 #![allow(unused_crate_dependencies)]
 
+mod apollo_tracing;
 mod custom_resolvers;
 mod defer;
 mod errors;
@@ -8,4 +9,6 @@ mod federation;
 mod graphql_connector;
 mod mongodb;
 mod openapi;
+mod operation_limits;
 mod postgres;
+mod stream;
@@ -0,0 +1,121 @@
+use expect_test::expect;
+use indoc::indoc;
+use integration_tests::with_mongodb;
+use serde_json::json;
+
+#[test]
+fn count_all() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          age: Int!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "age": 38 },
+            { "age": 39 },
+            { "age": 40 },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userAggregate(filter: { age: { gt: 38 } }) {
+                count
+                sum { age }
+                max { age }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userAggregate": [
+              {
+                "count": 2,
+                "sum": {
+                  "age": 79
+                },
+                "max": {
+                  "age": 40
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn group_by() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+          age: Int! @map(name: "years")
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "name": "Alice", "years": 30 },
+            { "name": "Alice", "years": 40 },
+            { "name": "Bob", "years": 20 },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userAggregate(groupBy: [NAME]) {
+                group { name }
+                count
+                avg { age }
+                min { age }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userAggregate": [
+              {
+                "group": {
+                  "name": "Alice"
+                },
+                "count": 2,
+                "avg": {
+                  "age": 35.0
+                },
+                "min": {
+                  "age": 30
+                }
+              },
+              {
+                "group": {
+                  "name": "Bob"
+                },
+                "count": 1,
+                "avg": {
+                  "age": 20.0
+                },
+                "min": {
+                  "age": 20
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
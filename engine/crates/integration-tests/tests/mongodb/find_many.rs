@@ -581,6 +581,148 @@ fn nin() {
     expected.assert_eq(&response);
 }
 
+#[test]
+fn starts_with() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "name": "Musti" },
+            { "name": "Naukio" },
+            { "name": "Mu.rmeli" },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { startsWith: "Mu." } }) {
+                edges { node { name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "name": "Mu.rmeli"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn starts_with_null() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "name": "Musti" },
+            { "name": "Naukio" },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { startsWith: null } }) {
+                edges { node { name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "name": "Musti"
+                  }
+                },
+                {
+                  "node": {
+                    "name": "Naukio"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn ilike() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "name": "Musti" },
+            { "name": "Naukio" },
+            { "name": "Mu.rmeli" },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { ilike: "%KIO" } }) {
+                edges { node { name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "name": "Naukio"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
 #[test]
 fn all() {
     let schema = indoc! {r#"
@@ -1424,3 +1566,78 @@ fn nested_sort() {
 
     expected.assert_eq(&response);
 }
+
+#[test]
+fn lookup() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+          pets: [Pet] @lookup(localField: "name", foreignField: "ownerName")
+        }
+
+        type Pet @model(connector: "test", collection: "pets") {
+          name: String!
+          ownerName: String! @map(name: "owner")
+          owner: User @lookup(localField: "ownerName", foreignField: "name")
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        api.insert_many("users", json!([{ "name": "Alice" }, { "name": "Bob" }]))
+            .await;
+
+        let pets = json!([
+            { "name": "Musti", "owner": "Alice" },
+            { "name": "Naukio", "owner": "Alice" },
+        ]);
+
+        api.insert_many("pets", pets).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, orderBy: [{ name: ASC }]) {
+                edges { node { name pets { name owner { name } } } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "name": "Alice",
+                    "pets": [
+                      {
+                        "name": "Musti",
+                        "owner": {
+                          "name": "Alice"
+                        }
+                      },
+                      {
+                        "name": "Naukio",
+                        "owner": {
+                          "name": "Alice"
+                        }
+                      }
+                    ]
+                  }
+                },
+                {
+                  "node": {
+                    "name": "Bob",
+                    "pets": []
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
@@ -1,3 +1,4 @@
+mod aggregate;
 mod create_many;
 mod create_one;
 mod delete_many;
@@ -6,3 +7,4 @@ mod find_many;
 mod find_one;
 mod update_many;
 mod update_one;
+mod wire_protocol;
@@ -0,0 +1,128 @@
+use expect_test::expect;
+use indoc::{formatdoc, indoc};
+use integration_tests::{with_mongodb_wire_protocol, GetPath};
+use serde_json::json;
+
+#[test]
+fn find_many() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+          age: Int!
+        }
+    "#};
+
+    let response = with_mongodb_wire_protocol(schema, |api| async move {
+        let documents = json!([
+            { "name": "Alice", "age": 30 },
+            { "name": "Bob", "age": 40 },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { age: { gt: 30 } }) {
+                edges { node { name age } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "name": "Bob",
+                    "age": 40
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn find_one_by_id() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb_wire_protocol(schema, |api| async move {
+        let id = api.insert_one("users", json!({ "name": "Alice" })).await.inserted_id;
+
+        let query = formatdoc! {r#"
+            query {{
+              user(by: {{ id: "{id}" }}) {{
+                name
+              }}
+            }}
+        "#};
+
+        api.execute(&query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "user": {
+              "name": "Alice"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn create_and_update() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb_wire_protocol(schema, |api| async move {
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { name: "Alice" }) { insertedId }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+        let inserted_id = result.get_string("userCreate.insertedId");
+
+        let mutation = formatdoc! {r#"
+            mutation {{
+              userUpdate(by: {{ id: "{inserted_id}" }}, input: {{ name: {{ set: "Bob" }} }}) {{
+                matchedCount
+                modifiedCount
+              }}
+            }}
+        "#};
+
+        api.execute(&mutation).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userUpdate": {
+              "matchedCount": 1,
+              "modifiedCount": 1
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
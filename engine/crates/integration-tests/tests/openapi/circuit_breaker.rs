@@ -0,0 +1,62 @@
+//! Tests of the circuit breaker of a connector
+
+use std::net::SocketAddr;
+
+use integration_tests::{runtime, ResponseExt};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use super::build_engine;
+
+#[test]
+fn test_circuit_breaker_opens_after_failures() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = build_engine(petstore_schema_with_circuit_breaker(mock_server.address())).await;
+
+        Mock::given(method("GET"))
+            .and(path("/pet/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let query = r#"
+            query {
+                petstore {
+                    pet(petId: 123) {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        for _ in 0..2 {
+            let response = engine.execute(query).await.into_value();
+            assert_eq!(response["errors"][0]["extensions"]["code"], json!(null));
+        }
+
+        let response = engine.execute(query).await.into_value();
+        assert_eq!(
+            response["errors"][0]["extensions"],
+            json!({"code": "CIRCUIT_OPEN", "connector": "petstore"})
+        );
+    });
+}
+
+fn petstore_schema_with_circuit_breaker(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            url: "http://{address}",
+            schema: "http://example.com/petstore.json",
+            circuitBreaker: {{ failureThreshold: 2, resetTimeout: 60000 }},
+          )
+        "#
+    )
+}
@@ -0,0 +1,125 @@
+//! Tests of multipart request bodies & non-JSON responses
+
+use std::net::SocketAddr;
+
+use integration_tests::{runtime, Engine, EngineBuilder, ResponseExt};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string_contains, header_regex, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[test]
+fn test_multipart_upload() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = content_types_engine(mock_server.address()).await;
+
+        Mock::given(method("POST"))
+            .and(path("/files"))
+            .and(header_regex("content-type", "^multipart/form-data; boundary="))
+            .and(body_string_contains(r#"name="purpose""#))
+            .and(body_string_contains("fine-tune"))
+            .and(body_string_contains(r#"name="file"; filename="hello.txt""#))
+            .and(body_string_contains("hello world"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "file-123"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    mutation UploadFile($input: FilesUploadFileRequestInput!) {
+                        files {
+                            uploadFile(input: $input) {
+                                id
+                            }
+                        }
+                    }
+                "#,
+                )
+                .variables(json!({"input": {"file": null, "purpose": "fine-tune"}}))
+                .upload("variables.input.file", "hello.txt", Some("text/plain"), "hello world")
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "files": {
+              "uploadFile": {
+                "id": "file-123"
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_text_and_binary_responses() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = content_types_engine(mock_server.address()).await;
+
+        Mock::given(method("GET"))
+            .and(path("/files/file-123/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("hello world"))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/files/file-123/download"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(vec![0, 1, 2, 255], "application/octet-stream"))
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        files {
+                            fileContent(fileId: "file-123")
+                            downloadFile(fileId: "file-123")
+                        }
+                    }
+                "#,
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "files": {
+              "fileContent": "hello world",
+              "downloadFile": "AAEC/w"
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+async fn content_types_engine(address: &SocketAddr) -> Engine {
+    EngineBuilder::new(format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "files",
+            url: "http://{address}",
+            schema: "http://example.com/content_types.json",
+          )
+        "#
+    ))
+    .with_openapi_schema(
+        "http://example.com/content_types.json",
+        include_str!("content_types_spec.json"),
+    )
+    .build()
+    .await
+}
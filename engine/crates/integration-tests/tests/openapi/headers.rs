@@ -2,9 +2,9 @@
 
 use std::{collections::BTreeMap, net::SocketAddr};
 
-use integration_tests::{runtime, ResponseExt};
+use integration_tests::{runtime, EngineBuilder, ResponseExt};
 use wiremock::{
-    matchers::{method, path},
+    matchers::{header, method, path, query_param},
     Mock, ResponseTemplate,
 };
 
@@ -85,6 +85,199 @@ fn test_header_passthrough() {
     });
 }
 
+#[test]
+fn test_header_and_cookie_parameters() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = EngineBuilder::new(header_params_schema(mock_server.address()))
+            .with_openapi_schema(
+                "http://example.com/header_params.json",
+                include_str!("header_params_spec.json"),
+            )
+            .build()
+            .await;
+
+        let mock_guard = Mock::given(method("GET"))
+            .and(path("/pets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 1, "name": "doggo"}])))
+            .mount_as_scoped(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        petstore {
+                            pets(xRequestId: "abc", xTags: ["good-boy"], session: "s3cr3t") {
+                                id
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await.into_value(),
+            @r###"
+        {
+          "data": {
+            "petstore": {
+              "pets": [
+                {
+                  "id": 1
+                }
+              ]
+            }
+          }
+        }
+        "###
+        );
+
+        let headers = mock_guard
+            .received_requests()
+            .await
+            .into_iter()
+            .map(|request| {
+                request
+                    .headers
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .filter(|(name, _)| {
+                        name != "host" && name != "connection" && name != "accept-encoding" && name != "mf-loop"
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            })
+            .collect::<Vec<_>>();
+
+        insta::assert_json_snapshot!(headers, @r###"
+        [
+          {
+            "accept": "[\"*/*\"]",
+            "cookie": "[\"session=s3cr3t\"]",
+            "x-request-id": "[\"abc\"]",
+            "x-tags": "[\"good-boy\"]"
+          }
+        ]
+        "###);
+    });
+}
+
+#[test]
+fn test_cookie_parameters_are_percent_encoded() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = EngineBuilder::new(header_params_schema(mock_server.address()))
+            .with_openapi_schema(
+                "http://example.com/header_params.json",
+                include_str!("header_params_spec.json"),
+            )
+            .build()
+            .await;
+
+        // the value can't smuggle in an `admin` cookie of its own.
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/pets"))
+            .and(header("cookie", "session=s3cr3t%3B%20admin%3Dtrue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 1, "name": "doggo"}])))
+            .expect(1)
+            .mount_as_scoped(&mock_server)
+            .await;
+
+        engine
+            .execute(
+                r#"
+                query {
+                    petstore {
+                        pets(xRequestId: "abc", session: "s3cr3t; admin=true") {
+                            id
+                        }
+                    }
+                }
+            "#,
+            )
+            .await
+            .assert_success();
+    });
+}
+
+#[test]
+fn test_parameters_with_the_same_name_in_different_locations() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = EngineBuilder::new(header_params_schema(mock_server.address()))
+            .with_openapi_schema(
+                "http://example.com/header_params.json",
+                include_str!("header_params_spec.json"),
+            )
+            .build()
+            .await;
+
+        let mock_guard = Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("session", "query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 1, "name": "doggo"}])))
+            .mount_as_scoped(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        petstore {
+                            search(session: "query", headerSession: "header", cookieSession: "cookie") {
+                                id
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await.into_value(),
+            @r###"
+        {
+          "data": {
+            "petstore": {
+              "search": [
+                {
+                  "id": 1
+                }
+              ]
+            }
+          }
+        }
+        "###
+        );
+
+        let headers = mock_guard
+            .received_requests()
+            .await
+            .into_iter()
+            .flat_map(|request| request.headers)
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .filter(|(name, _)| name == "session" || name == "cookie")
+            .collect::<BTreeMap<_, _>>();
+
+        insta::assert_json_snapshot!(headers, @r###"
+        {
+          "cookie": "[\"session=cookie\"]",
+          "session": "[\"header\"]"
+        }
+        "###);
+    });
+}
+
+fn header_params_schema(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            url: "http://{address}",
+            schema: "http://example.com/header_params.json",
+          )
+        "#
+    )
+}
+
 fn petstore_schema_with_header_forwarding(address: &SocketAddr) -> String {
     format!(
         r#"
@@ -1,6 +1,11 @@
+mod circuit_breaker;
+mod content_types;
 mod headers;
 mod http_spy;
+mod pagination;
 mod remote_unions;
+mod retries;
+mod security;
 mod transforms;
 
 use std::net::SocketAddr;
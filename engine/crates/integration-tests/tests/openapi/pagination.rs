@@ -0,0 +1,324 @@
+//! Tests of list operations exposed as connections
+
+use std::net::SocketAddr;
+
+use integration_tests::{runtime, Engine, EngineBuilder, ResponseExt};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[test]
+fn test_offset_pagination() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = pagination_engine(mock_server.address()).await;
+
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("offset", "0"))
+            .and(query_param("limit", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"id": 1, "name": "Alice"},
+                {"id": 2, "name": "Bob"},
+                {"id": 3, "name": "Carol"}
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("offset", "2"))
+            .and(query_param("limit", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"id": 3, "name": "Carol"}])))
+            .mount(&mock_server)
+            .await;
+
+        let query = r#"
+            query Users($after: String) {
+                api {
+                    users(first: 2, after: $after) {
+                        edges {
+                            node {
+                                id
+                                name
+                            }
+                        }
+                        pageInfo {
+                            hasNextPage
+                            hasPreviousPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        insta::assert_json_snapshot!(
+            engine.execute(query).await.into_value(),
+            @r###"
+        {
+          "data": {
+            "api": {
+              "users": {
+                "edges": [
+                  {
+                    "node": {
+                      "id": 1,
+                      "name": "Alice"
+                    }
+                  },
+                  {
+                    "node": {
+                      "id": 2,
+                      "name": "Bob"
+                    }
+                  }
+                ],
+                "pageInfo": {
+                  "hasNextPage": true,
+                  "hasPreviousPage": false,
+                  "endCursor": "eyJwYWdlIjp7Im9mZnNldCI6MH0sImluZGV4IjoxfQ"
+                }
+              }
+            }
+          }
+        }
+        "###
+        );
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(query)
+                .variables(json!({"after": "eyJwYWdlIjp7Im9mZnNldCI6MH0sImluZGV4IjoxfQ"}))
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "api": {
+              "users": {
+                "edges": [
+                  {
+                    "node": {
+                      "id": 3,
+                      "name": "Carol"
+                    }
+                  }
+                ],
+                "pageInfo": {
+                  "hasNextPage": false,
+                  "hasPreviousPage": true,
+                  "endCursor": "eyJwYWdlIjp7Im9mZnNldCI6Mn0sImluZGV4IjowfQ"
+                }
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_link_header_pagination() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = pagination_engine(mock_server.address()).await;
+        let address = mock_server.address();
+
+        // Mounted first so it takes precedence over the mock for the first page
+        Mock::given(method("GET"))
+            .and(path("/repositories"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"name": "three"}, {"name": "four"}])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repositories"))
+            .and(query_param("per_page", "4"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        format!(r#"<http://{address}/repositories?page=2&per_page=4>; rel="next""#).as_str(),
+                    )
+                    .set_body_json(json!([{"name": "one"}, {"name": "two"}])),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        api {
+                            repositories(first: 3) {
+                                edges {
+                                    node {
+                                        name
+                                    }
+                                }
+                                pageInfo {
+                                    hasNextPage
+                                }
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "api": {
+              "repositories": {
+                "edges": [
+                  {
+                    "node": {
+                      "name": "one"
+                    }
+                  },
+                  {
+                    "node": {
+                      "name": "two"
+                    }
+                  },
+                  {
+                    "node": {
+                      "name": "three"
+                    }
+                  }
+                ],
+                "pageInfo": {
+                  "hasNextPage": true
+                }
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_link_header_pagination_stops_at_repeated_links() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = pagination_engine(mock_server.address()).await;
+        let address = mock_server.address();
+
+        // The second page links back to itself
+        Mock::given(method("GET"))
+            .and(path("/repositories"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        format!(r#"<http://{address}/repositories?page=2&per_page=11>; rel="next""#).as_str(),
+                    )
+                    .set_body_json(json!([{"name": "two"}])),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repositories"))
+            .and(query_param("per_page", "11"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        format!(r#"<http://{address}/repositories?page=2&per_page=11>; rel="next""#).as_str(),
+                    )
+                    .set_body_json(json!([{"name": "one"}])),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        api {
+                            repositories(first: 10) {
+                                edges {
+                                    node {
+                                        name
+                                    }
+                                }
+                                pageInfo {
+                                    hasNextPage
+                                }
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "api": {
+              "repositories": {
+                "edges": [
+                  {
+                    "node": {
+                      "name": "one"
+                    }
+                  },
+                  {
+                    "node": {
+                      "name": "two"
+                    }
+                  }
+                ],
+                "pageInfo": {
+                  "hasNextPage": false
+                }
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+async fn pagination_engine(address: &SocketAddr) -> Engine {
+    EngineBuilder::new(format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "api",
+            url: "http://{address}",
+            schema: "http://example.com/pagination.json",
+            transforms: {{
+              queryNaming: OPERATION_ID,
+              pagination: [
+                {{ style: OFFSET_LIMIT }},
+                {{ operationId: "repositories", style: LINK_HEADER, limitParameter: "per_page" }}
+              ]
+            }}
+          )
+        "#
+    ))
+    .with_openapi_schema(
+        "http://example.com/pagination.json",
+        include_str!("pagination_spec.json"),
+    )
+    .build()
+    .await
+}
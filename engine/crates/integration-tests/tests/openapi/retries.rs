@@ -0,0 +1,151 @@
+//! Tests of the timeout & retries of requests to an API
+
+use std::{net::SocketAddr, time::Duration};
+
+use integration_tests::{runtime, ResponseExt};
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use super::{build_engine, doggo};
+
+#[test]
+fn test_server_errors_are_retried() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = build_engine(petstore_schema_with_fetch_policy(mock_server.address())).await;
+
+        Mock::given(method("GET"))
+            .and(path("/pet/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/pet/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(doggo()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        petstore {
+                            pet(petId: 123) {
+                                id
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "petstore": {
+              "pet": {
+                "id": 123
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_non_idempotent_requests_are_not_retried() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = build_engine(petstore_schema_with_fetch_policy(mock_server.address())).await;
+
+        Mock::given(method("POST"))
+            .and(path("/pet"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let response = engine
+            .execute(
+                r#"
+                    mutation {
+                        petstore {
+                            addPet(input: {
+                                id: 123
+                                name: "doggo"
+                                status: AVAILABLE
+                                tags: []
+                                photoUrls: []
+                                category: {}
+                            }) {
+                                id
+                            }
+                        }
+                    }
+                "#,
+            )
+            .await;
+
+        assert!(!response.errors.is_empty());
+    });
+}
+
+#[test]
+fn test_slow_requests_time_out() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = build_engine(petstore_schema_with_fetch_policy(mock_server.address())).await;
+
+        Mock::given(method("GET"))
+            .and(path("/pet/123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(doggo())
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let response = engine
+            .execute(
+                r#"
+                    query {
+                        petstore {
+                            pet(petId: 123) {
+                                id
+                            }
+                        }
+                    }
+                "#,
+            )
+            .await;
+
+        assert!(!response.errors.is_empty());
+    });
+}
+
+fn petstore_schema_with_fetch_policy(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            url: "http://{address}",
+            schema: "http://example.com/petstore.json",
+            timeout: 200,
+            retries: 2,
+          )
+        "#
+    )
+}
@@ -0,0 +1,195 @@
+//! Tests of applying credentials for the security schemes of a spec
+
+use std::net::SocketAddr;
+
+use integration_tests::{runtime, EngineBuilder, ResponseExt};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string_contains, header, method, path},
+    Mock, ResponseTemplate,
+};
+
+use super::{build_engine, doggo};
+
+#[test]
+fn test_api_key_security_scheme() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = build_engine(petstore_schema_with_api_key(mock_server.address())).await;
+
+        Mock::given(method("GET"))
+            .and(path("/pet/123"))
+            .and(header("api_key", "BLAH"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(doggo()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(
+                    r#"
+                    query {
+                        petstore {
+                            pet(petId: 123) {
+                                id
+                            }
+                        }
+                    }
+                "#,
+                )
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "petstore": {
+              "pet": {
+                "id": 123
+              }
+            }
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn test_client_credentials_token_is_cached() {
+    runtime().block_on(async {
+        let mock_server = wiremock::MockServer::start().await;
+        let engine = EngineBuilder::new(client_credentials_schema(mock_server.address()))
+            .with_openapi_schema(
+                "http://example.com/client_credentials.json",
+                client_credentials_spec(mock_server.address()).to_string(),
+            )
+            .with_env_var("CLIENT_SECRET", "i_am_a_secret")
+            .build()
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_string_contains("grant_type=client_credentials"))
+            .and(body_string_contains("client_secret=i_am_a_secret"))
+            .and(body_string_contains("scope=read%3Apets"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"access_token": "a_token", "expires_in": 3600})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/pets"))
+            .and(header("authorization", "Bearer a_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"id": 1}])))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        for _ in 0..2 {
+            insta::assert_json_snapshot!(
+                engine
+                    .execute(
+                        r#"
+                        query {
+                            petstore {
+                                pets {
+                                    id
+                                }
+                            }
+                        }
+                    "#,
+                    )
+                    .await
+                    .into_value(),
+                @r###"
+            {
+              "data": {
+                "petstore": {
+                  "pets": [
+                    {
+                      "id": 1
+                    }
+                  ]
+                }
+              }
+            }
+            "###
+            );
+        }
+    });
+}
+
+fn petstore_schema_with_api_key(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            url: "http://{address}",
+            schema: "http://example.com/petstore.json",
+            security: [{{ scheme: "api_key", value: "{{{{ env.API_KEY }}}}" }}],
+          )
+        "#
+    )
+}
+
+fn client_credentials_schema(address: &SocketAddr) -> String {
+    format!(
+        r#"
+          extend schema
+          @openapi(
+            name: "petstore",
+            url: "http://{address}",
+            schema: "http://example.com/client_credentials.json",
+            security: [{{ scheme: "oauth", clientId: "petstore", clientSecret: "{{{{ env.CLIENT_SECRET }}}}" }}],
+          )
+        "#
+    )
+}
+
+fn client_credentials_spec(address: &SocketAddr) -> serde_json::Value {
+    json!({
+        "openapi": "3.0.2",
+        "info": { "title": "Client credentials", "version": "1.0.0" },
+        "security": [{ "oauth": ["read:pets"] }],
+        "components": {
+            "securitySchemes": {
+                "oauth": {
+                    "type": "oauth2",
+                    "flows": {
+                        "clientCredentials": {
+                            "tokenUrl": format!("http://{address}/token"),
+                            "scopes": { "read:pets": "Read your pets" }
+                        }
+                    }
+                }
+            }
+        },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "operationId": "pets",
+                    "responses": {
+                        "200": {
+                            "description": "The pets",
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "array",
+                                        "items": {
+                                            "type": "object",
+                                            "properties": { "id": { "type": "integer" } }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
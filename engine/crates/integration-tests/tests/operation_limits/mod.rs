@@ -0,0 +1,81 @@
+//! Tests of the limits declared with `@operationLimits`
+
+use integration_tests::{runtime, udfs::RustUdfs, EngineBuilder, ResponseExt};
+use runtime::udf::CustomResolverResponse;
+use serde_json::{json, Value};
+
+const SCHEMA: &str = r#"
+    extend schema @operationLimits(depth: 3, complexity: 15, aliases: 1, rootFields: 2, tokens: 30, defaultListSize: 2)
+
+    type Query {
+        user: User @resolver(name: "user")
+    }
+
+    type User {
+        name: String
+        friends: [User] @complexity(weight: 10)
+    }
+"#;
+
+async fn execute(query: &str) -> Value {
+    let engine = EngineBuilder::new(SCHEMA)
+        .with_custom_resolvers(RustUdfs::new().resolver(
+            "user",
+            CustomResolverResponse::Success(json!({"name": "Jane", "friends": []})),
+        ))
+        .build()
+        .await;
+
+    engine.execute(query).await.into_value()
+}
+
+#[test]
+fn operations_within_the_limits() {
+    runtime().block_on(async {
+        // user #1, name #1, friends #10 plus the names of its 2 users
+        let response = execute("query { me: user { name friends { name } } }").await;
+
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "me": {
+              "friends": [],
+              "name": "Jane"
+            }
+          },
+          "extensions": {
+            "cost": {
+              "maximum": 15,
+              "requested": 14
+            }
+          }
+        }
+        "###);
+    });
+}
+
+#[test]
+fn operations_exceeding_the_limits() {
+    runtime().block_on(async {
+        let cases = [
+            ("{ user { friends { friends { name } } } }", "depth"),
+            ("{ user { n1: name n2: name } }", "aliases"),
+            (
+                "{ user { name friends { name } } ... on Query { user { friends { name } } } }",
+                "complexity",
+            ),
+            ("{ __typename user { name } ... on Query { __typename } }", "rootFields"),
+            (
+                "{ user { name } user { name } user { name } user { name } user { name } user { name } user { name } user { name } }",
+                "tokens",
+            ),
+        ];
+
+        for (query, limit) in cases {
+            let response = execute(query).await;
+            let error = &response["errors"][0];
+            assert_eq!(error["extensions"]["code"], "OPERATION_LIMIT_EXCEEDED", "{query}: {response}");
+            assert_eq!(error["extensions"]["limit"], limit, "{query}: {response}");
+        }
+    });
+}
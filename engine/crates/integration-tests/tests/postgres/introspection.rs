@@ -990,6 +990,21 @@ fn table_with_serial_primary_key_string_unique() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given pattern, where `%` matches any sequence of characters and `_` any single character
+          """ like: String
+          """
+            The value matches the given pattern, case-insensitively
+          """ ilike: String
+          """
+            The value starts with the given string
+          """ startsWith: String
+          """
+            The value ends with the given string
+          """ endsWith: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
           not: StringSearchFilterInput
         }
 
@@ -1194,6 +1209,21 @@ fn table_with_composite_primary_key() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given pattern, where `%` matches any sequence of characters and `_` any single character
+          """ like: String
+          """
+            The value matches the given pattern, case-insensitively
+          """ ilike: String
+          """
+            The value starts with the given string
+          """ startsWith: String
+          """
+            The value ends with the given string
+          """ endsWith: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
           not: StringSearchFilterInput
         }
 
@@ -2952,6 +2982,21 @@ fn two_tables_with_single_column_foreign_key() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given pattern, where `%` matches any sequence of characters and `_` any single character
+          """ like: String
+          """
+            The value matches the given pattern, case-insensitively
+          """ ilike: String
+          """
+            The value starts with the given string
+          """ startsWith: String
+          """
+            The value ends with the given string
+          """ endsWith: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
           not: StringSearchFilterInput
         }
 
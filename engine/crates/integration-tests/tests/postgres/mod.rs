@@ -5,5 +5,6 @@ mod delete_one;
 mod find_many;
 mod find_one;
 mod introspection;
+mod pool;
 mod update_many;
 mod update_one;
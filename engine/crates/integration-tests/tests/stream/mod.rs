@@ -0,0 +1,236 @@
+//! Tests of the stream directive
+
+use integration_tests::{runtime, udfs::RustUdfs, Engine, EngineBuilder, ResponseExt};
+use runtime::udf::CustomResolverResponse;
+use serde_json::json;
+
+#[test]
+fn stream_list_of_objects() {
+    runtime().block_on(async {
+        let engine = build_engine().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute_stream(
+                r#"
+                    query {
+                        users @stream(initialCount: 1, label: "users") {
+                            name
+                        }
+                    }
+                "#,
+                )
+                .into_iter()
+                .await
+                .map(ResponseExt::into_value)
+                .collect::<Vec<_>>(),
+            @r###"
+        [
+          {
+            "data": {
+              "users": [
+                {
+                  "name": "Alice"
+                }
+              ]
+            },
+            "hasNext": true
+          },
+          {
+            "hasNext": true,
+            "items": [
+              {
+                "name": "Bob"
+              }
+            ],
+            "label": "users",
+            "path": [
+              "users",
+              1
+            ]
+          },
+          {
+            "hasNext": false,
+            "items": [
+              {
+                "name": "Carol"
+              }
+            ],
+            "label": "users",
+            "path": [
+              "users",
+              2
+            ]
+          }
+        ]
+        "###
+        );
+    });
+}
+
+#[test]
+fn stream_list_of_scalars() {
+    runtime().block_on(async {
+        let engine = build_engine().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute_stream("query { tags @stream }")
+                .into_iter()
+                .await
+                .map(ResponseExt::into_value)
+                .collect::<Vec<_>>(),
+            @r###"
+        [
+          {
+            "data": {
+              "tags": []
+            },
+            "hasNext": true
+          },
+          {
+            "hasNext": true,
+            "items": [
+              "rust"
+            ],
+            "path": [
+              "tags",
+              0
+            ]
+          },
+          {
+            "hasNext": false,
+            "items": [
+              "graphql"
+            ],
+            "path": [
+              "tags",
+              1
+            ]
+          }
+        ]
+        "###
+        );
+    });
+}
+
+#[test]
+fn stream_with_if_false_doesnt_stream() {
+    runtime().block_on(async {
+        let engine = build_engine().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute_stream("query { tags @stream(if: false) }")
+                .into_iter()
+                .await
+                .map(ResponseExt::into_value)
+                .collect::<Vec<_>>(),
+            @r###"
+        [
+          {
+            "data": {
+              "tags": [
+                "rust",
+                "graphql"
+              ]
+            },
+            "hasNext": false
+          }
+        ]
+        "###
+        );
+    });
+}
+
+#[test]
+fn stream_on_non_streaming_request_doesnt_stream() {
+    runtime().block_on(async {
+        let engine = build_engine().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute("query { users @stream(initialCount: 1) { name } }")
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": {
+            "users": [
+              {
+                "name": "Alice"
+              },
+              {
+                "name": "Bob"
+              },
+              {
+                "name": "Carol"
+              }
+            ]
+          }
+        }
+        "###
+        );
+    });
+}
+
+#[test]
+fn stream_on_non_list_field_rejected() {
+    runtime().block_on(async {
+        let engine = build_engine().await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute("query { user @stream { name } }")
+                .await
+                .into_value(),
+            @r###"
+        {
+          "data": null,
+          "errors": [
+            {
+              "locations": [
+                {
+                  "column": 14,
+                  "line": 1
+                }
+              ],
+              "message": "The @stream directive can only be used on list fields, but \"user\" is of type \"User!\""
+            }
+          ]
+        }
+        "###
+        );
+    });
+}
+
+async fn build_engine() -> Engine {
+    let schema = r#"
+        extend type Query {
+            user: User! @resolver(name: "user")
+            users: [User!]! @resolver(name: "users")
+            tags: [String!]! @resolver(name: "tags")
+        }
+
+        type User {
+            name: String!
+        }
+    "#;
+
+    EngineBuilder::new(schema)
+        .with_custom_resolvers(
+            RustUdfs::new()
+                .resolver("user", CustomResolverResponse::Success(json!({"name": "Alice"})))
+                .resolver(
+                    "users",
+                    CustomResolverResponse::Success(json!([
+                        {"name": "Alice"},
+                        {"name": "Bob"},
+                        {"name": "Carol"}
+                    ])),
+                )
+                .resolver("tags", CustomResolverResponse::Success(json!(["rust", "graphql"]))),
+        )
+        .build()
+        .await
+}
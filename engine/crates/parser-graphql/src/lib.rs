@@ -1,9 +1,10 @@
 //! GraphQL schema parser for upstream APIs connected to Grafbase.
 //!
-//! The parser fetches a GraphQL schema from an upstream server, parses the response, and modifies
-//! it to allow the result to be exposed through the Grafbase API.
+//! The parser fetches a GraphQL schema from an upstream server (or reads it from an SDL document),
+//! parses the response, and modifies it to allow the result to be exposed through the Grafbase API.
 
 mod conversion;
+mod sdl;
 
 use cynic::{
     http::{CynicReqwestError, ReqwestExt},
@@ -13,7 +14,7 @@ use cynic_introspection::{query::IntrospectionQuery, SchemaError};
 use engine::{
     registry::{
         resolvers::{graphql, transformer::Transformer, Resolver},
-        ConnectorHeaders, Deprecation, MetaField, ObjectType, Registry,
+        ConnectorFetchPolicy, ConnectorHeaders, Deprecation, MetaField, ObjectType, Registry,
     },
     CacheControl,
 };
@@ -73,17 +74,19 @@ static BUILTIN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
 /// ```
 ///
 /// Any provided `headers` are passed to the upstream API as HTTP request headers. These can be
-/// used for authentication, etc.
+/// used for authentication, etc. The `fetch_policy` sets the timeout and retries of requests to it.
 ///
 /// # Errors
 ///
 /// See [`Error`] for more details.
+#[allow(clippy::too_many_arguments)]
 pub async fn parse_schema(
     client: reqwest::Client,
     name: &str,
     namespace: bool,
     url: &Url,
     headers: ConnectorHeaders,
+    fetch_policy: ConnectorFetchPolicy,
     introspection_headers: impl IntoIterator<Item = (&str, &str)>,
     type_prefix: Option<&str>,
 ) -> Result<Registry, Vec<Error>> {
@@ -108,6 +111,54 @@ pub async fn parse_schema(
 
     let schema = data.into_schema().map_err(|err| vec![err.into()])?;
 
+    Ok(build_registry(
+        schema,
+        name,
+        namespace,
+        url,
+        headers,
+        fetch_policy,
+        type_prefix,
+    ))
+}
+
+/// Like [`parse_schema()`], but the upstream schema is read from an SDL document instead of being
+/// introspected from the upstream server, which is still the one receiving requests.
+///
+/// # Errors
+///
+/// See [`Error`] for more details.
+pub fn parse_sdl(
+    name: &str,
+    namespace: bool,
+    url: &Url,
+    headers: ConnectorHeaders,
+    fetch_policy: ConnectorFetchPolicy,
+    sdl: &str,
+    type_prefix: Option<&str>,
+) -> Result<Registry, Vec<Error>> {
+    let schema = sdl::schema_from_sdl(sdl).map_err(|err| vec![err])?;
+
+    Ok(build_registry(
+        schema,
+        name,
+        namespace,
+        url,
+        headers,
+        fetch_policy,
+        type_prefix,
+    ))
+}
+
+fn build_registry(
+    schema: cynic_introspection::Schema,
+    name: &str,
+    namespace: bool,
+    url: &Url,
+    headers: ConnectorHeaders,
+    fetch_policy: ConnectorFetchPolicy,
+    type_prefix: Option<&str>,
+) -> Registry {
     let parser = Parser {
         name: name.to_string(),
         namespace,
@@ -119,8 +170,13 @@ pub async fn parse_schema(
 
     let mut registry = parser.into_registry(schema);
     registry.http_headers.insert(format!("GraphQLConnector{name}"), headers);
+    if fetch_policy != ConnectorFetchPolicy::default() {
+        registry
+            .http_fetch_policies
+            .insert(format!("GraphQLConnector{name}"), fetch_policy);
+    }
 
-    Ok(registry)
+    registry
 }
 
 struct Parser {
@@ -155,12 +211,20 @@ impl Parser {
             if registry.mutation_type.is_some() {
                 self.add_root_mutation_field(&mut registry, &self.name);
             }
+
+            if registry.subscription_type.is_some() {
+                self.add_root_subscription_field(&mut registry, &self.name);
+            }
         } else {
             self.update_root_query_fields(&mut registry);
 
             if registry.mutation_type.is_some() {
                 self.update_root_mutation_fields(&mut registry);
             }
+
+            if registry.subscription_type.is_some() {
+                self.update_root_subscription_fields(&mut registry);
+            }
         };
 
         Self::add_field_resolvers(&mut registry);
@@ -259,52 +323,14 @@ impl Parser {
 
     /// Add a new `Query` type with an `upstream` field to access the upstream API.
     fn add_root_query_field(&self, registry: &mut Registry, name: &str) {
-        let root = registry
-            .types
-            .entry(registry.query_type.clone())
-            .or_insert_with(|| ObjectType::new(registry.query_type.clone(), []).into());
-
-        let Some(fields) = root.fields_mut() else { return };
-
-        fields.insert(
-            name.to_camel_case(),
-            MetaField {
-                name: name.to_camel_case(),
-                description: Some(format!("Access to embedded {name} API.")),
-                ty: format!("{}{}!", name.to_pascal_case(), &registry.query_type).into(),
-                deprecation: Deprecation::NoDeprecated,
-                cache_control: CacheControl::default(),
-                resolver: Resolver::Graphql(graphql::Resolver::new(
-                    self.name.clone(),
-                    self.url.clone(),
-                    Some(name.to_owned()),
-                    self.type_prefix.clone(),
-                )),
-                ..Default::default()
-            },
-        );
+        let query_type = registry.query_type.clone();
+        self.add_root_field(registry, query_type, name);
     }
 
     /// Add a new `Query` type with an `upstream` field to access the upstream API.
     fn update_root_query_fields(&self, registry: &mut Registry) {
-        let root = registry
-            .types
-            .entry(registry.query_type.clone())
-            .or_insert_with(|| ObjectType::new(registry.query_type.clone(), []).into());
-
-        let Some(fields) = root.fields_mut() else { return };
-
-        // There should always be fields for us to iterate, as we're mutating the `Query` object
-        // fields from the upstream API. No fields, means no API access exposed by the upstream
-        // server.
-        for (_name, field) in fields {
-            field.resolver = Resolver::Graphql(graphql::Resolver::new(
-                self.name.clone(),
-                self.url.clone(),
-                None,
-                self.type_prefix.clone(),
-            ));
-        }
+        let query_type = registry.query_type.clone();
+        self.update_root_fields(registry, query_type);
     }
 
     /// Add an optional `Mutate` type with an `upstream` field to access the upstream API.
@@ -313,10 +339,44 @@ impl Parser {
             return;
         };
 
+        self.add_root_field(registry, mutation_type, name);
+    }
+
+    /// Add a new `Mutation` type with an `upstream` field to access the upstream API.
+    fn update_root_mutation_fields(&self, registry: &mut Registry) {
+        let Some(mutation_type) = registry.mutation_type.clone() else {
+            return;
+        };
+
+        self.update_root_fields(registry, mutation_type);
+    }
+
+    /// Add an optional `Subscription` type with an `upstream` field to subscribe to the upstream
+    /// API.
+    fn add_root_subscription_field(&self, registry: &mut Registry, name: &str) {
+        let Some(subscription_type) = registry.subscription_type.clone() else {
+            return;
+        };
+
+        self.add_root_field(registry, subscription_type, name);
+    }
+
+    /// Add a new `Subscription` type with the upstream subscription fields.
+    fn update_root_subscription_fields(&self, registry: &mut Registry) {
+        let Some(subscription_type) = registry.subscription_type.clone() else {
+            return;
+        };
+
+        self.update_root_fields(registry, subscription_type);
+    }
+
+    /// Add a root type with an `upstream` field, whose type holds the fields of the upstream
+    /// root type.
+    fn add_root_field(&self, registry: &mut Registry, root_type: String, name: &str) {
         let root = registry
             .types
-            .entry(mutation_type.clone())
-            .or_insert_with(|| ObjectType::new(mutation_type.clone(), []).into());
+            .entry(root_type.clone())
+            .or_insert_with(|| ObjectType::new(root_type.clone(), []).into());
 
         let Some(fields) = root.fields_mut() else { return };
 
@@ -325,7 +385,7 @@ impl Parser {
             MetaField {
                 name: name.to_camel_case(),
                 description: Some(format!("Access to embedded {name} API.")),
-                ty: format!("{}{mutation_type}!", name.to_pascal_case()).into(),
+                ty: format!("{}{root_type}!", name.to_pascal_case()).into(),
                 deprecation: Deprecation::NoDeprecated,
                 cache_control: CacheControl::default(),
                 resolver: Resolver::Graphql(graphql::Resolver::new(
@@ -339,20 +399,16 @@ impl Parser {
         );
     }
 
-    /// Add a new `Mutation` type with an `upstream` field to access the upstream API.
-    fn update_root_mutation_fields(&self, registry: &mut Registry) {
-        let Some(mutation_type) = registry.mutation_type.clone() else {
-            return;
-        };
-
+    /// Resolve each field of a root type at the upstream API.
+    fn update_root_fields(&self, registry: &mut Registry, root_type: String) {
         let root = registry
             .types
-            .entry(mutation_type.clone())
-            .or_insert_with(|| ObjectType::new(mutation_type.clone(), []).into());
+            .entry(root_type.clone())
+            .or_insert_with(|| ObjectType::new(root_type, []).into());
 
         let Some(fields) = root.fields_mut() else { return };
 
-        // There should always be fields for us to iterate, as we're mutating the `Mutation` object
+        // There should always be fields for us to iterate, as we're mutating the root object
         // fields from the upstream API. No fields, means no API access exposed by the upstream
         // server.
         for (_name, field) in fields {
@@ -391,6 +447,9 @@ pub enum Error {
 
     #[error("Could not find valid data in GraphQL response")]
     MissingData,
+
+    #[error("Could not parse the GraphQL SDL: {0}")]
+    SdlError(#[from] engine::parser::Error),
 }
 
 #[derive(Clone, Debug)]
@@ -435,6 +494,7 @@ mod tests {
             true,
             &Url::parse(&server.uri()).unwrap(),
             ConnectorHeaders::new([]),
+            ConnectorFetchPolicy::default(),
             introspection_headers,
             None,
         )
@@ -481,6 +541,7 @@ mod tests {
             true,
             &Url::parse(&server.uri()).unwrap(),
             headers.clone(),
+            ConnectorFetchPolicy::default(),
             std::iter::empty(),
             None,
         )
@@ -508,6 +569,7 @@ mod tests {
             false,
             &Url::parse(&server.uri()).unwrap(),
             ConnectorHeaders::new([]),
+            ConnectorFetchPolicy::default(),
             std::iter::empty(),
             None,
         )
@@ -533,6 +595,7 @@ mod tests {
             false,
             &Url::parse(&server.uri()).unwrap(),
             ConnectorHeaders::new([]),
+            ConnectorFetchPolicy::default(),
             std::iter::empty(),
             None,
         )
@@ -558,6 +621,7 @@ mod tests {
             true,
             &Url::parse(&server.uri()).unwrap(),
             ConnectorHeaders::new([]),
+            ConnectorFetchPolicy::default(),
             std::iter::empty(),
             None,
         )
@@ -567,4 +631,49 @@ mod tests {
 
         insta::assert_snapshot!(result);
     }
+
+    #[test]
+    fn test_sdl() {
+        let sdl = r#"
+            """
+            A pull request or an issue
+            """
+            interface Node {
+                id: ID!
+            }
+
+            type PullRequest implements Node {
+                id: ID!
+                title: String
+                status: Status
+            }
+
+            enum Status {
+                OPEN
+                CLOSED
+            }
+
+            type Query {
+                pullRequest(id: ID!): PullRequest
+            }
+
+            extend type Query {
+                node(id: ID!): Node
+            }
+        "#;
+
+        let result = parse_sdl(
+            "gothub",
+            true,
+            &Url::parse("http://example.com").unwrap(),
+            ConnectorHeaders::new([]),
+            ConnectorFetchPolicy::default(),
+            sdl,
+            None,
+        )
+        .unwrap()
+        .export_sdl(false);
+
+        insta::assert_snapshot!(result);
+    }
 }
@@ -0,0 +1,292 @@
+//! Support for upstream APIs whose schema is provided as an SDL document, rather than being
+//! introspected from the upstream server.
+//!
+//! The document is turned into the response the upstream server would have sent to an
+//! introspection query, so the rest of the parser doesn't have to care where the schema comes
+//! from.
+
+use std::collections::BTreeMap;
+
+use cynic_introspection::query::IntrospectionQuery;
+use engine::{
+    parser::{
+        types::{
+            BaseType, ConstDirective, DirectiveLocation, EnumValueDefinition, FieldDefinition, InputValueDefinition,
+            ServiceDocument, Type, TypeKind, TypeSystemDefinition,
+        },
+        Positioned,
+    },
+    Name,
+};
+use serde_json::{json, Value};
+
+use crate::Error;
+
+/// Parse an SDL document into the schema it describes.
+pub(crate) fn schema_from_sdl(sdl: &str) -> Result<cynic_introspection::Schema, Error> {
+    let document = engine::parser::parse_schema(sdl)?;
+    let query: IntrospectionQuery = serde_json::from_value(introspection_response(&document))?;
+
+    Ok(query.into_schema()?)
+}
+
+fn introspection_response(document: &ServiceDocument) -> Value {
+    let mut root_types = [None, None, None];
+    let mut types = BTreeMap::<&str, Value>::new();
+    let mut directives = Vec::new();
+
+    for definition in &document.definitions {
+        match definition {
+            TypeSystemDefinition::Schema(schema) => {
+                let schema = &schema.node;
+                for (root_type, name) in
+                    root_types
+                        .iter_mut()
+                        .zip([&schema.query, &schema.mutation, &schema.subscription])
+                {
+                    if let Some(name) = name {
+                        *root_type = Some(name.node.to_string());
+                    }
+                }
+            }
+            TypeSystemDefinition::Type(definition) => {
+                let definition = &definition.node;
+                let ty = types
+                    .entry(definition.name.node.as_str())
+                    .or_insert_with(|| empty_type(definition.name.node.as_str(), &definition.kind));
+
+                // Extensions only add to the type, so the two can be merged in any order.
+                if let Some(description) = definition.description() {
+                    ty["description"] = json!(description);
+                }
+                extend_type(ty, &definition.kind);
+            }
+            TypeSystemDefinition::Directive(definition) => {
+                let definition = &definition.node;
+                directives.push(json!({
+                    "name": definition.name.node.as_str(),
+                    "description": description(&definition.description),
+                    "isRepeatable": false,
+                    "locations": definition
+                        .locations
+                        .iter()
+                        .map(|location| directive_location(location.node))
+                        .collect::<Vec<_>>(),
+                    "args": definition.arguments.iter().map(input_value).collect::<Vec<_>>(),
+                }));
+            }
+        }
+    }
+
+    add_possible_types(&mut types);
+
+    // Without a schema definition, the root types are the ones using the default names.
+    let [query_type, mutation_type, subscription_type] = root_types;
+    let root_type = |root_type: Option<String>, default: &str| {
+        root_type
+            .or_else(|| types.contains_key(default).then(|| default.to_string()))
+            .map(|name| json!({ "name": name }))
+    };
+
+    json!({
+        "__schema": {
+            "description": null,
+            "queryType": root_type(query_type, "Query"),
+            "mutationType": root_type(mutation_type, "Mutation"),
+            "subscriptionType": root_type(subscription_type, "Subscription"),
+            "types": types.into_values().collect::<Vec<_>>(),
+            "directives": directives,
+        }
+    })
+}
+
+fn empty_type(name: &str, kind: &TypeKind) -> Value {
+    let (kind, lists): (_, &[&str]) = match kind {
+        TypeKind::Scalar => ("SCALAR", &[]),
+        TypeKind::Object(_) => ("OBJECT", &["fields", "interfaces"]),
+        TypeKind::Interface(_) => ("INTERFACE", &["fields", "interfaces", "possibleTypes"]),
+        TypeKind::Union(_) => ("UNION", &["possibleTypes"]),
+        TypeKind::Enum(_) => ("ENUM", &["enumValues"]),
+        TypeKind::InputObject(_) => ("INPUT_OBJECT", &["inputFields"]),
+    };
+
+    let mut ty = json!({
+        "kind": kind,
+        "name": name,
+        "description": null,
+        "specifiedByURL": null,
+        "fields": null,
+        "inputFields": null,
+        "interfaces": null,
+        "enumValues": null,
+        "possibleTypes": null,
+    });
+
+    for list in lists {
+        ty[*list] = json!([]);
+    }
+
+    ty
+}
+
+fn extend_type(ty: &mut Value, kind: &TypeKind) {
+    let mut extend = |key: &str, values: Vec<Value>| {
+        if let Some(existing) = ty[key].as_array_mut() {
+            existing.extend(values);
+        }
+    };
+
+    match kind {
+        TypeKind::Scalar => {}
+        TypeKind::Object(object) => {
+            extend("fields", object.fields.iter().map(field).collect());
+            extend("interfaces", object.implements.iter().map(named_type_ref).collect());
+        }
+        TypeKind::Interface(interface) => {
+            extend("fields", interface.fields.iter().map(field).collect());
+            extend("interfaces", interface.implements.iter().map(named_type_ref).collect());
+        }
+        TypeKind::Union(union) => {
+            extend("possibleTypes", union.members.iter().map(named_type_ref).collect());
+        }
+        TypeKind::Enum(enum_type) => {
+            extend("enumValues", enum_type.values.iter().map(enum_value).collect());
+        }
+        TypeKind::InputObject(input_object) => {
+            extend("inputFields", input_object.fields.iter().map(input_value).collect());
+        }
+    }
+}
+
+/// The possible types of an interface are the objects implementing it, which are only known once
+/// the whole document has been read.
+fn add_possible_types(types: &mut BTreeMap<&str, Value>) {
+    let implementations = types
+        .values()
+        .filter(|ty| ty["kind"] == "OBJECT")
+        .flat_map(|ty| {
+            let name = ty["name"].clone();
+            ty["interfaces"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |interface| (interface["name"].clone(), name.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    for (interface, object) in implementations {
+        let Some(ty) = interface.as_str().and_then(|interface| types.get_mut(interface)) else {
+            continue;
+        };
+
+        if let Some(possible_types) = ty["possibleTypes"].as_array_mut() {
+            possible_types.push(json!({ "kind": "OBJECT", "name": object, "ofType": null }));
+        }
+    }
+}
+
+fn field(field: &Positioned<FieldDefinition>) -> Value {
+    let field = &field.node;
+    let (is_deprecated, deprecation_reason) = deprecation(&field.directives);
+
+    json!({
+        "name": field.name.node.as_str(),
+        "description": description(&field.description),
+        "args": field.arguments.iter().map(input_value).collect::<Vec<_>>(),
+        "type": type_ref(&field.ty.node),
+        "isDeprecated": is_deprecated,
+        "deprecationReason": deprecation_reason,
+    })
+}
+
+fn input_value(input: &Positioned<InputValueDefinition>) -> Value {
+    let input = &input.node;
+    let (is_deprecated, deprecation_reason) = deprecation(&input.directives);
+
+    json!({
+        "name": input.name.node.as_str(),
+        "description": description(&input.description),
+        "type": type_ref(&input.ty.node),
+        "defaultValue": input.default_value.as_ref().map(|value| value.node.to_string()),
+        "isDeprecated": is_deprecated,
+        "deprecationReason": deprecation_reason,
+    })
+}
+
+fn enum_value(value: &Positioned<EnumValueDefinition>) -> Value {
+    let value = &value.node;
+    let (is_deprecated, deprecation_reason) = deprecation(&value.directives);
+
+    json!({
+        "name": value.value.node.as_str(),
+        "description": description(&value.description),
+        "isDeprecated": is_deprecated,
+        "deprecationReason": deprecation_reason,
+    })
+}
+
+/// Introspection only cares about the name of a named type, the kind is only needed for
+/// wrapping types.
+fn named_type_ref(name: &Positioned<Name>) -> Value {
+    json!({ "kind": "OBJECT", "name": name.node.as_str(), "ofType": null })
+}
+
+fn type_ref(ty: &Type) -> Value {
+    let base = match &ty.base {
+        BaseType::Named(name) => json!({ "kind": "SCALAR", "name": name.as_str(), "ofType": null }),
+        BaseType::List(inner) => json!({ "kind": "LIST", "name": null, "ofType": type_ref(inner) }),
+    };
+
+    if ty.nullable {
+        base
+    } else {
+        json!({ "kind": "NON_NULL", "name": null, "ofType": base })
+    }
+}
+
+fn deprecation(directives: &[Positioned<ConstDirective>]) -> (bool, Option<String>) {
+    let Some(deprecated) = directives
+        .iter()
+        .find(|directive| directive.node.name.node == "deprecated")
+    else {
+        return (false, None);
+    };
+
+    let reason = match deprecated.node.get_argument("reason") {
+        Some(reason) => match &reason.node {
+            engine::Value::String(reason) => Some(reason.clone()),
+            _ => None,
+        },
+        None => Some("No longer supported".to_string()),
+    };
+
+    (true, reason)
+}
+
+fn description(description: &Option<Positioned<String>>) -> Option<&str> {
+    description.as_ref().map(|description| description.node.as_str())
+}
+
+fn directive_location(location: DirectiveLocation) -> &'static str {
+    match location {
+        DirectiveLocation::Query => "QUERY",
+        DirectiveLocation::Mutation => "MUTATION",
+        DirectiveLocation::Subscription => "SUBSCRIPTION",
+        DirectiveLocation::Field => "FIELD",
+        DirectiveLocation::FragmentDefinition => "FRAGMENT_DEFINITION",
+        DirectiveLocation::FragmentSpread => "FRAGMENT_SPREAD",
+        DirectiveLocation::InlineFragment => "INLINE_FRAGMENT",
+        DirectiveLocation::Schema => "SCHEMA",
+        DirectiveLocation::Scalar => "SCALAR",
+        DirectiveLocation::Object => "OBJECT",
+        DirectiveLocation::FieldDefinition => "FIELD_DEFINITION",
+        DirectiveLocation::ArgumentDefinition => "ARGUMENT_DEFINITION",
+        DirectiveLocation::Interface => "INTERFACE",
+        DirectiveLocation::Union => "UNION",
+        DirectiveLocation::Enum => "ENUM",
+        DirectiveLocation::EnumValue => "ENUM_VALUE",
+        DirectiveLocation::InputObject => "INPUT_OBJECT",
+        DirectiveLocation::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+        DirectiveLocation::VariableDefinition => "VARIABLE_DEFINITION",
+    }
+}
@@ -39,6 +39,16 @@ pub enum ParentNode {
         encoding_style: QueryParameterEncodingStyle,
         required: bool,
     },
+    HeaderParameter {
+        name: String,
+        operation_index: NodeIndex,
+        required: bool,
+    },
+    CookieParameter {
+        name: String,
+        operation_index: NodeIndex,
+        required: bool,
+    },
     AllOf(NodeIndex),
 }
 
@@ -49,7 +59,9 @@ impl ParentNode {
             ParentNode::OperationResponse { operation_index, .. }
             | ParentNode::OperationRequest { operation_index, .. }
             | ParentNode::PathParameter { operation_index, .. }
-            | ParentNode::QueryParameter { operation_index, .. } => *operation_index,
+            | ParentNode::QueryParameter { operation_index, .. }
+            | ParentNode::HeaderParameter { operation_index, .. }
+            | ParentNode::CookieParameter { operation_index, .. } => *operation_index,
             ParentNode::Field { object_index, .. } => *object_index,
             ParentNode::List { parent, .. } => parent.node_index(),
         }
@@ -109,6 +121,14 @@ impl ParentNode {
                 wrapping: wrapping.set_required(*required),
                 encoding_style: *encoding_style,
             },
+            ParentNode::HeaderParameter { name, required, .. } => Edge::HasHeaderParameter {
+                name: name.clone(),
+                wrapping: wrapping.set_required(*required),
+            },
+            ParentNode::CookieParameter { name, required, .. } => Edge::HasCookieParameter {
+                name: name.clone(),
+                wrapping: wrapping.set_required(*required),
+            },
             ParentNode::AllOf(_) => Edge::AllOfMember,
         }
     }
@@ -73,7 +73,7 @@ impl InputValue {
 
     pub fn type_name(&self, graph: &OpenApiGraph) -> Option<String> {
         match &graph.graph[self.index] {
-            Node::Scalar(s) => Some(s.type_name()),
+            Node::Scalar(s) => Some(s.input_type_name()),
             Node::Enum { .. } => Enum::from_index(self.index, graph)?.name(graph),
             Node::Object | Node::Union => InputObject::from_index(self.index, graph)?.name(graph),
             Node::PlaceholderType => {
@@ -32,11 +32,14 @@ pub use self::{
     input_value::{InputValue, InputValueKind},
     operations::Operation,
     output_type::{OutputField, OutputFieldType, OutputType},
-    parameters::{PathParameter, QueryParameter, RequestBody},
+    parameters::{CookieParameter, HeaderParameter, PathParameter, QueryParameter, RequestBody},
     resource::{Resource, ResourceOperation},
     scalar::Scalar,
 };
-use crate::{parsing::ParseOutput, ApiMetadata, Error};
+use crate::{
+    parsing::{ParseOutput, Security},
+    ApiMetadata, Error,
+};
 
 /// A graph representation of an OpenApi schema.
 ///
@@ -45,6 +48,7 @@ pub struct OpenApiGraph {
     graph: Graph<Node, Edge>,
     operation_indices: Vec<NodeIndex>,
     pub metadata: ApiMetadata,
+    pub security: Security,
 }
 
 impl OpenApiGraph {
@@ -53,6 +57,7 @@ impl OpenApiGraph {
             graph: parsed.graph,
             operation_indices: vec![],
             metadata,
+            security: parsed.security,
         };
 
         transforms::run(&mut this)?;
@@ -71,7 +76,7 @@ impl OpenApiGraph {
 
     #[cfg(test)]
     pub fn from_petgraph(graph: Graph<Node, Edge>) -> Self {
-        use engine::registry::ConnectorHeaders;
+        use engine::registry::{ConnectorFetchPolicy, ConnectorHeaders};
         use parser_sdl::OpenApiQueryNamingStrategy;
 
         OpenApiGraph {
@@ -84,7 +89,11 @@ impl OpenApiGraph {
                 headers: ConnectorHeaders::default(),
                 query_naming: OpenApiQueryNamingStrategy::default(),
                 type_prefix: Some("Test".into()),
+                security: vec![],
+                pagination: vec![],
+                fetch_policy: ConnectorFetchPolicy::default(),
             },
+            security: Security::default(),
         }
     }
 
@@ -190,6 +199,18 @@ pub enum Edge {
         encoding_style: QueryParameterEncodingStyle,
     },
 
+    /// An edge between an operation and the type/schema of one of its header parameters
+    HasHeaderParameter {
+        name: String,
+        wrapping: WrappingType,
+    },
+
+    /// An edge between an operation and the type/schema of one of its cookie parameters
+    HasCookieParameter {
+        name: String,
+        wrapping: WrappingType,
+    },
+
     /// An edge bewteen an operation and it's request type
     HasRequestType {
         content_type: Box<RequestBodyContentType>,
@@ -248,6 +269,8 @@ pub enum ScalarKind {
     Float,
     Boolean,
     Json,
+    /// A string with a format of binary, i.e. the contents of a file.
+    Binary,
 }
 
 impl ScalarKind {
@@ -255,13 +278,26 @@ impl ScalarKind {
         use engine::registry::scalars::{JSONScalar, SDLDefinitionScalar};
 
         match self {
-            ScalarKind::String => "String".to_string(),
+            // Binary can't really appear in a JSON response, so whatever the API puts
+            // there we just return as a string.
+            ScalarKind::String | ScalarKind::Binary => "String".to_string(),
             ScalarKind::Integer => "Int".to_string(),
             ScalarKind::Float => "Float".to_string(),
             ScalarKind::Boolean => "Boolean".to_string(),
             ScalarKind::Json => JSONScalar::name().expect("JSONScalar to have a name").to_owned(),
         }
     }
+
+    /// The name of this scalar when it's used in an input, where binary is a file
+    /// uploaded alongside the request.
+    pub fn input_type_name(self) -> String {
+        use engine::registry::scalars::{SDLDefinitionScalar, UploadScalar};
+
+        match self {
+            ScalarKind::Binary => UploadScalar::name().expect("UploadScalar to have a name").to_owned(),
+            _ => self.type_name(),
+        }
+    }
 }
 
 impl std::fmt::Debug for Node {
@@ -292,7 +328,7 @@ pub struct OperationDetails {
     pub operation_id: Option<String>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "UPPERCASE", ascii_case_insensitive)]
 pub enum HttpMethod {
     Get,
@@ -404,6 +440,8 @@ impl OpenApiGraph {
                         Edge::HasField { .. }
                             | Edge::HasPathParameter { .. }
                             | Edge::HasQueryParameter { .. }
+                            | Edge::HasHeaderParameter { .. }
+                            | Edge::HasCookieParameter { .. }
                             | Edge::HasRequestType { .. }
                             | Edge::HasResponseType { .. }
                             | Edge::HasType { .. }
@@ -436,7 +474,9 @@ impl OpenApiGraph {
                         match edge.weight() {
                             Edge::HasField { name, .. }
                             | Edge::HasPathParameter { name, .. }
-                            | Edge::HasQueryParameter { name, .. } => Some(Cow::Borrowed(name.as_str())),
+                            | Edge::HasQueryParameter { name, .. }
+                            | Edge::HasHeaderParameter { name, .. }
+                            | Edge::HasCookieParameter { name, .. } => Some(Cow::Borrowed(name.as_str())),
                             _ => None,
                         }
                     }));
@@ -1,4 +1,4 @@
-use engine::registry::resolvers::http::ExpectedStatusCode;
+use engine::registry::resolvers::http::{ExpectedStatusCode, ResponseContentType, SecurityRequirement};
 use inflector::Inflector;
 use petgraph::{
     graph::NodeIndex,
@@ -6,8 +6,8 @@ use petgraph::{
 };
 
 use super::{
-    output_type::OutputFieldType, Arity, DebugNode, Edge, HttpMethod, Node, OperationDetails, PathParameter,
-    QueryParameter, RequestBody, RequestBodyContentType,
+    output_type::OutputFieldType, Arity, CookieParameter, DebugNode, Edge, HeaderParameter, HttpMethod, Node,
+    OperationDetails, PathParameter, QueryParameter, RequestBody, RequestBodyContentType, Scalar, ScalarKind,
 };
 use crate::{is_ok, QueryNamingStrategy};
 
@@ -90,6 +90,16 @@ impl Operation {
         )
     }
 
+    pub fn operation_id(self, graph: &super::OpenApiGraph) -> Option<&str> {
+        self.details(graph).operation_id.as_deref()
+    }
+
+    pub fn security_requirements(self, graph: &super::OpenApiGraph) -> Vec<SecurityRequirement> {
+        let details = self.details(graph);
+
+        graph.security.requirements(&details.path, details.http_method).to_vec()
+    }
+
     pub fn name(self, graph: &super::OpenApiGraph) -> Option<OperationName> {
         match graph.metadata.query_naming {
             QueryNamingStrategy::OperationId => self.name_by_operation_id(graph),
@@ -167,6 +177,28 @@ impl Operation {
             .collect()
     }
 
+    pub fn header_parameters(self, graph: &super::OpenApiGraph) -> Vec<HeaderParameter> {
+        graph
+            .graph
+            .edges(self.node_index())
+            .filter_map(|edge| match edge.weight() {
+                Edge::HasHeaderParameter { .. } => Some(HeaderParameter(edge.id())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn cookie_parameters(self, graph: &super::OpenApiGraph) -> Vec<CookieParameter> {
+        graph
+            .graph
+            .edges(self.node_index())
+            .filter_map(|edge| match edge.weight() {
+                Edge::HasCookieParameter { .. } => Some(CookieParameter(edge.id())),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn expected_status(self, graph: &super::OpenApiGraph) -> Option<ExpectedStatusCode> {
         // As in ty below, we're only taking succesful expected statuses for now
         graph
@@ -188,10 +220,11 @@ impl Operation {
             })
             .collect::<Vec<_>>();
 
-        // Sort the bodies such that we prefer JSON over form encoded
+        // Sort the bodies such that we prefer JSON over form encoded over multipart
         potential_bodies.sort_by_key(|(content_type, _)| match content_type.as_ref() {
-            RequestBodyContentType::Json => 2,
-            RequestBodyContentType::FormEncoded(_) => 1,
+            RequestBodyContentType::Json => 3,
+            RequestBodyContentType::FormEncoded(_) => 2,
+            RequestBodyContentType::Multipart => 1,
         });
 
         let (_, edge_index) = potential_bodies.pop()?;
@@ -206,25 +239,60 @@ impl Operation {
     }
 
     pub fn ty(self, graph: &super::OpenApiGraph) -> Option<OutputFieldType> {
+        self.response(graph).map(|(ty, _)| ty)
+    }
+
+    pub fn response_content_type(self, graph: &super::OpenApiGraph) -> ResponseContentType {
+        self.response(graph)
+            .map(|(_, content_type)| content_type)
+            .unwrap_or_default()
+    }
+
+    fn response(self, graph: &super::OpenApiGraph) -> Option<(OutputFieldType, ResponseContentType)> {
         // A query operation can have a lot of different types: successes/fails,
         // and different content types for each of those scenarios.
         //
-        // For now we're just picking the first success response we come across
-        // but we'll probably want to do something smarter in the future.
-        graph
-            .graph
-            .edges(self.node_index())
-            .find_map(|edge| match edge.weight() {
-                super::Edge::HasResponseType {
-                    content_type,
-                    status_code,
-                    wrapping,
-                    ..
-                } if is_ok(status_code) && is_json(content_type) => {
-                    OutputFieldType::from_index(edge.target(), wrapping, graph)
+        // For now we're just picking the first JSON success response we come across,
+        // falling back to the first text or binary one if there's no JSON.
+        let mut fallback = None;
+
+        for edge in graph.graph.edges(self.node_index()) {
+            let super::Edge::HasResponseType {
+                content_type,
+                status_code,
+                wrapping,
+                ..
+            } = edge.weight()
+            else {
+                continue;
+            };
+
+            if !is_ok(status_code) {
+                continue;
+            }
+
+            if is_json(content_type) {
+                if let Some(ty) = OutputFieldType::from_index(edge.target(), wrapping, graph) {
+                    return Some((ty, ResponseContentType::Json));
                 }
-                _ => None,
-            })
+                continue;
+            }
+
+            if fallback.is_some() || content_type.contains("json") || wrapping.contains_list() {
+                continue;
+            }
+
+            // Non-JSON responses can only be represented if their schema is a plain string
+            let is_string = Scalar::from_index(edge.target(), graph)
+                .is_some_and(|scalar| matches!(scalar.kind(graph), ScalarKind::String | ScalarKind::Binary));
+
+            if is_string {
+                fallback = OutputFieldType::from_index(edge.target(), wrapping, graph)
+                    .map(|ty| (ty, non_json_content_type(content_type)));
+            }
+        }
+
+        fallback
     }
 }
 
@@ -241,6 +309,23 @@ fn is_json(content_type: &str) -> bool {
     content_type == "application/json"
 }
 
+/// Decides whether we return a non-JSON response as text or as base64 encoded binary.
+fn non_json_content_type(content_type: &str) -> ResponseContentType {
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+
+    if essence.starts_with("text/")
+        || essence.ends_with("+xml")
+        || matches!(
+            essence,
+            "application/xml" | "application/csv" | "application/yaml" | "application/x-yaml"
+        )
+    {
+        ResponseContentType::Text
+    } else {
+        ResponseContentType::Binary
+    }
+}
+
 #[derive(Debug)]
 pub struct OperationName(String);
 
@@ -45,7 +45,11 @@ impl OpenApiGraph {
             // Don't follow edges that lead to input types
             !matches!(
                 edge.weight(),
-                Edge::HasPathParameter { .. } | Edge::HasQueryParameter { .. } | Edge::HasRequestType { .. }
+                Edge::HasPathParameter { .. }
+                    | Edge::HasQueryParameter { .. }
+                    | Edge::HasHeaderParameter { .. }
+                    | Edge::HasCookieParameter { .. }
+                    | Edge::HasRequestType { .. }
             )
         });
 
@@ -187,6 +191,31 @@ impl OutputFieldType {
         }
     }
 
+    pub fn output_type(&self, graph: &OpenApiGraph) -> Option<OutputType> {
+        OutputType::from_index(self.target_index, graph)
+    }
+
+    /// The type of the items of this field, if it's a list (but not a list of lists)
+    pub fn list_item_type(&self) -> Option<OutputFieldType> {
+        if self.wrapping.arity() != Some(Arity::Many) {
+            return None;
+        }
+
+        let mut wrapping = &self.wrapping;
+        loop {
+            match wrapping {
+                WrappingType::NonNull(inner) => wrapping = inner,
+                WrappingType::List(inner) => {
+                    return Some(OutputFieldType {
+                        wrapping: inner.as_ref().clone(),
+                        target_index: self.target_index,
+                    })
+                }
+                WrappingType::Named => return None,
+            }
+        }
+    }
+
     pub fn inner_kind(&self, graph: &OpenApiGraph) -> OutputFieldKind {
         if Enum::from_index(self.target_index, graph).is_some() {
             OutputFieldKind::Enum
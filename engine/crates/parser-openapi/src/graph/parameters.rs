@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
 use engine::registry::resolvers::http::{QueryParameterEncodingStyle, RequestBodyContentType};
-use petgraph::graph::EdgeIndex;
+use inflector::Inflector;
+use petgraph::{graph::EdgeIndex, visit::EdgeRef};
 
 use super::{input_value::InputValue, DebugNode, Edge, FieldName};
 
@@ -11,6 +12,12 @@ pub struct PathParameter(pub(super) EdgeIndex);
 #[derive(Clone, Copy)]
 pub struct QueryParameter(pub(super) EdgeIndex);
 
+#[derive(Clone, Copy)]
+pub struct HeaderParameter(pub(super) EdgeIndex);
+
+#[derive(Clone, Copy)]
+pub struct CookieParameter(pub(super) EdgeIndex);
+
 #[derive(Clone, Copy)]
 pub struct RequestBody(pub(super) EdgeIndex);
 
@@ -82,6 +89,89 @@ impl QueryParameter {
     }
 }
 
+impl HeaderParameter {
+    pub fn openapi_name(self, graph: &super::OpenApiGraph) -> &str {
+        match graph.graph.edge_weight(self.0) {
+            Some(Edge::HasHeaderParameter { name, .. }) => name,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The argument name of the parameter, prefixed with `header` if it would clash with
+    /// a path or query parameter, or the request body.
+    pub fn graphql_name(self, graph: &super::OpenApiGraph) -> FieldName<'_> {
+        let name = self.openapi_name(graph);
+
+        if argument_name_is_taken(graph, self.0, name) {
+            FieldName(Cow::Owned(format!("header_{name}")))
+        } else {
+            FieldName(Cow::Borrowed(name))
+        }
+    }
+
+    pub fn input_value(self, graph: &super::OpenApiGraph) -> Option<InputValue> {
+        let (_, dest_index) = graph.graph.edge_endpoints(self.0)?;
+        match graph.graph.edge_weight(self.0)? {
+            Edge::HasHeaderParameter { wrapping, .. } => InputValue::from_index(dest_index, wrapping.clone(), graph),
+            _ => None,
+        }
+    }
+}
+
+impl CookieParameter {
+    pub fn openapi_name(self, graph: &super::OpenApiGraph) -> &str {
+        match graph.graph.edge_weight(self.0) {
+            Some(Edge::HasCookieParameter { name, .. }) => name,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The argument name of the parameter, prefixed with `cookie` if it would clash with
+    /// a path, query or header parameter, or the request body.
+    pub fn graphql_name(self, graph: &super::OpenApiGraph) -> FieldName<'_> {
+        let name = self.openapi_name(graph);
+
+        if argument_name_is_taken(graph, self.0, name) {
+            FieldName(Cow::Owned(format!("cookie_{name}")))
+        } else {
+            FieldName(Cow::Borrowed(name))
+        }
+    }
+
+    pub fn input_value(self, graph: &super::OpenApiGraph) -> Option<InputValue> {
+        let (_, dest_index) = graph.graph.edge_endpoints(self.0)?;
+        match graph.graph.edge_weight(self.0)? {
+            Edge::HasCookieParameter { wrapping, .. } => InputValue::from_index(dest_index, wrapping.clone(), graph),
+            _ => None,
+        }
+    }
+}
+
+/// Checks whether an operation has an argument named `name` that is taken before the
+/// header or cookie parameter at `edge_index`. Path & query parameters keep their names,
+/// then header parameters, then cookie parameters.
+fn argument_name_is_taken(graph: &super::OpenApiGraph, edge_index: EdgeIndex, name: &str) -> bool {
+    let Some((operation_index, _)) = graph.graph.edge_endpoints(edge_index) else {
+        return false;
+    };
+    let is_cookie = matches!(
+        graph.graph.edge_weight(edge_index),
+        Some(Edge::HasCookieParameter { .. })
+    );
+    let name = name.to_camel_case();
+
+    graph.graph.edges(operation_index).any(|edge| {
+        let other_name = match edge.weight() {
+            Edge::HasPathParameter { name, .. } | Edge::HasQueryParameter { name, .. } => name.as_str(),
+            Edge::HasHeaderParameter { name, .. } if is_cookie => name.as_str(),
+            Edge::HasRequestType { .. } => RequestBody(edge.id()).argument_name(),
+            _ => return false,
+        };
+
+        other_name.to_camel_case() == name
+    })
+}
+
 impl RequestBody {
     pub fn argument_name(self) -> &'static str {
         "input"
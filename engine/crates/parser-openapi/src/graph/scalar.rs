@@ -1,6 +1,6 @@
 use petgraph::graph::NodeIndex;
 
-use super::Node;
+use super::{Node, ScalarKind};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Scalar(NodeIndex);
@@ -17,4 +17,11 @@ impl Scalar {
     pub fn name(self, graph: &super::OpenApiGraph) -> Option<String> {
         graph.type_name(self.0)
     }
+
+    pub fn kind(self, graph: &super::OpenApiGraph) -> ScalarKind {
+        match graph.graph[self.0] {
+            Node::Scalar(kind) => kind,
+            _ => unreachable!("Scalar should always point at a scalar node"),
+        }
+    }
 }
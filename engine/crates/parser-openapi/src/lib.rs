@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 
-use engine::registry::{resolvers::http::ExpectedStatusCode, ConnectorHeaders, Registry};
+use engine::registry::{
+    resolvers::http::ExpectedStatusCode, ConnectorFetchPolicy, ConnectorHeaders, ConnectorSecurity, Registry,
+};
 use graph::OpenApiGraph;
 use inflector::Inflector;
-use parser_sdl::OpenApiQueryNamingStrategy as QueryNamingStrategy;
+use parser_sdl::{OpenApiPagination, OpenApiQueryNamingStrategy as QueryNamingStrategy, OpenApiSecurity};
 use tracing as _;
 use url::Url;
 
@@ -36,8 +38,20 @@ pub fn parse_spec(
 
     validation::validate(&graph)?;
 
+    let security = connector_security(&graph, &metadata.security).map_err(|error| vec![error])?;
+
     output::output(&graph, registry);
 
+    if let Some(security) = security {
+        registry.http_security.insert(metadata.unique_namespace(), security);
+    }
+
+    if metadata.fetch_policy != ConnectorFetchPolicy::default() {
+        registry
+            .http_fetch_policies
+            .insert(metadata.unique_namespace(), metadata.fetch_policy);
+    }
+
     registry
         .http_headers
         .insert(metadata.unique_namespace(), metadata.headers);
@@ -45,6 +59,32 @@ pub fn parse_spec(
     Ok(())
 }
 
+/// Combines the security schemes of the spec with the credentials from the directive.
+fn connector_security(
+    graph: &OpenApiGraph,
+    credentials: &[OpenApiSecurity],
+) -> Result<Option<ConnectorSecurity>, Error> {
+    if credentials.is_empty() {
+        return Ok(None);
+    }
+
+    let schemes = credentials
+        .iter()
+        .map(|security| {
+            let scheme = graph
+                .security
+                .schemes
+                .get(&security.scheme)
+                .ok_or_else(|| Error::UnknownSecurityScheme(security.scheme.clone()))?
+                .with_credentials(&security.scheme, security.credentials.clone())?;
+
+            Ok((security.scheme.clone(), scheme))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Some(ConnectorSecurity::new(schemes)))
+}
+
 #[derive(Clone, Debug)]
 pub struct ApiMetadata {
     pub name: String,
@@ -53,6 +93,9 @@ pub struct ApiMetadata {
     pub headers: ConnectorHeaders,
     pub query_naming: QueryNamingStrategy,
     pub type_prefix: Option<String>,
+    pub security: Vec<OpenApiSecurity>,
+    pub pagination: Vec<OpenApiPagination>,
+    pub fetch_policy: ConnectorFetchPolicy,
 }
 
 impl ApiMetadata {
@@ -71,6 +114,8 @@ impl ApiMetadata {
 impl From<parser_sdl::OpenApiDirective> for ApiMetadata {
     fn from(val: parser_sdl::OpenApiDirective) -> Self {
         let headers = val.headers();
+        let security = val.security();
+        let fetch_policy = val.fetch_policy();
 
         let type_prefix = val
             .transforms
@@ -85,6 +130,9 @@ impl From<parser_sdl::OpenApiDirective> for ApiMetadata {
             headers,
             query_naming: val.transforms.query_naming,
             type_prefix,
+            security,
+            pagination: val.transforms.pagination,
+            fetch_policy,
         }
     }
 }
@@ -186,6 +234,12 @@ pub enum Error {
     ListNestedInsideObjectQueryParameter(String, String),
     #[error("We found a cycle of allOf objects in the OpenAPI schema, which is unsupported")]
     AllOfCycle,
+    #[error("There are credentials for the security scheme {0}, which isn't in the OpenAPI spec")]
+    UnknownSecurityScheme(String),
+    #[error(
+        "The credentials for the security scheme {0} don't match the type of the scheme, or the scheme is unsupported"
+    )]
+    UnsupportedSecurityScheme(String),
 }
 
 fn is_ok(status: &ExpectedStatusCode) -> bool {
@@ -154,7 +154,7 @@ impl TryFrom<ScalarKind> for engine::registry::union_discriminator::ScalarKind {
     fn try_from(value: ScalarKind) -> Result<Self, Self::Error> {
         use engine::registry::union_discriminator::ScalarKind as EngineScalarKind;
         match value {
-            ScalarKind::String => Ok(EngineScalarKind::String),
+            ScalarKind::String | ScalarKind::Binary => Ok(EngineScalarKind::String),
             ScalarKind::Integer | ScalarKind::Float => Ok(EngineScalarKind::Number),
             ScalarKind::Boolean => Ok(EngineScalarKind::Boolean),
             ScalarKind::Json => {
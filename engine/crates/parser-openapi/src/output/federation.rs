@@ -109,9 +109,13 @@ fn key_for_operation(
     // Knowing OpenAPI that assumption will come back to bite me, but
     // I'll postpone that pain till it happens
 
-    let resolver = operation
-        .operation
-        .http_resolver(graph, operation.federation_path_parameters(graph), vec![])?;
+    let resolver = operation.operation.http_resolver(
+        graph,
+        operation.federation_path_parameters(graph),
+        vec![],
+        vec![],
+        vec![],
+    )?;
 
     let Resolver::Http(resolver) = resolver else {
         unreachable!();
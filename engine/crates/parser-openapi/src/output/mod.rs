@@ -1,6 +1,7 @@
 mod discriminators;
 mod federation;
 mod namespacing;
+mod pagination;
 
 use std::borrow::Cow;
 
@@ -8,10 +9,11 @@ use engine::{
     indexmap::IndexMap,
     registry::{
         resolvers::{
-            http::{self, HttpResolver},
+            http::{self, HttpResolver, ResponseContentType},
             transformer::Transformer,
             Resolver,
         },
+        scalars::{BytesScalar, SDLDefinitionScalar},
         variables::VariableResolveDefinition,
         EnumType, InputObjectType, InputValueType, MetaEnumValue, MetaField, MetaInputValue, MetaType, ObjectType,
         Registry, UnionType,
@@ -21,14 +23,15 @@ use inflector::Inflector;
 
 use self::namespacing::RegistryExt;
 use crate::graph::{
-    Enum, InputField, InputObject, InputValue, OpenApiGraph, Operation, OutputField, OutputFieldType, OutputType,
-    PathParameter, QueryParameter, RequestBody, WrappingType,
+    CookieParameter, Enum, HeaderParameter, InputField, InputObject, InputValue, OpenApiGraph, Operation, OutputField,
+    OutputFieldType, OutputType, PathParameter, QueryParameter, RequestBody, ScalarKind, WrappingType,
 };
 
 pub fn output(graph: &OpenApiGraph, registry: &mut Registry) {
     registry.types.extend(types_to_metatypes(graph.output_types(), graph));
     registry.types.extend(types_to_metatypes(graph.input_objects(), graph));
     registry.types.extend(types_to_metatypes(graph.enums(), graph));
+    registry.types.extend(pagination::connection_types(graph));
 
     let query_operations = graph.query_operations();
     if !query_operations.is_empty() {
@@ -228,7 +231,10 @@ impl Operation {
     fn into_meta_field(self, graph: &OpenApiGraph) -> Option<MetaField> {
         let path_parameters = self.path_parameters(graph);
         let query_parameters = self.query_parameters(graph);
+        let header_parameters = self.header_parameters(graph);
+        let cookie_parameters = self.cookie_parameters(graph);
         let request_body = self.request_body(graph);
+        let connection = self.connection(graph);
 
         let mut args = IndexMap::new();
         args.extend(path_parameters.iter().filter_map(|param| {
@@ -236,6 +242,20 @@ impl Operation {
             Some((input_value.name.clone(), input_value))
         }));
         args.extend(query_parameters.iter().filter_map(|param| {
+            if connection
+                .as_ref()
+                .is_some_and(|connection| connection.hides(*param, graph))
+            {
+                return None;
+            }
+            let input_value = param.to_meta_input_value(graph)?;
+            Some((input_value.name.clone(), input_value))
+        }));
+        args.extend(header_parameters.iter().filter_map(|param| {
+            let input_value = param.to_meta_input_value(graph)?;
+            Some((input_value.name.clone(), input_value))
+        }));
+        args.extend(cookie_parameters.iter().filter_map(|param| {
             let input_value = param.to_meta_input_value(graph)?;
             Some((input_value.name.clone(), input_value))
         }));
@@ -244,6 +264,24 @@ impl Operation {
             Some((input_value.name.clone(), input_value))
         }));
 
+        if let Some(connection) = connection {
+            args.insert("first".into(), MetaInputValue::new("first", "Int"));
+            args.insert("after".into(), MetaInputValue::new("after", "String"));
+
+            // The resolver returns the connection itself, the items get transformed by the edges.
+            return Some(MetaField {
+                resolver: self.http_resolver(
+                    graph,
+                    self.http_path_parameters(graph),
+                    self.http_query_parameters(graph),
+                    self.http_header_parameters(graph),
+                    self.http_cookie_parameters(graph),
+                )?,
+                args,
+                ..meta_field(self.name(graph)?.to_string(), connection.type_name(graph)?)
+            });
+        }
+
         let mut output_type = self.ty(graph)?;
 
         // HTTP requests can fail so it's best if we make Operation fields
@@ -251,7 +289,19 @@ impl Operation {
         // bubbling further up the query heirarchy.
         output_type.wrapping = output_type.wrapping.unwrap_required();
 
-        let type_string = TypeDisplay::from_output_field_type(&output_type, graph)?.to_string();
+        // Non-JSON responses are returned as a scalar according to how we decode them,
+        // regardless of what the schema says.
+        let type_string = match self.response_content_type(graph) {
+            ResponseContentType::Json => TypeDisplay::from_output_field_type(&output_type, graph)?.to_string(),
+            ResponseContentType::Text => {
+                TypeDisplay::new(&output_type.wrapping, ScalarKind::String.type_name()).to_string()
+            }
+            ResponseContentType::Binary => TypeDisplay::new(
+                &output_type.wrapping,
+                BytesScalar::name().expect("BytesScalar to have a name").to_owned(),
+            )
+            .to_string(),
+        };
 
         Some(MetaField {
             resolver: self
@@ -259,6 +309,8 @@ impl Operation {
                     graph,
                     self.http_path_parameters(graph),
                     self.http_query_parameters(graph),
+                    self.http_header_parameters(graph),
+                    self.http_cookie_parameters(graph),
                 )?
                 .and_then_maybe(output_type.transforming_resolver(graph)),
             args,
@@ -281,8 +333,15 @@ impl Operation {
     }
 
     fn http_query_parameters(self, graph: &OpenApiGraph) -> Vec<http::QueryParameter> {
+        let connection = self.connection(graph);
+
         self.query_parameters(graph)
             .iter()
+            .filter(|param| {
+                !connection
+                    .as_ref()
+                    .is_some_and(|connection| connection.hides(**param, graph))
+            })
             .map(|param| {
                 let name = param.openapi_name(graph).to_string();
                 let input_name = param.graphql_name(graph).to_string();
@@ -295,11 +354,41 @@ impl Operation {
             .collect()
     }
 
+    fn http_header_parameters(self, graph: &OpenApiGraph) -> Vec<http::HeaderParameter> {
+        self.header_parameters(graph)
+            .iter()
+            .map(|param| {
+                let name = param.openapi_name(graph).to_string();
+                let input_name = param.graphql_name(graph).to_string();
+                http::HeaderParameter {
+                    name,
+                    variable_resolve_definition: VariableResolveDefinition::connector_input_type_name(input_name),
+                }
+            })
+            .collect()
+    }
+
+    fn http_cookie_parameters(self, graph: &OpenApiGraph) -> Vec<http::CookieParameter> {
+        self.cookie_parameters(graph)
+            .iter()
+            .map(|param| {
+                let name = param.openapi_name(graph).to_string();
+                let input_name = param.graphql_name(graph).to_string();
+                http::CookieParameter {
+                    name,
+                    variable_resolve_definition: VariableResolveDefinition::connector_input_type_name(input_name),
+                }
+            })
+            .collect()
+    }
+
     fn http_resolver(
         self,
         graph: &OpenApiGraph,
         path_parameters: Vec<http::PathParameter>,
         query_parameters: Vec<http::QueryParameter>,
+        header_parameters: Vec<http::HeaderParameter>,
+        cookie_parameters: Vec<http::CookieParameter>,
     ) -> Option<Resolver> {
         Some(Resolver::Http(HttpResolver {
             method: self.http_method(graph),
@@ -308,6 +397,9 @@ impl Operation {
             expected_status: self.expected_status(graph)?,
             path_parameters,
             query_parameters,
+            header_parameters,
+            cookie_parameters,
+            security: self.security_requirements(graph),
             request_body: self
                 .request_body(graph)
                 .map(|request_body| engine::registry::resolvers::http::RequestBody {
@@ -316,6 +408,8 @@ impl Operation {
                     ),
                     content_type: request_body.content_type(graph).clone(),
                 }),
+            response_content_type: self.response_content_type(graph),
+            pagination: self.connection(graph).map(|connection| connection.pagination),
         }))
     }
 }
@@ -334,6 +428,20 @@ impl QueryParameter {
     }
 }
 
+impl HeaderParameter {
+    fn to_meta_input_value(self, graph: &OpenApiGraph) -> Option<MetaInputValue> {
+        self.input_value(graph)?
+            .to_meta_input_value(&self.graphql_name(graph).to_string(), graph)
+    }
+}
+
+impl CookieParameter {
+    fn to_meta_input_value(self, graph: &OpenApiGraph) -> Option<MetaInputValue> {
+        self.input_value(graph)?
+            .to_meta_input_value(&self.graphql_name(graph).to_string(), graph)
+    }
+}
+
 impl RequestBody {
     fn to_meta_input_value(self, graph: &OpenApiGraph) -> Option<MetaInputValue> {
         self.input_value(graph)?
@@ -0,0 +1,239 @@
+//! List queries can be exposed as Relay connections if the connector is configured
+//! with the way the API paginates them.
+
+use std::collections::BTreeMap;
+
+use engine::registry::{
+    resolvers::{
+        http::{HttpPagination, PaginationStyle, ResponseContentType},
+        transformer::Transformer,
+        Resolver,
+    },
+    MetaField, MetaType,
+};
+use inflector::Inflector;
+use parser_sdl::{OpenApiPagination, OpenApiPaginationStyle};
+
+use super::{meta_field, object, OutputFieldKind};
+use crate::graph::{OpenApiGraph, Operation, OutputFieldType, QueryParameter};
+
+pub(super) struct Connection {
+    pub pagination: HttpPagination,
+    /// The query parameters we set when fetching pages, so they aren't arguments of the field
+    parameters: Vec<String>,
+    item_type: OutputFieldType,
+}
+
+impl Operation {
+    pub(super) fn connection(self, graph: &OpenApiGraph) -> Option<Connection> {
+        let Operation::Query(_) = self else {
+            return None;
+        };
+
+        if self.response_content_type(graph) != ResponseContentType::Json {
+            return None;
+        }
+
+        let operation_id = self.operation_id(graph);
+        let operation_config = graph
+            .metadata
+            .pagination
+            .iter()
+            .find(|config| config.operation_id.is_some() && config.operation_id.as_deref() == operation_id);
+
+        // Configuration for a specific operation is trusted even if the spec doesn't list the
+        // parameters, but we only apply the global configuration where they're present.
+        let is_global = operation_config.is_none();
+        let config = operation_config.or_else(|| {
+            graph
+                .metadata
+                .pagination
+                .iter()
+                .find(|config| config.operation_id.is_none())
+        })?;
+
+        let list_type = match &config.items_field {
+            Some(items_field) => self.ty(graph)?.output_type(graph)?.field(items_field, graph)?.ty,
+            None => self.ty(graph)?,
+        };
+
+        let item_type = list_type.list_item_type()?;
+        if !matches!(
+            item_type.inner_kind(graph),
+            OutputFieldKind::Object | OutputFieldKind::Union
+        ) {
+            return None;
+        }
+
+        let query_parameters = self
+            .query_parameters(graph)
+            .into_iter()
+            .map(|parameter| parameter.openapi_name(graph).to_string())
+            .collect::<Vec<_>>();
+
+        let parameter = config
+            .parameter
+            .clone()
+            .or_else(|| default_parameter(config.style).map(str::to_string));
+
+        if is_global && parameter.as_ref().is_some_and(|name| !query_parameters.contains(name)) {
+            return None;
+        }
+
+        let limit_parameter = match &config.limit_parameter {
+            Some(name) if !is_global => Some(name.clone()),
+            configured => Some(
+                configured
+                    .clone()
+                    .unwrap_or_else(|| default_limit_parameter(config.style).to_string()),
+            )
+            .filter(|name| query_parameters.contains(name)),
+        };
+
+        let style = match (config.style, parameter.clone()) {
+            (OpenApiPaginationStyle::OffsetLimit, Some(offset_parameter)) => {
+                PaginationStyle::OffsetLimit { offset_parameter }
+            }
+            (OpenApiPaginationStyle::PageNumber, Some(page_parameter)) => {
+                PaginationStyle::PageNumber { page_parameter }
+            }
+            (OpenApiPaginationStyle::Cursor, Some(cursor_parameter)) => PaginationStyle::Cursor {
+                cursor_parameter,
+                next_cursor_path: next_cursor_path(config),
+            },
+            (OpenApiPaginationStyle::LinkHeader, _) => PaginationStyle::LinkHeader,
+            _ => return None,
+        };
+
+        Some(Connection {
+            pagination: HttpPagination {
+                style,
+                limit_parameter: limit_parameter.clone(),
+                max_page_size: config.max_page_size,
+                items_field: config.items_field.clone(),
+            },
+            parameters: parameter.into_iter().chain(limit_parameter).collect(),
+            item_type,
+        })
+    }
+}
+
+impl Connection {
+    /// Whether a query parameter is set by the resolver when fetching pages
+    pub fn hides(&self, parameter: QueryParameter, graph: &OpenApiGraph) -> bool {
+        self.parameters.iter().any(|name| name == parameter.openapi_name(graph))
+    }
+
+    pub fn type_name(&self, graph: &OpenApiGraph) -> Option<String> {
+        Some(format!("{}Connection", self.item_type.type_name(graph)?))
+    }
+
+    fn edge_type_name(&self, graph: &OpenApiGraph) -> Option<String> {
+        Some(format!("{}Edge", self.item_type.type_name(graph)?))
+    }
+}
+
+/// The connection & edge types of all the paginated queries, and the page info they share.
+pub(super) fn connection_types(graph: &OpenApiGraph) -> BTreeMap<String, MetaType> {
+    let mut types = BTreeMap::new();
+
+    for connection in graph
+        .query_operations()
+        .into_iter()
+        .filter_map(|operation| operation.connection(graph))
+    {
+        let (Some(connection_name), Some(edge_name), Some(item_name)) = (
+            connection.type_name(graph),
+            connection.edge_type_name(graph),
+            connection.item_type.type_name(graph),
+        ) else {
+            continue;
+        };
+
+        let node_resolver: Resolver = Transformer::select("node").into();
+
+        types.insert(
+            edge_name.clone(),
+            object(
+                edge_name.clone(),
+                [
+                    MetaField {
+                        resolver: node_resolver.and_then_maybe(connection.item_type.transforming_resolver(graph)),
+                        ..meta_field("node".into(), format!("{item_name}!"))
+                    },
+                    selecting_field("cursor", "String!"),
+                ],
+            ),
+        );
+
+        types.insert(
+            connection_name.clone(),
+            object(
+                connection_name,
+                [
+                    selecting_field("edges", &format!("[{edge_name}!]!")),
+                    selecting_field("pageInfo", &format!("{}!", page_info_type_name(graph))),
+                ],
+            ),
+        );
+    }
+
+    if !types.is_empty() {
+        let page_info_name = page_info_type_name(graph);
+        types.insert(
+            page_info_name.clone(),
+            object(
+                page_info_name,
+                [
+                    selecting_field("hasNextPage", "Boolean!"),
+                    selecting_field("hasPreviousPage", "Boolean!"),
+                    selecting_field("startCursor", "String"),
+                    selecting_field("endCursor", "String"),
+                ],
+            ),
+        );
+    }
+
+    types
+}
+
+/// Each connector gets its own page info type, so it can't clash with the one of `@model`
+/// or another connector.
+fn page_info_type_name(graph: &OpenApiGraph) -> String {
+    let prefix = graph.metadata.type_prefix.as_deref().unwrap_or(&graph.metadata.name);
+
+    format!("{prefix}_PageInfo").to_pascal_case()
+}
+
+fn selecting_field(name: &str, ty: &str) -> MetaField {
+    MetaField {
+        resolver: Transformer::select(name).into(),
+        ..meta_field(name.into(), ty.into())
+    }
+}
+
+fn default_parameter(style: OpenApiPaginationStyle) -> Option<&'static str> {
+    match style {
+        OpenApiPaginationStyle::OffsetLimit => Some("offset"),
+        OpenApiPaginationStyle::PageNumber => Some("page"),
+        OpenApiPaginationStyle::Cursor => Some("cursor"),
+        OpenApiPaginationStyle::LinkHeader => None,
+    }
+}
+
+fn default_limit_parameter(style: OpenApiPaginationStyle) -> &'static str {
+    match style {
+        OpenApiPaginationStyle::PageNumber => "per_page",
+        _ => "limit",
+    }
+}
+
+fn next_cursor_path(config: &OpenApiPagination) -> Vec<String> {
+    config
+        .next_cursor_field
+        .as_deref()
+        .unwrap_or("next_cursor")
+        .split('.')
+        .map(str::to_string)
+        .collect()
+}
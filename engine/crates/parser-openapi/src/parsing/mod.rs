@@ -1,4 +1,5 @@
 mod grouping;
+mod security;
 mod v2;
 mod v3;
 mod v3_1;
@@ -9,6 +10,7 @@ use std::collections::HashMap;
 use petgraph::{dot::Dot, graph::NodeIndex, Graph};
 use url::Url;
 
+pub use self::security::{Security, SecuritySchemeKind};
 use self::version::OpenApiVersion;
 use crate::{
     graph::{Edge, Node},
@@ -17,32 +19,38 @@ use crate::{
 
 pub fn parse(data: String, format: Format) -> Result<ParseOutput, Vec<Error>> {
     let version = from_str::<OpenApiVersion>(&data, format)?;
+    let security = Security::parse(&data, format)?;
 
-    match version {
+    let mut output: ParseOutput = match version {
         OpenApiVersion::V2 => {
             let spec = from_str(&data, format)?;
             drop(data);
-            v2::parse(spec).try_into()
+            v2::parse(spec).try_into()?
         }
         OpenApiVersion::V3 => {
             let spec = from_str(&data, format)?;
             drop(data);
-            v3::parse(spec).try_into()
+            v3::parse(spec).try_into()?
         }
         OpenApiVersion::V3_1 => {
             let spec = from_str(&data, format)?;
             drop(data);
             let result: ParseOutput = v3_1::parse(spec).try_into()?;
             // println!("{:?}", result.dot());
-            Ok(result)
+            result
         }
-        OpenApiVersion::Unknown(version) => Err(vec![Error::UnsupportedVersion(version)]),
-    }
+        OpenApiVersion::Unknown(version) => return Err(vec![Error::UnsupportedVersion(version)]),
+    };
+
+    output.security = security;
+
+    Ok(output)
 }
 
 pub struct ParseOutput {
     pub graph: Graph<Node, Edge>,
     pub url: Result<Url, Error>,
+    pub security: Security,
 }
 
 impl ParseOutput {
@@ -74,6 +82,7 @@ impl TryFrom<Context> for ParseOutput {
         Ok(ParseOutput {
             graph,
             url: url.expect("parsing should always fill in url"),
+            security: Security::default(),
         })
     }
 }
@@ -0,0 +1,319 @@
+//! Security schemes and requirements look the same in every version of OpenAPI we
+//! support (bar some naming in v2) so we parse them directly from the spec, rather
+//! than through the version specific crates.
+
+use std::collections::{BTreeMap, HashMap};
+
+use engine::registry::{resolvers::http::SecurityRequirement, ApiKeyLocation, SecurityScheme};
+use parser_sdl::OpenApiCredentials;
+use serde::de::IgnoredAny;
+
+use crate::{graph::HttpMethod, Error, Format};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecuritySchemeKind {
+    ApiKey {
+        name: String,
+        location: ApiKeyLocation,
+    },
+    Basic,
+    Bearer,
+    OAuth2 {
+        client_credentials_token_url: Option<String>,
+    },
+    /// A scheme we don't know how to provide credentials for, e.g. OpenID Connect.
+    Unsupported,
+}
+
+#[derive(Debug, Default)]
+pub struct Security {
+    pub schemes: BTreeMap<String, SecuritySchemeKind>,
+    global: Vec<SecurityRequirement>,
+    operations: HashMap<(String, HttpMethod), Vec<SecurityRequirement>>,
+}
+
+impl Security {
+    pub fn parse(data: &str, format: Format) -> Result<Self, Vec<Error>> {
+        let spec = super::from_str::<SpecSecurity>(data, format)?;
+
+        let schemes = spec
+            .components
+            .unwrap_or_default()
+            .security_schemes
+            .into_iter()
+            .chain(spec.security_definitions)
+            .map(|(name, scheme)| (name, scheme.into_kind()))
+            .collect();
+
+        let mut operations = HashMap::new();
+        for (path, path_item) in spec.paths {
+            let MaybePathItem::PathItem(path_item) = path_item else {
+                continue;
+            };
+
+            let path_operations = [
+                (HttpMethod::Get, path_item.get),
+                (HttpMethod::Post, path_item.post),
+                (HttpMethod::Put, path_item.put),
+                (HttpMethod::Delete, path_item.delete),
+                (HttpMethod::Patch, path_item.patch),
+            ];
+
+            for (method, operation) in path_operations {
+                if let Some(security) = operation.and_then(|operation| operation.security) {
+                    operations.insert((path.clone(), method), into_requirements(security));
+                }
+            }
+        }
+
+        Ok(Security {
+            schemes,
+            global: spec.security.map(into_requirements).unwrap_or_default(),
+            operations,
+        })
+    }
+
+    /// The requirements of an operation. An operations own requirements replace the
+    /// global ones, so an operation can make itself public with an empty list.
+    pub fn requirements(&self, path: &str, method: HttpMethod) -> &[SecurityRequirement] {
+        self.operations.get(&(path.to_string(), method)).unwrap_or(&self.global)
+    }
+}
+
+impl SecuritySchemeKind {
+    /// Combines a scheme from the spec with the credentials the user gave us for it.
+    pub fn with_credentials(
+        &self,
+        scheme_name: &str,
+        credentials: OpenApiCredentials,
+    ) -> Result<SecurityScheme, Error> {
+        match (self, credentials) {
+            (SecuritySchemeKind::ApiKey { name, location }, OpenApiCredentials::Value(value)) => {
+                Ok(SecurityScheme::ApiKey {
+                    name: name.clone(),
+                    location: *location,
+                    value,
+                })
+            }
+            (SecuritySchemeKind::Basic, OpenApiCredentials::Value(value)) => Ok(SecurityScheme::Basic { value }),
+            // A token for an OAuth2 scheme can be sent like any other bearer token.
+            (SecuritySchemeKind::Bearer | SecuritySchemeKind::OAuth2 { .. }, OpenApiCredentials::Value(value)) => {
+                Ok(SecurityScheme::Bearer { value })
+            }
+            (
+                SecuritySchemeKind::OAuth2 {
+                    client_credentials_token_url: Some(token_url),
+                },
+                OpenApiCredentials::ClientCredentials {
+                    client_id,
+                    client_secret,
+                },
+            ) => Ok(SecurityScheme::OAuth2ClientCredentials {
+                token_url: token_url.clone(),
+                client_id,
+                client_secret,
+            }),
+            _ => Err(Error::UnsupportedSecurityScheme(scheme_name.to_string())),
+        }
+    }
+}
+
+fn into_requirements(requirements: Vec<BTreeMap<String, Vec<String>>>) -> Vec<SecurityRequirement> {
+    requirements.into_iter().map(SecurityRequirement).collect()
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpecSecurity {
+    #[serde(default)]
+    components: Option<Components>,
+    /// The v2 equivalent of components.securitySchemes
+    #[serde(default)]
+    security_definitions: BTreeMap<String, MaybeScheme>,
+    #[serde(default)]
+    security: Option<Vec<BTreeMap<String, Vec<String>>>>,
+    #[serde(default)]
+    paths: BTreeMap<String, MaybePathItem>,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Components {
+    #[serde(default)]
+    security_schemes: BTreeMap<String, MaybeScheme>,
+}
+
+// Paths can contain extensions & path items can be references, neither of which
+// we care about here.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MaybePathItem {
+    PathItem(PathItem),
+    Other(#[allow(dead_code)] IgnoredAny),
+}
+
+#[derive(serde::Deserialize)]
+struct PathItem {
+    get: Option<Operation>,
+    post: Option<Operation>,
+    put: Option<Operation>,
+    delete: Option<Operation>,
+    patch: Option<Operation>,
+}
+
+#[derive(serde::Deserialize)]
+struct Operation {
+    #[serde(default)]
+    security: Option<Vec<BTreeMap<String, Vec<String>>>>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MaybeScheme {
+    Scheme(Scheme),
+    Other(#[allow(dead_code)] IgnoredAny),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "type")]
+enum Scheme {
+    #[serde(rename = "apiKey")]
+    ApiKey {
+        name: String,
+        #[serde(rename = "in")]
+        location: String,
+    },
+    #[serde(rename = "http")]
+    Http { scheme: String },
+    /// v2 only, v3 uses http with a scheme of basic
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        #[serde(default)]
+        flows: Option<OAuth2Flows>,
+        /// v2 only, v3 has a flows object instead
+        #[serde(default)]
+        flow: Option<String>,
+        /// v2 only, v3 has a flows object instead
+        #[serde(default, rename = "tokenUrl")]
+        token_url: Option<String>,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OAuth2Flows {
+    #[serde(default)]
+    client_credentials: Option<OAuth2Flow>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OAuth2Flow {
+    token_url: String,
+}
+
+impl MaybeScheme {
+    fn into_kind(self) -> SecuritySchemeKind {
+        let MaybeScheme::Scheme(scheme) = self else {
+            return SecuritySchemeKind::Unsupported;
+        };
+
+        match scheme {
+            Scheme::ApiKey { name, location } => {
+                let location = match location.as_str() {
+                    "header" => ApiKeyLocation::Header,
+                    "query" => ApiKeyLocation::Query,
+                    "cookie" => ApiKeyLocation::Cookie,
+                    _ => return SecuritySchemeKind::Unsupported,
+                };
+                SecuritySchemeKind::ApiKey { name, location }
+            }
+            Scheme::Http { scheme } if scheme.eq_ignore_ascii_case("basic") => SecuritySchemeKind::Basic,
+            Scheme::Http { scheme } if scheme.eq_ignore_ascii_case("bearer") => SecuritySchemeKind::Bearer,
+            Scheme::Basic => SecuritySchemeKind::Basic,
+            Scheme::OAuth2 { flows, flow, token_url } => {
+                let client_credentials_token_url = match (flows, flow.as_deref()) {
+                    (Some(flows), _) => flows.client_credentials.map(|flow| flow.token_url),
+                    (None, Some("application")) => token_url,
+                    (None, _) => None,
+                };
+                SecuritySchemeKind::OAuth2 {
+                    client_credentials_token_url,
+                }
+            }
+            Scheme::Http { .. } | Scheme::Unsupported => SecuritySchemeKind::Unsupported,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_requirements_override_global() {
+        let security = Security::parse(
+            r#"{
+                "openapi": "3.0.0",
+                "security": [{ "api_key": [] }],
+                "components": {
+                    "securitySchemes": {
+                        "api_key": { "type": "apiKey", "name": "X-Api-Key", "in": "header" },
+                        "oauth": {
+                            "type": "oauth2",
+                            "flows": { "clientCredentials": { "tokenUrl": "https://example.com/token", "scopes": {} } }
+                        },
+                        "oidc": { "type": "openIdConnect", "openIdConnectUrl": "https://example.com" }
+                    }
+                },
+                "paths": {
+                    "x-extension": "hello",
+                    "/pets": {
+                        "get": { "security": [{ "oauth": ["read:pets"] }] },
+                        "post": { "security": [] },
+                        "delete": {}
+                    }
+                }
+            }"#,
+            Format::Json,
+        )
+        .unwrap();
+
+        assert_eq!(
+            security.schemes,
+            BTreeMap::from([
+                (
+                    "api_key".to_string(),
+                    SecuritySchemeKind::ApiKey {
+                        name: "X-Api-Key".to_string(),
+                        location: ApiKeyLocation::Header
+                    }
+                ),
+                (
+                    "oauth".to_string(),
+                    SecuritySchemeKind::OAuth2 {
+                        client_credentials_token_url: Some("https://example.com/token".to_string())
+                    }
+                ),
+                ("oidc".to_string(), SecuritySchemeKind::Unsupported),
+            ])
+        );
+
+        assert_eq!(
+            security.requirements("/pets", HttpMethod::Get),
+            [SecurityRequirement(BTreeMap::from([(
+                "oauth".to_string(),
+                vec!["read:pets".to_string()]
+            )]))]
+        );
+        assert!(security.requirements("/pets", HttpMethod::Post).is_empty());
+        assert_eq!(
+            security.requirements("/pets", HttpMethod::Delete),
+            [SecurityRequirement(BTreeMap::from([("api_key".to_string(), vec![])]))]
+        );
+    }
+}
@@ -87,6 +87,20 @@ fn extract_operations(ctx: &mut Context, components: &Components, paths: &BTreeM
                             }
                         }
                     }
+                    "header" => {
+                        let parent = ParentNode::HeaderParameter {
+                            name: parameter.name.clone(),
+                            operation_index,
+                            required: parameter.required.unwrap_or(false),
+                        };
+                        match &parameter.schema {
+                            Some(schema) => extract_types(ctx, schema, parent),
+                            None => {
+                                // If the parameter has no schema we just assume it's a string.
+                                ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), false);
+                            }
+                        }
+                    }
                     "body" => {
                         let Some(schema) = &parameter.schema else {
                             ctx.errors.push(Error::OperationMissingRequestSchema(
@@ -3,7 +3,7 @@
 use indexmap::IndexMap;
 use inflector::Inflector;
 use once_cell::sync::Lazy;
-use openapiv3::{AdditionalProperties, ReferenceOr, Type};
+use openapiv3::{AdditionalProperties, ReferenceOr, StringFormat, Type, VariantOrUnknownOrEmpty};
 use regex::Regex;
 use url::Url;
 
@@ -130,6 +130,36 @@ fn extract_operations(ctx: &mut Context, paths: &openapiv3::Paths, components: C
                 }
             }
 
+            for parameter in operation.header_parameters {
+                let parent = ParentNode::HeaderParameter {
+                    name: parameter.name,
+                    operation_index,
+                    required: parameter.required,
+                };
+                match parameter.schema {
+                    Some(schema) => extract_types(ctx, &schema, parent),
+                    None => {
+                        // If the parameter has no schema we just assume it's a string.
+                        ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), false);
+                    }
+                }
+            }
+
+            for parameter in operation.cookie_parameters {
+                let parent = ParentNode::CookieParameter {
+                    name: parameter.name,
+                    operation_index,
+                    required: parameter.required,
+                };
+                match parameter.schema {
+                    Some(schema) => extract_types(ctx, &schema, parent),
+                    None => {
+                        // If the parameter has no schema we just assume it's a string.
+                        ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), false);
+                    }
+                }
+            }
+
             for response in operation.responses {
                 let Some(schema) = &response.schema else {
                     ctx.errors.push(Error::OperationMissingResponseSchema(
@@ -192,6 +222,11 @@ fn extract_types(ctx: &mut Context, schema_or_ref: &ReferenceOr<openapiv3::Schem
             ctx.add_type_edge(parent, *schema, false);
         }
         ReferenceOr::Item(schema) => match &schema.schema_kind {
+            SchemaKind::Type(Type::String(ty))
+                if matches!(ty.format, VariantOrUnknownOrEmpty::Item(StringFormat::Binary)) =>
+            {
+                ctx.add_type_node(parent, Node::Scalar(ScalarKind::Binary), schema.schema_data.nullable);
+            }
             SchemaKind::Type(Type::String(ty)) => {
                 if ty.enumeration.is_empty() || !ty.enumeration.iter().all(is_valid_enum_value) {
                     ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), schema.schema_data.nullable)
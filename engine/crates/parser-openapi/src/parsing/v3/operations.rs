@@ -17,6 +17,8 @@ pub struct OperationDetails {
     pub responses: Vec<Response>,
     pub(super) path_parameters: Vec<PathParameter>,
     pub(super) query_parameters: Vec<QueryParameter>,
+    pub(super) header_parameters: Vec<HeaderParameter>,
+    pub(super) cookie_parameters: Vec<CookieParameter>,
 }
 
 impl std::fmt::Debug for OperationDetails {
@@ -81,11 +83,10 @@ impl OperationDetails {
             }
         }
 
-        let mut path_parameters = Vec::new();
-        let mut query_parameters = Vec::new();
+        let mut parameters = Parameters::default();
         for parameter in &operation.parameters {
             let parameter = resolve_parameter(parameter, components)?;
-            register_parameter(parameter, &mut path_parameters, &mut query_parameters, operation)?;
+            register_parameter(parameter, &mut parameters, operation)?;
         }
 
         // We need to handle the parameters that were declared on the parent
@@ -95,7 +96,8 @@ impl OperationDetails {
             let parameter = resolve_parameter(parameter, components)?;
             match parameter {
                 Parameter::Query { parameter_data, .. } => {
-                    if query_parameters
+                    if parameters
+                        .query
                         .iter()
                         .any(|existing_param| existing_param.name == parameter_data.name)
                     {
@@ -103,16 +105,34 @@ impl OperationDetails {
                     }
                 }
                 Parameter::Path { parameter_data, .. } => {
-                    if path_parameters
+                    if parameters
+                        .path
+                        .iter()
+                        .any(|existing_param| existing_param.name == parameter_data.name)
+                    {
+                        continue;
+                    }
+                }
+                Parameter::Header { parameter_data, .. } => {
+                    if parameters
+                        .header
+                        .iter()
+                        .any(|existing_param| existing_param.name.eq_ignore_ascii_case(&parameter_data.name))
+                    {
+                        continue;
+                    }
+                }
+                Parameter::Cookie { parameter_data, .. } => {
+                    if parameters
+                        .cookie
                         .iter()
                         .any(|existing_param| existing_param.name == parameter_data.name)
                     {
                         continue;
                     }
                 }
-                _ => {}
             }
-            register_parameter(parameter, &mut path_parameters, &mut query_parameters, operation)?;
+            register_parameter(parameter, &mut parameters, operation)?;
         }
 
         Ok(OperationDetails {
@@ -121,8 +141,10 @@ impl OperationDetails {
             operation_id: operation.operation_id.clone(),
             request_bodies,
             responses,
-            path_parameters,
-            query_parameters,
+            path_parameters: parameters.path,
+            query_parameters: parameters.query,
+            header_parameters: parameters.header,
+            cookie_parameters: parameters.cookie,
         })
     }
 }
@@ -146,13 +168,12 @@ fn resolve_parameter<'a>(
 
 fn register_parameter(
     parameter: &Parameter,
-    path_parameters: &mut Vec<PathParameter>,
-    query_parameters: &mut Vec<QueryParameter>,
+    parameters: &mut Parameters,
     operation: &openapiv3::Operation,
 ) -> Result<(), Error> {
     match parameter {
         Parameter::Path { parameter_data, .. } => {
-            path_parameters.push(PathParameter {
+            parameters.path.push(PathParameter {
                 name: parameter_data.name.clone(),
                 schema: match &parameter_data.format {
                     ParameterSchemaOrContent::Schema(schema) => Some(schema.clone()),
@@ -162,7 +183,7 @@ fn register_parameter(
         }
         Parameter::Query {
             parameter_data, style, ..
-        } => query_parameters.push(QueryParameter {
+        } => parameters.query.push(QueryParameter {
             name: parameter_data.name.clone(),
             schema: match &parameter_data.format {
                 ParameterSchemaOrContent::Schema(schema) => Some(schema.clone()),
@@ -179,12 +200,44 @@ fn register_parameter(
             )?,
             required: parameter_data.required,
         }),
-        _ => {}
+        // The spec says these must be ignored, they're controlled by other parts of the spec.
+        Parameter::Header { parameter_data, .. } if is_reserved_header(&parameter_data.name) => {}
+        Parameter::Header { parameter_data, .. } => parameters.header.push(HeaderParameter {
+            name: parameter_data.name.clone(),
+            schema: match &parameter_data.format {
+                ParameterSchemaOrContent::Schema(schema) => Some(schema.clone()),
+                ParameterSchemaOrContent::Content(_) => None,
+            },
+            required: parameter_data.required,
+        }),
+        Parameter::Cookie { parameter_data, .. } => parameters.cookie.push(CookieParameter {
+            name: parameter_data.name.clone(),
+            schema: match &parameter_data.format {
+                ParameterSchemaOrContent::Schema(schema) => Some(schema.clone()),
+                ParameterSchemaOrContent::Content(_) => None,
+            },
+            required: parameter_data.required,
+        }),
     }
 
     Ok(())
 }
 
+fn is_reserved_header(name: &str) -> bool {
+    ["accept", "authorization", "content-type"]
+        .iter()
+        .any(|reserved| name.eq_ignore_ascii_case(reserved))
+}
+
+/// The parameters of an operation, grouped by where they're sent.
+#[derive(Default)]
+struct Parameters {
+    path: Vec<PathParameter>,
+    query: Vec<QueryParameter>,
+    header: Vec<HeaderParameter>,
+    cookie: Vec<CookieParameter>,
+}
+
 #[derive(Clone, Debug)]
 pub struct RequestBody {
     pub content_type: RequestBodyContentType,
@@ -229,6 +282,20 @@ pub(super) struct QueryParameter {
     pub required: bool,
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct HeaderParameter {
+    pub name: String,
+    pub schema: Option<ReferenceOr<openapiv3::Schema>>,
+    pub required: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CookieParameter {
+    pub name: String,
+    pub schema: Option<ReferenceOr<openapiv3::Schema>>,
+    pub required: bool,
+}
+
 fn query_param_encoding_style(query_style: &QueryStyle, explode: bool) -> Option<QueryParameterEncodingStyle> {
     match (query_style, explode) {
         (QueryStyle::Form, true) => Some(QueryParameterEncodingStyle::FormExploded),
@@ -264,6 +331,7 @@ fn request_body_content_type(
                 })
                 .collect(),
         )),
+        "multipart/form-data" => Some(RequestBodyContentType::Multipart),
         _ => None,
     }
 }
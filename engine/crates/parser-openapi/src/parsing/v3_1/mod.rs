@@ -147,6 +147,38 @@ fn extract_operations(ctx: &mut Context, paths: Option<&openapiv3_1::Paths>, com
                 }
             }
 
+            for parameter in operation.header_parameters {
+                tracing::trace!("Parsing header parameter {}", parameter.name);
+                let parent = ParentNode::HeaderParameter {
+                    name: parameter.name,
+                    operation_index,
+                    required: parameter.required,
+                };
+                match parameter.schema {
+                    Some(schema) => extract_types(ctx, &schema.json_schema, parent),
+                    None => {
+                        // If the parameter has no schema we just assume it's a string.
+                        ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), false);
+                    }
+                }
+            }
+
+            for parameter in operation.cookie_parameters {
+                tracing::trace!("Parsing cookie parameter {}", parameter.name);
+                let parent = ParentNode::CookieParameter {
+                    name: parameter.name,
+                    operation_index,
+                    required: parameter.required,
+                };
+                match parameter.schema {
+                    Some(schema) => extract_types(ctx, &schema.json_schema, parent),
+                    None => {
+                        // If the parameter has no schema we just assume it's a string.
+                        ctx.add_type_node(parent, Node::Scalar(ScalarKind::String), false);
+                    }
+                }
+            }
+
             for response in operation.responses {
                 let Some(schema) = &response.schema else {
                     ctx.errors.push(Error::OperationMissingResponseSchema(
@@ -322,6 +354,9 @@ fn extract_instance_type(
         InstanceType::Array => {
             extract_array_type(ctx, parent, schema, nullable);
         }
+        InstanceType::String if schema.format.as_deref() == Some("binary") => {
+            ctx.add_type_node(parent, Node::Scalar(ScalarKind::Binary), nullable);
+        }
         InstanceType::String => {
             let enum_values = schema.enum_values.clone().unwrap_or_default();
             if enum_values.is_empty() || !enum_values.iter().all(is_valid_enum_value) {
@@ -1,7 +1,14 @@
 use std::sync::Once;
 
 use assert_matches::assert_matches;
-use engine::registry::{MetaType, UnionType};
+use engine::registry::{
+    resolvers::{
+        http::{HttpPagination, PaginationStyle},
+        Resolver,
+    },
+    ConnectorHeaderValue, MetaType, UnionType,
+};
+use parser_sdl::{OpenApiCredentials, OpenApiPaginationStyle};
 
 use super::*;
 
@@ -85,6 +92,122 @@ fn test_url_without_host_failure() {
     );
 }
 
+#[test]
+fn test_petstore_security() {
+    let metadata = ApiMetadata {
+        security: vec![OpenApiSecurity {
+            scheme: "api_key".into(),
+            credentials: OpenApiCredentials::Value(ConnectorHeaderValue::Forward("x-api-key".into())),
+        }],
+        ..metadata("petstore", true)
+    };
+    let registry = build_registry("test_data/petstore.openapi.json", Format::Json, metadata).unwrap();
+
+    insta::assert_debug_snapshot!(registry.http_security, @r###"
+    {
+        "petstore": ConnectorSecurity(
+            {
+                "api_key": ApiKey {
+                    name: "api_key",
+                    location: Header,
+                    value: Forward(
+                        "x-api-key",
+                    ),
+                },
+            },
+        ),
+    }
+    "###);
+}
+
+#[test]
+fn test_security_for_unknown_scheme() {
+    let metadata = ApiMetadata {
+        security: vec![OpenApiSecurity {
+            scheme: "nope".into(),
+            credentials: OpenApiCredentials::Value(ConnectorHeaderValue::Static("secret".into())),
+        }],
+        ..metadata("petstore", true)
+    };
+    assert_matches!(
+        build_registry("test_data/petstore.openapi.json", Format::Json, metadata)
+            .unwrap_err()
+            .as_slice(),
+        [Error::UnknownSecurityScheme(scheme)] => {
+            assert_eq!(scheme, "nope");
+        }
+    );
+}
+
+#[test]
+fn test_petstore_pagination() {
+    let metadata = ApiMetadata {
+        pagination: vec![
+            OpenApiPagination {
+                operation_id: Some("findPetsByStatus".into()),
+                style: OpenApiPaginationStyle::LinkHeader,
+                parameter: None,
+                limit_parameter: Some("limit".into()),
+                max_page_size: Some(100),
+                next_cursor_field: None,
+                items_field: None,
+            },
+            // None of the other operations have an offset parameter, so this shouldn't apply to them
+            OpenApiPagination {
+                operation_id: None,
+                style: OpenApiPaginationStyle::OffsetLimit,
+                parameter: None,
+                limit_parameter: None,
+                max_page_size: None,
+                next_cursor_field: None,
+                items_field: None,
+            },
+        ],
+        ..metadata("petstore", false)
+    };
+    let registry = build_registry("test_data/petstore.openapi.json", Format::Json, metadata).unwrap();
+    let sdl = registry.export_sdl(false);
+
+    assert!(sdl.contains(
+        "findPetsByStatus(status: PetstoreFindPetsByStatusStatus = AVAILABLE, first: Int, after: String): PetstorePetConnection"
+    ));
+    assert!(sdl.contains("findPetsByTags(tags: [String!]): [PetstorePet!]"));
+
+    let connection_types = sdl
+        .split_inclusive("}\n")
+        .filter(|definition| {
+            ["PetstorePageInfo", "PetstorePetConnection", "PetstorePetEdge"]
+                .iter()
+                .any(|name| definition.starts_with(&format!("type {name} {{")))
+        })
+        .collect::<String>();
+
+    assert_eq!(
+        connection_types,
+        "type PetstorePageInfo {\n\thasNextPage: Boolean!\n\thasPreviousPage: Boolean!\n\tstartCursor: String\n\tendCursor: String\n}\n\
+         type PetstorePetConnection {\n\tedges: [PetstorePetEdge!]!\n\tpageInfo: PetstorePageInfo!\n}\n\
+         type PetstorePetEdge {\n\tnode: PetstorePet!\n\tcursor: String!\n}\n"
+    );
+
+    let MetaType::Object(query) = &registry.types["Query"] else {
+        unreachable!()
+    };
+    assert_matches!(
+        &query.fields["findPetsByStatus"].resolver,
+        Resolver::Http(resolver) => {
+            assert_eq!(
+                resolver.pagination,
+                Some(HttpPagination {
+                    style: PaginationStyle::LinkHeader,
+                    limit_parameter: Some("limit".into()),
+                    max_page_size: Some(100),
+                    items_field: None,
+                })
+            );
+        }
+    );
+}
+
 #[test]
 fn test_openai_output() {
     insta::assert_snapshot!(build_registry(
@@ -229,6 +352,9 @@ fn metadata(name: &str, namespace: bool) -> ApiMetadata {
         headers: ConnectorHeaders::new([]),
         query_naming: QueryNamingStrategy::SchemaName,
         type_prefix: Some(name.to_string()),
+        security: vec![],
+        pagination: vec![],
+        fetch_policy: ConnectorFetchPolicy::default(),
     }
 }
 
@@ -45,7 +45,12 @@ where
 
         let database_type = match enum_id {
             Some(enum_id) => ColumnType::Enum(enum_id),
-            None => ColumnType::Scalar(ScalarType::from(row.type_id)),
+            None => match ScalarType::from(row.type_id) {
+                ScalarType::Other(type_id) if !row.is_array => {
+                    ColumnType::Scalar(ScalarType::from_extension(type_id, type_name))
+                }
+                scalar => ColumnType::Scalar(scalar),
+            },
         };
 
         let mut column = TableColumn::new(table_id, row.column_name, database_type);
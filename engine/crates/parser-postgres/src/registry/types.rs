@@ -17,9 +17,10 @@ pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
     scalar::register(input_ctx, output_ctx);
 
     let direction_type = order_direction::register(input_ctx, output_ctx);
+    let vector_order_type = order_direction::register_vector(input_ctx, output_ctx);
 
     for table in tables {
-        table::generate(input_ctx, table, &direction_type, output_ctx);
+        table::generate(input_ctx, table, &direction_type, &vector_order_type, output_ctx);
     }
 
     for r#enum in input_ctx.database_definition().enums() {
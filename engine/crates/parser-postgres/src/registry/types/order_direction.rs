@@ -1,4 +1,4 @@
-use engine::registry::{EnumType, MetaEnumValue};
+use engine::registry::{EnumType, InputObjectType, MetaEnumValue, MetaInputValue};
 
 use crate::registry::context::{InputContext, OutputContext};
 
@@ -17,3 +17,32 @@ pub(super) fn register(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
     output_ctx.create_enum_type(r#enum);
     type_name.into_owned()
 }
+
+/// Ordering for vector columns, sorting the rows by their distance to the given vector, nearest first.
+pub(super) fn register_vector(input_ctx: &InputContext<'_>, output_ctx: &mut OutputContext) -> String {
+    let distance_type_name = input_ctx.type_name("VectorDistance");
+
+    let variants = ["L2", "COSINE", "INNER_PRODUCT"].iter().map(|name| {
+        let mut variant = MetaEnumValue::new((*name).to_string());
+        variant.value = Some((*name).to_string());
+
+        variant
+    });
+
+    output_ctx.create_enum_type(EnumType::new(distance_type_name.to_string(), variants));
+
+    let type_name = input_ctx.type_name("VectorOrderByInput");
+
+    let fields = [
+        MetaInputValue::new("nearestTo", "Vector!").with_description("The vector to measure the distance to."),
+        MetaInputValue::new("distance", distance_type_name.as_ref())
+            .with_description("The distance function. Defaults to L2 (euclidean) distance."),
+    ];
+
+    let description = "Orders the rows by their distance to the given vector, nearest first.";
+    let input_object =
+        InputObjectType::new(type_name.to_string(), fields).with_description(Some(description.to_string()));
+
+    output_ctx.create_input_type(input_object);
+    type_name.into_owned()
+}
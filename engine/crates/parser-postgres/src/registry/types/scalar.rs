@@ -1,4 +1,4 @@
-use engine::registry::{InputObjectType, MetaInputValue};
+use engine::registry::{scalars::STRING_SCALARS, InputObjectType, MetaInputValue};
 
 use crate::registry::context::{InputContext, OutputContext};
 
@@ -23,10 +23,38 @@ static SCALARS: &[&str] = &[
     "IPAddress",
     "NaiveDateTime",
     "Time",
+    "Range",
+    "MultiRange",
+    "Interval",
+    "TsVector",
+    "Geometry",
+    "Vector",
 ];
 
 static NUMERIC_SCALARS: &[&str] = &["BigInt", "Float", "Decimal", "Int"];
 
+static RANGE_SCALARS: &[&str] = &["Range", "MultiRange"];
+
+static RANGE_FILTERS: &[(&str, &str)] = &[
+    ("contains", "The range contains all values of the given range"),
+    (
+        "containedBy",
+        "All values of the range are contained in the given range",
+    ),
+    ("overlaps", "The ranges have values in common"),
+];
+
+static PATTERN_FILTERS: &[(&str, &str)] = &[
+    (
+        "like",
+        "The value matches the given pattern, where `%` matches any sequence of characters and `_` any single character",
+    ),
+    ("ilike", "The value matches the given pattern, case-insensitively"),
+    ("startsWith", "The value starts with the given string"),
+    ("endsWith", "The value ends with the given string"),
+    ("regex", "The value matches the given POSIX regular expression"),
+];
+
 static SCALAR_FILTERS: &[(&str, &str, &str)] = &[
     ("eq", "=", "The value is exactly the one given"),
     ("ne", "<>", "The value is not the one given"),
@@ -205,6 +233,30 @@ fn create_filter_types(input_ctx: &InputContext<'_>, scalar: &&str, output_ctx:
         input
     });
 
+    if RANGE_SCALARS.contains(scalar) {
+        for (filter, description) in RANGE_FILTERS {
+            fields.push(MetaInputValue::new(*filter, *scalar).with_description(*description));
+        }
+    }
+
+    if STRING_SCALARS.contains(scalar) {
+        for (filter, description) in PATTERN_FILTERS {
+            fields.push(MetaInputValue::new(*filter, "String").with_description(*description));
+        }
+    }
+
+    if *scalar == "TsVector" {
+        fields.push({
+            let mut input = MetaInputValue::new("matches", "String");
+
+            input.description = Some(String::from(
+                "The document matches the given web search query, e.g. `fat (rat or cat) -dog`",
+            ));
+
+            input
+        });
+    }
+
     fields.push(MetaInputValue::new("not", type_name.as_str()));
 
     let description = format!("Search filter input for {scalar} type.");
@@ -12,12 +12,13 @@ pub(super) fn generate(
     input_ctx: &InputContext<'_>,
     table: TableWalker<'_>,
     direction_type: &str,
+    vector_order_type: &str,
     output_ctx: &mut OutputContext,
 ) {
     let type_name = input_ctx.type_name(table.client_name());
     let edge_type_name = register_edge_type(input_ctx, table, &type_name, output_ctx);
 
-    register_orderby_input(input_ctx, direction_type, table, output_ctx);
+    register_orderby_input(input_ctx, direction_type, vector_order_type, table, output_ctx);
     register_connection_type(input_ctx, table, &edge_type_name, output_ctx);
 
     // The full type with relations
@@ -218,14 +219,21 @@ fn register_edge_type(
 fn register_orderby_input(
     input_ctx: &InputContext<'_>,
     direction_type: &str,
+    vector_order_type: &str,
     table: TableWalker<'_>,
     output_ctx: &mut OutputContext,
 ) {
     let type_name = input_ctx.orderby_input_type_name(table.client_name());
 
-    let input_fields = table
-        .columns()
-        .map(|column| MetaInputValue::new(column.client_name().to_string(), direction_type));
+    let input_fields = table.columns().map(|column| {
+        let r#type = if column.database_type().is_vector() {
+            vector_order_type
+        } else {
+            direction_type
+        };
+
+        MetaInputValue::new(column.client_name().to_string(), r#type)
+    });
 
     let input_object = InputObjectType::new(type_name.to_string(), input_fields).with_oneof(true);
 
@@ -70,6 +70,11 @@ pub(crate) fn merge_registry(ctx: &mut VisitorContext<'_>, mut src_registry: Reg
             .extend(type_fields(src_registry.types.remove(mutation_type).unwrap()));
     }
 
+    if let Some(subscription_type) = &src_registry.subscription_type {
+        ctx.subscriptions
+            .extend(type_fields(src_registry.types.remove(subscription_type).unwrap()));
+    }
+
     // The parser relies on `ctx.types` in a few places, which contains parsed SDL
     // TypeDefinitions (rather than the processed MetaTypes that our connectors give
     // us).  We hackishly fake these TypeDefinitions here to work around that
@@ -89,6 +94,13 @@ pub(crate) fn merge_registry(ctx: &mut VisitorContext<'_>, mut src_registry: Reg
 
     main_registry.implements.extend(src_registry.implements);
     main_registry.http_headers.extend(src_registry.http_headers);
+    main_registry.http_security.extend(src_registry.http_security);
+    main_registry
+        .http_fetch_policies
+        .extend(src_registry.http_fetch_policies);
+    main_registry
+        .graphql_error_extensions
+        .extend(src_registry.graphql_error_extensions);
     main_registry.postgres_databases.extend(src_registry.postgres_databases);
     main_registry
         .federation_entities
@@ -35,7 +35,7 @@ use rules::{
     length_directive::LengthDirective,
     map_directive::MapDirective,
     model_directive::ModelDirective,
-    mongodb_directive::{MongoDBModelDirective, MongoDBTypeDirective},
+    mongodb_directive::{LookupDirective, MongoDBModelDirective, MongoDBTypeDirective},
     one_of_directive::OneOfDirective,
     openapi_directive::OpenApiVisitor,
     postgres_directive::PostgresVisitor,
@@ -58,15 +58,21 @@ pub use rules::{
     cache_directive::global::{GlobalCacheRules, GlobalCacheTarget},
     graphql_directive::GraphqlDirective,
     mongodb_directive::MongoDBDirective,
-    openapi_directive::{OpenApiDirective, OpenApiQueryNamingStrategy, OpenApiTransforms},
+    openapi_directive::{
+        OpenApiCredentials, OpenApiDirective, OpenApiPagination, OpenApiPaginationStyle, OpenApiQueryNamingStrategy,
+        OpenApiSecurity, OpenApiTransforms,
+    },
     postgres_directive::PostgresDirective,
 };
 use validations::post_parsing_validations;
 
 use crate::rules::{
     cache_directive::{visitor::CacheVisitor, CacheDirective},
+    complexity_directive::{ComplexityDirective, ComplexityVisitor},
     experimental::{ExperimentalDirective, ExperimentalDirectiveVisitor},
     mongodb_directive::MongoDBVisitor,
+    operation_limits_directive::{OperationLimitsDirective, OperationLimitsVisitor},
+    rate_limit_directive::{RateLimitDirective, RateLimitVisitor},
     scalar_hydratation::ScalarHydratation,
 };
 
@@ -136,8 +142,12 @@ fn parse_schema(schema: &str) -> engine::parser::Result<ServiceDocument> {
         .with::<GraphqlDirective>()
         .with::<CacheDirective>()
         .with::<MongoDBDirective>()
+        .with::<LookupDirective>()
         .with::<PostgresDirective>()
         .with::<ExperimentalDirective>()
+        .with::<RateLimitDirective>()
+        .with::<OperationLimitsDirective>()
+        .with::<ComplexityDirective>()
         .with::<FederationDirective>()
         .with::<RequiresDirective>()
         .with::<KeyDirective>()
@@ -233,11 +243,18 @@ async fn parse_connectors<'a>(
     for (directive, position) in std::mem::take(&mut ctx.graphql_directives) {
         let directive_name = directive.name.clone();
         let transforms = directive.transforms.clone();
+        let error_extensions = directive.error_extensions.clone();
         match connector_parsers.fetch_and_parse_graphql(directive).await {
             Ok(mut registry) => {
                 if let Some(transforms) = &transforms {
                     run_transforms(&mut registry, transforms);
                 }
+                if let Some(error_extensions) = error_extensions {
+                    registry.graphql_error_extensions.insert(
+                        format!("GraphQLConnector{directive_name}"),
+                        error_extensions.into_iter().collect(),
+                    );
+                }
                 connector_parsers::merge_registry(ctx, registry, position);
             }
             Err(errors) => return Err(Error::ConnectorErrors(directive_name, errors, position)),
@@ -246,7 +263,12 @@ async fn parse_connectors<'a>(
 
     for (directive, position) in std::mem::take(&mut ctx.postgres_directives) {
         match connector_parsers.fetch_and_parse_postgres(&directive).await {
-            Ok(registry) => {
+            Ok(mut registry) => {
+                // the pool settings aren't introspected, so they also apply to cached registries.
+                if let Some(database_definition) = registry.postgres_databases.get_mut(directive.name()) {
+                    database_definition.set_pool_settings(directive.pool_settings());
+                }
+
                 connector_parsers::merge_registry(ctx, registry, position);
             }
             Err(errors) => return Err(Error::ConnectorErrors(directive.name().to_string(), errors, position)),
@@ -324,6 +346,8 @@ fn parse_types<'a>(schema: &'a ServiceDocument, ctx: &mut VisitorContext<'a>) {
         .with(UniqueObjectFields)
         .with(CheckAllDirectivesAreKnown::default())
         .with(ExperimentalDirectiveVisitor)
+        .with(RateLimitVisitor)
+        .with(OperationLimitsVisitor)
         .with(FederationDirectiveVisitor); // This will likely need moved.  Here'll do for now though
 
     visit(&mut rules, ctx, schema);
@@ -333,7 +357,8 @@ fn parse_types<'a>(schema: &'a ServiceDocument, ctx: &mut VisitorContext<'a>) {
 fn parse_post_types<'a>(schema: &'a ServiceDocument, ctx: &mut VisitorContext<'a>) {
     let mut rules = rules::visitor::VisitorNil
         .with(DefaultDirectiveTypes)
-        .with(SearchDirective);
+        .with(SearchDirective)
+        .with(ComplexityVisitor);
 
     visit(&mut rules, ctx, schema);
 }
@@ -281,6 +281,7 @@ pub fn add_query_search(
         }),
         required_operation: Some(Operations::LIST),
         auth: model_auth.cloned(),
+        complexity: None,
     });
 }
 
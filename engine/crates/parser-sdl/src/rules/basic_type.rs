@@ -71,7 +71,7 @@ impl<'a> Visitor<'a> for BasicType {
                         ctx.report_error(vec![field.pos], "A field can't have a join and a requires on it");
                     }
                     requires = join_directive.select.required_fieldset();
-                    resolver = Resolver::Join(join_directive.select.to_join_resolver());
+                    resolver = Resolver::Join(join_directive.to_join_resolver());
                 }
 
                 MetaField {
@@ -160,6 +160,7 @@ fn field_resolver(field: &Positioned<FieldDefinition>, mapped_name: Option<&str>
     if let Some(resolver_name) = ResolverDirective::resolver_name(&field.node) {
         return Resolver::CustomResolver(CustomResolver {
             resolver_name: resolver_name.to_owned(),
+            batched: ResolverDirective::is_batched(&field.node),
         });
     }
 
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use engine_parser::{
+    types::{FieldDefinition, TypeDefinition},
+    Positioned,
+};
+
+use crate::{
+    directive_de::parse_directive,
+    rules::{
+        directive::Directive,
+        visitor::{Visitor, VisitorContext, MUTATION_TYPE, QUERY_TYPE},
+    },
+};
+
+const COMPLEXITY_DIRECTIVE_NAME: &str = "complexity";
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComplexityDirective {
+    weight: usize,
+}
+
+impl Directive for ComplexityDirective {
+    fn definition() -> String {
+        r#"
+        directive @complexity(
+          "How much the field counts for in the complexity of operations, instead of 1"
+          weight: Int!
+        ) on FIELD_DEFINITION
+        "#
+        .to_string()
+    }
+}
+
+/// Sets the complexity of the fields, which must have been added to the registry already.
+pub struct ComplexityVisitor;
+
+impl<'a> Visitor<'a> for ComplexityVisitor {
+    fn enter_field(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        field: &'a Positioned<FieldDefinition>,
+        parent_type: &'a Positioned<TypeDefinition>,
+    ) {
+        let Some(directive) = field
+            .node
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == COMPLEXITY_DIRECTIVE_NAME)
+        else {
+            return;
+        };
+
+        let weight = match parse_directive::<ComplexityDirective>(directive, &HashMap::default()) {
+            Ok(ComplexityDirective { weight }) => weight,
+            Err(error) => return ctx.append_errors(vec![error]),
+        };
+
+        let type_name = parent_type.node.name.node.as_str();
+        let field_name = field.node.name.node.as_str();
+
+        // root fields are only added to the registry once all of them have been parsed
+        let meta_field = match type_name {
+            QUERY_TYPE => ctx.queries.iter_mut().find(|meta_field| meta_field.name == field_name),
+            MUTATION_TYPE => ctx
+                .mutations
+                .iter_mut()
+                .find(|meta_field| meta_field.name == field_name),
+            _ => ctx
+                .registry
+                .get_mut()
+                .types
+                .get_mut(type_name)
+                .and_then(|meta_type| meta_type.field_by_name_mut(field_name)),
+        };
+
+        match meta_field {
+            Some(meta_field) => meta_field.complexity = Some(weight),
+            None => ctx.report_error(
+                vec![directive.pos],
+                format!("@complexity error: the field {type_name}.{field_name} can't have a complexity"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine::registry::MetaType;
+
+    use crate::parse_registry;
+
+    #[test]
+    fn sets_field_complexity() {
+        let registry = parse_registry(
+            r#"
+            type Post {
+              title: String
+              comments: [String] @complexity(weight: 10)
+            }
+
+            extend type Query {
+              posts: [Post] @resolver(name: "posts") @complexity(weight: 5)
+            }
+            "#,
+        )
+        .unwrap();
+
+        let complexity = |type_name: &str, field_name: &str| match registry.types.get(type_name) {
+            Some(MetaType::Object(object)) => object.fields.get(field_name).and_then(|field| field.complexity),
+            _ => None,
+        };
+
+        assert_eq!(complexity("Post", "title"), None);
+        assert_eq!(complexity("Post", "comments"), Some(10));
+        assert_eq!(complexity("Query", "posts"), Some(5));
+    }
+}
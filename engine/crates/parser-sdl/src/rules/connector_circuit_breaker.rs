@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use engine::registry::CircuitBreakerPolicy;
+
+/// How long an open circuit breaker waits before probing the server again, in milliseconds
+const DEFAULT_RESET_TIMEOUT: u64 = 30_000;
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreaker {
+    /// How many requests in a row have to fail before the breaker opens
+    pub failure_threshold: u32,
+
+    /// How long the breaker stays open before letting a request through, in milliseconds
+    pub reset_timeout: Option<u64>,
+}
+
+impl CircuitBreaker {
+    pub fn policy(&self) -> CircuitBreakerPolicy {
+        CircuitBreakerPolicy {
+            failure_threshold: self.failure_threshold,
+            reset_timeout: Duration::from_millis(self.reset_timeout.unwrap_or(DEFAULT_RESET_TIMEOUT)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.failure_threshold == 0 {
+            return Err("the failureThreshold of a circuit breaker must be at least 1".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -52,6 +52,7 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                     }
                     (None, Some(resolver_name)) => Resolver::CustomResolver(CustomResolver {
                         resolver_name: resolver_name.to_owned(),
+                        batched: ResolverDirective::is_batched(&field.node),
                     }),
                     (Some(join_directive), None) => {
                         if requires.is_some() {
@@ -60,7 +61,7 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                             ctx.report_error(vec![field.pos], "A field can't have a join and a requires on it");
                         }
                         requires = join_directive.select.required_fieldset();
-                        Resolver::Join(join_directive.select.to_join_resolver())
+                        Resolver::Join(join_directive.to_join_resolver())
                     }
                     (Some(_), Some(_)) => {
                         ctx.report_error(vec![field.pos], "A field can't have a join and a custom resolver on it");
@@ -77,11 +77,13 @@ impl<'a> Visitor<'a> for ExtendQueryAndMutationTypes {
                     compute_complexity: None,
                     resolver: Resolver::CustomResolver(CustomResolver {
                         resolver_name: resolver_name.to_owned(),
+                        batched: ResolverDirective::is_batched(&field.node),
                     }),
                     edges: Vec::new(),
                     relation: None,
                     required_operation,
                     auth: None,
+                    complexity: None,
                 });
             }
         }
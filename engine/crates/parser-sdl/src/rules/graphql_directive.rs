@@ -1,8 +1,11 @@
-use engine::registry::{ConnectorHeaderValue, ConnectorHeaders};
+use std::time::Duration;
+
+use engine::registry::{ConnectorFetchPolicy, ConnectorHeaderValue, ConnectorHeaders};
 use engine_parser::types::SchemaDefinition;
 use url::Url;
 
 use super::{
+    connector_circuit_breaker::CircuitBreaker,
     connector_headers::{Header, IntrospectionHeader},
     connector_transforms::Transforms,
     directive::Directive,
@@ -23,13 +26,35 @@ pub struct GraphqlDirective {
     pub namespace: bool,
 
     pub url: Url,
+
+    /// A path to the SDL of the upstream schema, read instead of introspecting `url`. URLs are
+    /// ignored, the schema is introspected from `url` for them.
+    #[serde(default)]
+    pub schema: Option<String>,
+
     #[serde(default)]
     headers: Vec<Header>,
     #[serde(default)]
     introspection_headers: Option<Vec<IntrospectionHeader>>,
 
+    /// How long to wait for each attempt at a request, in milliseconds.
+    #[serde(default)]
+    timeout: Option<u64>,
+
+    /// How many times to retry failed requests.
+    #[serde(default)]
+    retries: u32,
+
+    /// Stops sending requests to the upstream for a while after it keeps failing.
+    #[serde(default)]
+    circuit_breaker: Option<CircuitBreaker>,
+
     #[serde(default)]
     pub transforms: Option<Transforms>,
+
+    /// The extensions of upstream errors forwarded to clients, all of them when not set.
+    #[serde(default)]
+    pub error_extensions: Option<Vec<String>>,
 }
 
 impl GraphqlDirective {
@@ -41,6 +66,14 @@ impl GraphqlDirective {
         )
     }
 
+    pub fn fetch_policy(&self) -> ConnectorFetchPolicy {
+        ConnectorFetchPolicy {
+            timeout: self.timeout.map(Duration::from_millis),
+            retries: self.retries,
+            circuit_breaker: self.circuit_breaker.as_ref().map(CircuitBreaker::policy),
+        }
+    }
+
     pub fn introspection_headers(&self) -> Vec<(&str, &str)> {
         match &self.introspection_headers {
             Some(introspection_headers) => introspection_headers
@@ -76,6 +109,13 @@ impl Directive for GraphqlDirective {
           """
           url: Url!
 
+          """
+          A path to the SDL of the GraphQL source, relative to the grafbase directory.
+          When set, the schema is read from it instead of being introspected from the URL.
+          URLs are ignored, the schema being introspected as when the argument isn't set.
+          """
+          schema: String
+
           """
           Optional headers to embed in every HTTP request.
           """
@@ -85,8 +125,33 @@ impl Directive for GraphqlDirective {
           Optional headers to embed in an introspection HTTP request.
           """
           introspectionHeaders: [GraphqlIntrospectionHeader!]
+
+          """
+          How long to wait for each attempt at a request to the upstream, in milliseconds.
+          """
+          timeout: Int
+
+          """
+          How many times to retry queries that fail with a server error, time out or are rate limited.
+          """
+          retries: Int = 0
+
+          """
+          Stops sending requests to the upstream for a while after it keeps failing.
+          """
+          circuitBreaker: GraphqlCircuitBreaker
+
+          """
+          The extensions of upstream errors to forward to clients. All of them are forwarded when not set.
+          """
+          errorExtensions: [String!]
         ) on SCHEMA
 
+        input GraphqlCircuitBreaker {
+            failureThreshold: Int!
+            resetTimeout: Int = 30000
+        }
+
         input GraphqlIntrospectionHeader {
             name: String!
             value: String!
@@ -131,6 +196,14 @@ impl GraphqlDirective {
     fn validate(self) -> Result<Self, String> {
         validate_connector_name(&self.name)?;
 
+        if self.timeout == Some(0) {
+            return Err("the timeout must be greater than zero".to_string());
+        }
+
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.validate()?;
+        }
+
         Ok(self)
     }
 }
@@ -189,6 +262,7 @@ mod tests {
                     query: None,
                     fragment: None,
                 },
+                schema: None,
                 headers: [
                     Header {
                         name: "authorization",
@@ -205,7 +279,11 @@ mod tests {
                         },
                     ],
                 ),
+                timeout: None,
+                retries: 0,
+                circuit_breaker: None,
                 transforms: None,
+                error_extensions: None,
             },
         ]
         "###);
@@ -253,6 +331,7 @@ mod tests {
                     query: None,
                     fragment: None,
                 },
+                schema: None,
                 headers: [
                     Header {
                         name: "authorization",
@@ -269,7 +348,11 @@ mod tests {
                         },
                     ],
                 ),
+                timeout: None,
+                retries: 0,
+                circuit_breaker: None,
                 transforms: None,
+                error_extensions: None,
             },
         ]
         "###);
@@ -396,4 +479,19 @@ mod tests {
             vec![("authorization", "Bearer blah")]
         );
     }
+
+    #[test]
+    fn test_zero_timeout() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @graphql(
+                name: "Test",
+                url: "https://countries.trevorblades.com",
+                timeout: 0,
+              )
+            "#,
+            "the timeout must be greater than zero"
+        );
+    }
 }
@@ -1,7 +1,15 @@
 use std::collections::{BTreeSet, HashMap};
 
-use engine::registry::{field_set::Selection, resolvers::join::JoinResolver, FieldSet};
-use engine_parser::{parse_field, types::ConstDirective, Positioned};
+use engine::registry::{
+    field_set::Selection,
+    resolvers::join::{JoinBatch, JoinResolver},
+    FieldSet,
+};
+use engine_parser::{
+    parse_field,
+    types::{ConstDirective, Selection},
+    Positioned,
+};
 use engine_value::{Name, Value};
 use serde::de::Error;
 
@@ -13,10 +21,13 @@ use super::{directive::Directive, visitor::VisitorContext};
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct JoinDirective {
     pub select: FieldSelection,
+    #[serde(default)]
+    pub batch: Option<JoinBatch>,
 }
 
 #[derive(Debug)]
 pub struct FieldSelection {
+    namespaces: Vec<String>,
     field_name: String,
     arguments: Vec<(Name, Value)>,
     required_fields: Vec<String>,
@@ -24,9 +35,18 @@ pub struct FieldSelection {
 
 impl Directive for JoinDirective {
     fn definition() -> String {
-        "
-        directive @join(select: FieldSelection!) on FIELD_DEFINITION
-        "
+        r#"
+        directive @join(
+          select: FieldSelection!
+          "Resolves the joins of all the parents at once, if the selected field accepts a list"
+          batch: JoinBatch
+        ) on FIELD_DEFINITION
+
+        input JoinBatch {
+          "The field of the returned items holding the argument value of their parent. They're matched by position otherwise."
+          key: String
+        }
+        "#
         .into()
     }
 }
@@ -46,6 +66,12 @@ impl JoinDirective {
             }
         }
     }
+
+    pub fn to_join_resolver(&self) -> JoinResolver {
+        let mut resolver = self.select.to_join_resolver();
+        resolver.batch = self.batch.clone();
+        resolver
+    }
 }
 
 impl FieldSelection {
@@ -61,7 +87,9 @@ impl FieldSelection {
     }
 
     pub fn to_join_resolver(&self) -> JoinResolver {
-        JoinResolver::new(self.field_name.clone(), self.arguments.clone())
+        let mut resolver = JoinResolver::new(self.field_name.clone(), self.arguments.clone());
+        resolver.namespaces = self.namespaces.clone();
+        resolver
     }
 }
 
@@ -72,12 +100,32 @@ impl<'de> serde::Deserialize<'de> for FieldSelection {
     {
         let select = String::deserialize(deserializer)?;
 
-        let field = parse_field(select).map_err(|error| D::Error::custom(format!("Could not parse join: {error}")))?;
+        let mut field =
+            parse_field(select).map_err(|error| D::Error::custom(format!("Could not parse join: {error}")))?;
+
+        // A join can select a field nested in namespaces, e.g. `stripe { customer(id: $id) }`.
+        let mut namespaces = vec![];
+        while !field.node.selection_set.items.is_empty() {
+            let namespace = field.node.name.node.to_string();
+
+            if !field.node.arguments.is_empty() {
+                return Err(D::Error::custom(format!(
+                    "this join attempts to pass arguments to the namespace {namespace}, but only the joined field can have arguments"
+                )));
+            }
+
+            let mut items = std::mem::take(&mut field.node.selection_set.node.items);
+            let child = match items.pop().map(|item| item.node) {
+                Some(Selection::Field(child)) if items.is_empty() => child,
+                _ => {
+                    return Err(D::Error::custom(
+                        "this join attempts to select children, but joins can only select a single field",
+                    ))
+                }
+            };
 
-        if !field.node.selection_set.items.is_empty() {
-            return Err(D::Error::custom(
-                "this join attempts to select children, but joins can only select a single field",
-            ));
+            namespaces.push(namespace);
+            field = child;
         }
 
         let arguments = field
@@ -96,6 +144,7 @@ impl<'de> serde::Deserialize<'de> for FieldSelection {
             .collect::<Vec<_>>();
 
         Ok(FieldSelection {
+            namespaces,
             field_name: field.node.name.node.to_string(),
             arguments,
             required_fields,
@@ -328,6 +377,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn join_through_namespaces() {
+        let schema = r#"
+            extend type Query {
+                stripe: Stripe! @resolver(name: "stripe")
+            }
+
+            type Stripe {
+                customer(id: ID!): Customer @resolver(name: "customer")
+            }
+
+            type Customer {
+                id: ID!
+            }
+
+            type Order {
+                customerId: ID!
+                customer: Customer @join(select: "stripe { customer(id: $customerId) }")
+            }
+        "#;
+
+        let registry = crate::to_parse_result_with_variables(schema, &HashMap::new())
+            .unwrap()
+            .registry;
+
+        let resolver = &registry.types["Order"].fields().as_ref().unwrap()["customer"].resolver;
+
+        assert_json_snapshot!(resolver, @r###"
+        {
+          "J": {
+            "namespaces": [
+              "stripe"
+            ],
+            "field_name": "customer",
+            "arguments": [
+              [
+                "id",
+                {
+                  "$var": "customerId"
+                }
+              ]
+            ]
+          }
+        }
+        "###);
+    }
+
+    #[test]
+    fn join_through_missing_namespace() {
+        assert_validation_error!(
+            r#"
+            type Customer {
+                id: ID!
+            }
+
+            type Order {
+                customerId: ID!
+                customer: Customer @join(select: "stripe { customer(id: $customerId) }")
+            }
+            "#,
+            "The field customer on the type Order is trying to join with a field nested in stripe, which doesn't exist on the Query type"
+        );
+    }
+
+    #[test]
+    fn join_through_namespace_returning_a_list() {
+        assert_validation_error!(
+            r#"
+            extend type Query {
+                stripes: [Stripe!]! @resolver(name: "stripes")
+            }
+
+            type Stripe {
+                customer(id: ID!): Customer @resolver(name: "customer")
+            }
+
+            type Customer {
+                id: ID!
+            }
+
+            type Order {
+                customerId: ID!
+                customer: Customer @join(select: "stripes { customer(id: $customerId) }")
+            }
+            "#,
+            "The field customer on the type Order is trying to join with a field nested in stripes, but stripes doesn't return an object"
+        );
+    }
+
+    #[test]
+    fn join_through_namespace_with_arguments() {
+        let directive =
+            JoinDirective::deserialize(json!({"select": "stripe(account: $account) { customer(id: $id) }"}));
+
+        assert_eq!(
+            directive.unwrap_err().to_string(),
+            "this join attempts to pass arguments to the namespace stripe, but only the joined field can have arguments"
+        );
+    }
+
+    #[test]
+    fn join_through_namespace_selecting_several_fields() {
+        let directive = JoinDirective::deserialize(json!({"select": "stripe { customer(id: $id) invoice(id: $id) }"}));
+
+        assert_eq!(
+            directive.unwrap_err().to_string(),
+            "this join attempts to select children, but joins can only select a single field"
+        );
+    }
+
     #[test]
     fn join_directive_deser() {
         let directive = JoinDirective::deserialize(json!({"select": "findUser(name: $name, filters: {eq: $filters})"}));
@@ -336,6 +495,7 @@ mod tests {
         Ok(
             JoinDirective {
                 select: FieldSelection {
+                    namespaces: [],
                     field_name: "findUser",
                     arguments: [
                         (
@@ -370,8 +530,99 @@ mod tests {
                         "name",
                     ],
                 },
+                batch: None,
             },
         )
         "###);
     }
+
+    #[test]
+    fn batched_join() {
+        let schema = r#"
+            extend type Query {
+                usersByIds(ids: [ID!]!, active: Boolean): [User!]! @resolver(name: "usersByIds")
+            }
+
+            type User {
+                id: ID!
+            }
+
+            type Post {
+                authorId: ID!
+                author: User @join(select: "usersByIds(ids: $authorId, active: true)", batch: { key: "id" })
+            }
+        "#;
+
+        let registry = crate::to_parse_result_with_variables(schema, &HashMap::new())
+            .unwrap()
+            .registry;
+
+        let resolver = &registry.types["Post"].fields().as_ref().unwrap()["author"].resolver;
+
+        assert_json_snapshot!(resolver, @r###"
+        {
+          "J": {
+            "field_name": "usersByIds",
+            "arguments": [
+              [
+                "ids",
+                {
+                  "$var": "authorId"
+                }
+              ],
+              [
+                "active",
+                true
+              ]
+            ],
+            "batch": {
+              "key": "id"
+            }
+          }
+        }
+        "###);
+    }
+
+    #[test]
+    fn batched_join_on_a_field_not_returning_a_list() {
+        assert_validation_error!(
+            r#"
+            extend type Query {
+                user(id: ID!): User @resolver(name: "user")
+            }
+
+            type User {
+                id: ID!
+            }
+
+            type Post {
+                authorId: ID!
+                author: User @join(select: "user(id: $authorId)", batch: { key: "id" })
+            }
+            "#,
+            "The field author on the type Post is trying to join with the field named user, but those fields do not have compatible types"
+        );
+    }
+
+    #[test]
+    fn batched_join_with_several_parent_arguments() {
+        assert_validation_error!(
+            r#"
+            extend type Query {
+                users(ids: [ID!]!, names: [String!]): [User!]! @resolver(name: "users")
+            }
+
+            type User {
+                id: ID!
+            }
+
+            type Post {
+                authorId: ID!
+                authorName: String!
+                author: User @join(select: "users(ids: $authorId, names: $authorName)", batch: {})
+            }
+            "#,
+            "The field author on the type Post is trying to batch its join with the field named users, but exactly one of its arguments must refer to the fields of the parent"
+        );
+    }
 }
@@ -6,6 +6,8 @@ pub mod check_known_directives;
 pub mod check_type_collision;
 pub mod check_type_validity;
 pub mod check_types_underscore;
+pub mod complexity_directive;
+mod connector_circuit_breaker;
 mod connector_headers;
 pub mod connector_transforms;
 pub mod default_directive;
@@ -25,7 +27,9 @@ pub mod model_directive;
 pub mod mongodb_directive;
 pub mod one_of_directive;
 pub mod openapi_directive;
+pub mod operation_limits_directive;
 pub mod postgres_directive;
+pub mod rate_limit_directive;
 pub mod relations;
 pub mod requires_directive;
 pub mod resolver_directive;
@@ -136,6 +136,7 @@ fn insert_metadata_field(
             relation: None,
             required_operation: None,
             auth: auth.cloned(),
+            complexity: None,
         },
     )
 }
@@ -257,6 +258,7 @@ impl<'a> Visitor<'a> for ModelDirective {
                                     (
                                         Resolver::CustomResolver(CustomResolver {
                                             resolver_name: resolver_name.to_owned(),
+                                            batched: ResolverDirective::is_batched(&field.node),
                                         }),
                                         None,
                                         vec![],
@@ -1,9 +1,11 @@
+mod lookup_directive;
 mod model_directive;
 mod type_directive;
 
 use engine::registry::{MetaField, MongoDBConfiguration, ObjectType};
 use engine_parser::types::SchemaDefinition;
 use inflector::Inflector;
+pub use lookup_directive::LookupDirective;
 pub(super) use model_directive::create_type_context::CreateTypeContext;
 pub use model_directive::MongoDBModelDirective;
 pub use type_directive::MongoDBTypeDirective;
@@ -39,9 +41,10 @@ static DATE_TIME_SCALARS: &[&str] = &["Date", "DateTime", "Timestamp"];
 #[serde(rename_all = "camelCase")]
 pub struct MongoDBDirective {
     name: String,
-    url: String,
-    api_key: String,
-    data_source: String,
+    url: Option<String>,
+    api_key: Option<String>,
+    data_source: Option<String>,
+    connection_string: Option<String>,
     database: String,
     #[serde(default = "default_to_true")]
     namespace: bool,
@@ -49,8 +52,8 @@ pub struct MongoDBDirective {
 
 impl MongoDBDirective {
     /// The host url for the Atlas Data API.
-    pub fn url(&self) -> &str {
-        &self.url
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
     }
 
     /// A unique name for the given directive. Used in the model
@@ -61,14 +64,21 @@ impl MongoDBDirective {
 
     /// An API key for the MongoDB Atlas Data API. Generated
     /// in the Atlas dashboard.
-    pub fn api_key(&self) -> &str {
-        &self.api_key
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
     }
 
     /// The name of the database cluster. Found from the
     /// MongoDB Atlas dashboard.
-    pub fn data_source(&self) -> &str {
-        &self.data_source
+    pub fn data_source(&self) -> Option<&str> {
+        self.data_source.as_deref()
+    }
+
+    /// A MongoDB connection string. If set, the connector talks to
+    /// the database using the MongoDB wire protocol instead of the
+    /// Atlas Data API.
+    pub fn connection_string(&self) -> Option<&str> {
+        self.connection_string.as_deref()
     }
 
     /// The database holding the collections. Found from the
@@ -97,18 +107,26 @@ impl Directive for MongoDBDirective {
           An API key for the MongoDB Atlas Data API. Generated
           in the Atlas dashboard.
           """
-          apiKey: String!
+          apiKey: String
 
           """
           The full URL. Found from the MongoDB Atlas dashboard.
           """
-          url: String!
+          url: String
 
           """
           The name of the database cluster. Found from the
           MongoDB Atlas dashboard.
           """
-          dataSource: String!
+          dataSource: String
+
+          """
+          A MongoDB connection string. If set, the connector
+          uses the MongoDB wire protocol instead of the Atlas
+          Data API, and `apiKey`, `url` and `dataSource` must
+          not be defined.
+          """
+          connectionString: String
 
           """
           The database holding the collections. Found from the
@@ -149,11 +167,12 @@ impl<'a> Visitor<'a> for MongoDBVisitor {
                     ctx.registry.get_mut().create_mongo_config(
                         |_| MongoDBConfiguration {
                             name: parsed_directive.name().to_string(),
-                            api_key: parsed_directive.api_key().to_string(),
-                            url: parsed_directive.url().to_string(),
-                            data_source: parsed_directive.data_source().to_string(),
+                            api_key: parsed_directive.api_key().unwrap_or_default().to_string(),
+                            url: parsed_directive.url().unwrap_or_default().to_string(),
+                            data_source: parsed_directive.data_source().unwrap_or_default().to_string(),
                             database: parsed_directive.database().to_string(),
                             namespace: parsed_directive.namespace,
+                            connection_string: parsed_directive.connection_string().map(ToString::to_string),
                         },
                         parsed_directive.name(),
                     );
@@ -200,6 +219,20 @@ impl MongoDBDirective {
     fn validate(self) -> Result<Self, String> {
         validate_connector_name(&self.name)?;
 
+        let data_api_settings = [&self.url, &self.api_key, &self.data_source];
+
+        if self.connection_string.is_some() {
+            if data_api_settings.iter().any(|setting| setting.is_some()) {
+                return Err(String::from(
+                    "MongoDB connector with a `connectionString` cannot define `url`, `apiKey` or `dataSource`",
+                ));
+            }
+        } else if data_api_settings.iter().any(|setting| setting.is_none()) {
+            return Err(String::from(
+                "MongoDB connector must define either `connectionString`, or `url`, `apiKey` and `dataSource`",
+            ));
+        }
+
         Ok(self)
     }
 }
@@ -241,4 +274,37 @@ mod tests {
             "Connector names must be alphanumeric and cannot start with a number"
         );
     }
+
+    #[test]
+    fn connection_string_with_data_api_settings() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @mongodb(
+                name: "test"
+                apiKey: "i am a key"
+                url: "http://example.com/mongodbinnit"
+                dataSource: "woop"
+                connectionString: "mongodb://localhost:27017"
+                database: "poow"
+              )
+            "#,
+            "MongoDB connector with a `connectionString` cannot define `url`, `apiKey` or `dataSource`"
+        );
+    }
+
+    #[test]
+    fn missing_data_api_settings() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @mongodb(
+                name: "test"
+                url: "http://example.com/mongodbinnit"
+                database: "poow"
+              )
+            "#,
+            "MongoDB connector must define either `connectionString`, or `url`, `apiKey` and `dataSource`"
+        );
+    }
 }
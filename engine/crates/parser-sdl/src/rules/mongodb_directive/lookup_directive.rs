@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use engine_parser::types::FieldDefinition;
+
+use crate::{
+    directive_de::parse_directive,
+    rules::{directive::Directive, visitor::VisitorContext},
+};
+
+const LOOKUP_DIRECTIVE_NAME: &str = "lookup";
+
+/// Defines a relation between two MongoDB models, resolved with a `$lookup`
+/// stage in the query of the parent model.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LookupDirective {
+    local_field: String,
+    foreign_field: String,
+}
+
+impl LookupDirective {
+    pub(crate) fn from_field(ctx: &mut VisitorContext<'_>, field: &FieldDefinition) -> Option<Self> {
+        let directive = field
+            .directives
+            .iter()
+            .find(|directive| directive.name.node == LOOKUP_DIRECTIVE_NAME)?;
+
+        match parse_directive::<Self>(directive, &HashMap::new()) {
+            Ok(directive) => Some(directive),
+            Err(error) => {
+                ctx.append_errors(vec![error]);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn is_lookup(field: &FieldDefinition) -> bool {
+        field
+            .directives
+            .iter()
+            .any(|directive| directive.name.node == LOOKUP_DIRECTIVE_NAME)
+    }
+
+    /// The field in the parent model, as named in the schema.
+    pub(crate) fn local_field(&self) -> &str {
+        &self.local_field
+    }
+
+    /// The field in the related model, as named in the schema.
+    pub(crate) fn foreign_field(&self) -> &str {
+        &self.foreign_field
+    }
+}
+
+impl Directive for LookupDirective {
+    fn definition() -> String {
+        r#"
+        directive @lookup(
+          """
+          The field in this model to match with the related model.
+          """
+          localField: String!
+
+          """
+          The field in the related model to match with `localField`.
+          """
+          foreignField: String!
+        ) on FIELD_DEFINITION
+        "#
+        .to_string()
+    }
+}
@@ -3,14 +3,23 @@ mod resolver_data;
 use engine::{
     indexmap::IndexMap,
     names::{MONGODB_OUTPUT_FIELD_ID, OUTPUT_FIELD_ID},
-    registry::{self, resolvers::transformer::Transformer, MetaField, MetaType},
+    registry::{
+        self,
+        resolvers::{
+            atlas_data_api::{AtlasDataApiResolver, AtlasLookup, OperationType},
+            transformer::Transformer,
+            Resolver,
+        },
+        MetaField, MetaType,
+    },
 };
+use engine_parser::types::{FieldDefinition, ObjectType, TypeKind};
 use resolver_data::ResolverData;
 
-use super::CreateTypeContext;
+use super::{CreateTypeContext, COLLECTION_KEY, CONNECTOR_KEY};
 use crate::rules::{
-    auth_directive::AuthDirective, requires_directive::RequiresDirective, resolver_directive::ResolverDirective,
-    visitor::VisitorContext,
+    auth_directive::AuthDirective, mongodb_directive::LookupDirective, requires_directive::RequiresDirective,
+    resolver_directive::ResolverDirective, visitor::VisitorContext,
 };
 
 pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTypeContext<'_>) {
@@ -44,11 +53,18 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
         }
         .or_else(|| create_ctx.model_auth().clone());
 
-        let resolver_data = match ResolverDirective::resolver_name(field) {
+        let mut resolver_data = match ResolverDirective::resolver_name(field) {
             Some(resolver_name) => ResolverData::resolver(resolver_name, field),
             None => ResolverData::projection(field),
         };
 
+        if let Some(lookup) = LookupDirective::from_field(visitor_ctx, field) {
+            match lookup_resolver(visitor_ctx, create_ctx, field, &lookup) {
+                Ok(resolver) => resolver_data.resolver = resolver,
+                Err(message) => visitor_ctx.report_error(vec![field.name.pos], message),
+            }
+        }
+
         let requires =
             RequiresDirective::from_directives(&field.directives, visitor_ctx).map(RequiresDirective::into_fields);
 
@@ -93,3 +109,70 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
         .get_mut()
         .create_type(|_| object, &type_name, &type_name);
 }
+
+/// Relation fields are embedded to the parent document with a `$lookup` stage, and
+/// selected from there by the resolver.
+fn lookup_resolver(
+    visitor_ctx: &VisitorContext<'_>,
+    create_ctx: &CreateTypeContext<'_>,
+    field: &FieldDefinition,
+    lookup: &LookupDirective,
+) -> Result<Resolver, String> {
+    let type_name = field.ty.node.base.to_base_type_str();
+    let connector = create_ctx.config().name.as_str();
+
+    let related = visitor_ctx
+        .types
+        .get(type_name)
+        .and_then(|r#type| {
+            let model = r#type.node.directives.iter().find(|directive| directive.is_model())?;
+            let same_connector = model.node.get_argument(CONNECTOR_KEY)?.node.as_str() == Some(connector);
+
+            match r#type.node.kind {
+                TypeKind::Object(ref object) if same_connector => Some((r#type, model, object)),
+                _ => None,
+            }
+        })
+        .ok_or_else(|| format!("The @lookup field must point to a @model type of the `{connector}` connector."))?;
+
+    let (related_type, related_model, related_object) = related;
+
+    let collection = related_model
+        .node
+        .get_argument(COLLECTION_KEY)
+        .and_then(|argument| argument.node.as_str())
+        .unwrap_or_else(|| related_type.node.name.node.as_str())
+        .to_string();
+
+    let local_field = database_name(create_ctx.object, lookup.local_field())
+        .ok_or_else(|| format!("Field `{}` not found from the model.", lookup.local_field()))?;
+
+    let foreign_field = database_name(related_object, lookup.foreign_field()).ok_or_else(|| {
+        format!(
+            "Field `{}` not found from the type {type_name}.",
+            lookup.foreign_field()
+        )
+    })?;
+
+    Ok(Resolver::MongoResolver(AtlasDataApiResolver {
+        operation_type: OperationType::Lookup,
+        directive_name: connector.to_string(),
+        collection,
+        lookup: Some(AtlasLookup {
+            local_field,
+            foreign_field,
+        }),
+    }))
+}
+
+fn database_name(object: &ObjectType, field_name: &str) -> Option<String> {
+    if field_name == OUTPUT_FIELD_ID {
+        return Some(MONGODB_OUTPUT_FIELD_ID.to_string());
+    }
+
+    object
+        .fields
+        .iter()
+        .find(|field| field.node.name() == field_name)
+        .map(|field| field.node.mapped_name().unwrap_or(field_name).to_string())
+}
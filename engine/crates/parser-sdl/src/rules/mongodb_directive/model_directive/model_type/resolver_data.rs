@@ -8,7 +8,7 @@ use engine::{
 };
 use engine_parser::types::FieldDefinition;
 
-use crate::rules::cache_directive::CacheDirective;
+use crate::rules::{cache_directive::CacheDirective, resolver_directive::ResolverDirective};
 
 #[derive(Debug)]
 pub(super) struct ResolverData {
@@ -22,6 +22,7 @@ impl ResolverData {
     pub(super) fn resolver(resolver_name: &str, field: &FieldDefinition) -> Self {
         let resolver = Resolver::CustomResolver(CustomResolver {
             resolver_name: resolver_name.to_owned(),
+            batched: ResolverDirective::is_batched(field),
         });
 
         let field_type = field.ty.node.to_string();
@@ -0,0 +1,67 @@
+use common_types::auth::Operations;
+use engine::{
+    indexmap::IndexMap,
+    registry::{
+        resolvers::{
+            atlas_data_api::{AtlasDataApiResolver, OperationType},
+            Resolver,
+        },
+        MetaField, MetaInputValue,
+    },
+};
+
+use crate::{
+    registry::names::INPUT_ARG_FILTER,
+    rules::{
+        mongodb_directive::model_directive::{types::aggregate::AggregateTypes, CreateTypeContext},
+        visitor::VisitorContext,
+    },
+    utils::to_lower_camelcase,
+};
+
+const INPUT_ARG_GROUP_BY: &str = "groupBy";
+
+pub(super) fn create(
+    visitor_ctx: &mut VisitorContext<'_>,
+    create_ctx: &CreateTypeContext<'_>,
+    filter_type: &str,
+    aggregate_types: &AggregateTypes,
+) {
+    let type_name = create_ctx.model_name();
+    let query_name = format!("{}Aggregate", to_lower_camelcase(type_name));
+
+    let mut args = IndexMap::new();
+
+    args.insert(
+        INPUT_ARG_FILTER.to_string(),
+        MetaInputValue::new(INPUT_ARG_FILTER, filter_type),
+    );
+
+    if let Some(ref group_by_type) = aggregate_types.group_by_type {
+        let input = MetaInputValue::new(INPUT_ARG_GROUP_BY, format!("[{group_by_type}!]"))
+            .with_description("The fields to group the documents by. Without grouping, all documents form one group.");
+
+        args.insert(INPUT_ARG_GROUP_BY.to_string(), input);
+    }
+
+    let resolver = Resolver::MongoResolver(AtlasDataApiResolver {
+        collection: create_ctx.collection().to_string(),
+        operation_type: OperationType::Aggregate,
+        directive_name: create_ctx.config().name.clone(),
+        lookup: None,
+    });
+
+    let meta_field = MetaField {
+        name: query_name,
+        description: Some(format!("Aggregate values of {type_name} documents")),
+        args,
+        ty: format!("[{}!]!", aggregate_types.output_type).into(),
+        cache_control: create_ctx.model_cache().clone(),
+        resolver,
+        required_operation: Some(Operations::LIST),
+        auth: create_ctx.model_auth().clone(),
+        ..Default::default()
+    };
+
+    visitor_ctx.push_namespaced_query(create_ctx.query_type_name(), meta_field);
+}
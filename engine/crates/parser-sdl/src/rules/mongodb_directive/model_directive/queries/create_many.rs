@@ -36,6 +36,7 @@ pub(super) fn create(
     query.resolver = Resolver::MongoResolver(AtlasDataApiResolver {
         operation_type: OperationType::InsertMany,
         directive_name: create_ctx.config().name.clone(),
+        lookup: None,
         collection: create_ctx.collection().to_string(),
     });
 
@@ -32,6 +32,7 @@ pub(super) fn create(
         collection: create_ctx.collection().to_string(),
         operation_type: OperationType::DeleteOne,
         directive_name: create_ctx.config().name.clone(),
+        lookup: None,
     });
 
     query.required_operation = Some(Operations::DELETE);
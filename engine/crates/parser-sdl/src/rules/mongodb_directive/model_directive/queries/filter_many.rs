@@ -64,6 +64,7 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
         collection: create_ctx.collection().to_string(),
         operation_type: OperationType::FindMany,
         directive_name: create_ctx.config().name.clone(),
+        lookup: None,
     });
 
     field.args = args;
@@ -36,6 +36,7 @@ pub(super) fn create(
         collection: create_ctx.collection().to_string(),
         operation_type: OperationType::FindOne,
         directive_name: create_ctx.config().name.clone(),
+        lookup: None,
     });
 
     let meta_field = MetaField {
@@ -37,6 +37,7 @@ pub(crate) fn create(
     mutation.resolver = Resolver::MongoResolver(AtlasDataApiResolver {
         operation_type: OperationType::UpdateMany,
         directive_name: create_ctx.config().name.clone(),
+        lookup: None,
         collection: create_ctx.collection().to_string(),
     });
 
@@ -0,0 +1,189 @@
+use engine::registry::{
+    resolvers::{transformer::Transformer, Resolver},
+    EnumType, MetaEnumValue, MetaField, ObjectType,
+};
+use engine_parser::types::FieldDefinition;
+use inflector::Inflector;
+
+use crate::rules::{
+    mongodb_directive::{CreateTypeContext, LookupDirective, MONGODB_SCALARS, NUMERIC_SCALARS},
+    visitor::VisitorContext,
+};
+
+/// Scalars we can't group by or compare in a meaningful way.
+static NON_COMPARABLE_SCALARS: &[&str] = &["Bytes", "JSON"];
+
+pub(crate) struct AggregateTypes {
+    pub(crate) output_type: String,
+    pub(crate) group_by_type: Option<String>,
+}
+
+pub(crate) fn register_output(
+    visitor_ctx: &mut VisitorContext<'_>,
+    create_ctx: &CreateTypeContext<'_>,
+) -> AggregateTypes {
+    let model_name = create_ctx.model_name();
+    let output_type_name = format!("{model_name}Aggregate");
+
+    let scalar_fields: Vec<_> = create_ctx
+        .fields()
+        .filter(|field| !LookupDirective::is_lookup(field) && !field.ty.node.base.is_list())
+        .filter(|field| MONGODB_SCALARS.contains(&field.ty.node.base.to_base_type_str()))
+        .collect();
+
+    let comparable_fields: Vec<_> = scalar_fields
+        .iter()
+        .copied()
+        .filter(|field| !NON_COMPARABLE_SCALARS.contains(&field.ty.node.base.to_base_type_str()))
+        .collect();
+
+    let numeric_fields: Vec<_> = scalar_fields
+        .iter()
+        .copied()
+        .filter(|field| NUMERIC_SCALARS.contains(&field.ty.node.base.to_base_type_str()))
+        .collect();
+
+    let mut fields = Vec::new();
+
+    fields.push({
+        let mut field = MetaField::new("count", "Int!");
+        field.description = Some(String::from("The number of documents in the group."));
+        field.resolver = Transformer::select("count").into();
+
+        field
+    });
+
+    let group_by_type = if comparable_fields.is_empty() {
+        None
+    } else {
+        let group_type = format!("{model_name}AggregateGroup");
+        register_values_type(visitor_ctx, &group_type, &comparable_fields, None);
+
+        fields.push({
+            let mut field = MetaField::new("group", group_type);
+            field.description = Some(String::from("The values of the fields the documents are grouped by."));
+            field.resolver = Transformer::select("group").into();
+
+            field
+        });
+
+        Some(register_group_by_enum(visitor_ctx, model_name, &comparable_fields))
+    };
+
+    if !numeric_fields.is_empty() {
+        let sum_type = format!("{model_name}AggregateNumbers");
+        register_values_type(visitor_ctx, &sum_type, &numeric_fields, None);
+
+        let avg_type = format!("{model_name}AggregateAverages");
+        register_values_type(visitor_ctx, &avg_type, &numeric_fields, Some("Float"));
+
+        fields.push({
+            let mut field = MetaField::new("sum", sum_type);
+            field.description = Some(String::from("The sum of the numeric fields in the group."));
+            field.resolver = Transformer::select("sum").into();
+
+            field
+        });
+
+        fields.push({
+            let mut field = MetaField::new("avg", avg_type);
+            field.description = Some(String::from("The average of the numeric fields in the group."));
+            field.resolver = Transformer::select("avg").into();
+
+            field
+        });
+    }
+
+    if !comparable_fields.is_empty() {
+        let values_type = format!("{model_name}AggregateValues");
+        register_values_type(visitor_ctx, &values_type, &comparable_fields, None);
+
+        fields.push({
+            let mut field = MetaField::new("min", values_type.as_str());
+            field.description = Some(String::from("The smallest values of the fields in the group."));
+            field.resolver = Transformer::select("min").into();
+
+            field
+        });
+
+        fields.push({
+            let mut field = MetaField::new("max", values_type);
+            field.description = Some(String::from("The largest values of the fields in the group."));
+            field.resolver = Transformer::select("max").into();
+
+            field
+        });
+    }
+
+    let description = format!("Aggregated values of a group of {model_name} documents");
+    let object_type = ObjectType::new(output_type_name.clone(), fields).with_description(description);
+
+    visitor_ctx
+        .registry
+        .get_mut()
+        .create_type(|_| object_type.into(), &output_type_name, &output_type_name);
+
+    AggregateTypes {
+        output_type: output_type_name,
+        group_by_type,
+    }
+}
+
+/// An object with a nullable field for each of the given model fields. If `scalar` is
+/// given, all fields are of that type instead of the type of the model field.
+fn register_values_type(
+    visitor_ctx: &mut VisitorContext<'_>,
+    type_name: &str,
+    model_fields: &[&FieldDefinition],
+    scalar: Option<&str>,
+) {
+    let fields = model_fields.iter().map(|field| {
+        let database_name = field.mapped_name().unwrap_or_else(|| field.name()).to_string();
+        let base_type = field.ty.node.base.to_base_type_str();
+
+        let mut resolver = Resolver::Transformer(Transformer::Select {
+            key: database_name.clone(),
+        });
+
+        if base_type == "Timestamp" && scalar.is_none() {
+            resolver = resolver.and_then(Transformer::MongoTimestamp);
+        }
+
+        let mut meta_field = MetaField::new(field.name(), scalar.unwrap_or(base_type));
+        meta_field.mapped_name = Some(database_name);
+        meta_field.resolver = resolver;
+
+        meta_field
+    });
+
+    let object_type = ObjectType::new(type_name, fields);
+
+    visitor_ctx
+        .registry
+        .get_mut()
+        .create_type(|_| object_type.into(), type_name, type_name);
+}
+
+fn register_group_by_enum(
+    visitor_ctx: &mut VisitorContext<'_>,
+    model_name: &str,
+    model_fields: &[&FieldDefinition],
+) -> String {
+    let type_name = format!("{model_name}GroupBy");
+
+    let variants = model_fields.iter().map(|field| {
+        let mut variant = MetaEnumValue::new(field.name().to_screaming_snake_case());
+        variant.value = Some(field.mapped_name().unwrap_or_else(|| field.name()).to_string());
+
+        variant
+    });
+
+    let r#enum = EnumType::new(type_name.clone(), variants);
+
+    visitor_ctx
+        .registry
+        .get_mut()
+        .create_type(|_| r#enum.into(), &type_name, &type_name);
+
+    type_name
+}
@@ -12,7 +12,8 @@ use crate::{
     registry::{get_length_validator, names::MetaNames},
     rules::{
         default_directive::DefaultDirective,
-        mongodb_directive::model_directive::create_type_context::CreateTypeContext, visitor::VisitorContext,
+        mongodb_directive::{model_directive::create_type_context::CreateTypeContext, LookupDirective},
+        visitor::VisitorContext,
     },
     utils::to_input_type,
 };
@@ -27,17 +28,22 @@ pub(crate) fn register_input(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &
         input
     });
 
-    let explicit_fields = create_ctx.object.fields.iter().map(|field| {
-        let r#type = to_input_type(&visitor_ctx.types, field.r#type().clone());
-        let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type.to_string());
-
-        input.description = field.description().map(ToString::to_string);
-        input.rename = field.mapped_name().map(ToString::to_string);
-        input.default_value = DefaultDirective::default_value_of(field);
-        input.validators = get_length_validator(field).map(|validator| vec![validator]);
-
-        input
-    });
+    let explicit_fields = create_ctx
+        .object
+        .fields
+        .iter()
+        .filter(|field| !LookupDirective::is_lookup(field))
+        .map(|field| {
+            let r#type = to_input_type(&visitor_ctx.types, field.r#type().clone());
+            let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type.to_string());
+
+            input.description = field.description().map(ToString::to_string);
+            input.rename = field.mapped_name().map(ToString::to_string);
+            input.default_value = DefaultDirective::default_value_of(field);
+            input.validators = get_length_validator(field).map(|validator| vec![validator]);
+
+            input
+        });
 
     let input_fields = implicit_fields.chain(explicit_fields);
     let description = format!("Input to create a {}", create_ctx.model_name().to_camel());
@@ -7,7 +7,10 @@ use engine_parser::types::{BaseType, ObjectType, Type};
 use super::generic;
 use crate::{
     registry::names::MetaNames,
-    rules::{mongodb_directive::CreateTypeContext, visitor::VisitorContext},
+    rules::{
+        mongodb_directive::{CreateTypeContext, LookupDirective},
+        visitor::VisitorContext,
+    },
 };
 
 const LOGICAL_OPERATIONS: &[(&str, &str, &str)] = &[
@@ -52,22 +55,26 @@ pub(crate) fn register_type_input<'a>(
     input_type_name: &str,
     extra_fields: impl Iterator<Item = MetaInputValue> + 'a,
 ) {
-    let explicit_fields = object.fields.iter().map(|field| {
-        let r#type = if field.r#type().base.is_list() {
-            let base = field.r#type().base.to_base_type_str();
-            generic::filter_type_name(&format!("{base}Array"))
-        } else {
-            let base = field.r#type().base.to_base_type_str();
-            generic::filter_type_name(base)
-        };
-
-        let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type);
-
-        input.description = field.description().map(ToString::to_string);
-        input.rename = field.mapped_name().map(ToString::to_string);
-
-        input
-    });
+    let explicit_fields = object
+        .fields
+        .iter()
+        .filter(|field| !LookupDirective::is_lookup(field))
+        .map(|field| {
+            let r#type = if field.r#type().base.is_list() {
+                let base = field.r#type().base.to_base_type_str();
+                generic::filter_type_name(&format!("{base}Array"))
+            } else {
+                let base = field.r#type().base.to_base_type_str();
+                generic::filter_type_name(base)
+            };
+
+            let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type);
+
+            input.description = field.description().map(ToString::to_string);
+            input.rename = field.mapped_name().map(ToString::to_string);
+
+            input
+        });
 
     let input_fields = extra_fields.chain(explicit_fields);
     let input_type = InputObjectType::new(input_type_name.to_string(), input_fields);
@@ -93,21 +100,25 @@ pub(crate) fn register_orderby_input<'a>(
         input
     });
 
-    let input_fields = object.fields.iter().map(|field| {
-        let registry = visitor_ctx.registry.borrow();
-        let composite = registry.types.get(field.ty.base.to_base_type_str());
-
-        let type_name = match composite {
-            Some(composite) if composite.is_object() => {
-                MetaNames::pagination_orderby_input_by_str(composite.name()).to_string()
-            }
-            _ => direction_type.to_string(),
-        };
-
-        let mut input = MetaInputValue::new(field.node.name.node.to_string(), type_name);
-        input.rename = field.mapped_name().map(ToString::to_string);
-        input
-    });
+    let input_fields = object
+        .fields
+        .iter()
+        .filter(|field| !LookupDirective::is_lookup(field))
+        .map(|field| {
+            let registry = visitor_ctx.registry.borrow();
+            let composite = registry.types.get(field.ty.base.to_base_type_str());
+
+            let type_name = match composite {
+                Some(composite) if composite.is_object() => {
+                    MetaNames::pagination_orderby_input_by_str(composite.name()).to_string()
+                }
+                _ => direction_type.to_string(),
+            };
+
+            let mut input = MetaInputValue::new(field.node.name.node.to_string(), type_name);
+            input.rename = field.mapped_name().map(ToString::to_string);
+            input
+        });
 
     let fields = extra_fields.chain(input_fields);
     let input_object = InputObjectType::new(input_type_name.to_string(), fields).with_oneof(true);
@@ -1,4 +1,4 @@
-use engine::registry::{EnumType, InputObjectType, MetaEnumValue, MetaInputValue};
+use engine::registry::{scalars::STRING_SCALARS, EnumType, InputObjectType, MetaEnumValue, MetaInputValue};
 
 use crate::rules::{
     mongodb_directive::{DATE_TIME_SCALARS, MONGODB_SCALARS, NUMERIC_SCALARS},
@@ -82,6 +82,22 @@ pub(crate) fn register_singular_type(visitor_ctx: &mut VisitorContext<'_>, scala
         ("lte", "$lte", "The value is less than, or equal to the one given"),
     ];
 
+    static PATTERN_FILTERS: &[(&str, &str, &str)] = &[
+        (
+            "like",
+            "$like",
+            "The value matches the given pattern, where `%` matches any sequence of characters and `_` any single character",
+        ),
+        (
+            "ilike",
+            "$ilike",
+            "The value matches the given pattern, case-insensitively",
+        ),
+        ("startsWith", "$startsWith", "The value starts with the given string"),
+        ("endsWith", "$endsWith", "The value ends with the given string"),
+        ("regex", "$regex", "The value matches the given regular expression"),
+    ];
+
     let type_name = filter_type_name(scalar);
     let mut fields = Vec::new();
 
@@ -93,6 +109,16 @@ pub(crate) fn register_singular_type(visitor_ctx: &mut VisitorContext<'_>, scala
         fields.push(input);
     }
 
+    if STRING_SCALARS.contains(&scalar) {
+        for (filter, mapped_name, description) in PATTERN_FILTERS {
+            let mut input = MetaInputValue::new(*filter, "String");
+            input.description = Some(String::from(*description));
+            input.rename = Some((*mapped_name).to_string());
+
+            fields.push(input);
+        }
+    }
+
     fields.push({
         let mut input = MetaInputValue::new("not", type_name.clone());
         input.description = Some(String::from("The value does not match the filters."));
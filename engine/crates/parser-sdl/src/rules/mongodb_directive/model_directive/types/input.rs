@@ -5,7 +5,10 @@ use engine_parser::types::{BaseType, ObjectType, Type, TypeDefinition};
 use super::generic::{self, filter_type_name, MONGO_POP_POSITION};
 use crate::{
     registry::names::MetaNames,
-    rules::{mongodb_directive::CreateTypeContext, visitor::VisitorContext},
+    rules::{
+        mongodb_directive::{CreateTypeContext, LookupDirective},
+        visitor::VisitorContext,
+    },
 };
 
 pub(crate) fn register_input(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTypeContext<'_>) -> String {
@@ -19,29 +22,33 @@ pub(crate) fn register_type_input(
 ) -> String {
     let input_type_name = MetaNames::update_input(r#type);
 
-    let input_fields = object.fields.iter().map(|field| {
-        let base = field.r#type().base.to_base_type_str();
-        let is_list = field.r#type().base.is_list();
-        let is_optional = field.r#type().nullable;
+    let input_fields = object
+        .fields
+        .iter()
+        .filter(|field| !LookupDirective::is_lookup(field))
+        .map(|field| {
+            let base = field.r#type().base.to_base_type_str();
+            let is_list = field.r#type().base.is_list();
+            let is_optional = field.r#type().nullable;
+
+            let composite_type = visitor_ctx.types.get(base).filter(|r#type| r#type.is_composite());
+
+            let r#type = match composite_type {
+                Some(_) if is_list => {
+                    register_list_input(visitor_ctx, field.r#type(), field.name(), &input_type_name, true)
+                }
+                Some(r#type) => MetaNames::update_input(&r#type.node),
+                _ if is_list => register_list_input(visitor_ctx, field.r#type(), field.name(), &input_type_name, false),
+                _ if is_optional => generic::optional_update_type_name(base),
+                _ => generic::required_update_type_name(base),
+            };
+
+            let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type);
+            input.description = field.description().map(ToString::to_string);
+            input.rename = field.mapped_name().map(ToString::to_string);
 
-        let composite_type = visitor_ctx.types.get(base).filter(|r#type| r#type.is_composite());
-
-        let r#type = match composite_type {
-            Some(_) if is_list => {
-                register_list_input(visitor_ctx, field.r#type(), field.name(), &input_type_name, true)
-            }
-            Some(r#type) => MetaNames::update_input(&r#type.node),
-            _ if is_list => register_list_input(visitor_ctx, field.r#type(), field.name(), &input_type_name, false),
-            _ if is_optional => generic::optional_update_type_name(base),
-            _ => generic::required_update_type_name(base),
-        };
-
-        let mut input = MetaInputValue::new(field.node.name.node.to_string(), r#type);
-        input.description = field.description().map(ToString::to_string);
-        input.rename = field.mapped_name().map(ToString::to_string);
-
-        input
-    });
+            input
+        });
 
     let input_type = InputObjectType::new(input_type_name.to_string(), input_fields);
 
@@ -1,7 +1,10 @@
-use engine::registry::ConnectorHeaders;
+use std::time::Duration;
+
+use engine::registry::{ConnectorFetchPolicy, ConnectorHeaderValue, ConnectorHeaders};
 use url::Url;
 
 use super::{
+    connector_circuit_breaker::CircuitBreaker,
     connector_headers::{Header, IntrospectionHeader},
     connector_transforms::Transforms,
     directive::Directive,
@@ -23,14 +26,89 @@ pub struct OpenApiDirective {
     #[serde(default)]
     introspection_headers: Vec<IntrospectionHeader>,
     #[serde(default)]
+    security: Vec<OpenApiSecurity>,
+    /// How long to wait for each attempt at a request, in milliseconds
+    #[serde(default)]
+    timeout: Option<u64>,
+    /// How many times to retry failed requests
+    #[serde(default)]
+    retries: u32,
+    #[serde(default)]
+    circuit_breaker: Option<CircuitBreaker>,
+    #[serde(default)]
     pub transforms: OpenApiTransforms,
 }
 
+/// Credentials for one of the `securitySchemes` in the spec of the API
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(try_from = "OpenApiSecurityDeserialize")]
+pub struct OpenApiSecurity {
+    pub scheme: String,
+    pub credentials: OpenApiCredentials,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpenApiCredentials {
+    /// An API key, `username:password` pair for basic auth or a token, either
+    /// static or forwarded from the incoming request
+    Value(ConnectorHeaderValue),
+    /// A client to fetch tokens for with the OAuth2 client credentials flow
+    ClientCredentials { client_id: String, client_secret: String },
+}
+
+impl TryFrom<OpenApiSecurityDeserialize> for OpenApiSecurity {
+    type Error = &'static str;
+
+    fn try_from(security: OpenApiSecurityDeserialize) -> Result<Self, Self::Error> {
+        let credentials = match (
+            security.value,
+            security.forward,
+            security.client_id,
+            security.client_secret,
+        ) {
+            (Some(value), None, None, None) => OpenApiCredentials::Value(ConnectorHeaderValue::Static(value)),
+            (None, Some(header_name), None, None) => {
+                OpenApiCredentials::Value(ConnectorHeaderValue::Forward(header_name))
+            }
+            (None, None, Some(client_id), Some(client_secret)) => OpenApiCredentials::ClientCredentials {
+                client_id,
+                client_secret,
+            },
+            (None, None, Some(_), None) | (None, None, None, Some(_)) => {
+                return Err("security credentials need both of clientId and clientSecret")
+            }
+            _ => return Err("security credentials must have exactly one of value, forward or clientId & clientSecret"),
+        };
+
+        Ok(OpenApiSecurity {
+            scheme: security.scheme,
+            credentials,
+        })
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenApiSecurityDeserialize {
+    scheme: String,
+
+    /// A hardcoded credential
+    value: Option<String>,
+
+    /// We should forward the credential from the named header in the incoming request.
+    forward: Option<String>,
+
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
 #[derive(Debug, Default, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenApiTransforms {
     #[serde(default)]
     pub query_naming: OpenApiQueryNamingStrategy,
+    #[serde(default)]
+    pub pagination: Vec<OpenApiPagination>,
     #[serde(default, flatten)]
     pub transforms: Option<Transforms>,
 }
@@ -43,6 +121,33 @@ pub enum OpenApiQueryNamingStrategy {
     SchemaName,
 }
 
+/// How a list operation is paginated, for exposing it as a connection
+#[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenApiPagination {
+    /// The operation this applies to, or every list operation if not set
+    pub operation_id: Option<String>,
+    pub style: OpenApiPaginationStyle,
+    /// The query parameter for the offset, page number or cursor
+    pub parameter: Option<String>,
+    /// The query parameter for the page size
+    pub limit_parameter: Option<String>,
+    pub max_page_size: Option<usize>,
+    /// The field of the response with the cursor of the next page, dot separated if nested
+    pub next_cursor_field: Option<String>,
+    /// The field of the response with the items, if the response isn't a list
+    pub items_field: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OpenApiPaginationStyle {
+    OffsetLimit,
+    PageNumber,
+    Cursor,
+    LinkHeader,
+}
+
 impl OpenApiDirective {
     pub fn headers(&self) -> ConnectorHeaders {
         ConnectorHeaders::new(
@@ -52,6 +157,18 @@ impl OpenApiDirective {
         )
     }
 
+    pub fn security(&self) -> Vec<OpenApiSecurity> {
+        self.security.clone()
+    }
+
+    pub fn fetch_policy(&self) -> ConnectorFetchPolicy {
+        ConnectorFetchPolicy {
+            timeout: self.timeout.map(Duration::from_millis),
+            retries: self.retries,
+            circuit_breaker: self.circuit_breaker.as_ref().map(CircuitBreaker::policy),
+        }
+    }
+
     pub fn introspection_headers(&self) -> Vec<(String, String)> {
         self.introspection_headers
             .iter()
@@ -72,13 +189,41 @@ impl Directive for OpenApiDirective {
           namespace: Boolean!
           "The URL of the API"
           url: Url!,
-          "The URL of this APIs schema"
+          "The URL of this APIs schema, or a path to it relative to the grafbase directory"
           schema: String!
           headers: [OpenApiHeader!]
           introspectionHeaders: [OpenApiHeaderIntrospectionHeader!]!
+          "Credentials for the security schemes of the API"
+          security: [OpenApiSecurity!]
+          "How long to wait for each attempt at a request to the API, in milliseconds"
+          timeout: Int
+          "How many times to retry requests that fail with a server error, time out or are rate limited"
+          retries: Int = 0
+          "Stops sending requests to the API for a while after it keeps failing"
+          circuitBreaker: OpenApiCircuitBreaker
           transforms: OpenApiTransforms
         ) on SCHEMA
 
+        input OpenApiCircuitBreaker {
+            "How many requests in a row have to fail before the breaker opens"
+            failureThreshold: Int!
+            "How long the breaker stays open before letting a request through to probe the API, in milliseconds"
+            resetTimeout: Int = 30000
+        }
+
+        input OpenApiSecurity {
+            "The name of the scheme in the securitySchemes of the OpenAPI spec"
+            scheme: String!
+            "An API key or token, or `username:password` for basic auth"
+            value: String
+            "Forwards the credential from the named header of the incoming request"
+            forward: String
+            "The client ID for the OAuth2 client credentials flow"
+            clientId: String
+            "The client secret for the OAuth2 client credentials flow"
+            clientSecret: String
+        }
+
         input OpenApiHeader {
             name: String!
             value: String
@@ -93,6 +238,35 @@ impl Directive for OpenApiDirective {
         input OpenApiTransforms {
           "How we determine the field names of the generated query type"
           queryNaming: QueryNamingStrategy = SCHEMA_NAME
+          "Exposes list queries as connections, following the pages of the API as needed"
+          pagination: [OpenApiPagination!]
+        }
+
+        input OpenApiPagination {
+          "The operationId of the query this applies to. If not set it applies to every list query"
+          operationId: String
+          style: OpenApiPaginationStyle!
+          "The query parameter for the offset, page number or cursor. Defaults to offset, page or cursor"
+          parameter: String
+          "The query parameter for the page size. Defaults to limit, or per_page for PAGE_NUMBER"
+          limitParameter: String
+          "The largest page size the API accepts"
+          maxPageSize: Int
+          "The field of the response with the cursor of the next page, dot separated if nested. Defaults to next_cursor"
+          nextCursorField: String
+          "The field of the response with the items, if the response is an object"
+          itemsField: String
+        }
+
+        enum OpenApiPaginationStyle {
+            "The offset of the first item and the page size are sent as query parameters"
+            OFFSET_LIMIT
+            "The page number, starting at 1, and the page size are sent as query parameters"
+            PAGE_NUMBER
+            "The response contains the cursor of the next page, which is sent as a query parameter"
+            CURSOR
+            "The URL of the next page is in a Link header of the response"
+            LINK_HEADER
         }
 
         enum QueryNamingStrategy {
@@ -139,6 +313,14 @@ impl OpenApiDirective {
     fn validate(self) -> Result<Self, String> {
         validate_connector_name(&self.name)?;
 
+        if self.timeout == Some(0) {
+            return Err("the timeout must be greater than zero".to_string());
+        }
+
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.validate()?;
+        }
+
         Ok(self)
     }
 }
@@ -149,6 +331,9 @@ fn default_to_true() -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use engine::registry::{CircuitBreakerPolicy, ConnectorFetchPolicy};
     use rstest::rstest;
 
     use super::OpenApiQueryNamingStrategy;
@@ -204,8 +389,13 @@ mod tests {
                     },
                 ],
                 introspection_headers: [],
+                security: [],
+                timeout: None,
+                retries: 0,
+                circuit_breaker: None,
                 transforms: OpenApiTransforms {
                     query_naming: SchemaName,
+                    pagination: [],
                     transforms: None,
                 },
             },
@@ -283,6 +473,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parsing_security() {
+        let variables = maplit::hashmap! {
+                "CLIENT_SECRET".to_string() => "i_am_a_secret".to_string()
+        };
+        let connector_parsers = MockConnectorParsers::default();
+        let schema = r#"
+            extend schema
+              @openapi(
+                name: "Petstore",
+                schema: "https://petstore3.swagger.io/api/v3/openapi.json",
+                security: [
+                  { scheme: "api_key", forward: "x-api-key" }
+                  { scheme: "petstore_auth", clientId: "petstore", clientSecret: "{{env.CLIENT_SECRET}}" }
+                ],
+              )
+            "#;
+        futures::executor::block_on(crate::parse(schema, &variables, false, &connector_parsers)).unwrap();
+
+        insta::assert_debug_snapshot!(connector_parsers.openapi_directives.lock().unwrap()[0].security(), @r###"
+        [
+            OpenApiSecurity {
+                scheme: "api_key",
+                credentials: Value(
+                    Forward(
+                        "x-api-key",
+                    ),
+                ),
+            },
+            OpenApiSecurity {
+                scheme: "petstore_auth",
+                credentials: ClientCredentials {
+                    client_id: "petstore",
+                    client_secret: "i_am_a_secret",
+                },
+            },
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_parsing_fetch_policy() {
+        let connector_parsers = MockConnectorParsers::default();
+        let schema = r#"
+            extend schema
+              @openapi(
+                name: "Petstore",
+                schema: "https://petstore3.swagger.io/api/v3/openapi.json",
+                timeout: 1500,
+                retries: 2,
+                circuitBreaker: { failureThreshold: 5 },
+              )
+            "#;
+        futures::executor::block_on(crate::parse(schema, &HashMap::new(), false, &connector_parsers)).unwrap();
+
+        assert_eq!(
+            connector_parsers.openapi_directives.lock().unwrap()[0].fetch_policy(),
+            ConnectorFetchPolicy {
+                timeout: Some(Duration::from_millis(1500)),
+                retries: 2,
+                circuit_breaker: Some(CircuitBreakerPolicy {
+                    failure_threshold: 5,
+                    reset_timeout: Duration::from_secs(30),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_circuit_breaker_without_threshold() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @openapi(
+                name: "Petstore",
+                schema: "https://petstore3.swagger.io/api/v3/openapi.json",
+                circuitBreaker: { failureThreshold: 0 },
+              )
+            "#,
+            "the failureThreshold of a circuit breaker must be at least 1"
+        );
+    }
+
+    #[test]
+    fn test_security_with_value_and_forward() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @openapi(
+                name: "Petstore",
+                schema: "https://petstore3.swagger.io/api/v3/openapi.json",
+                security: [{ scheme: "api_key", value: "key", forward: "x-api-key" }],
+              )
+            "#,
+            "[6:27] security credentials must have exactly one of value, forward or clientId & clientSecret"
+        );
+    }
+
     #[test]
     fn empty_name() {
         assert_validation_error!(
@@ -361,4 +649,19 @@ mod tests {
             "Name \"Test\" is not unique. A connector must have a unique name."
         );
     }
+
+    #[test]
+    fn test_zero_timeout() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @openapi(
+                name: "Petstore",
+                schema: "https://petstore3.swagger.io/api/v3/openapi.json",
+                timeout: 0,
+              )
+            "#,
+            "the timeout must be greater than zero"
+        );
+    }
 }
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use engine::registry::OperationLimits;
+use engine_parser::{types::SchemaDefinition, Positioned};
+
+use crate::{
+    directive_de::parse_directive,
+    rules::{
+        directive::Directive,
+        visitor::{Visitor, VisitorContext},
+    },
+};
+
+const OPERATION_LIMITS_DIRECTIVE_NAME: &str = "operationLimits";
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OperationLimitsDirective {
+    depth: Option<usize>,
+    complexity: Option<usize>,
+    aliases: Option<usize>,
+    root_fields: Option<usize>,
+    tokens: Option<usize>,
+    default_list_size: Option<usize>,
+}
+
+impl Directive for OperationLimitsDirective {
+    fn definition() -> String {
+        r#"
+        directive @operationLimits(
+          "How deeply fields can be nested"
+          depth: Int
+          "The complexity of operations, each field counting for its @complexity weight or 1, plus the complexity of its children times the size of its list"
+          complexity: Int
+          "How many fields can have an alias"
+          aliases: Int
+          "How many fields can be selected on the root type"
+          rootFields: Int
+          "How many tokens the query document can have"
+          tokens: Int
+          "The size assumed for lists in the complexity when the query doesn't give one with first, last or limit"
+          defaultListSize: Int
+        ) on SCHEMA
+        "#
+        .to_string()
+    }
+}
+
+pub struct OperationLimitsVisitor;
+
+impl<'a> Visitor<'a> for OperationLimitsVisitor {
+    fn enter_schema(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a Positioned<SchemaDefinition>) {
+        let Some(directive) = doc
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == OPERATION_LIMITS_DIRECTIVE_NAME)
+        else {
+            return;
+        };
+
+        match parse_directive::<OperationLimitsDirective>(directive, &HashMap::default()) {
+            Ok(limits) => {
+                ctx.registry.get_mut().operation_limits = OperationLimits {
+                    depth: limits.depth,
+                    complexity: limits.complexity,
+                    aliases: limits.aliases,
+                    root_fields: limits.root_fields,
+                    tokens: limits.tokens,
+                    default_list_size: limits.default_list_size,
+                };
+            }
+            Err(error) => ctx.append_errors(vec![error]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine::registry::OperationLimits;
+    use engine_parser::parse_schema;
+
+    use super::OperationLimitsVisitor;
+    use crate::rules::visitor::{visit, VisitorContext};
+
+    #[test]
+    fn parses_limits() {
+        let schema = parse_schema(
+            r"
+            extend schema @operationLimits(depth: 10, complexity: 1000, rootFields: 5, tokens: 2000, defaultListSize: 10)
+            ",
+        )
+        .unwrap();
+
+        let mut ctx = VisitorContext::new_for_tests(&schema);
+        visit(&mut OperationLimitsVisitor, &mut ctx, &schema);
+        assert!(ctx.errors.is_empty(), "{:?}", ctx.errors);
+
+        assert_eq!(
+            ctx.registry.borrow().operation_limits,
+            OperationLimits {
+                depth: Some(10),
+                complexity: Some(1000),
+                aliases: None,
+                root_fields: Some(5),
+                tokens: Some(2000),
+                default_list_size: Some(10),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_negative_limits() {
+        let schema = parse_schema("extend schema @operationLimits(depth: -1)").unwrap();
+
+        let mut ctx = VisitorContext::new_for_tests(&schema);
+        visit(&mut OperationLimitsVisitor, &mut ctx, &schema);
+        assert_eq!(ctx.errors.len(), 1, "{:?}", ctx.errors);
+    }
+}
@@ -0,0 +1,323 @@
+use std::{collections::HashMap, time::Duration};
+
+use engine::registry::{RateLimitAlgorithm, RateLimitKey, RateLimitQuota};
+use engine_parser::{
+    types::{ConstDirective, FieldDefinition, SchemaDefinition, TypeDefinition},
+    Positioned,
+};
+
+use crate::{
+    directive_de::parse_directive,
+    rules::{
+        directive::Directive,
+        visitor::{Visitor, VisitorContext, MUTATION_TYPE, QUERY_TYPE},
+    },
+};
+
+const RATE_LIMIT_DIRECTIVE_NAME: &str = "rateLimit";
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimitDirective {
+    #[serde(default)]
+    key: Option<RateLimitKeyKind>,
+    #[serde(default)]
+    key_name: Option<String>,
+    #[serde(default)]
+    trust_proxy_headers: Option<bool>,
+    #[serde(default)]
+    algorithm: Option<RateLimitAlgorithmKind>,
+    limit: u32,
+    duration: u64,
+    #[serde(default)]
+    operations: Option<Vec<OperationRateLimit>>,
+    #[serde(default)]
+    cost: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum RateLimitKeyKind {
+    Ip,
+    ApiKey,
+    JwtClaim,
+    Header,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum RateLimitAlgorithmKind {
+    TokenBucket,
+    SlidingWindow,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OperationRateLimit {
+    name: String,
+    limit: u32,
+    duration: u64,
+}
+
+impl Directive for RateLimitDirective {
+    fn definition() -> String {
+        r#"
+        directive @rateLimit(
+          "What the requests are counted by. Defaults to the IP address of the client, only on the schema"
+          key: RateLimitKey
+          "The name of the JWT claim or header the requests are counted by"
+          keyName: String
+          "Whether the IP address of clients is read from the X-Forwarded-For and similar headers set by a proxy, only on the schema"
+          trustProxyHeaders: Boolean
+          "How the requests are counted. Defaults to a token bucket, only on the schema"
+          algorithm: RateLimitAlgorithm
+          "The number of requests allowed within the duration"
+          limit: Int!
+          "The duration in seconds"
+          duration: Int!
+          "Overrides of the limit for specific operations, only on the schema"
+          operations: [RateLimitOperation!]
+          "Whether requests count for the complexity of their operation instead of 1, operations costing more than the limit being rejected. Only on the schema"
+          cost: Boolean
+        ) on SCHEMA | FIELD_DEFINITION
+
+        enum RateLimitKey {
+          IP
+          API_KEY
+          JWT_CLAIM
+          HEADER
+        }
+
+        enum RateLimitAlgorithm {
+          TOKEN_BUCKET
+          SLIDING_WINDOW
+        }
+
+        input RateLimitOperation {
+          "The name of the operation"
+          name: String!
+          limit: Int!
+          "The duration in seconds"
+          duration: Int!
+        }
+        "#
+        .to_string()
+    }
+}
+
+impl RateLimitDirective {
+    fn from_directives<'a>(
+        directives: &'a [Positioned<ConstDirective>],
+        ctx: &mut VisitorContext<'_>,
+    ) -> Option<(RateLimitDirective, &'a Positioned<ConstDirective>)> {
+        let directive = directives
+            .iter()
+            .find(|directive| directive.node.name.node == RATE_LIMIT_DIRECTIVE_NAME)?;
+
+        match parse_directive::<Self>(directive, &HashMap::new()) {
+            Ok(parsed) if parsed.duration == 0 => {
+                ctx.report_error(vec![directive.pos], "@rateLimit error: the duration must be positive");
+                None
+            }
+            Ok(parsed) => Some((parsed, directive)),
+            Err(error) => {
+                ctx.append_errors(vec![error]);
+                None
+            }
+        }
+    }
+
+    fn quota(&self) -> RateLimitQuota {
+        RateLimitQuota {
+            limit: self.limit,
+            duration: Duration::from_secs(self.duration),
+        }
+    }
+
+    fn key(&self) -> Result<RateLimitKey, &'static str> {
+        match (&self.key, &self.key_name) {
+            (None | Some(RateLimitKeyKind::Ip), None) => Ok(RateLimitKey::Ip),
+            (Some(RateLimitKeyKind::ApiKey), None) => Ok(RateLimitKey::ApiKey),
+            (Some(RateLimitKeyKind::JwtClaim), Some(name)) => Ok(RateLimitKey::JwtClaim(name.clone())),
+            (Some(RateLimitKeyKind::Header), Some(name)) => Ok(RateLimitKey::Header(name.clone())),
+            (Some(RateLimitKeyKind::JwtClaim | RateLimitKeyKind::Header), None) => {
+                Err("@rateLimit error: the keyName argument is required for JWT_CLAIM and HEADER keys")
+            }
+            (_, Some(_)) => Err("@rateLimit error: the keyName argument is only used by JWT_CLAIM and HEADER keys"),
+        }
+    }
+}
+
+pub struct RateLimitVisitor;
+
+impl<'a> Visitor<'a> for RateLimitVisitor {
+    fn enter_schema(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a Positioned<SchemaDefinition>) {
+        let Some((directive, positioned)) = RateLimitDirective::from_directives(&doc.node.directives, ctx) else {
+            return;
+        };
+
+        let key = match directive.key() {
+            Ok(key) => key,
+            Err(error) => return ctx.report_error(vec![positioned.pos], error),
+        };
+
+        let config = &mut ctx.registry.get_mut().rate_limiting;
+        config.key = key;
+        config.trust_proxy_headers = directive.trust_proxy_headers.unwrap_or_default();
+        config.algorithm = match directive.algorithm {
+            None | Some(RateLimitAlgorithmKind::TokenBucket) => RateLimitAlgorithm::TokenBucket,
+            Some(RateLimitAlgorithmKind::SlidingWindow) => RateLimitAlgorithm::SlidingWindow,
+        };
+        config.global = Some(directive.quota());
+        config.cost = directive.cost.unwrap_or_default();
+        config
+            .operations
+            .extend(directive.operations.into_iter().flatten().map(|operation| {
+                let quota = RateLimitQuota {
+                    limit: operation.limit,
+                    duration: Duration::from_secs(operation.duration),
+                };
+                (operation.name, quota)
+            }));
+    }
+
+    fn enter_field(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        field: &'a Positioned<FieldDefinition>,
+        parent_type: &'a Positioned<TypeDefinition>,
+    ) {
+        let Some((directive, positioned)) = RateLimitDirective::from_directives(&field.node.directives, ctx) else {
+            return;
+        };
+
+        let type_name = parent_type.node.name.node.as_str();
+        if type_name != QUERY_TYPE && type_name != MUTATION_TYPE {
+            return ctx.report_error(
+                vec![positioned.pos],
+                "@rateLimit error: only the fields of the Query and Mutation types can have rate limits",
+            );
+        }
+
+        if directive.key.is_some()
+            || directive.key_name.is_some()
+            || directive.trust_proxy_headers.is_some()
+            || directive.algorithm.is_some()
+            || directive.operations.is_some()
+            || directive.cost.is_some()
+        {
+            return ctx.report_error(
+                vec![positioned.pos],
+                "@rateLimit error: fields can only override the limit and duration, the other arguments are only allowed on the schema",
+            );
+        }
+
+        let field_name = field.node.name.node.as_str();
+        ctx.registry
+            .get_mut()
+            .rate_limiting
+            .root_fields
+            .insert(format!("{type_name}.{field_name}"), directive.quota());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use engine::registry::{RateLimitAlgorithm, RateLimitKey, RateLimitQuota};
+    use engine_parser::parse_schema;
+
+    use super::RateLimitVisitor;
+    use crate::rules::visitor::{visit, VisitorContext};
+
+    fn quota(limit: u32, seconds: u64) -> RateLimitQuota {
+        RateLimitQuota {
+            limit,
+            duration: Duration::from_secs(seconds),
+        }
+    }
+
+    #[test]
+    fn schema_and_field_limits() {
+        let schema = parse_schema(
+            r#"
+            extend schema @rateLimit(
+              key: JWT_CLAIM,
+              keyName: "sub",
+              trustProxyHeaders: true,
+              algorithm: SLIDING_WINDOW,
+              limit: 100,
+              duration: 60,
+              operations: [{ name: "Search", limit: 10, duration: 60 }]
+              cost: true
+            )
+
+            type Query {
+              hello: String @rateLimit(limit: 5, duration: 1)
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut ctx = VisitorContext::new_for_tests(&schema);
+        visit(&mut RateLimitVisitor, &mut ctx, &schema);
+        assert!(ctx.errors.is_empty(), "{:?}", ctx.errors);
+
+        let registry = ctx.registry.borrow();
+        let config = &registry.rate_limiting;
+        assert_eq!(config.key, RateLimitKey::JwtClaim("sub".to_string()));
+        assert!(config.trust_proxy_headers);
+        assert_eq!(config.algorithm, RateLimitAlgorithm::SlidingWindow);
+        assert_eq!(config.global, Some(quota(100, 60)));
+        assert!(config.cost);
+        assert_eq!(config.operations.get("Search"), Some(&quota(10, 60)));
+        assert_eq!(config.root_fields.get("Query.hello"), Some(&quota(5, 1)));
+    }
+
+    #[rstest::rstest]
+    #[case::missing_key_name(
+        r#"
+        extend schema @rateLimit(key: HEADER, limit: 1, duration: 1)
+    "#,
+        "@rateLimit error: the keyName argument is required for JWT_CLAIM and HEADER keys"
+    )]
+    #[case::unused_key_name(
+        r#"
+        extend schema @rateLimit(key: API_KEY, keyName: "sub", limit: 1, duration: 1)
+    "#,
+        "@rateLimit error: the keyName argument is only used by JWT_CLAIM and HEADER keys"
+    )]
+    #[case::zero_duration(
+        r#"
+        extend schema @rateLimit(limit: 1, duration: 0)
+    "#,
+        "@rateLimit error: the duration must be positive"
+    )]
+    #[case::non_root_field(
+        r#"
+        type User {
+          name: String @rateLimit(limit: 1, duration: 1)
+        }
+    "#,
+        "@rateLimit error: only the fields of the Query and Mutation types can have rate limits"
+    )]
+    #[case::key_on_field(r#"
+        type Mutation {
+          createUser: String @rateLimit(key: API_KEY, limit: 1, duration: 1)
+        }
+    "#, "@rateLimit error: fields can only override the limit and duration, the other arguments are only allowed on the schema")]
+    #[case::cost_on_field(r#"
+        type Query {
+          search: String @rateLimit(limit: 1, duration: 1, cost: true)
+        }
+    "#, "@rateLimit error: fields can only override the limit and duration, the other arguments are only allowed on the schema")]
+    fn errors(#[case] schema: &str, #[case] expected_message: &str) {
+        let schema = parse_schema(schema).unwrap();
+        let mut ctx = VisitorContext::new_for_tests(&schema);
+        visit(&mut RateLimitVisitor, &mut ctx, &schema);
+
+        let messages: Vec<_> = ctx.errors.iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(messages, [expected_message]);
+    }
+}
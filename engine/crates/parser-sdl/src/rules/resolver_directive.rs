@@ -9,20 +9,31 @@ use super::{
 
 pub const RESOLVER_DIRECTIVE: &str = "resolver";
 pub const NAME_ARGUMENT: &str = "name";
+pub const BATCH_ARGUMENT: &str = "batch";
 
 pub struct ResolverDirective;
 
 impl ResolverDirective {
     pub fn resolver_name(field: &FieldDefinition) -> Option<&str> {
+        Self::argument(field, NAME_ARGUMENT).and_then(|value| match value {
+            ConstValue::String(resolver_name) => Some(resolver_name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Whether the resolver of the field receives all the parents of a given depth in a single
+    /// invocation, rather than being invoked once per parent.
+    pub fn is_batched(field: &FieldDefinition) -> bool {
+        matches!(Self::argument(field, BATCH_ARGUMENT), Some(ConstValue::Boolean(true)))
+    }
+
+    fn argument<'a>(field: &'a FieldDefinition, name: &str) -> Option<&'a ConstValue> {
         field
             .directives
             .iter()
             .find(|directive| directive.node.name.node == RESOLVER_DIRECTIVE)
-            .and_then(|directive| directive.node.get_argument(NAME_ARGUMENT))
-            .and_then(|value| match &value.node {
-                ConstValue::String(resolver_name) => Some(resolver_name.as_str()),
-                _ => None,
-            })
+            .and_then(|directive| directive.node.get_argument(name))
+            .map(|value| &value.node)
     }
 }
 
@@ -39,7 +50,17 @@ impl<'a> Visitor<'a> for ResolverDirective {
             .iter()
             .find(|d| d.node.name.node == RESOLVER_DIRECTIVE)
         {
-            if let Ok(arguments) = super::directive::extract_arguments(ctx, directive, &[&[NAME_ARGUMENT]], None) {
+            // `batch` is optional, so directives without it are validated as they were before it existed.
+            let (allowed_argument_combinations, argument_help_string): (&[&[&str]], _) =
+                if directive.node.get_argument(BATCH_ARGUMENT).is_some() {
+                    (&[&[BATCH_ARGUMENT, NAME_ARGUMENT]], Some("`name`"))
+                } else {
+                    (&[&[NAME_ARGUMENT]], None)
+                };
+
+            if let Ok(arguments) =
+                super::directive::extract_arguments(ctx, directive, allowed_argument_combinations, argument_help_string)
+            {
                 if let ConstValue::String(resolver_name) = arguments.get(NAME_ARGUMENT).unwrap() {
                     // OK.
                     ctx.required_resolvers.insert(resolver_name.clone());
@@ -49,6 +70,13 @@ impl<'a> Visitor<'a> for ResolverDirective {
                         "The @{RESOLVER_DIRECTIVE} directive expects the `{name}` argument to be a string".to_string(),
                     );
                 }
+
+                if !matches!(arguments.get(BATCH_ARGUMENT), None | Some(ConstValue::Boolean(_))) {
+                    ctx.report_error(
+                        vec![directive.pos],
+                        format!("The @{RESOLVER_DIRECTIVE} directive expects the `{BATCH_ARGUMENT}` argument to be a boolean"),
+                    );
+                }
             }
         }
     }
@@ -57,7 +85,7 @@ impl<'a> Visitor<'a> for ResolverDirective {
 impl Directive for ResolverDirective {
     fn definition() -> String {
         r#"
-        directive @resolver(name: String) on FIELD_DEFINITION
+        directive @resolver(name: String, batch: Boolean) on FIELD_DEFINITION
         "#
         .to_string()
     }
@@ -88,6 +116,36 @@ mod tests {
     "#, &[
         "The @resolver directive takes a single `name` argument"
     ])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(path: "resolvers/balance", batch: true)
+        }
+    "#, &[
+        "Unexpected argument path, @resolver directive only supports the following arguments: `batch` and `name`"
+    ])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(batch: true)
+        }
+    "#, &[
+        "The @resolver directive expects at least one of the `name` arguments"
+    ])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(name: "resolvers/balance", batch: "yes")
+        }
+    "#, &[
+        "The @resolver directive expects the `batch` argument to be a boolean"
+    ])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(name: "resolvers/balance", batch: true)
+        }
+    "#, &[])]
     #[case(r#"
         type Customer @model {
             id: ID!
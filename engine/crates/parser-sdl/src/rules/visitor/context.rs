@@ -1,4 +1,4 @@
-use super::{warnings::Warnings, RuleError, TypeStackType, Warning, MUTATION_TYPE, QUERY_TYPE};
+use super::{warnings::Warnings, RuleError, TypeStackType, Warning, MUTATION_TYPE, QUERY_TYPE, SUBSCRIPTION_TYPE};
 use crate::{
     rules::federation::FederationVersion, GlobalCacheRules, GlobalCacheTarget, GraphqlDirective, MongoDBDirective,
     OpenApiDirective, ParseResult, PostgresDirective,
@@ -34,6 +34,7 @@ pub struct VisitorContext<'a> {
     pub(crate) type_stack: TypeStackType<'a>,
     pub(crate) queries: Vec<MetaField>,
     pub(crate) mutations: Vec<MetaField>,
+    pub(crate) subscriptions: Vec<MetaField>,
     /// Relations by name
     pub(crate) relations: IndexMap<String, MetaRelation>,
     pub schema_id_generator: SchemaIDGenerator,
@@ -131,6 +132,7 @@ impl<'a> VisitorContext<'a> {
             registry: RefCell::new(Schema::create_registry()),
             mutations: Default::default(),
             queries: Default::default(),
+            subscriptions: Default::default(),
             relations: Default::default(),
             schema_to_build: Default::default(),
             connector_id_generator: Default::default(),
@@ -161,6 +163,10 @@ impl<'a> VisitorContext<'a> {
             registry.mutation_type = Some(MUTATION_TYPE.to_string());
         }
 
+        if !self.subscriptions.is_empty() {
+            registry.subscription_type = Some(SUBSCRIPTION_TYPE.to_string());
+        }
+
         registry.create_type(
             |registry| {
                 let schema_type = __Schema::create_type_info(registry);
@@ -204,6 +210,14 @@ impl<'a> VisitorContext<'a> {
             );
         }
 
+        if !self.subscriptions.is_empty() {
+            registry.create_type(
+                |_| engine::registry::ObjectType::new(SUBSCRIPTION_TYPE.to_owned(), self.subscriptions).into(),
+                SUBSCRIPTION_TYPE,
+                SUBSCRIPTION_TYPE,
+            );
+        }
+
         registry.remove_unused_types();
 
         let mut required_udfs = self
@@ -5,6 +5,7 @@ use engine::{
         federation::FederationResolver,
         field_set::Selection,
         resolvers::{join::JoinResolver, Resolver},
+        type_kinds::SelectionSetTarget,
         MetaField, MetaFieldType,
     },
     Registry,
@@ -144,22 +145,58 @@ fn validate_join(
 ) -> Vec<RuleError> {
     let mut errors = vec![];
 
-    let root_query_type = registry.root_type(engine_parser::types::OperationType::Query);
-    let Some(destination_field) = root_query_type.field(&join.field_name) else {
+    let mut parent_type = registry.root_type(engine_parser::types::OperationType::Query);
+    for namespace in &join.namespaces {
+        let Some(namespace_field) = parent_type.field(namespace) else {
+            errors.push(RuleError::new(
+                vec![],
+                format!(
+                    "{coord} is trying to join with a field nested in {namespace}, which doesn't exist on the {} type",
+                    parent_type.name()
+                ),
+            ));
+            return errors;
+        };
+
+        // Namespaces are objects, a list would have no single field to descend into.
+        let namespace_type = match Type::new(namespace_field.ty.as_str()).map(|ty| ty.base) {
+            Some(BaseType::Named(_)) => registry
+                .lookup_expecting::<SelectionSetTarget<'_>>(&namespace_field.ty)
+                .ok(),
+            _ => None,
+        };
+        let Some(namespace_type) = namespace_type else {
+            errors.push(RuleError::new(
+                vec![],
+                format!("{coord} is trying to join with a field nested in {namespace}, but {namespace} doesn't return an object"),
+            ));
+            return errors;
+        };
+
+        parent_type = namespace_type;
+    }
+
+    let Some(destination_field) = parent_type.field(&join.field_name) else {
         errors.push(RuleError::new(
             vec![],
             format!(
                 "{coord} is trying to join with a field named {}, which doesn't exist on the {} type",
                 join.field_name,
-                root_query_type.name()
+                parent_type.name()
             ),
         ));
         return errors;
     };
 
+    // A batched join returns one of the items of the list returned by the destination field.
+    let destination_type = match &join.batch {
+        Some(_) => list_item_type(&destination_field.ty),
+        None => Some(destination_field.ty.clone()),
+    };
+
     // TODO: Make this a bit more forgiving.
     // If destination_field is non-null but expected_return is null that's fine...
-    if !types_are_compatible(&destination_field.ty, expected_return_type) {
+    if !destination_type.is_some_and(|destination_type| types_are_compatible(&destination_type, expected_return_type)) {
         errors.push(RuleError::new(
             vec![],
             format!(
@@ -181,9 +218,36 @@ fn validate_join(
         // we don't have type information by the time we get here...
     }
 
+    if join.batch.is_some() {
+        let batched_arguments = join
+            .arguments
+            .clone()
+            .into_iter()
+            .filter(|(_, value)| value.variables_used().next().is_some())
+            .count();
+
+        if batched_arguments != 1 {
+            errors.push(RuleError::new(
+                vec![],
+                format!(
+                    "{coord} is trying to batch its join with the field named {}, but exactly one of its arguments must refer to the fields of the parent",
+                    join.field_name
+                ),
+            ));
+        }
+    }
+
     errors
 }
 
+/// The type of the items of a list type, if it is one.
+fn list_item_type(ty: &MetaFieldType) -> Option<MetaFieldType> {
+    match Type::new(ty.as_str())?.base {
+        BaseType::List(item_type) => Some(item_type.to_string().into()),
+        BaseType::Named(_) => None,
+    }
+}
+
 fn types_are_compatible(actual_type: &MetaFieldType, expected_type: &MetaFieldType) -> bool {
     let Some(actual) = Type::new(actual_type.as_str()) else {
         return false;
@@ -4,6 +4,7 @@ mod foreign_key;
 mod foreign_key_column;
 mod ids;
 mod names;
+mod pool_settings;
 mod postgres_type;
 mod relations;
 mod table;
@@ -20,6 +21,7 @@ pub use ids::{
     BackRelationId, EnumId, EnumVariantId, ForeignKeyColumnId, ForeignKeyId, ForwardRelationId, RelationId, SchemaId,
     TableColumnId, TableId, UniqueConstraintColumnId, UniqueConstraintId,
 };
+pub use pool_settings::PoolSettings;
 pub use postgres_type::{ColumnType, DatabaseType, ScalarType};
 pub use r#enum::Enum;
 pub use table::Table;
@@ -56,6 +58,9 @@ use std::collections::HashMap;
 pub struct DatabaseDefinition {
     /// The database connection string.
     connection_string: String,
+    /// Set from the directive rather than introspected.
+    #[serde(default)]
+    pool_settings: PoolSettings,
     /// Ordered by name.
     schemas: Vec<String>,
     /// Ordered by schema id, then table name.
@@ -82,6 +87,7 @@ impl DatabaseDefinition {
     pub fn new(connection_string: &str) -> Self {
         Self {
             connection_string: connection_string.to_string(),
+            pool_settings: PoolSettings::default(),
             schemas: Vec::new(),
             tables: Vec::new(),
             table_columns: Vec::new(),
@@ -101,6 +107,15 @@ impl DatabaseDefinition {
         &self.connection_string
     }
 
+    /// The settings of the connection pool to the database.
+    pub fn pool_settings(&self) -> &PoolSettings {
+        &self.pool_settings
+    }
+
+    pub fn set_pool_settings(&mut self, pool_settings: PoolSettings) {
+        self.pool_settings = pool_settings;
+    }
+
     /// Iterates over all tables of the introspected database.
     pub fn tables(&self) -> impl ExactSizeIterator<Item = TableWalker<'_>> + '_ {
         (0..self.tables.len()).map(move |id| self.walk(TableId(id as u32)))
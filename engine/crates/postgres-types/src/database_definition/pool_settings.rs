@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// The connection pool settings of a `@postgres` directive. Unset values use the defaults of the
+/// runtime.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct PoolSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_connections: Option<usize>,
+    /// In seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_cache_capacity: Option<usize>,
+}
@@ -22,6 +22,18 @@ impl<'a> DatabaseType<'a> {
     pub fn is_jsonb(&self) -> bool {
         matches!(self, DatabaseType::Scalar(ScalarType::Jsonb | ScalarType::JsonbArray))
     }
+
+    pub fn is_range(&self) -> bool {
+        matches!(self, DatabaseType::Scalar(scalar) if scalar.is_range())
+    }
+
+    pub fn is_tsvector(&self) -> bool {
+        matches!(self, DatabaseType::Scalar(ScalarType::TsVector))
+    }
+
+    pub fn is_vector(&self) -> bool {
+        matches!(self, DatabaseType::Scalar(ScalarType::Vector))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -217,10 +229,48 @@ pub enum ScalarType {
     TstzmultiRangeArray,
     DatemultiRangeArray,
     Int8multiRangeArray,
+    /// PostGIS `geometry`. Extension types don't have a fixed oid, so these are detected by name.
+    Geometry,
+    /// PostGIS `geography`.
+    Geography,
+    /// pgvector `vector`.
+    Vector,
     Other(u32),
 }
 
 impl ScalarType {
+    /// Resolves a type defined by an extension from its name. Returns `Other` for types we
+    /// don't support.
+    pub fn from_extension(type_id: u32, type_name: &str) -> Self {
+        match type_name {
+            "geometry" => Self::Geometry,
+            "geography" => Self::Geography,
+            "vector" => Self::Vector,
+            _ => Self::Other(type_id),
+        }
+    }
+
+    /// True for range and multirange types, not including arrays of them.
+    pub fn is_range(self) -> bool {
+        use ScalarType::*;
+
+        matches!(
+            self,
+            Int4Range
+                | Int8Range
+                | NumRange
+                | TsRange
+                | TstzRange
+                | DateRange
+                | Int4multiRange
+                | Int8multiRange
+                | NummultiRange
+                | TsmultiRange
+                | TstzmultiRange
+                | DatemultiRange
+        )
+    }
+
     pub(crate) fn client_type(self) -> Option<&'static str> {
         use ScalarType::*;
 
@@ -260,6 +310,21 @@ impl ScalarType {
             TimestamptzArray => "[DateTime]",
             Uuid => "Uuid",
             UuidArray => "[Uuid]",
+            Int4Range | Int8Range | NumRange | TsRange | TstzRange | DateRange => "Range",
+            Int4RangeArray | Int8RangeArray | NumRangeArray | TsRangeArray | TstzRangeArray | DateRangeArray => {
+                "[Range]"
+            }
+            Int4multiRange | Int8multiRange | NummultiRange | TsmultiRange | TstzmultiRange | DatemultiRange => {
+                "MultiRange"
+            }
+            Int4multiRangeArray | Int8multiRangeArray | NummultiRangeArray | TsmultiRangeArray
+            | TstzmultiRangeArray | DatemultiRangeArray => "[MultiRange]",
+            Interval => "Interval",
+            IntervalArray => "[Interval]",
+            TsVector => "TsVector",
+            TsVectorArray => "[TsVector]",
+            Geometry | Geography => "Geometry",
+            Vector => "Vector",
             _ => return None,
         };
 
@@ -1,7 +1,11 @@
 mod ext;
+#[cfg(not(target_arch = "wasm32"))]
+mod pool;
 mod tcp;
 
 pub use ext::TransportExt;
+#[cfg(not(target_arch = "wasm32"))]
+pub use pool::{PoolConfig, PooledTcpTransport};
 pub use tcp::TcpTransport;
 
 use crate::database_definition::ScalarType;
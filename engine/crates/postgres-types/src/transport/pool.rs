@@ -0,0 +1,326 @@
+use std::{
+    collections::VecDeque,
+    ops::Deref,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use futures::{pin_mut, stream::BoxStream, StreamExt};
+use serde_json::Value;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+use super::{TcpTransport, Transport};
+use crate::{database_definition::PoolSettings, error::Error};
+
+/// Settings for [`PooledTcpTransport`].
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    min_connections: usize,
+    max_connections: usize,
+    idle_timeout: Option<Duration>,
+    acquire_timeout: Duration,
+    health_check_interval: Option<Duration>,
+    statement_cache_capacity: usize,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            min_connections: 0,
+            max_connections: 10,
+            idle_timeout: Some(Duration::from_secs(600)),
+            acquire_timeout: Duration::from_secs(30),
+            health_check_interval: Some(Duration::from_secs(30)),
+            statement_cache_capacity: 0,
+        }
+    }
+}
+
+impl PoolConfig {
+    /// The number of connections opened when the pool is created, and never closed for being idle.
+    pub fn min_connections(mut self, min_connections: usize) -> Self {
+        self.min_connections = min_connections;
+        self
+    }
+
+    /// The maximum number of connections open at the same time. Requests wait for a free
+    /// connection when all of them are in use.
+    pub fn max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections.max(1);
+        self
+    }
+
+    /// Connections idle for longer than this are closed. `None` or zero keeps them open forever.
+    pub fn idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.idle_timeout = idle_timeout.filter(|timeout| !timeout.is_zero());
+        self
+    }
+
+    /// How long a request waits for a free connection before failing with a timeout.
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// Idle connections not used for longer than this are pinged before being handed out.
+    /// `None` disables the check.
+    pub fn health_check_interval(mut self, health_check_interval: Option<Duration>) -> Self {
+        self.health_check_interval = health_check_interval;
+        self
+    }
+
+    /// The number of prepared statements cached per connection, see
+    /// [`TcpTransport::with_statement_cache`]. Zero, the default, disables the cache: prepared
+    /// statements don't work through poolers in transaction mode, such as pgbouncer.
+    pub fn statement_cache_capacity(mut self, statement_cache_capacity: usize) -> Self {
+        self.statement_cache_capacity = statement_cache_capacity;
+        self
+    }
+
+    /// Overrides these settings with the ones set in the `@postgres` directive.
+    pub fn with_settings(self, settings: &PoolSettings) -> Self {
+        let PoolSettings {
+            max_connections,
+            min_connections,
+            idle_timeout,
+            statement_cache_capacity,
+        } = *settings;
+
+        let mut config = self;
+
+        if let Some(max_connections) = max_connections {
+            config = config.max_connections(max_connections);
+        }
+
+        if let Some(min_connections) = min_connections {
+            config = config.min_connections(min_connections);
+        }
+
+        if let Some(idle_timeout) = idle_timeout {
+            config = config.idle_timeout(Some(Duration::from_secs(idle_timeout)));
+        }
+
+        if let Some(statement_cache_capacity) = statement_cache_capacity {
+            config = config.statement_cache_capacity(statement_cache_capacity);
+        }
+
+        config
+    }
+}
+
+/// A transport keeping a pool of TCP connections to the database. Cloning the transport is
+/// cheap, and all the clones share the same connections.
+#[derive(Clone)]
+pub struct PooledTcpTransport {
+    inner: Arc<Pool>,
+}
+
+impl PooledTcpTransport {
+    pub async fn new(connection_string: &str, config: PoolConfig) -> crate::Result<Self> {
+        let pool = Arc::new(Pool {
+            connection_string: connection_string.to_string(),
+            tls: TcpTransport::tls_connector(),
+            idle: Mutex::new(VecDeque::with_capacity(config.max_connections)),
+            permits: Arc::new(Semaphore::new(config.max_connections)),
+            config,
+        });
+
+        for _ in 0..pool.config.min_connections.min(pool.config.max_connections) {
+            let transport = pool.connect().await?;
+            pool.release(transport);
+        }
+
+        if let Some(idle_timeout) = pool.config.idle_timeout {
+            async_runtime::spawn(reap_idle_connections(Arc::downgrade(&pool), idle_timeout));
+        }
+
+        Ok(Self { inner: pool })
+    }
+
+    /// The number of idle connections currently kept in the pool.
+    pub fn idle_connections(&self) -> usize {
+        self.inner.idle.lock().expect("pool lock poisoned").len()
+    }
+}
+
+#[async_trait]
+impl Transport for PooledTcpTransport {
+    fn parameterized_query<'a>(&'a self, query: &'a str, params: Vec<Value>) -> BoxStream<'a, Result<Value, Error>> {
+        Box::pin(async_stream::try_stream! {
+            let connection = self.inner.acquire().await?;
+            let rows = connection.parameterized_query(query, params);
+
+            pin_mut!(rows);
+
+            while let Some(row) = rows.next().await {
+                yield row?;
+            }
+        })
+    }
+
+    async fn parameterized_execute(&self, query: &str, params: Vec<Value>) -> crate::Result<i64> {
+        let connection = self.inner.acquire().await?;
+        connection.parameterized_execute(query, params).await
+    }
+
+    fn connection_string(&self) -> &str {
+        &self.inner.connection_string
+    }
+}
+
+struct Pool {
+    connection_string: String,
+    config: PoolConfig,
+    tls: MakeRustlsConnect,
+    idle: Mutex<VecDeque<IdleConnection>>,
+    permits: Arc<Semaphore>,
+}
+
+struct IdleConnection {
+    transport: TcpTransport,
+    idle_since: Instant,
+}
+
+impl Pool {
+    async fn acquire(self: &Arc<Self>) -> crate::Result<PooledConnection> {
+        let permit = tokio::time::timeout(self.config.acquire_timeout, self.permits.clone().acquire_owned())
+            .await
+            .map_err(|_| Error::Timeout)?
+            .map_err(|error| Error::Internal(error.to_string()))?;
+
+        while let Some(connection) = self.pop_idle() {
+            if connection.transport.is_closed() || self.is_expired(&connection) {
+                continue;
+            }
+
+            let needs_check = self
+                .config
+                .health_check_interval
+                .is_some_and(|interval| connection.idle_since.elapsed() >= interval);
+
+            if needs_check && connection.transport.ping().await.is_err() {
+                continue;
+            }
+
+            return Ok(PooledConnection::new(connection.transport, self.clone(), permit));
+        }
+
+        let transport = self.connect().await?;
+
+        Ok(PooledConnection::new(transport, self.clone(), permit))
+    }
+
+    async fn connect(&self) -> crate::Result<TcpTransport> {
+        let transport = TcpTransport::connect(&self.connection_string, self.tls.clone()).await?;
+
+        Ok(transport.with_statement_cache(self.config.statement_cache_capacity))
+    }
+
+    fn pop_idle(&self) -> Option<IdleConnection> {
+        // the most recently used connection is the most likely to still be alive.
+        self.idle.lock().expect("pool lock poisoned").pop_back()
+    }
+
+    fn release(&self, transport: TcpTransport) {
+        if transport.is_closed() {
+            return;
+        }
+
+        self.idle.lock().expect("pool lock poisoned").push_back(IdleConnection {
+            transport,
+            idle_since: Instant::now(),
+        });
+    }
+
+    fn is_expired(&self, connection: &IdleConnection) -> bool {
+        self.config
+            .idle_timeout
+            .is_some_and(|timeout| connection.idle_since.elapsed() >= timeout)
+    }
+
+    /// Closes the connections idle for too long, keeping at least the minimum amount open.
+    fn reap(&self) {
+        let mut idle = self.idle.lock().expect("pool lock poisoned");
+
+        // connections are pushed to the back, so the oldest ones are in the front.
+        while idle.len() > self.config.min_connections && idle.front().is_some_and(|c| self.is_expired(c)) {
+            idle.pop_front();
+        }
+    }
+}
+
+async fn reap_idle_connections(pool: Weak<Pool>, idle_timeout: Duration) {
+    let mut interval = tokio::time::interval(idle_timeout);
+
+    loop {
+        interval.tick().await;
+
+        // the pool was dropped, stop reaping.
+        let Some(pool) = pool.upgrade() else { break };
+
+        pool.reap();
+    }
+}
+
+/// A connection borrowed from the pool. Returned to the pool when dropped.
+struct PooledConnection {
+    transport: Option<TcpTransport>,
+    pool: Arc<Pool>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledConnection {
+    fn new(transport: TcpTransport, pool: Arc<Pool>, permit: OwnedSemaphorePermit) -> Self {
+        Self {
+            transport: Some(transport),
+            pool,
+            _permit: permit,
+        }
+    }
+}
+
+impl Deref for PooledConnection {
+    type Target = TcpTransport;
+
+    fn deref(&self) -> &Self::Target {
+        self.transport.as_ref().expect("connection used after release")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(transport) = self.transport.take() {
+            self.pool.release(transport);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directive_settings_override_the_defaults() {
+        let settings = PoolSettings {
+            max_connections: Some(20),
+            statement_cache_capacity: Some(100),
+            ..Default::default()
+        };
+
+        let config = PoolConfig::default().with_settings(&settings);
+
+        assert_eq!(config.max_connections, 20);
+        assert_eq!(config.min_connections, 0);
+        assert_eq!(config.idle_timeout, Some(Duration::from_secs(600)));
+        assert_eq!(config.statement_cache_capacity, 100);
+    }
+
+    #[test]
+    fn zero_idle_timeout_keeps_connections_open() {
+        let config = PoolConfig::default().idle_timeout(Some(Duration::ZERO));
+
+        assert_eq!(config.idle_timeout, None);
+    }
+}
@@ -1,6 +1,8 @@
 mod conversion;
+mod statement_cache;
 
 use self::conversion::json_to_string;
+use self::statement_cache::StatementCache;
 
 use super::Transport;
 use crate::error::Error;
@@ -8,15 +10,25 @@ use async_trait::async_trait;
 use futures::stream::BoxStream;
 use futures::{pin_mut, StreamExt};
 use serde_json::Value;
+use tokio_postgres::RowStream;
 
 pub struct TcpTransport {
     client: tokio_postgres::Client,
     connection_string: String,
+    statement_cache: Option<StatementCache>,
 }
 
 impl TcpTransport {
     #[cfg(not(target_arch = "wasm32"))]
     pub async fn new(connection_string: &str) -> crate::Result<Self> {
+        Self::connect(connection_string, Self::tls_connector()).await
+    }
+
+    /// Builds a TLS connector trusting the platform certificates. Loading the certificates is
+    /// expensive, so callers opening many connections should build the connector once and pass
+    /// it to [`TcpTransport::connect`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tls_connector() -> tokio_postgres_rustls::MakeRustlsConnect {
         let mut roots = rustls::RootCertStore::empty();
 
         for cert in rustls_native_certs::load_native_certs().expect("could not load platform certs") {
@@ -30,8 +42,14 @@ impl TcpTransport {
             .with_root_certificates(roots)
             .with_no_client_auth();
 
-        let tls = tokio_postgres_rustls::MakeRustlsConnect::new(config);
+        tokio_postgres_rustls::MakeRustlsConnect::new(config)
+    }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn connect(
+        connection_string: &str,
+        tls: tokio_postgres_rustls::MakeRustlsConnect,
+    ) -> crate::Result<Self> {
         let (client, connection) = tokio_postgres::connect(connection_string, tls)
             .await
             .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
@@ -45,6 +63,7 @@ impl TcpTransport {
         let this = Self {
             client,
             connection_string: connection_string.to_string(),
+            statement_cache: None,
         };
 
         Ok(this)
@@ -97,10 +116,51 @@ impl TcpTransport {
         let this = Self {
             client,
             connection_string: connection_string.to_string(),
+            statement_cache: None,
         };
 
         Ok(this)
     }
+
+    /// Keeps up to `capacity` prepared statements per connection, so repeated queries skip the
+    /// parse and plan step. Must not be enabled when connecting through a pooler running in
+    /// transaction mode, such as pgbouncer, because the statements are bound to the backend.
+    pub fn with_statement_cache(mut self, capacity: usize) -> Self {
+        self.statement_cache = (capacity > 0).then(|| StatementCache::new(capacity));
+        self
+    }
+
+    /// True if the underlying connection has been closed and cannot be used anymore.
+    pub fn is_closed(&self) -> bool {
+        self.client.is_closed()
+    }
+
+    /// Runs a trivial query to check the connection is still usable.
+    pub async fn ping(&self) -> crate::Result<()> {
+        self.client.simple_query("SELECT 1").await?;
+
+        Ok(())
+    }
+
+    async fn query_raw(&self, query: &str, params: Vec<Value>) -> crate::Result<RowStream> {
+        let params = json_to_string(params);
+
+        let Some(ref cache) = self.statement_cache else {
+            return Ok(self.client.query_raw_txt(query, params).await?);
+        };
+
+        let statement = match cache.get(query) {
+            Some(statement) => statement,
+            None => {
+                let statement = self.client.prepare(query).await?;
+                cache.insert(query, statement.clone());
+
+                statement
+            }
+        };
+
+        Ok(self.client.query_raw_txt(&statement, params).await?)
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -108,8 +168,7 @@ impl TcpTransport {
 impl Transport for TcpTransport {
     fn parameterized_query<'a>(&'a self, query: &'a str, params: Vec<Value>) -> BoxStream<'a, Result<Value, Error>> {
         Box::pin(async_stream::try_stream! {
-            let params = json_to_string(params);
-            let row_stream = self.client.query_raw_txt(query, params).await?;
+            let row_stream = self.query_raw(query, params).await?;
 
             pin_mut!(row_stream);
 
@@ -120,8 +179,7 @@ impl Transport for TcpTransport {
     }
 
     async fn parameterized_execute(&self, query: &str, params: Vec<Value>) -> crate::Result<i64> {
-        let params = json_to_string(params);
-        let row_stream = self.client.query_raw_txt(query, params).await?;
+        let row_stream = self.query_raw(query, params).await?;
 
         pin_mut!(row_stream);
 
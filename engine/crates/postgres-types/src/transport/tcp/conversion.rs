@@ -72,6 +72,18 @@ fn pg_text_to_json(pg_value: Option<&str>, pg_type: &Type) -> Value {
             }
         }
         Type::JSON | Type::JSONB => serde_json::from_str(val).expect("the database says this is json"),
+        // Ranges, intervals and text search documents are returned in their text format, which
+        // is also what the database accepts as input.
+        Type::INT4_RANGE
+        | Type::INT8_RANGE
+        | Type::NUM_RANGE
+        | Type::TS_RANGE
+        | Type::TSTZ_RANGE
+        | Type::DATE_RANGE
+        | Type::INTERVAL
+        | Type::TS_VECTOR => Value::String(val.to_string()),
+        // pgvector's text format, e.g. `[1,2,3]`, is a valid JSON array.
+        _ if pg_type.name() == "vector" => serde_json::from_str(val).unwrap_or_else(|_| Value::String(val.to_string())),
         _ => Value::String(val.to_string()),
     }
 }
@@ -0,0 +1,82 @@
+use std::sync::Mutex;
+
+use indexmap::IndexMap;
+use tokio_postgres::Statement;
+
+/// A bounded cache of prepared statements, keyed by the SQL string. When full, the least
+/// recently used statement is evicted.
+///
+/// Generic over the statement so it can be tested without a connection to prepare them.
+pub(super) struct StatementCache<S = Statement> {
+    capacity: usize,
+    statements: Mutex<IndexMap<String, S>>,
+}
+
+impl<S: Clone> StatementCache<S> {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            statements: Mutex::new(IndexMap::with_capacity(capacity)),
+        }
+    }
+
+    pub(super) fn get(&self, query: &str) -> Option<S> {
+        let mut statements = self.statements.lock().expect("statement cache lock poisoned");
+
+        // re-inserting moves the statement to the back, marking it the most recently used one.
+        let (query, statement) = statements.shift_remove_entry(query)?;
+        statements.insert(query, statement.clone());
+
+        Some(statement)
+    }
+
+    pub(super) fn insert(&self, query: &str, statement: S) {
+        let mut statements = self.statements.lock().expect("statement cache lock poisoned");
+
+        if statements.len() >= self.capacity && !statements.contains_key(query) {
+            statements.shift_remove_index(0);
+        }
+
+        statements.insert(query.to_string(), statement);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatementCache;
+
+    #[test]
+    fn cache_hit() {
+        let cache = StatementCache::new(2);
+        assert_eq!(cache.get("SELECT 1"), None);
+
+        cache.insert("SELECT 1", 1);
+        assert_eq!(cache.get("SELECT 1"), Some(1));
+    }
+
+    #[test]
+    fn least_recently_used_statement_is_evicted() {
+        let cache = StatementCache::new(2);
+        cache.insert("SELECT 1", 1);
+        cache.insert("SELECT 2", 2);
+
+        // makes the first statement the most recently used one.
+        cache.get("SELECT 1");
+        cache.insert("SELECT 3", 3);
+
+        assert_eq!(cache.get("SELECT 1"), Some(1));
+        assert_eq!(cache.get("SELECT 2"), None);
+        assert_eq!(cache.get("SELECT 3"), Some(3));
+    }
+
+    #[test]
+    fn replacing_a_statement_evicts_nothing() {
+        let cache = StatementCache::new(2);
+        cache.insert("SELECT 1", 1);
+        cache.insert("SELECT 2", 2);
+        cache.insert("SELECT 2", 22);
+
+        assert_eq!(cache.get("SELECT 1"), Some(1));
+        assert_eq!(cache.get("SELECT 2"), Some(22));
+    }
+}
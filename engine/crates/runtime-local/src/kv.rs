@@ -0,0 +1,179 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use runtime::kv::{Kv, KvError, KvGet, KvPut, KvResult, KvStore};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Expired entries are evicted once the store holds this many, and then whenever it has doubled
+/// since the last eviction.
+const MIN_EVICTION_THRESHOLD: usize = 1024;
+
+/// A key-value store that lives as long as the process, used where the deployed
+/// environment would use a persistent store. Cloning it is cheap, and all the clones share the
+/// same entries.
+#[derive(Clone, Default)]
+pub struct InMemoryKv {
+    entries: Arc<Mutex<Entries>>,
+}
+
+#[derive(Default)]
+struct Entries {
+    map: HashMap<String, Entry>,
+    eviction_threshold: usize,
+}
+
+struct Entry {
+    bytes: Vec<u8>,
+    expires_at: Option<Instant>,
+}
+
+impl Entry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+impl Entries {
+    fn insert(&mut self, name: String, entry: Entry) {
+        // expired entries are otherwise only dropped when read, so keys that are never read
+        // again, such as the rate limit counters of a client gone away, would be kept forever.
+        if self.map.len() >= self.eviction_threshold.max(MIN_EVICTION_THRESHOLD) {
+            let now = Instant::now();
+            self.map.retain(|_, entry| !entry.is_expired(now));
+            self.eviction_threshold = self.map.len() * 2;
+        }
+
+        self.map.insert(name, entry);
+    }
+}
+
+impl InMemoryKv {
+    pub fn runtime_kv() -> Kv {
+        Kv::new(Self::default())
+    }
+
+    fn lock(&self) -> KvResult<std::sync::MutexGuard<'_, Entries>> {
+        self.entries.lock().map_err(|error| KvError::Kv(error.to_string()))
+    }
+}
+
+impl KvStore for InMemoryKv {
+    type Error = KvError;
+    type Get = InMemoryKvGet;
+    type Put = InMemoryKvPut;
+
+    fn get(&self, name: &str) -> Self::Get {
+        InMemoryKvGet {
+            kv: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    fn put<T: Serialize>(&self, name: &str, value: T) -> KvResult<Self::Put> {
+        let bytes = serde_json::to_vec(&value).map_err(|error| KvError::Serialization(error.to_string()))?;
+
+        Ok(InMemoryKvPut {
+            kv: self.clone(),
+            name: name.to_string(),
+            bytes,
+            expiration_ttl: None,
+        })
+    }
+}
+
+pub struct InMemoryKvGet {
+    kv: InMemoryKv,
+    name: String,
+}
+
+#[async_trait::async_trait]
+impl KvGet for InMemoryKvGet {
+    type Error = KvError;
+
+    // entries are always up to date, there's nothing to cache.
+    fn cache_ttl(self, _cache_ttl: Duration) -> Self {
+        self
+    }
+
+    async fn json<T: DeserializeOwned>(self) -> KvResult<Option<T>> {
+        let mut entries = self.kv.lock()?;
+
+        let bytes = match entries.map.get(&self.name) {
+            Some(entry) if entry.is_expired(Instant::now()) => {
+                entries.map.remove(&self.name);
+                return Ok(None);
+            }
+            Some(entry) => &entry.bytes,
+            None => return Ok(None),
+        };
+
+        serde_json::from_slice(bytes)
+            .map(Some)
+            .map_err(|error| KvError::Serialization(error.to_string()))
+    }
+}
+
+pub struct InMemoryKvPut {
+    kv: InMemoryKv,
+    name: String,
+    bytes: Vec<u8>,
+    expiration_ttl: Option<Duration>,
+}
+
+#[async_trait::async_trait]
+impl KvPut for InMemoryKvPut {
+    type Error = KvError;
+
+    fn expiration_ttl(self, expiration_ttl: Duration) -> Self {
+        Self {
+            expiration_ttl: Some(expiration_ttl),
+            ..self
+        }
+    }
+
+    async fn execute(self) -> KvResult<()> {
+        let entry = Entry {
+            bytes: self.bytes,
+            expires_at: self.expiration_ttl.map(|ttl| Instant::now() + ttl),
+        };
+
+        self.kv.lock()?.insert(self.name, entry);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_expiration() {
+        let kv = InMemoryKv::runtime_kv();
+
+        kv.put_json("forever", &1, None).await.unwrap();
+        kv.put_json("expired", &2, Some(Duration::ZERO)).await.unwrap();
+
+        assert_eq!(kv.get_json::<u32>("forever").await.unwrap(), Some(1));
+        assert_eq!(kv.get_json::<u32>("expired").await.unwrap(), None);
+        assert_eq!(kv.get_json::<u32>("missing").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_eviction_of_unread_entries() {
+        let kv = InMemoryKv::default();
+
+        for i in 0..MIN_EVICTION_THRESHOLD {
+            let put = kv.put(&format!("expired:{i}"), i).unwrap();
+            put.expiration_ttl(Duration::ZERO).execute().await.unwrap();
+        }
+        kv.put("forever", 1).unwrap().execute().await.unwrap();
+
+        let entries = kv.entries.lock().unwrap();
+        assert_eq!(entries.map.len(), 1);
+        assert!(entries.map.contains_key("forever"));
+    }
+}
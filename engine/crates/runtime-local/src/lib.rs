@@ -1,12 +1,18 @@
 mod bridge;
 mod cache;
+mod kv;
 mod log;
+#[cfg(not(target_arch = "wasm32"))]
+mod mongo;
 mod pg;
 pub mod search;
 mod ufd_invoker;
 
 pub use bridge::Bridge;
 pub use cache::InMemoryCache;
+pub use kv::InMemoryKv;
+#[cfg(not(target_arch = "wasm32"))]
+pub use mongo::LocalMongoTransport;
 pub use pg::LocalPgTransportFactory;
 pub use search::LocalSearchEngine;
 pub use ufd_invoker::UdfInvokerImpl;
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use futures_util::{lock::Mutex, TryStreamExt};
+use mongodb::{
+    bson::{Bson, Document},
+    options::{FindOneOptions, FindOptions},
+    Client, Collection,
+};
+use runtime::mongodb::{MongoTransport, MongoTransportError, MongoTransportInner, MongoTransportResult};
+use serde_json::{json, Value};
+
+/// Executes the `@mongodb` connector requests over the MongoDB wire protocol. Keeps one
+/// client per connection string, each holding its own connection pool.
+#[derive(Default)]
+pub struct LocalMongoTransport {
+    clients: Mutex<HashMap<String, Client>>,
+}
+
+impl LocalMongoTransport {
+    pub fn runtime_transport() -> MongoTransport {
+        MongoTransport::new(Box::<LocalMongoTransport>::default())
+    }
+
+    async fn client(&self, connection_string: &str) -> MongoTransportResult<Client> {
+        let mut clients = self.clients.lock().await;
+
+        if let Some(client) = clients.get(connection_string) {
+            return Ok(client.clone());
+        }
+
+        let client = Client::with_uri_str(connection_string)
+            .await
+            .map_err(|error| MongoTransportError::Connection(error.to_string()))?;
+
+        clients.insert(connection_string.to_string(), client.clone());
+
+        Ok(client)
+    }
+}
+
+/// The body of an Atlas Data API request.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    database: String,
+    collection: String,
+    filter: Option<Value>,
+    projection: Option<Value>,
+    sort: Option<Value>,
+    limit: Option<i64>,
+    document: Option<Value>,
+    documents: Option<Vec<Value>>,
+    update: Option<Value>,
+    pipeline: Option<Vec<Value>>,
+}
+
+#[async_trait::async_trait]
+impl MongoTransportInner for LocalMongoTransport {
+    async fn execute(&self, connection_string: &str, action: &str, request: Value) -> MongoTransportResult<Value> {
+        let request: Request =
+            serde_json::from_value(request).map_err(|error| MongoTransportError::Request(error.to_string()))?;
+
+        let client = self.client(connection_string).await?;
+
+        let collection: Collection<Document> = client.database(&request.database).collection(&request.collection);
+
+        let filter = request.filter.map(to_document).transpose()?;
+
+        let response = match action {
+            "find" => {
+                let options = FindOptions::builder()
+                    .projection(request.projection.map(to_document).transpose()?)
+                    .sort(request.sort.map(to_document).transpose()?)
+                    .limit(request.limit)
+                    .build();
+
+                let documents: Vec<Document> = collection
+                    .find(filter, options)
+                    .await
+                    .map_err(query_error)?
+                    .try_collect()
+                    .await
+                    .map_err(query_error)?;
+
+                json!({ "documents": documents.into_iter().map(document_to_json).collect::<Vec<_>>() })
+            }
+            "findOne" => {
+                let options = FindOneOptions::builder()
+                    .projection(request.projection.map(to_document).transpose()?)
+                    .build();
+
+                let document = collection.find_one(filter, options).await.map_err(query_error)?;
+
+                json!({ "document": document.map(document_to_json) })
+            }
+            "aggregate" => {
+                let pipeline = request
+                    .pipeline
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(to_document)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let documents: Vec<Document> = collection
+                    .aggregate(pipeline, None)
+                    .await
+                    .map_err(query_error)?
+                    .try_collect()
+                    .await
+                    .map_err(query_error)?;
+
+                json!({ "documents": documents.into_iter().map(document_to_json).collect::<Vec<_>>() })
+            }
+            "insertOne" => {
+                let document = to_document(request.document.unwrap_or_default())?;
+                let result = collection.insert_one(document, None).await.map_err(query_error)?;
+
+                json!({ "insertedId": to_json(result.inserted_id) })
+            }
+            "insertMany" => {
+                let documents = request
+                    .documents
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(to_document)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let result = collection.insert_many(documents, None).await.map_err(query_error)?;
+
+                // the driver returns the ids keyed by the index of the inserted document.
+                let mut inserted_ids: Vec<_> = result.inserted_ids.into_iter().collect();
+                inserted_ids.sort_by_key(|(index, _)| *index);
+
+                let inserted_ids: Vec<_> = inserted_ids.into_iter().map(|(_, id)| to_json(id)).collect();
+
+                json!({ "insertedIds": inserted_ids })
+            }
+            "updateOne" | "updateMany" => {
+                let filter = filter.unwrap_or_default();
+                let update = to_document(request.update.unwrap_or_default())?;
+
+                let result = if action == "updateOne" {
+                    collection.update_one(filter, update, None).await
+                } else {
+                    collection.update_many(filter, update, None).await
+                }
+                .map_err(query_error)?;
+
+                json!({ "matchedCount": result.matched_count, "modifiedCount": result.modified_count })
+            }
+            "deleteOne" | "deleteMany" => {
+                let filter = filter.unwrap_or_default();
+
+                let result = if action == "deleteOne" {
+                    collection.delete_one(filter, None).await
+                } else {
+                    collection.delete_many(filter, None).await
+                }
+                .map_err(query_error)?;
+
+                json!({ "deletedCount": result.deleted_count })
+            }
+            action => return Err(MongoTransportError::Request(format!("unsupported action: {action}"))),
+        };
+
+        Ok(response)
+    }
+}
+
+/// Parses an extended JSON object, as sent to the Data API, into a BSON document.
+fn to_document(value: Value) -> MongoTransportResult<Document> {
+    let value = if value.is_null() { json!({}) } else { value };
+
+    match Bson::try_from(value) {
+        Ok(Bson::Document(document)) => Ok(document),
+        Ok(_) => Err(MongoTransportError::Request(String::from("expected an object"))),
+        Err(error) => Err(MongoTransportError::Request(error.to_string())),
+    }
+}
+
+fn document_to_json(document: Document) -> Value {
+    to_json(Bson::Document(document))
+}
+
+/// Converts BSON into the JSON the Data API responds with when asked for
+/// `application/json`, so the results can be handled the same way for both
+/// transports.
+fn to_json(value: Bson) -> Value {
+    match value {
+        Bson::ObjectId(id) => Value::String(id.to_hex()),
+        Bson::DateTime(date_time) => date_time
+            .try_to_rfc3339_string()
+            .map(Value::String)
+            .unwrap_or_else(|_| Value::from(date_time.timestamp_millis())),
+        Bson::Timestamp(timestamp) => json!({ "T": timestamp.time, "I": timestamp.increment }),
+        Bson::Decimal128(decimal) => Value::String(decimal.to_string()),
+        Bson::Document(document) => {
+            Value::Object(document.into_iter().map(|(key, value)| (key, to_json(value))).collect())
+        }
+        Bson::Array(values) => Value::Array(values.into_iter().map(to_json).collect()),
+        value => value.into_relaxed_extjson(),
+    }
+}
+
+fn query_error(error: mongodb::error::Error) -> MongoTransportError {
+    MongoTransportError::Query(error.to_string())
+}
@@ -1,14 +1,23 @@
+use std::collections::HashMap;
+
+use futures_util::lock::Mutex;
 use postgres_types::{
-    database_definition::DatabaseDefinition,
-    transport::{TcpTransport, Transport},
+    database_definition::{DatabaseDefinition, PoolSettings},
+    transport::{PoolConfig, PooledTcpTransport, Transport},
 };
 use runtime::pg::{PgTransportFactory, PgTransportFactoryError, PgTransportFactoryInner, PgTransportFactoryResult};
 
-pub struct LocalPgTransportFactory;
+/// Keeps one connection pool per `@postgres` directive, created on first use with the pool
+/// settings of the directive.
+pub struct LocalPgTransportFactory {
+    pools: Mutex<HashMap<String, (PoolSettings, PooledTcpTransport)>>,
+}
 
 impl LocalPgTransportFactory {
     pub fn runtime_factory() -> PgTransportFactory {
-        PgTransportFactory::new(Box::new(LocalPgTransportFactory))
+        PgTransportFactory::new(Box::new(LocalPgTransportFactory {
+            pools: Mutex::default(),
+        }))
     }
 }
 
@@ -16,13 +25,37 @@ impl LocalPgTransportFactory {
 impl PgTransportFactoryInner for LocalPgTransportFactory {
     async fn try_new(
         &self,
-        _name: &str,
+        name: &str,
         database_definition: &DatabaseDefinition,
     ) -> PgTransportFactoryResult<Box<dyn Transport>> {
-        let tcp_transport = TcpTransport::new(database_definition.connection_string())
+        let settings = database_definition.pool_settings();
+
+        // the connection string and settings change when the schema gets reloaded with a different directive.
+        let is_current = |(pool_settings, pool): &(PoolSettings, PooledTcpTransport)| {
+            pool.connection_string() == database_definition.connection_string() && pool_settings == settings
+        };
+
+        if let Some((_, pool)) = self.pools.lock().await.get(name).filter(|&entry| is_current(entry)) {
+            return Ok(Box::new(pool.clone()));
+        }
+
+        // connecting takes a while, so we don't hold the lock and block the other connectors meanwhile.
+        let config = PoolConfig::default().with_settings(settings);
+        let pool = PooledTcpTransport::new(database_definition.connection_string(), config)
             .await
             .map_err(PgTransportFactoryError::TransportCreation)?;
 
-        Ok(Box::new(tcp_transport))
+        let mut pools = self.pools.lock().await;
+
+        // another request might have created a pool while we were connecting, keep the first one.
+        let pool = match pools.get(name).filter(|&entry| is_current(entry)) {
+            Some((_, pool)) => pool.clone(),
+            None => {
+                pools.insert(name.to_string(), (settings.clone(), pool.clone()));
+                pool
+            }
+        };
+
+        Ok(Box::new(pool))
     }
 }
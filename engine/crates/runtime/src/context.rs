@@ -37,6 +37,11 @@ pub trait RequestContext: Send + Sync {
     async fn wait_until(&self, fut: BoxFuture<'static, ()>);
     fn headers(&self) -> &http::HeaderMap;
 
+    /// The address of the peer that sent the request, if known.
+    fn client_ip(&self) -> Option<std::net::IpAddr> {
+        None
+    }
+
     fn headers_as_map(&self) -> HashMap<String, String> {
         self.headers()
             .iter()
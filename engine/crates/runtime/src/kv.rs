@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -35,3 +35,84 @@ pub trait KvPut {
     fn expiration_ttl(self, expiration_ttl: Duration) -> Self;
     async fn execute(self) -> Result<(), Self::Error>;
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum KvError {
+    #[error("Kv error: {0}")]
+    Kv(String),
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+}
+
+pub type KvResult<T> = std::result::Result<T, KvError>;
+
+/// A [`KvStore`] that can be shared through the engine context, which needs a concrete type
+/// rather than one generic over the environment.
+#[derive(Clone)]
+pub struct Kv {
+    inner: Arc<dyn DynKvStore>,
+}
+
+impl Kv {
+    pub fn new<S>(store: S) -> Kv
+    where
+        S: KvStore + Send + Sync + 'static,
+        S::Get: Send,
+        S::Put: Send,
+    {
+        Kv { inner: Arc::new(store) }
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(&self, name: &str) -> KvResult<Option<T>> {
+        self.inner
+            .get_value(name)
+            .await?
+            .map(|value| serde_json::from_value(value).map_err(|error| KvError::Serialization(error.to_string())))
+            .transpose()
+    }
+
+    pub async fn put_json<T: Serialize>(
+        &self,
+        name: &str,
+        value: &T,
+        expiration_ttl: Option<Duration>,
+    ) -> KvResult<()> {
+        let value = serde_json::to_value(value).map_err(|error| KvError::Serialization(error.to_string()))?;
+
+        self.inner.put_value(name, value, expiration_ttl).await
+    }
+}
+
+/// The object safe part of [`KvStore`], implemented for all of them.
+#[async_trait::async_trait]
+trait DynKvStore: Send + Sync {
+    async fn get_value(&self, name: &str) -> KvResult<Option<serde_json::Value>>;
+    async fn put_value(&self, name: &str, value: serde_json::Value, expiration_ttl: Option<Duration>) -> KvResult<()>;
+}
+
+#[async_trait::async_trait]
+impl<S> DynKvStore for S
+where
+    S: KvStore + Send + Sync,
+    S::Get: Send,
+    S::Put: Send,
+{
+    async fn get_value(&self, name: &str) -> KvResult<Option<serde_json::Value>> {
+        self.get(name)
+            .json()
+            .await
+            .map_err(|error| KvError::Kv(format!("{error:?}")))
+    }
+
+    async fn put_value(&self, name: &str, value: serde_json::Value, expiration_ttl: Option<Duration>) -> KvResult<()> {
+        let mut put = self
+            .put(name, value)
+            .map_err(|error| KvError::Kv(format!("{error:?}")))?;
+
+        if let Some(expiration_ttl) = expiration_ttl {
+            put = put.expiration_ttl(expiration_ttl);
+        }
+
+        put.execute().await.map_err(|error| KvError::Kv(format!("{error:?}")))
+    }
+}
@@ -2,6 +2,7 @@ pub mod cache;
 pub mod context;
 pub mod kv;
 pub mod log;
+pub mod mongodb;
 pub mod pg;
 pub mod search;
 pub mod udf;
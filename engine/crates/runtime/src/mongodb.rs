@@ -0,0 +1,50 @@
+use std::{ops::Deref, sync::Arc};
+
+use serde_json::Value;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MongoTransportError {
+    #[error("Connection error: {0}")]
+    Connection(String),
+    #[error("Malformed request: {0}")]
+    Request(String),
+    #[error("Query error: {0}")]
+    Query(String),
+}
+
+pub type MongoTransportResult<T> = std::result::Result<T, MongoTransportError>;
+
+/// Executes MongoDB requests over the wire protocol. The request body and the response
+/// use the same JSON format as the Atlas Data API, so the connector can generate the
+/// same queries for both transports.
+#[async_trait::async_trait]
+pub trait MongoTransportInner {
+    /// Executes a Data API `action` (e.g. `find` or `insertOne`) against the server
+    /// behind the connection string.
+    async fn execute(&self, connection_string: &str, action: &str, request: Value) -> MongoTransportResult<Value>;
+}
+
+type BoxedMongoTransportImpl = Box<dyn MongoTransportInner + Send + Sync>;
+
+/// Cloning the transport is cheap, and the clones share any state the inner implementation keeps,
+/// such as connection pools.
+#[derive(Clone)]
+pub struct MongoTransport {
+    inner: Arc<dyn MongoTransportInner + Send + Sync>,
+}
+
+impl MongoTransport {
+    pub fn new(transport: BoxedMongoTransportImpl) -> MongoTransport {
+        MongoTransport {
+            inner: Arc::from(transport),
+        }
+    }
+}
+
+impl Deref for MongoTransport {
+    type Target = dyn MongoTransportInner + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
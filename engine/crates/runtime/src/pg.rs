@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::{ops::Deref, sync::Arc};
 
 use postgres_types::{database_definition::DatabaseDefinition, transport::Transport};
 
@@ -21,18 +21,23 @@ pub trait PgTransportFactoryInner {
 
 type BoxedPgTransportFactoryImpl = Box<dyn PgTransportFactoryInner + Send + Sync>;
 
+/// Cloning the factory is cheap, and the clones share any state the inner implementation keeps,
+/// such as connection pools.
+#[derive(Clone)]
 pub struct PgTransportFactory {
-    inner: BoxedPgTransportFactoryImpl,
+    inner: Arc<dyn PgTransportFactoryInner + Send + Sync>,
 }
 
 impl PgTransportFactory {
     pub fn new(factory: BoxedPgTransportFactoryImpl) -> PgTransportFactory {
-        PgTransportFactory { inner: factory }
+        PgTransportFactory {
+            inner: Arc::from(factory),
+        }
     }
 }
 
 impl Deref for PgTransportFactory {
-    type Target = BoxedPgTransportFactoryImpl;
+    type Target = dyn PgTransportFactoryInner + Send + Sync;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
@@ -1,16 +1,49 @@
-use std::{collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    sync::Arc,
+};
 
-use common_types::UdfKind;
+use common_types::{auth::ExecutionAuth, UdfKind};
 use serde::Serialize;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct UdfRequestContextRequest {
     pub headers: serde_json::Value,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct UdfRequestContext {
     pub request: UdfRequestContextRequest,
+    /// The identity the request was authorized with, absent if it hasn't been authorized yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<UdfRequestContextAuth>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum UdfRequestContextAuth {
+    ApiKey,
+    Public,
+    Token {
+        subject: Option<String>,
+        groups: BTreeSet<String>,
+        claims: BTreeMap<String, serde_json::Value>,
+    },
+}
+
+impl From<&ExecutionAuth> for UdfRequestContextAuth {
+    fn from(auth: &ExecutionAuth) -> Self {
+        match auth {
+            ExecutionAuth::ApiKey => Self::ApiKey,
+            ExecutionAuth::Public { .. } => Self::Public,
+            ExecutionAuth::Token(token) => Self::Token {
+                subject: token.subject_and_owner_ops().map(|(subject, _)| subject.clone()),
+                groups: token.groups_from_token().clone(),
+                claims: token.token_claims().clone(),
+            },
+        }
+    }
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -3,6 +3,7 @@ import { CacheParams, GlobalCache } from './cache'
 import { GrafbaseSchema } from './grafbase-schema'
 import { Experimental, ExperimentalParams } from './experimental'
 import { Federation, FederationParams } from './federation'
+import { OperationLimits, OperationLimitsParams } from './operation-limits'
 
 /**
  * An interface to create the complete config definition.
@@ -13,6 +14,7 @@ export interface ConfigInput {
   cache?: CacheParams
   experimental?: ExperimentalParams
   federation?: FederationParams
+  operationLimits?: OperationLimitsParams
 }
 
 /**
@@ -24,6 +26,7 @@ export class Config {
   private readonly cache?: GlobalCache
   private readonly experimental?: Experimental
   private readonly federation?: Federation
+  private readonly operationLimits?: OperationLimits
 
   constructor(input: ConfigInput) {
     this.schema = input.schema
@@ -43,6 +46,10 @@ export class Config {
     if (input.federation) {
       this.federation = new Federation(input.federation)
     }
+
+    if (input.operationLimits) {
+      this.operationLimits = new OperationLimits(input.operationLimits)
+    }
   }
 
   public toString(): string {
@@ -51,7 +58,10 @@ export class Config {
     const cache = this.cache ? this.cache.toString() : ''
     const experimental = this.experimental ? this.experimental.toString() : ''
     const federation = this.federation ? this.federation.toString() : ''
+    const operationLimits = this.operationLimits
+      ? this.operationLimits.toString()
+      : ''
 
-    return `${experimental}${auth}${cache}${federation}${schema}`
+    return `${experimental}${operationLimits}${auth}${cache}${federation}${schema}`
   }
 }
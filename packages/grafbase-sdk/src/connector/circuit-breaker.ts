@@ -0,0 +1,26 @@
+/**
+ * Stops sending requests to a connector for a while after it keeps failing.
+ *
+ * The breaker opens after `failureThreshold` requests in a row have failed, and
+ * lets a single request through to probe the connector after `resetTimeout`
+ * milliseconds, 30 seconds by default.
+ */
+export interface CircuitBreaker {
+  failureThreshold: number
+  resetTimeout?: number
+}
+
+export const renderCircuitBreaker = (
+  circuitBreaker?: CircuitBreaker
+): string => {
+  if (!circuitBreaker) {
+    return ''
+  }
+
+  const resetTimeout =
+    circuitBreaker.resetTimeout !== undefined
+      ? `, resetTimeout: ${circuitBreaker.resetTimeout}`
+      : ''
+
+  return `    circuitBreaker: { failureThreshold: ${circuitBreaker.failureThreshold}${resetTimeout} }\n`
+}
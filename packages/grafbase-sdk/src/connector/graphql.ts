@@ -1,3 +1,4 @@
+import { CircuitBreaker, renderCircuitBreaker } from './circuit-breaker'
 import { Header, Headers, HeaderGenerator } from './header'
 import {
   SchemaTransform,
@@ -9,6 +10,11 @@ export interface GraphQLParams {
   url: string
   headers?: HeaderGenerator
   transforms?: TransformsGenerator
+  /** How long to wait for each attempt at a request to the upstream, in milliseconds */
+  timeout?: number
+  /** How many times to retry queries that fail with a server error, time out or are rate limited */
+  retries?: number
+  circuitBreaker?: CircuitBreaker
 }
 
 export class PartialGraphQLAPI {
@@ -17,6 +23,9 @@ export class PartialGraphQLAPI {
   private headers: Header[]
   private introspectionHeaders: Header[]
   private transforms: SchemaTransform[]
+  private timeout?: number
+  private retries?: number
+  private circuitBreaker?: CircuitBreaker
 
   constructor(name: string, params: GraphQLParams) {
     const headers = new Headers()
@@ -35,6 +44,9 @@ export class PartialGraphQLAPI {
     this.headers = headers.headers
     this.introspectionHeaders = headers.introspectionHeaders
     this.transforms = transforms.transforms
+    this.timeout = params.timeout
+    this.retries = params.retries
+    this.circuitBreaker = params.circuitBreaker
   }
 
   finalize(namespace?: boolean): GraphQLAPI {
@@ -44,7 +56,10 @@ export class PartialGraphQLAPI {
       this.headers,
       this.introspectionHeaders,
       this.transforms,
-      namespace
+      namespace,
+      this.timeout,
+      this.retries,
+      this.circuitBreaker
     )
   }
 }
@@ -56,6 +71,9 @@ export class GraphQLAPI {
   private headers: Header[]
   private introspectionHeaders: Header[]
   private transforms: SchemaTransform[]
+  private timeout?: number
+  private retries?: number
+  private circuitBreaker?: CircuitBreaker
 
   constructor(
     name: string,
@@ -63,7 +81,10 @@ export class GraphQLAPI {
     headers: Header[],
     introspectionHeaders: Header[],
     transforms: SchemaTransform[],
-    namespace?: boolean
+    namespace?: boolean,
+    timeout?: number,
+    retries?: number,
+    circuitBreaker?: CircuitBreaker
   ) {
     this.name = name
     this.namespace = namespace
@@ -71,6 +92,9 @@ export class GraphQLAPI {
     this.headers = headers
     this.introspectionHeaders = introspectionHeaders
     this.transforms = transforms
+    this.timeout = timeout
+    this.retries = retries
+    this.circuitBreaker = circuitBreaker
   }
 
   public toString(): string {
@@ -105,8 +129,14 @@ export class GraphQLAPI {
         ? `    transforms: {\n${transforms}\n    }\n`
         : ''
 
+    const timeout =
+      this.timeout !== undefined ? `    timeout: ${this.timeout}\n` : ''
+    const retries =
+      this.retries !== undefined ? `    retries: ${this.retries}\n` : ''
+    const circuitBreaker = renderCircuitBreaker(this.circuitBreaker)
+
     const footer = '  )'
 
-    return `${header}${name}${namespace}${url}${headers}${introspectionHeaders}${transforms}${footer}`
+    return `${header}${name}${namespace}${url}${headers}${introspectionHeaders}${transforms}${timeout}${retries}${circuitBreaker}${footer}`
   }
 }
@@ -1,26 +1,29 @@
 import { ModelFields, MongoDBModel } from './mongodb/model'
 
-export interface MongoDBParams {
+/** Connects to MongoDB using the Atlas Data API. */
+export interface MongoDBDataApiParams {
   url: string
   apiKey: string
   dataSource: string
   database: string
 }
 
+/** Connects to MongoDB using the MongoDB wire protocol. */
+export interface MongoDBConnectionStringParams {
+  connectionString: string
+  database: string
+}
+
+export type MongoDBParams = MongoDBDataApiParams | MongoDBConnectionStringParams
+
 export class PartialMongoDBAPI {
   private name: string
-  private url: string
-  private apiKey: string
-  private dataSource: string
-  private database: string
+  private params: MongoDBParams
   private models: MongoDBModel[]
 
   constructor(name: string, params: MongoDBParams) {
     this.name = name
-    this.url = params.url
-    this.apiKey = params.apiKey
-    this.dataSource = params.dataSource
-    this.database = params.database
+    this.params = params
     this.models = []
   }
 
@@ -42,41 +45,24 @@ export class PartialMongoDBAPI {
   }
 
   finalize(namespace?: boolean): MongoDBAPI {
-    return new MongoDBAPI(
-      this.name,
-      this.apiKey,
-      this.url,
-      this.dataSource,
-      this.database,
-      this.models,
-      namespace
-    )
+    return new MongoDBAPI(this.name, this.params, this.models, namespace)
   }
 }
 
 export class MongoDBAPI {
   private name: string
-  private apiKey: string
-  private url: string
-  private dataSource: string
-  private database: string
+  private params: MongoDBParams
   private namespace?: boolean
   public models: MongoDBModel[]
 
   constructor(
     name: string,
-    apiKey: string,
-    url: string,
-    dataSource: string,
-    database: string,
+    params: MongoDBParams,
     models: MongoDBModel[],
     namespace?: boolean
   ) {
     this.name = name
-    this.apiKey = apiKey
-    this.url = url
-    this.dataSource = dataSource
-    this.database = database
+    this.params = params
     this.namespace = namespace
     this.models = models
   }
@@ -84,10 +70,19 @@ export class MongoDBAPI {
   public toString(): string {
     const header = '  @mongodb(\n'
     const name = `    name: "${this.name}"\n`
-    const url = `    url: "${this.url}"\n`
-    const apiKey = `    apiKey: "${this.apiKey}"\n`
-    const dataSource = `    dataSource: "${this.dataSource}"\n`
-    const database = `    database: "${this.database}"\n`
+
+    let connection
+    if ('connectionString' in this.params) {
+      connection = `    connectionString: "${this.params.connectionString}"\n`
+    } else {
+      const url = `    url: "${this.params.url}"\n`
+      const apiKey = `    apiKey: "${this.params.apiKey}"\n`
+      const dataSource = `    dataSource: "${this.params.dataSource}"\n`
+
+      connection = `${url}${apiKey}${dataSource}`
+    }
+
+    const database = `    database: "${this.params.database}"\n`
 
     let namespace
     if (this.namespace === undefined || this.namespace === true) {
@@ -98,6 +93,6 @@ export class MongoDBAPI {
 
     const footer = '  )'
 
-    return `${header}${namespace}${name}${url}${apiKey}${dataSource}${database}${footer}`
+    return `${header}${namespace}${name}${connection}${database}${footer}`
   }
 }
@@ -1,3 +1,4 @@
+import { CircuitBreaker, renderCircuitBreaker } from './circuit-breaker'
 import { Header, Headers, HeaderGenerator } from './header'
 import {
   OpenApiQueryNamingStrategy,
@@ -13,11 +14,37 @@ export interface OpenApiTransformParams {
   queryNaming: OpenApiQueryNamingStrategy
 }
 
+/**
+ * Credentials for one of the `securitySchemes` of the OpenAPI spec.
+ *
+ * `value` holds an API key, a token or `username:password` for basic auth,
+ * `forward` takes the credential from a header of the incoming request, and
+ * `clientId` with `clientSecret` fetch tokens with the OAuth2 client credentials flow.
+ */
+export type OpenApiSecurity =
+  | { scheme: string; value: string }
+  | { scheme: string; forward: string }
+  | { scheme: string; clientId: string; clientSecret: string }
+
 export interface OpenAPIParams {
   schema: string
   url?: string
   transforms?: OpenApiTransformParams | OpenApiTransformsGenerator
   headers?: HeaderGenerator
+  security?: OpenApiSecurity[]
+  /** How long to wait for each attempt at a request to the API, in milliseconds */
+  timeout?: number
+  /** How many times to retry requests that fail with a server error, time out or are rate limited */
+  retries?: number
+  circuitBreaker?: CircuitBreaker
+}
+
+const renderSecurity = (security: OpenApiSecurity): string => {
+  const fields = Object.entries(security)
+    .map(([key, value]) => `${key}: "${value}"`)
+    .join(', ')
+
+  return `{ ${fields} }`
 }
 
 export class PartialOpenAPI {
@@ -27,6 +54,10 @@ export class PartialOpenAPI {
   private transforms: OpenApiTransform[]
   private headers: Header[]
   private introspectionHeaders: Header[]
+  private security: OpenApiSecurity[]
+  private timeout?: number
+  private retries?: number
+  private circuitBreaker?: CircuitBreaker
 
   constructor(name: string, params: OpenAPIParams) {
     const headers = new Headers()
@@ -48,6 +79,10 @@ export class PartialOpenAPI {
     this.transforms = transforms.transforms
     this.headers = headers.headers
     this.introspectionHeaders = headers.introspectionHeaders
+    this.security = params.security ?? []
+    this.timeout = params.timeout
+    this.retries = params.retries
+    this.circuitBreaker = params.circuitBreaker
   }
 
   finalize(namespace?: boolean): OpenAPI {
@@ -56,9 +91,13 @@ export class PartialOpenAPI {
       this.schema,
       this.headers,
       this.introspectionHeaders,
+      this.security,
       this.transforms,
       this.apiUrl,
-      namespace
+      namespace,
+      this.timeout,
+      this.retries,
+      this.circuitBreaker
     )
   }
 }
@@ -71,15 +110,23 @@ export class OpenAPI {
   private transforms: OpenApiTransform[]
   private headers: Header[]
   private introspectionHeaders: Header[]
+  private security: OpenApiSecurity[]
+  private timeout?: number
+  private retries?: number
+  private circuitBreaker?: CircuitBreaker
 
   constructor(
     name: string,
     schema: string,
     headers: Header[],
     introspectionHeaders: Header[],
+    security: OpenApiSecurity[],
     transforms: OpenApiTransform[],
     url?: string,
-    namespace?: boolean
+    namespace?: boolean,
+    timeout?: number,
+    retries?: number,
+    circuitBreaker?: CircuitBreaker
   ) {
     this.name = name
     this.namespace = namespace
@@ -88,6 +135,10 @@ export class OpenAPI {
     this.transforms = transforms
     this.headers = headers
     this.introspectionHeaders = introspectionHeaders
+    this.security = security
+    this.timeout = timeout
+    this.retries = retries
+    this.circuitBreaker = circuitBreaker
   }
 
   public toString(): string {
@@ -123,8 +174,20 @@ export class OpenAPI {
       ? `    introspectionHeaders: [\n${introspectionHeaders}\n    ]\n`
       : ''
 
+    let security = this.security
+      .map((security) => `      ${renderSecurity(security)}`)
+      .join('\n')
+
+    security = security ? `    security: [\n${security}\n    ]\n` : ''
+
+    const timeout =
+      this.timeout !== undefined ? `    timeout: ${this.timeout}\n` : ''
+    const retries =
+      this.retries !== undefined ? `    retries: ${this.retries}\n` : ''
+    const circuitBreaker = renderCircuitBreaker(this.circuitBreaker)
+
     const footer = '  )'
 
-    return `${header}${name}${namespace}${url}${schema}${transforms}${headers}${introspectionHeaders}${footer}`
+    return `${header}${name}${namespace}${url}${schema}${transforms}${headers}${introspectionHeaders}${security}${timeout}${retries}${circuitBreaker}${footer}`
   }
 }
@@ -1,20 +1,50 @@
 import { SchemaTransforms, SchemaTransform } from '../transforms'
 
 export type OpenApiTransformsGenerator = (schema: OpenApiTransforms) => void
-export type OpenApiTransform = SchemaTransform | QueryNamingTransform
+export type OpenApiTransform =
+  | SchemaTransform
+  | QueryNamingTransform
+  | PaginationTransform
 
 export type OpenApiQueryNamingStrategy = 'OPERATION_ID' | 'SCHEMA_NAME'
 
+export type OpenApiPaginationStyle =
+  | 'OFFSET_LIMIT'
+  | 'PAGE_NUMBER'
+  | 'CURSOR'
+  | 'LINK_HEADER'
+
+/**
+ * How a list query of the API is paginated
+ */
+export interface OpenApiPagination {
+  /** The operationId of the query, every list query if not set */
+  operationId?: string
+  style: OpenApiPaginationStyle
+  /** The query parameter for the offset, page number or cursor */
+  parameter?: string
+  /** The query parameter for the page size */
+  limitParameter?: string
+  /** The largest page size the API accepts */
+  maxPageSize?: number
+  /** The field of the response with the next cursor, dot separated if nested */
+  nextCursorField?: string
+  /** The field of the response with the items, if the response is an object */
+  itemsField?: string
+}
+
 /**
  * An accumulator class to gather transforms for an OpenAPI connector
  */
 export class OpenApiTransforms {
   private _schemaTransforms: SchemaTransforms
   private _queryNaming: OpenApiQueryNamingStrategy | null
+  private _pagination: OpenApiPagination[]
 
   constructor() {
     this._schemaTransforms = new SchemaTransforms()
     this._queryNaming = null
+    this._pagination = []
   }
 
   public get transforms(): OpenApiTransform[] {
@@ -22,6 +52,9 @@ export class OpenApiTransforms {
     if (this._queryNaming != null) {
       transforms.push(new QueryNamingTransform(this._queryNaming))
     }
+    if (this._pagination.length != 0) {
+      transforms.push(new PaginationTransform(this._pagination))
+    }
     return transforms
   }
 
@@ -32,6 +65,16 @@ export class OpenApiTransforms {
     this._queryNaming = strategy
   }
 
+  /**
+   * Exposes list queries as connections with `first` and `after` arguments,
+   * following the pages of the API as needed
+   *
+   * @param pagination - How the API paginates, for one query if `operationId` is set or every list query if not
+   */
+  public pagination(...pagination: OpenApiPagination[]) {
+    this._pagination.push(...pagination)
+  }
+
   /**
    * Excludes one or more fields from the connectors schema
    *
@@ -69,3 +112,32 @@ export class QueryNamingTransform {
     return `queryNaming: ${this.value}`
   }
 }
+
+/**
+ * The pagination of the list queries of a connector
+ */
+export class PaginationTransform {
+  private values: OpenApiPagination[]
+
+  constructor(values: OpenApiPagination[]) {
+    this.values = values
+  }
+
+  public toString(): string {
+    const values = this.values
+      .map((pagination) => {
+        const fields = Object.entries(pagination)
+          .map(([key, value]) =>
+            typeof value === 'string' && key !== 'style'
+              ? `${key}: "${value}"`
+              : `${key}: ${value}`
+          )
+          .join(', ')
+
+        return `        { ${fields} }`
+      })
+      .join('\n')
+
+    return `pagination: [\n${values}\n      ]`
+  }
+}
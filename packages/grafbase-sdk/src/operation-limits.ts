@@ -0,0 +1,28 @@
+/**
+ * Defines the limits on the operations clients can send.
+ */
+export interface OperationLimitsParams {
+  depth?: number
+  complexity?: number
+  aliases?: number
+  rootFields?: number
+  tokens?: number
+  defaultListSize?: number
+}
+
+export class OperationLimits {
+  private params: OperationLimitsParams
+
+  constructor(params: OperationLimitsParams) {
+    this.params = params
+  }
+
+  public toString(): string {
+    const params = Object.entries(this.params)
+      .filter(([, value]) => value !== undefined)
+      .map(([key, value]) => `${key}: ${value}`)
+      .join(', ')
+
+    return params ? `extend schema\n  @operationLimits(${params})\n\n` : ''
+  }
+}
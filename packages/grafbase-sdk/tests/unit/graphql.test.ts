@@ -39,6 +39,29 @@ describe('GraphQL connector', () => {
     `)
   })
 
+  it('generates the timeout, retries and circuit breaker', () => {
+    const contentful = connector.GraphQL('Contentful', {
+      url: 'https://graphql.contentful.com/content/v1/spaces/{{ env.CONTENTFUL_SPACE_ID }}/environments/{{ env.CONTENTFUL_ENVIRONMENT }}',
+      timeout: 5000,
+      retries: 2,
+      circuitBreaker: { failureThreshold: 5, resetTimeout: 10000 }
+    })
+
+    g.datasource(contentful)
+
+    expect(renderGraphQL(config({ schema: g }))).toMatchInlineSnapshot(`
+      "extend schema
+        @graphql(
+          name: "Contentful"
+          namespace: true
+          url: "https://graphql.contentful.com/content/v1/spaces/{{ env.CONTENTFUL_SPACE_ID }}/environments/{{ env.CONTENTFUL_ENVIRONMENT }}"
+          timeout: 5000
+          retries: 2
+          circuitBreaker: { failureThreshold: 5, resetTimeout: 10000 }
+        )"
+    `)
+  })
+
   it('generates the maximum possible GraphQL datasource', () => {
     const contentful = connector.GraphQL('Contentful', {
       url: 'https://graphql.contentful.com/content/v1/spaces/{{ env.CONTENTFUL_SPACE_ID }}/environments/{{ env.CONTENTFUL_ENVIRONMENT }}',
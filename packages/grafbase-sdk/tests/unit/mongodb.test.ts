@@ -46,6 +46,25 @@ describe('MongoDB generator', () => {
     `)
   })
 
+  it('generates a MongoDB datasource with a connection string', () => {
+    const mongo = connector.MongoDB('Test', {
+      connectionString: 'mongodb://localhost:27017',
+      database: 'tables'
+    })
+
+    g.datasource(mongo)
+
+    expect(renderGraphQL(config({ schema: g }))).toMatchInlineSnapshot(`
+      "extend schema
+        @mongodb(
+          namespace: true
+          name: "Test"
+          connectionString: "mongodb://localhost:27017"
+          database: "tables"
+        )"
+    `)
+  })
+
   it('generates a simple model', () => {
     const mongo = connector.MongoDB('Test', mongoParams)
 
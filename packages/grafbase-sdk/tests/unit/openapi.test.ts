@@ -94,6 +94,69 @@ describe('OpenAPI generator', () => {
 `)
   })
 
+  it('generates security scheme credentials', () => {
+    const petstore = connector.OpenAPI('Petstore', {
+      schema: 'https://petstore3.swagger.io/api/v3/openapi.json',
+      security: [
+        { scheme: 'api_key', forward: 'x-api-key' },
+        {
+          scheme: 'petstore_auth',
+          clientId: 'petstore',
+          clientSecret: '{{ env.PETSTORE_CLIENT_SECRET }}'
+        }
+      ]
+    })
+
+    g.datasource(petstore)
+
+    expect(renderGraphQL(config({ schema: g }))).toMatchInlineSnapshot(`
+      "extend schema
+        @openapi(
+          name: "Petstore"
+          namespace: true
+          schema: "https://petstore3.swagger.io/api/v3/openapi.json"
+          security: [
+            { scheme: "api_key", forward: "x-api-key" }
+            { scheme: "petstore_auth", clientId: "petstore", clientSecret: "{{ env.PETSTORE_CLIENT_SECRET }}" }
+          ]
+        )"
+    `)
+  })
+
+  it('generates pagination transforms', () => {
+    const petstore = connector.OpenAPI('Petstore', {
+      schema: 'https://petstore3.swagger.io/api/v3/openapi.json',
+      transforms: (schema) => {
+        schema.pagination(
+          { style: 'OFFSET_LIMIT', maxPageSize: 100 },
+          {
+            operationId: 'findPetsByStatus',
+            style: 'CURSOR',
+            nextCursorField: 'meta.next',
+            itemsField: 'data'
+          }
+        )
+      }
+    })
+
+    g.datasource(petstore)
+
+    expect(renderGraphQL(config({ schema: g }))).toMatchInlineSnapshot(`
+      "extend schema
+        @openapi(
+          name: "Petstore"
+          namespace: true
+          schema: "https://petstore3.swagger.io/api/v3/openapi.json"
+          transforms: {
+            pagination: [
+              { style: OFFSET_LIMIT, maxPageSize: 100 }
+              { operationId: "findPetsByStatus", style: CURSOR, nextCursorField: "meta.next", itemsField: "data" }
+            ]
+          }
+        )"
+    `)
+  })
+
   it('combines multiple apis into one extension', () => {
     const stripe = connector.OpenAPI('Stripe', {
       schema:
@@ -0,0 +1,73 @@
+import { config, g } from '../../src/index'
+import { describe, expect, it, beforeEach } from '@jest/globals'
+import { renderGraphQL } from '../utils'
+
+describe('Operation limits generator', () => {
+  beforeEach(() => g.clear())
+
+  it('renders all the operation limits', async () => {
+    const cfg = config({
+      schema: g,
+      operationLimits: {
+        depth: 10,
+        complexity: 1000,
+        aliases: 20,
+        rootFields: 5,
+        tokens: 2000,
+        defaultListSize: 10
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @operationLimits(depth: 10, complexity: 1000, aliases: 20, rootFields: 5, tokens: 2000, defaultListSize: 10)
+      
+      "
+    `)
+  })
+
+  it('renders some of the operation limits', async () => {
+    const cfg = config({
+      schema: g,
+      operationLimits: {
+        depth: 3,
+        rootFields: undefined
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @operationLimits(depth: 3)
+      
+      "
+    `)
+  })
+
+  it('doesnt render empty operation limits', async () => {
+    const cache = {
+      rules: [
+        {
+          types: 'Query',
+          maxAge: 60
+        }
+      ]
+    }
+
+    const cfg = config({ schema: g, cache })
+    const cfg2 = config({ schema: g, cache, operationLimits: {} })
+
+    const expected = `
+      "extend schema
+        @cache(rules: [
+          {
+            types: "Query",
+            maxAge: 60
+          }
+        ])
+      
+      "
+    `
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(expected)
+    expect(renderGraphQL(cfg2)).toMatchInlineSnapshot(expected)
+  })
+})